@@ -1,6 +1,9 @@
+use futures::Stream;
+
 use crate::{
     config::Config,
     error::OpenAIError,
+    pagination::{impl_list_page, paginate},
     types::assistants::{
         AssistantObject, CreateAssistantRequest, DeleteAssistantResponse, ListAssistantsResponse,
         ModifyAssistantRequest,
@@ -8,6 +11,8 @@ use crate::{
     Client, RequestOptions,
 };
 
+impl_list_page!(ListAssistantsResponse => AssistantObject);
+
 /// Build assistants that can call models and use tools to perform tasks.
 ///
 /// [Get started with the Assistants API](https://platform.openai.com/docs/assistants)
@@ -81,4 +86,13 @@ impl<'c, C: Config> Assistants<'c, C> {
     pub async fn list(&self) -> Result<ListAssistantsResponse, OpenAIError> {
         self.client.get("/assistants", &self.request_options).await
     }
+
+    /// All the assistants, following the `after` cursor of [list](Self::list) page by page.
+    pub fn list_stream(&self) -> impl Stream<Item = Result<AssistantObject, OpenAIError>> + 'c {
+        paginate::<_, ListAssistantsResponse>(
+            self.client,
+            "/assistants".to_string(),
+            self.request_options.clone(),
+        )
+    }
 }
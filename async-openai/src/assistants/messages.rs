@@ -1,6 +1,9 @@
+use futures::Stream;
+
 use crate::{
     config::Config,
     error::OpenAIError,
+    pagination::{impl_list_page, paginate},
     types::assistants::{
         CreateMessageRequest, DeleteMessageResponse, ListMessagesResponse, MessageObject,
         ModifyMessageRequest,
@@ -8,6 +11,8 @@ use crate::{
     Client, RequestOptions,
 };
 
+impl_list_page!(ListMessagesResponse => MessageObject);
+
 /// Represents a message within a [thread](https://platform.openai.com/docs/api-reference/threads).
 #[deprecated(
     note = "Assistants API is deprecated and will be removed in August 2026. Use the Responses API."
@@ -81,6 +86,15 @@ impl<'c, C: Config> Messages<'c, C> {
             .await
     }
 
+    /// All the messages of the thread, following the `after` cursor of [list](Self::list) page by page.
+    pub fn list_stream(&self) -> impl Stream<Item = Result<MessageObject, OpenAIError>> + 'c {
+        paginate::<_, ListMessagesResponse>(
+            self.client,
+            format!("/threads/{}/messages", self.thread_id),
+            self.request_options.clone(),
+        )
+    }
+
     #[crate::byot(T0 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn delete(&self, message_id: &str) -> Result<DeleteMessageResponse, OpenAIError> {
         self.client
@@ -1,7 +1,10 @@
+use futures::Stream;
+
 use crate::{
     assistants::Steps,
     config::Config,
     error::OpenAIError,
+    pagination::{impl_list_page, paginate},
     types::assistants::{
         CreateRunRequest, ListRunsResponse, ModifyRunRequest, RunObject,
         SubmitToolOutputsRunRequest,
@@ -10,7 +13,13 @@ use crate::{
 };
 
 #[cfg(not(target_family = "wasm"))]
-use crate::types::assistants::AssistantEventStream;
+use crate::{
+    poll::poll_until,
+    types::assistants::{AssistantEventStream, RunStatus},
+    PollOptions,
+};
+
+impl_list_page!(ListRunsResponse => RunObject);
 
 /// Represents an execution run on a thread.
 ///
@@ -125,6 +134,15 @@ impl<'c, C: Config> Runs<'c, C> {
             .await
     }
 
+    /// All the runs of the thread, following the `after` cursor of [list](Self::list) page by page.
+    pub fn list_stream(&self) -> impl Stream<Item = Result<RunObject, OpenAIError>> + 'c {
+        paginate::<_, ListRunsResponse>(
+            self.client,
+            format!("/threads/{}/runs", self.thread_id),
+            self.request_options.clone(),
+        )
+    }
+
     /// When a run has the status: "requires_action" and required_action.type is submit_tool_outputs, this endpoint can be used to submit the outputs from the tool calls once they're all completed. All outputs must be submitted in a single request.
     #[crate::byot(T0 = std::fmt::Display, T1 = serde::Serialize, R = serde::de::DeserializeOwned)]
     pub async fn submit_tool_outputs(
@@ -184,6 +202,50 @@ impl<'c, C: Config> Runs<'c, C> {
             .await)
     }
 
+    /// Creates a run and polls it until it is terminal or requires action, see [poll](Self::poll).
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn create_and_poll(
+        &self,
+        request: CreateRunRequest,
+        poll: impl Into<PollOptions<RunObject>>,
+    ) -> Result<RunObject, OpenAIError> {
+        let run = self.create(request).await?;
+        self.poll(&run.id, poll).await
+    }
+
+    /// Submits the outputs of the tool calls of a run that requires action, and polls it until
+    /// it is terminal or requires action again, see [poll](Self::poll).
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn submit_tool_outputs_and_poll(
+        &self,
+        run_id: &str,
+        request: SubmitToolOutputsRunRequest,
+        poll: impl Into<PollOptions<RunObject>>,
+    ) -> Result<RunObject, OpenAIError> {
+        let run = self.submit_tool_outputs(run_id, request).await?;
+        self.poll(&run.id, poll).await
+    }
+
+    /// Polls a run until it is terminal, see [RunStatus::is_terminal], or until its status is
+    /// `requires_action`, and returns it.
+    ///
+    /// A run requiring action waits for the outputs of its [required_tool_calls](RunObject::required_tool_calls),
+    /// submit them with [submit_tool_outputs_and_poll](Self::submit_tool_outputs_and_poll).
+    /// `poll` is either the interval between polls as a [Duration](std::time::Duration), or [PollOptions].
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn poll(
+        &self,
+        run_id: &str,
+        poll: impl Into<PollOptions<RunObject>>,
+    ) -> Result<RunObject, OpenAIError> {
+        poll_until(
+            poll.into(),
+            || self.retrieve(run_id),
+            |run| run.status.is_terminal() || run.status == RunStatus::RequiresAction,
+        )
+        .await
+    }
+
     /// Cancels a run that is `in_progress`
     #[crate::byot(T0 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn cancel(&self, run_id: &str) -> Result<RunObject, OpenAIError> {
@@ -1,10 +1,15 @@
+use futures::Stream;
+
 use crate::{
     config::Config,
     error::OpenAIError,
+    pagination::{impl_list_page, paginate},
     types::assistants::{ListRunStepsResponse, RunStepObject},
     Client, RequestOptions,
 };
 
+impl_list_page!(ListRunStepsResponse => RunStepObject);
+
 /// Represents a step in execution of a run.
 #[deprecated(
     note = "Assistants API is deprecated and will be removed in August 2026. Use the Responses API."
@@ -50,4 +55,13 @@ impl<'c, C: Config> Steps<'c, C> {
             )
             .await
     }
+
+    /// All the steps of the run, following the `after` cursor of [list](Self::list) page by page.
+    pub fn list_stream(&self) -> impl Stream<Item = Result<RunStepObject, OpenAIError>> + 'c {
+        paginate::<_, ListRunStepsResponse>(
+            self.client,
+            format!("/threads/{}/runs/{}/steps", self.thread_id, self.run_id),
+            self.request_options.clone(),
+        )
+    }
 }
@@ -6,7 +6,7 @@ use crate::{
 };
 
 #[cfg(not(target_family = "wasm"))]
-use crate::types::audio::SpeechResponseStream;
+use crate::types::audio::{SpeechAudioStream, SpeechResponseStream, StreamFormat};
 
 pub struct Speech<'c, C: Config> {
     client: &'c Client<C>,
@@ -34,6 +34,26 @@ impl<'c, C: Config> Speech<'c, C> {
         Ok(CreateSpeechResponse { bytes })
     }
 
+    /// Generates audio from the input text, streaming its bytes in the `response_format` of the
+    /// request as they are generated, so that playback can start before the audio is complete.
+    ///
+    /// `pcm` and `wav` are the fastest formats to decode. Unlike [create](Self::create), the
+    /// request is not retried.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn create_audio_stream(
+        &self,
+        request: CreateSpeechRequest,
+    ) -> Result<SpeechAudioStream, OpenAIError> {
+        if request.stream_format == Some(StreamFormat::SSE) {
+            return Err(OpenAIError::InvalidArgument(
+                "When stream_format is SSE, use Speech::create_stream".into(),
+            ));
+        }
+        self.client
+            .post_bytes_stream("/audio/speech", request, &self.request_options)
+            .await
+    }
+
     /// Generates audio from the input text in SSE stream format.
     #[cfg(not(target_family = "wasm"))]
     #[crate::byot(
@@ -49,7 +69,6 @@ impl<'c, C: Config> Speech<'c, C> {
     ) -> Result<SpeechResponseStream, OpenAIError> {
         #[cfg(not(feature = "byot"))]
         {
-            use crate::types::audio::StreamFormat;
             if let Some(stream_format) = request.stream_format {
                 if stream_format != StreamFormat::SSE {
                     return Err(OpenAIError::InvalidArgument(
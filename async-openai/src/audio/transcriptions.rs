@@ -70,16 +70,29 @@ impl<'c, C: Config> Transcriptions<'c, C> {
             .await
     }
 
-    /// Transcribes audio into the input language.
+    /// Transcribes audio into the input language, with the timestamps of the
+    /// `timestamp_granularities` of the request.
+    ///
+    /// The `response_format` of the request defaults to `verbose_json`, unless the `byot` feature
+    /// is enabled.
     #[crate::byot(
         T0 = Clone,
         R = serde::de::DeserializeOwned,
         where_clause =  "reqwest::multipart::Form: crate::traits::AsyncTryFrom<T0, Error = OpenAIError>",
     )]
+    #[allow(unused_mut)]
     pub async fn create_verbose_json(
         &self,
-        request: CreateTranscriptionRequest,
+        mut request: CreateTranscriptionRequest,
     ) -> Result<CreateTranscriptionResponseVerboseJson, OpenAIError> {
+        #[cfg(not(feature = "byot"))]
+        {
+            use crate::types::audio::AudioResponseFormat;
+            request
+                .response_format
+                .get_or_insert(AudioResponseFormat::VerboseJson);
+        }
+
         self.client
             .post_form("/audio/transcriptions", request, &self.request_options)
             .await
@@ -100,6 +113,17 @@ impl<'c, C: Config> Transcriptions<'c, C> {
             .await
     }
 
+    /// Transcribes audio into the input language, in the `text`, `srt` or `vtt` response format
+    /// of the request, which are not JSON. Subtitles can be parsed with [Caption::parse_srt](crate::types::audio::Caption::parse_srt)
+    /// and [Caption::parse_vtt](crate::types::audio::Caption::parse_vtt).
+    pub async fn create_text(
+        &self,
+        request: CreateTranscriptionRequest,
+    ) -> Result<String, OpenAIError> {
+        let bytes = self.create_raw(request).await?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
     /// Transcribes audio into the input language.
     pub async fn create_raw(
         &self,
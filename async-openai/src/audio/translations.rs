@@ -38,22 +38,46 @@ impl<'c, C: Config> Translations<'c, C> {
             .await
     }
 
-    /// Translates audio into English.
+    /// Translates audio into English, with the timestamps of its segments.
+    ///
+    /// The `response_format` of the request defaults to `verbose_json`, unless the `byot` feature
+    /// is enabled.
     #[crate::byot(
         T0 = Clone,
         R = serde::de::DeserializeOwned,
         where_clause =  "reqwest::multipart::Form: crate::traits::AsyncTryFrom<T0, Error = OpenAIError>",
     )]
+    #[allow(unused_mut)]
     pub async fn create_verbose_json(
         &self,
-        request: CreateTranslationRequest,
+        mut request: CreateTranslationRequest,
     ) -> Result<CreateTranslationResponseVerboseJson, OpenAIError> {
+        #[cfg(not(feature = "byot"))]
+        {
+            use crate::types::audio::TranslationResponseFormat;
+            request
+                .response_format
+                .get_or_insert(TranslationResponseFormat::VerboseJson);
+        }
+
         self.client
             .post_form("/audio/translations", request, &self.request_options)
             .await
     }
 
-    /// Transcribes audio into the input language.
+    /// Translates audio into English, in the `text`, `srt` or `vtt` response format of the
+    /// request, which are not JSON. Subtitles can be parsed with
+    /// [Caption::parse_srt](crate::types::audio::Caption::parse_srt) and
+    /// [Caption::parse_vtt](crate::types::audio::Caption::parse_vtt).
+    pub async fn create_text(
+        &self,
+        request: CreateTranslationRequest,
+    ) -> Result<String, OpenAIError> {
+        let bytes = self.create_raw(request).await?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Translates audio into English, returning the body of the response as is.
     pub async fn create_raw(
         &self,
         request: CreateTranslationRequest,
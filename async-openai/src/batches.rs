@@ -1,10 +1,18 @@
+use futures::Stream;
+
 use crate::{
     config::Config,
     error::OpenAIError,
+    pagination::{impl_list_page, paginate},
     types::batches::{Batch, BatchRequest, ListBatchesResponse},
     Client, RequestOptions,
 };
 
+#[cfg(not(target_family = "wasm"))]
+use crate::{poll::poll_until, PollOptions};
+
+impl_list_page!(ListBatchesResponse => Batch);
+
 /// Create large batches of API requests for asynchronous processing. The Batch API returns completions within 24 hours for a 50% discount.
 ///
 /// Related guide: [Batch](https://platform.openai.com/docs/guides/batch)
@@ -35,6 +43,15 @@ impl<'c, C: Config> Batches<'c, C> {
         self.client.get("/batches", &self.request_options).await
     }
 
+    /// All the batches of your organization, following the `after` cursor of [list](Self::list) page by page.
+    pub fn list_stream(&self) -> impl Stream<Item = Result<Batch, OpenAIError>> + 'c {
+        paginate::<_, ListBatchesResponse>(
+            self.client,
+            "/batches".to_string(),
+            self.request_options.clone(),
+        )
+    }
+
     /// Retrieves a batch.
     #[crate::byot(T0 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn retrieve(&self, batch_id: &str) -> Result<Batch, OpenAIError> {
@@ -54,4 +71,22 @@ impl<'c, C: Config> Batches<'c, C> {
             )
             .await
     }
+
+    /// Polls the batch until its status is terminal, see [BatchStatus::is_terminal](crate::types::batches::BatchStatus::is_terminal), and returns it.
+    ///
+    /// `poll` is either the interval between polls as a [Duration](std::time::Duration), or
+    /// [PollOptions] with a timeout and a progress callback, e.g. to report the `request_counts`.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn wait_for_completion(
+        &self,
+        batch_id: &str,
+        poll: impl Into<PollOptions<Batch>>,
+    ) -> Result<Batch, OpenAIError> {
+        poll_until(
+            poll.into(),
+            || self.retrieve(batch_id),
+            |batch| batch.status.is_terminal(),
+        )
+        .await
+    }
 }
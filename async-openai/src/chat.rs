@@ -1,11 +1,14 @@
+use reqwest::header::HeaderMap;
+
 use crate::{
     config::Config,
     error::OpenAIError,
     types::chat::{
         ChatCompletionDeleted, ChatCompletionList, ChatCompletionMessageList,
-        CreateChatCompletionRequest, CreateChatCompletionResponse, UpdateChatCompletionRequest,
+        ChatCompletionRequestMessage, CreateChatCompletionRequest, CreateChatCompletionResponse,
+        UpdateChatCompletionRequest,
     },
-    Client, RequestOptions,
+    Client, PreparedRequest, RequestOptions,
 };
 
 #[cfg(not(target_family = "wasm"))]
@@ -17,6 +20,7 @@ use crate::types::chat::ChatCompletionResponseStream;
 pub struct Chat<'c, C: Config> {
     client: &'c Client<C>,
     pub(crate) request_options: RequestOptions,
+    model: Option<String>,
 }
 
 impl<'c, C: Config> Chat<'c, C> {
@@ -24,9 +28,58 @@ impl<'c, C: Config> Chat<'c, C> {
         Self {
             client,
             request_options: RequestOptions::new(),
+            model: None,
         }
     }
 
+    /// The model of [quick](Self::quick), instead of the
+    /// [default chat model](Client::with_default_chat_model) of the client.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Creates a model response for `messages` with the [model](Self::model) set on this `Chat`,
+    /// or else the [default chat model](Client::with_default_chat_model) of the client, e.g. for
+    /// small scripts. Fails with [OpenAIError::InvalidArgument] when neither is set.
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), async_openai::error::OpenAIError> {
+    /// use async_openai::{types::chat::ChatCompletionRequestUserMessage, Client};
+    ///
+    /// let client = Client::new().with_default_chat_model("gpt-4o-mini");
+    /// let message = ChatCompletionRequestUserMessage::from("Say this is a test");
+    ///
+    /// let response = client.chat().quick([message.clone()]).await?;
+    /// let response = client.chat().model("gpt-4o").quick([message]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quick<M>(
+        &self,
+        messages: impl IntoIterator<Item = M>,
+    ) -> Result<CreateChatCompletionResponse, OpenAIError>
+    where
+        M: Into<ChatCompletionRequestMessage>,
+    {
+        let model = self
+            .model
+            .as_ref()
+            .or(self.client.default_chat_model.as_ref())
+            .ok_or_else(|| {
+                OpenAIError::InvalidArgument(
+                    "set a model with Chat::model or Client::with_default_chat_model".into(),
+                )
+            })?;
+
+        let request = CreateChatCompletionRequest {
+            model: model.clone(),
+            messages: messages.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        };
+        self.create(request).await
+    }
+
     /// Creates a model response for the given chat conversation.
     ///
     /// Returns a [chat completion](https://platform.openai.com/docs/api-reference/chat/object) object, or a streamed sequence of [chat completion chunk](https://platform.openai.com/docs/api-reference/chat/streaming) objects if the request is streamed.
@@ -57,6 +110,71 @@ impl<'c, C: Config> Chat<'c, C> {
             .await
     }
 
+    /// The request [create](Self::create) would send for `request`, without sending it, see
+    /// [Client::build_request].
+    pub fn build_request(
+        &self,
+        request: &CreateChatCompletionRequest,
+    ) -> Result<PreparedRequest, OpenAIError> {
+        self.client.build_request(
+            reqwest::Method::POST,
+            "/chat/completions",
+            Some(request),
+            &self.request_options,
+        )
+    }
+
+    /// Same as [Chat::create] but also returns the response headers, such as
+    /// `x-ratelimit-remaining-requests`, `x-ratelimit-reset-tokens` and `x-request-id`.
+    pub async fn create_with_headers(
+        &self,
+        request: CreateChatCompletionRequest,
+    ) -> Result<(CreateChatCompletionResponse, HeaderMap), OpenAIError> {
+        if request.stream.is_some() && request.stream.unwrap() {
+            return Err(OpenAIError::InvalidArgument(
+                "When stream is true, use Chat::create_stream".into(),
+            ));
+        }
+        self.client
+            .post_with_headers("/chat/completions", request, &self.request_options)
+            .await
+    }
+
+    /// Creates a model response constrained to the JSON schema of `T`, and deserializes the
+    /// assistant message content of the first choice into `T`.
+    ///
+    /// The `response_format` of `request` is replaced with [ResponseFormat::json_schema_for].
+    /// Returns [OpenAIError::Refusal] when the model refuses to respond.
+    ///
+    /// [ResponseFormat::json_schema_for]: crate::types::chat::ResponseFormat::json_schema_for
+    #[cfg(feature = "schema")]
+    pub async fn create_typed<T>(
+        &self,
+        mut request: CreateChatCompletionRequest,
+    ) -> Result<T, OpenAIError>
+    where
+        T: serde::de::DeserializeOwned + schemars::JsonSchema,
+    {
+        request.response_format = Some(crate::types::chat::ResponseFormat::json_schema_for::<T>());
+
+        let response = self.create(request).await?;
+        let message = response
+            .choices
+            .into_iter()
+            .next()
+            .map(|choice| choice.message);
+
+        if let Some(refusal) = message.as_ref().and_then(|message| message.refusal.clone()) {
+            return Err(OpenAIError::Refusal(refusal));
+        }
+
+        let content = message
+            .and_then(|message| message.content)
+            .unwrap_or_default();
+        serde_json::from_str(&content)
+            .map_err(|e| crate::error::map_deserialization_error(e, content.as_bytes()))
+    }
+
     /// Creates a completion for the chat message.
     ///
     /// If set to true, the model response data will be streamed to the client as it is generated using [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events/Using_server-sent_events#Event_stream_format).
@@ -64,6 +182,7 @@ impl<'c, C: Config> Chat<'c, C> {
     /// See the [Streaming section](https://platform.openai.com/docs/api-reference/chat/streaming) for more information, along with the [streaming responses](https://platform.openai.com/docs/guides/streaming-responses) guide for more information on how to handle the streaming events.
     ///
     /// [ChatCompletionResponseStream] is a parsed SSE stream until a \[DONE\] is received from server.
+    /// Dropping it, or calling [cancel](crate::traits::CancelStream::cancel), closes the connection.
     ///
     /// byot: You must ensure "stream: true" in serialized `request`
     #[cfg(not(target_family = "wasm"))]
@@ -96,6 +215,9 @@ impl<'c, C: Config> Chat<'c, C> {
 
     /// List stored Chat Completions. Only Chat Completions that have been stored
     /// with the `store` parameter set to `true` will be returned.
+    ///
+    /// Filter and paginate them with [query](crate::traits::RequestOptionsBuilder::query), e.g.
+    /// `&[("model", "gpt-4o-mini"), ("metadata[pipeline]", "eval"), ("limit", "100")]`.
     #[crate::byot(R = serde::de::DeserializeOwned)]
     pub async fn list(&self) -> Result<ChatCompletionList, OpenAIError> {
         self.client
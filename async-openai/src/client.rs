@@ -1,5 +1,6 @@
+use std::{borrow::Cow, sync::Arc};
 #[cfg(not(target_family = "wasm"))]
-use std::pin::Pin;
+use std::{pin::Pin, time::Duration};
 
 use bytes::Bytes;
 #[cfg(not(target_family = "wasm"))]
@@ -8,15 +9,24 @@ use reqwest::{header::HeaderMap, multipart::Form, Response};
 #[cfg(not(target_family = "wasm"))]
 use reqwest_eventsource::{Error as EventSourceError, Event, EventSource, RequestBuilderExt};
 use serde::{de::DeserializeOwned, Serialize};
+#[cfg(all(feature = "tracing", not(target_family = "wasm")))]
+use tracing::Instrument;
 
-#[cfg(not(target_family = "wasm"))]
-use crate::error::StreamError;
 use crate::{
     config::{Config, OpenAIConfig},
     error::{map_deserialization_error, ApiError, OpenAIError, WrappedError},
     traits::AsyncTryFrom,
     RequestOptions,
 };
+#[cfg(not(target_family = "wasm"))]
+use crate::{
+    error::StreamError,
+    rate_limiter::{hold_permit, Limiter},
+    request_options::{uuid_v4, IDEMPOTENCY_KEY},
+    transport::HttpTransport,
+    types::RateLimitInfo,
+    ClientBuilder, RateLimits,
+};
 
 #[cfg(feature = "administration")]
 use crate::admin::Admin;
@@ -26,6 +36,8 @@ use crate::chatkit::Chatkit;
 use crate::file::Files;
 #[cfg(feature = "image")]
 use crate::image::Images;
+#[cfg(all(feature = "model", not(target_family = "wasm")))]
+use crate::model::ModelCache;
 #[cfg(feature = "moderation")]
 use crate::moderation::Moderations;
 #[cfg(feature = "assistant")]
@@ -67,14 +79,79 @@ use crate::VectorStores;
 #[cfg(feature = "video")]
 use crate::Videos;
 
-#[derive(Debug, Clone)]
 /// Client is a container for config, backoff and http_client
 /// used to make API calls.
+///
+/// Cloning a client is cheap: the config is shared and the [reqwest::Client] keeps its connection
+/// pool behind an [Arc], so clones reuse connections. Clients are `Send + Sync`, so one client can
+/// be cloned into every task, or shared by the handlers of a web server.
+///
+/// ```
+/// use async_openai::{config::Config, Client};
+///
+/// let client = Client::new();
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let client = client.clone();
+///         std::thread::spawn(move || client.config().api_base().to_string())
+///     })
+///     .collect();
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), "https://api.openai.com/v1");
+/// }
+/// ```
+#[derive(Debug)]
 pub struct Client<C: Config> {
     http_client: reqwest::Client,
-    config: C,
+    config: Arc<C>,
     #[cfg(not(target_family = "wasm"))]
     backoff: backoff::ExponentialBackoff,
+    #[cfg(not(target_family = "wasm"))]
+    max_retries: Option<u32>,
+    #[cfg(not(target_family = "wasm"))]
+    transport: Option<Arc<dyn HttpTransport>>,
+    #[cfg(not(target_family = "wasm"))]
+    limiter: Limiter,
+    #[cfg(not(target_family = "wasm"))]
+    idempotency_keys: bool,
+    #[cfg(all(feature = "model", not(target_family = "wasm")))]
+    pub(crate) model_cache: Option<Arc<ModelCache>>,
+    #[cfg(feature = "chat-completion")]
+    pub(crate) default_chat_model: Option<String>,
+}
+
+// not derived, to not require `C: Clone`
+impl<C: Config> Clone for Client<C> {
+    fn clone(&self) -> Self {
+        Self {
+            http_client: self.http_client.clone(),
+            config: self.config.clone(),
+            #[cfg(not(target_family = "wasm"))]
+            backoff: self.backoff.clone(),
+            #[cfg(not(target_family = "wasm"))]
+            max_retries: self.max_retries,
+            #[cfg(not(target_family = "wasm"))]
+            transport: self.transport.clone(),
+            #[cfg(not(target_family = "wasm"))]
+            limiter: self.limiter.clone(),
+            #[cfg(not(target_family = "wasm"))]
+            idempotency_keys: self.idempotency_keys,
+            #[cfg(all(feature = "model", not(target_family = "wasm")))]
+            model_cache: self.model_cache.clone(),
+            #[cfg(feature = "chat-completion")]
+            default_chat_model: self.default_chat_model.clone(),
+        }
+    }
+}
+
+/// A request built by [Client::build_request] and not sent.
+#[derive(Debug, Clone)]
+pub struct PreparedRequest {
+    pub method: reqwest::Method,
+    pub url: reqwest::Url,
+    pub headers: HeaderMap,
+    /// The JSON body, `None` for requests without one.
+    pub body: Option<serde_json::Value>,
 }
 
 impl<C: Config> Default for Client<C>
@@ -84,9 +161,21 @@ where
     fn default() -> Self {
         Self {
             http_client: reqwest::Client::new(),
-            config: C::default(),
+            config: Arc::new(C::default()),
             #[cfg(not(target_family = "wasm"))]
             backoff: Default::default(),
+            #[cfg(not(target_family = "wasm"))]
+            max_retries: None,
+            #[cfg(not(target_family = "wasm"))]
+            transport: None,
+            #[cfg(not(target_family = "wasm"))]
+            limiter: Limiter::default(),
+            #[cfg(not(target_family = "wasm"))]
+            idempotency_keys: false,
+            #[cfg(all(feature = "model", not(target_family = "wasm")))]
+            model_cache: None,
+            #[cfg(feature = "chat-completion")]
+            default_chat_model: None,
         }
     }
 }
@@ -96,10 +185,29 @@ impl Client<OpenAIConfig> {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// [ClientBuilder] with default [OpenAIConfig], to configure a proxy or root certificates.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn builder() -> ClientBuilder<OpenAIConfig> {
+        ClientBuilder::default()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Client<Box<dyn Config>> {
+    /// Client for Azure OpenAI Service when `AZURE_OPENAI_ENDPOINT` is set, see
+    /// [AzureConfig::from_env](crate::config::AzureConfig::from_env), and for OpenAI with
+    /// the default [OpenAIConfig] otherwise, which reads `OPENAI_API_KEY`, `OPENAI_BASE_URL`,
+    /// `OPENAI_ORG_ID` and `OPENAI_PROJECT_ID`.
+    pub fn from_env() -> Self {
+        Self::with_config(crate::config::config_from_env())
+    }
 }
 
 impl<C: Config> Client<C> {
     /// Create client with a custom HTTP client, OpenAI config, and backoff.
+    ///
+    /// See [with_http_client](Self::with_http_client) for what the HTTP client is used for.
     #[cfg(not(target_family = "wasm"))]
     pub fn build(
         http_client: reqwest::Client,
@@ -108,8 +216,16 @@ impl<C: Config> Client<C> {
     ) -> Self {
         Self {
             http_client,
-            config,
+            config: Arc::new(config),
             backoff,
+            max_retries: None,
+            transport: None,
+            limiter: Limiter::default(),
+            idempotency_keys: false,
+            #[cfg(feature = "model")]
+            model_cache: None,
+            #[cfg(feature = "chat-completion")]
+            default_chat_model: None,
         }
     }
 
@@ -118,7 +234,9 @@ impl<C: Config> Client<C> {
     pub fn build(http_client: reqwest::Client, config: C) -> Self {
         Self {
             http_client,
-            config,
+            config: Arc::new(config),
+            #[cfg(feature = "chat-completion")]
+            default_chat_model: None,
         }
     }
 
@@ -126,13 +244,43 @@ impl<C: Config> Client<C> {
     pub fn with_config(config: C) -> Self {
         Self {
             http_client: reqwest::Client::new(),
-            config,
+            config: Arc::new(config),
             #[cfg(not(target_family = "wasm"))]
             backoff: Default::default(),
+            #[cfg(not(target_family = "wasm"))]
+            max_retries: None,
+            #[cfg(not(target_family = "wasm"))]
+            transport: None,
+            #[cfg(not(target_family = "wasm"))]
+            limiter: Limiter::default(),
+            #[cfg(not(target_family = "wasm"))]
+            idempotency_keys: false,
+            #[cfg(all(feature = "model", not(target_family = "wasm")))]
+            model_cache: None,
+            #[cfg(feature = "chat-completion")]
+            default_chat_model: None,
         }
     }
 
-    /// Provide your own [client] to make HTTP requests with.
+    /// Provide your own [client] to make HTTP requests with, e.g. to tune connection pooling,
+    /// keep-alive or HTTP/2 (with the `http2` feature):
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use async_openai::Client;
+    /// let http_client = reqwest::Client::builder()
+    ///     .pool_max_idle_per_host(32)
+    ///     .pool_idle_timeout(Duration::from_secs(90))
+    ///     .tcp_keepalive(Duration::from_secs(60))
+    ///     .build()
+    ///     .unwrap();
+    /// let client = Client::new().with_http_client(http_client);
+    /// ```
+    ///
+    /// The headers, query parameters and API key of the [Config] are added to every request, and
+    /// replace default headers of the client with the same name.
+    ///
+    /// For a proxy or additional root certificates, [ClientBuilder] configures the client.
     ///
     /// [client]: reqwest::Client
     pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
@@ -141,12 +289,110 @@ impl<C: Config> Client<C> {
     }
 
     /// Exponential backoff for retrying [rate limited](https://platform.openai.com/docs/guides/rate-limits) requests.
+    ///
+    /// Requests are retried on `429` (except `insufficient_quota`), `5xx` and connection resets.
+    /// When the server sends `retry-after-ms` or `retry-after` headers, they take precedence
+    /// over the computed backoff interval. Total elapsed time is capped by
+    /// [backoff::ExponentialBackoff::max_elapsed_time]; SSE streaming requests and streamed uploads
+    /// are never retried.
     #[cfg(not(target_family = "wasm"))]
     pub fn with_backoff(mut self, backoff: backoff::ExponentialBackoff) -> Self {
         self.backoff = backoff;
         self
     }
 
+    /// Maximum number of retries after the first attempt. By default retries are
+    /// bounded only by `max_elapsed_time` of the backoff.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Send requests through `transport` instead of the HTTP client, for example a
+    /// [MockTransport](crate::transport::MockTransport) in tests.
+    ///
+    /// SSE streaming requests are not affected and still use the HTTP client.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Send at most `limit` requests at a time. Further requests wait for one of them to finish,
+    /// in the order they were made, so fanning out many calls does not exceed the rate limits.
+    ///
+    /// A request waiting longer than its [timeout](crate::traits::RequestOptionsBuilder::timeout)
+    /// fails with [OpenAIError::Timeout]. Retries wait for a slot again. A streaming request keeps
+    /// its slot until its stream ends or is dropped. Clones of the client share the limit. A
+    /// `limit` of 0 is treated as 1.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_concurrency_limit(mut self, limit: usize) -> Self {
+        self.limiter.set_concurrency_limit(limit);
+        self
+    }
+
+    /// Delay requests that would exceed the requests or tokens per minute of `limits`, like
+    /// [with_concurrency_limit](Self::with_concurrency_limit) does for concurrent requests.
+    ///
+    /// Limits are enforced with token buckets that start full and refill continuously, so up to a
+    /// minute worth of requests can be sent at once. The tokens of a request are estimated from
+    /// the size of its body, about 4 bytes per token, as the output tokens are not known ahead.
+    /// Use [update_rate_limits](Self::update_rate_limits) to follow the limits reported by the API.
+    ///
+    /// ```
+    /// use async_openai::{Client, RateLimits};
+    ///
+    /// let client = Client::new()
+    ///     .with_concurrency_limit(16)
+    ///     .with_rate_limits(RateLimits::new().requests_per_minute(500).tokens_per_minute(200_000));
+    /// ```
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_rate_limits(mut self, limits: RateLimits) -> Self {
+        self.limiter.set_rate_limits(limits);
+        self
+    }
+
+    /// Set the limits of [with_rate_limits](Self::with_rate_limits) to `info`, e.g. parsed from
+    /// the headers of [Chat::create_with_headers](crate::Chat::create_with_headers), with the
+    /// remaining requests and tokens available right away. Limits that were not set stay unset.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn update_rate_limits(&self, info: &RateLimitInfo) {
+        self.limiter.update(info);
+    }
+
+    /// Send an `Idempotency-Key` header with a random UUID in POST requests that do not set one
+    /// with [RequestOptionsBuilder::idempotency_key](crate::traits::RequestOptionsBuilder::idempotency_key).
+    /// The key is generated once per request and kept by its retries, so a request that timed out
+    /// or failed after reaching the server is recognized when it is retried.
+    ///
+    /// See [idempotency_key](crate::traits::RequestOptionsBuilder::idempotency_key) for the
+    /// endpoints honoring it. SSE streaming requests are not retried, and do not get a key.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_idempotency_keys(mut self) -> Self {
+        self.idempotency_keys = true;
+        self
+    }
+
+    /// Keep the responses of [Models::list] and [Models::retrieve] for `ttl`, so that repeated
+    /// calls within it do not send requests, e.g. to check that a model is available before every
+    /// request. Use [Models::refresh] to fetch the list again before the `ttl` expires.
+    ///
+    /// Clones of the client share the cache. Failed requests are not cached, and requests with
+    /// other headers or query parameters, such as another `OpenAI-Project`, are cached apart.
+    #[cfg(all(feature = "model", not(target_family = "wasm")))]
+    pub fn with_model_cache(mut self, ttl: Duration) -> Self {
+        self.model_cache = Some(Arc::new(ModelCache::new(ttl)));
+        self
+    }
+
+    /// The model of [Chat::quick] requests that do not set one with [Chat::model].
+    #[cfg(feature = "chat-completion")]
+    pub fn with_default_chat_model(mut self, model: impl Into<String>) -> Self {
+        self.default_chat_model = Some(model.into());
+        self
+    }
+
     // API groups
 
     /// To call [Models] group related APIs using this client.
@@ -299,6 +545,60 @@ impl<C: Config> Client<C> {
         &self.config
     }
 
+    /// The request that would be sent for `method` on `path`, e.g. `/chat/completions`, with
+    /// `body` as JSON, without sending it. Use it to compare requests with the API reference, or
+    /// to record fixtures for a cache or replay layer.
+    ///
+    /// The URL, query parameters and headers are those of the [Config] and `request_options`,
+    /// so the headers include the API key. The body includes the extra body fields of
+    /// `request_options`. API groups have the same method for their operations, such as
+    /// [Chat::build_request].
+    ///
+    /// ```
+    /// use async_openai::{Client, RequestOptions};
+    ///
+    /// let client = Client::new();
+    /// let request = client
+    ///     .build_request(
+    ///         reqwest::Method::POST,
+    ///         "/embeddings",
+    ///         Some(&serde_json::json!({ "model": "text-embedding-3-small", "input": "hi" })),
+    ///         &RequestOptions::default(),
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(request.url.as_str(), "https://api.openai.com/v1/embeddings");
+    /// ```
+    pub fn build_request<I: Serialize + ?Sized>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&I>,
+        request_options: &RequestOptions,
+    ) -> Result<PreparedRequest, OpenAIError> {
+        let body = match body {
+            Some(body) => Some(match request_options.merge_extra_body(body)? {
+                Some(body) => body,
+                None => serde_json::to_value(body).map_err(|e| {
+                    OpenAIError::InvalidArgument(format!("Failed to serialize request: {}", e))
+                })?,
+            }),
+            None => None,
+        };
+
+        let mut request_builder = self.build_request_builder(method, path, request_options);
+        if let Some(body) = &body {
+            request_builder = request_builder.json(body);
+        }
+        let request = request_builder.build()?;
+
+        Ok(PreparedRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body,
+        })
+    }
+
     /// Helper function to build a request builder with common configuration
     fn build_request_builder(
         &self,
@@ -313,21 +613,61 @@ impl<C: Config> Client<C> {
             self.http_client.request(method, self.config.url(path))
         };
 
-        request_builder = request_builder
-            .query(&self.config.query())
-            .headers(self.config.headers());
-
-        if let Some(headers) = request_options.headers() {
-            request_builder = request_builder.headers(headers.clone());
+        let mut headers = self.config.headers();
+        if let Some(request_headers) = request_options.headers() {
+            headers.extend(request_headers.clone());
         }
+        self.config.filter_headers(&mut headers);
+
+        request_builder = request_builder.query(&self.config.query()).headers(headers);
 
         if !request_options.query().is_empty() {
             request_builder = request_builder.query(request_options.query());
         }
 
+        if let Some(timeout) = request_options.timeout() {
+            request_builder = request_builder.timeout(timeout);
+        }
+
         request_builder
     }
 
+    /// Helper function to build a POST request builder with `request` as the JSON body,
+    /// including the extra body fields of `request_options`
+    fn build_json_request_builder<I: Serialize>(
+        &self,
+        path: &str,
+        request: &I,
+        request_options: &RequestOptions,
+    ) -> Result<reqwest::RequestBuilder, OpenAIError> {
+        let request_builder =
+            self.build_request_builder(reqwest::Method::POST, path, request_options);
+        Ok(match request_options.merge_extra_body(request)? {
+            Some(body) => request_builder.json(&body),
+            None => request_builder.json(request),
+        })
+    }
+
+    /// `request_options` with a new `Idempotency-Key` when
+    /// [with_idempotency_keys](Self::with_idempotency_keys) is set and they do not have one.
+    fn idempotent_options<'a>(
+        &self,
+        request_options: &'a RequestOptions,
+    ) -> Cow<'a, RequestOptions> {
+        #[cfg(not(target_family = "wasm"))]
+        if self.idempotency_keys && !request_options.has_header(IDEMPOTENCY_KEY) {
+            let mut request_options = request_options.clone();
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                IDEMPOTENCY_KEY,
+                uuid_v4().parse().expect("a UUID is a valid header value"),
+            );
+            request_options.with_headers(headers);
+            return Cow::Owned(request_options);
+        }
+        Cow::Borrowed(request_options)
+    }
+
     /// Make a GET request to {path} and deserialize the response body
     #[allow(unused)]
     pub(crate) async fn get<O>(
@@ -393,10 +733,10 @@ impl<C: Config> Client<C> {
     where
         I: Serialize,
     {
+        let request_options: &RequestOptions = &self.idempotent_options(request_options);
         let request_maker = || async {
             Ok(self
-                .build_request_builder(reqwest::Method::POST, path, request_options)
-                .json(&request)
+                .build_json_request_builder(path, &request, request_options)?
                 .build()?)
         };
 
@@ -415,16 +755,38 @@ impl<C: Config> Client<C> {
         I: Serialize,
         O: DeserializeOwned,
     {
+        let request_options: &RequestOptions = &self.idempotent_options(request_options);
         let request_maker = || async {
             Ok(self
-                .build_request_builder(reqwest::Method::POST, path, request_options)
-                .json(&request)
+                .build_json_request_builder(path, &request, request_options)?
                 .build()?)
         };
 
         self.execute(request_maker).await
     }
 
+    /// Make a POST request to {path} and deserialize the response body along with response headers
+    #[allow(unused)]
+    pub(crate) async fn post_with_headers<I, O>(
+        &self,
+        path: &str,
+        request: I,
+        request_options: &RequestOptions,
+    ) -> Result<(O, HeaderMap), OpenAIError>
+    where
+        I: Serialize,
+        O: DeserializeOwned,
+    {
+        let request_options: &RequestOptions = &self.idempotent_options(request_options);
+        let request_maker = || async {
+            Ok(self
+                .build_json_request_builder(path, &request, request_options)?
+                .build()?)
+        };
+
+        self.execute_with_headers(request_maker).await
+    }
+
     /// POST a form at {path} and return the response body
     #[allow(unused)]
     pub(crate) async fn post_form_raw<F>(
@@ -437,6 +799,7 @@ impl<C: Config> Client<C> {
         Form: AsyncTryFrom<F, Error = OpenAIError>,
         F: Clone,
     {
+        let request_options: &RequestOptions = &self.idempotent_options(request_options);
         let request_maker = || async {
             Ok(self
                 .build_request_builder(reqwest::Method::POST, path, request_options)
@@ -460,6 +823,7 @@ impl<C: Config> Client<C> {
         Form: AsyncTryFrom<F, Error = OpenAIError>,
         F: Clone,
     {
+        let request_options: &RequestOptions = &self.idempotent_options(request_options);
         let request_maker = || async {
             Ok(self
                 .build_request_builder(reqwest::Method::POST, path, request_options)
@@ -485,11 +849,17 @@ impl<C: Config> Client<C> {
     {
         // Build and execute request manually since multipart::Form is not Clone
         // and .eventsource() requires cloneability
-        let request_builder = self
+        let request = self
             .build_request_builder(reqwest::Method::POST, path, request_options)
-            .multipart(<Form as AsyncTryFrom<F>>::try_from(form.clone()).await?);
+            .multipart(<Form as AsyncTryFrom<F>>::try_from(form.clone()).await?)
+            .build()?;
 
-        let response = request_builder.send().await.map_err(OpenAIError::Reqwest)?;
+        let permit = self.limiter.acquire(&request).await?;
+        let response = self
+            .http_client
+            .execute(request)
+            .await
+            .map_err(OpenAIError::Reqwest)?;
 
         // Check for error status
         if !response.status().is_success() {
@@ -504,12 +874,21 @@ impl<C: Config> Client<C> {
 
         // Convert EventSource stream to our expected format
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let idle_timeout = request_options.stream_idle_timeout();
 
         tokio::spawn(async move {
-            use futures::StreamExt;
             let mut event_stream = std::pin::pin!(event_stream);
 
-            while let Some(event_result) = event_stream.next().await {
+            loop {
+                let event_result = match next_event(&mut event_stream, idle_timeout, &tx).await {
+                    Ok(Some(event_result)) => event_result,
+                    Ok(None) => break,
+                    Err(e) => {
+                        let _ = tx.send(Err(e));
+                        break;
+                    }
+                };
+
                 match event_result {
                     Err(e) => {
                         if let Err(_e) = tx.send(Err(OpenAIError::StreamError(Box::new(
@@ -537,9 +916,41 @@ impl<C: Config> Client<C> {
             }
         });
 
-        Ok(Box::pin(
+        Ok(Box::pin(hold_permit(
             tokio_stream::wrappers::UnboundedReceiverStream::new(rx),
-        ))
+            permit,
+        )))
+    }
+
+    /// POST a JSON request at {path} and stream the bytes of the response body as they arrive,
+    /// e.g. for audio. The request is not retried.
+    #[allow(unused)]
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) async fn post_bytes_stream<I: Serialize>(
+        &self,
+        path: &str,
+        request: I,
+        request_options: &RequestOptions,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<Bytes, OpenAIError>> + Send>>, OpenAIError> {
+        let request = self
+            .build_json_request_builder(path, &request, request_options)?
+            .build()?;
+        let permit = self.limiter.acquire(&request).await?;
+        let response = match &self.transport {
+            Some(transport) => transport.execute(request).await?,
+            None => self.http_client.execute(request).await?,
+        };
+
+        if !response.status().is_success() {
+            return Err(read_response(response).await.unwrap_err());
+        }
+
+        Ok(Box::pin(hold_permit(
+            response
+                .bytes_stream()
+                .map(|chunk| chunk.map_err(OpenAIError::Reqwest)),
+            permit,
+        )))
     }
 
     /// Execute a HTTP request and retry on rate limit (non-WASM version with backoff)
@@ -554,16 +965,63 @@ impl<C: Config> Client<C> {
         Fut: core::future::Future<Output = Result<reqwest::Request, OpenAIError>>,
     {
         let client = self.http_client.clone();
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+        #[cfg(feature = "tracing")]
+        let span = request_span();
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
+
+        let retry = backoff::future::retry(self.backoff.clone(), || async {
+            let attempt = attempts.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            let (request, streamed) = STREAMED_UPLOAD
+                .scope(std::cell::Cell::new(false), async {
+                    let request = request_maker().await;
+                    (request, STREAMED_UPLOAD.with(std::cell::Cell::get))
+                })
+                .await;
+            let request = request.map_err(backoff::Error::Permanent)?;
+            // the stream of a streamed upload has been read, and cannot be sent again
+            let can_retry = !streamed && self.max_retries.map_or(true, |max| attempt < max);
+
+            let transient = |err: OpenAIError, retry_after: Option<std::time::Duration>| {
+                if can_retry {
+                    backoff::Error::Transient { err, retry_after }
+                } else {
+                    backoff::Error::Permanent(err)
+                }
+            };
 
-        backoff::future::retry(self.backoff.clone(), || async {
-            let request = request_maker().await.map_err(backoff::Error::Permanent)?;
-            let response = client
-                .execute(request)
+            #[cfg(feature = "tracing")]
+            if attempt == 0 {
+                record_request(&span, &request);
+            }
+            let _permit = self
+                .limiter
+                .acquire(&request)
                 .await
-                .map_err(OpenAIError::Reqwest)
                 .map_err(backoff::Error::Permanent)?;
+            let response = match &self.transport {
+                Some(transport) => transport.execute(request).await,
+                None => client.execute(request).await.map_err(OpenAIError::Reqwest),
+            };
+            let response = match response {
+                Ok(response) => response,
+                Err(OpenAIError::Reqwest(e)) if is_connection_reset(&e) => {
+                    tracing::warn!("Connection reset: {e}");
+                    return Err(transient(OpenAIError::Reqwest(e), None));
+                }
+                Err(e) => return Err(backoff::Error::Permanent(e)),
+            };
 
             let status = response.status();
+            let retry_after = retry_after(response.headers());
+            #[cfg(feature = "tracing")]
+            {
+                span.record("status", status.as_u16());
+                if let Some(request_id) = request_id(response.headers()) {
+                    span.record("request_id", request_id);
+                }
+            }
 
             match read_response(response).await {
                 Ok((bytes, headers)) => Ok((bytes, headers)),
@@ -571,19 +1029,15 @@ impl<C: Config> Client<C> {
                     match e {
                         OpenAIError::ApiError(api_error) => {
                             if status.is_server_error() {
-                                Err(backoff::Error::Transient {
-                                    err: OpenAIError::ApiError(api_error),
-                                    retry_after: None,
-                                })
+                                Err(transient(OpenAIError::ApiError(api_error), retry_after))
                             } else if status.as_u16() == 429
                                 && api_error.r#type != Some("insufficient_quota".to_string())
+                                && api_error.error_code()
+                                    != Some(crate::error::ApiErrorCode::InsufficientQuota)
                             {
                                 // Rate limited retry...
                                 tracing::warn!("Rate limited: {}", api_error.message);
-                                Err(backoff::Error::Transient {
-                                    err: OpenAIError::ApiError(api_error),
-                                    retry_after: None,
-                                })
+                                Err(transient(OpenAIError::ApiError(api_error), retry_after))
                             } else {
                                 Err(backoff::Error::Permanent(OpenAIError::ApiError(api_error)))
                             }
@@ -592,8 +1046,16 @@ impl<C: Config> Client<C> {
                     }
                 }
             }
-        })
-        .await
+        });
+
+        #[cfg(feature = "tracing")]
+        {
+            let result = retry.instrument(span.clone()).await;
+            record_outcome(&span, started.elapsed(), &result);
+            result
+        }
+        #[cfg(not(feature = "tracing"))]
+        retry.await
     }
 
     /// Execute a HTTP request (WASM version - single attempt, no retry)
@@ -624,12 +1086,27 @@ impl<C: Config> Client<C> {
         M: Fn() -> Fut,
         Fut: core::future::Future<Output = Result<reqwest::Request, OpenAIError>>,
     {
-        let (bytes, _headers) = self.execute_raw(request_maker).await?;
+        let (response, _headers) = self.execute_with_headers(request_maker).await?;
+        Ok(response)
+    }
+
+    /// Execute a HTTP request and retry on rate limit, returning the deserialized
+    /// response body along with the response headers.
+    async fn execute_with_headers<O, M, Fut>(
+        &self,
+        request_maker: M,
+    ) -> Result<(O, HeaderMap), OpenAIError>
+    where
+        O: DeserializeOwned,
+        M: Fn() -> Fut,
+        Fut: core::future::Future<Output = Result<reqwest::Request, OpenAIError>>,
+    {
+        let (bytes, headers) = self.execute_raw(request_maker).await?;
 
         let response: O = serde_json::from_slice(bytes.as_ref())
             .map_err(|e| map_deserialization_error(e, bytes.as_ref()))?;
 
-        Ok(response)
+        Ok((response, headers))
     }
 
     /// Make HTTP POST request to receive SSE
@@ -645,13 +1122,25 @@ impl<C: Config> Client<C> {
         I: Serialize,
         O: DeserializeOwned + std::marker::Send + 'static,
     {
-        let request_builder = self
-            .build_request_builder(reqwest::Method::POST, path, request_options)
-            .json(&request);
+        let request_builder = match self.build_json_request_builder(path, &request, request_options)
+        {
+            Ok(request_builder) => request_builder,
+            Err(e) => return Box::pin(futures::stream::once(async { Err(e) })),
+        };
+        let permit = match self.acquire_stream(&request_builder).await {
+            Ok(permit) => permit,
+            Err(e) => return Box::pin(futures::stream::once(async { Err(e) })),
+        };
 
         let event_source = request_builder.eventsource().unwrap();
 
-        stream(event_source).await
+        let stream = stream(
+            event_source,
+            request_options.stream_idle_timeout(),
+            request_options.stream_reconnect(),
+        )
+        .await;
+        Box::pin(hold_permit(stream, permit))
     }
 
     #[allow(unused)]
@@ -667,13 +1156,26 @@ impl<C: Config> Client<C> {
         I: Serialize,
         O: DeserializeOwned + std::marker::Send + 'static,
     {
-        let request_builder = self
-            .build_request_builder(reqwest::Method::POST, path, request_options)
-            .json(&request);
+        let request_builder = match self.build_json_request_builder(path, &request, request_options)
+        {
+            Ok(request_builder) => request_builder,
+            Err(e) => return Box::pin(futures::stream::once(async { Err(e) })),
+        };
+        let permit = match self.acquire_stream(&request_builder).await {
+            Ok(permit) => permit,
+            Err(e) => return Box::pin(futures::stream::once(async { Err(e) })),
+        };
 
         let event_source = request_builder.eventsource().unwrap();
 
-        stream_mapped_raw_events(event_source, event_mapper).await
+        let stream = stream_mapped_raw_events(
+            event_source,
+            request_options.stream_idle_timeout(),
+            request_options.stream_reconnect(),
+            event_mapper,
+        )
+        .await;
+        Box::pin(hold_permit(stream, permit))
     }
 
     /// Make HTTP GET request to receive SSE
@@ -689,10 +1191,34 @@ impl<C: Config> Client<C> {
     {
         let request_builder =
             self.build_request_builder(reqwest::Method::GET, path, request_options);
+        let permit = match self.acquire_stream(&request_builder).await {
+            Ok(permit) => permit,
+            Err(e) => return Box::pin(futures::stream::once(async { Err(e) })),
+        };
 
         let event_source = request_builder.eventsource().unwrap();
 
-        stream(event_source).await
+        let stream = stream(
+            event_source,
+            request_options.stream_idle_timeout(),
+            request_options.stream_reconnect(),
+        )
+        .await;
+        Box::pin(hold_permit(stream, permit))
+    }
+
+    /// Waits until the SSE request of `request_builder` can be sent within the limits of the
+    /// client, see [Limiter::acquire].
+    #[cfg(not(target_family = "wasm"))]
+    async fn acquire_stream(
+        &self,
+        request_builder: &reqwest::RequestBuilder,
+    ) -> Result<Option<tokio::sync::OwnedSemaphorePermit>, OpenAIError> {
+        // SSE requests have JSON or no bodies, which can be cloned
+        match request_builder.try_clone() {
+            Some(request_builder) => self.limiter.acquire(&request_builder.build()?).await,
+            None => Ok(None),
+        }
     }
 }
 
@@ -700,6 +1226,7 @@ async fn read_response(response: Response) -> Result<(Bytes, HeaderMap), OpenAIE
     let status = response.status();
     let headers = response.headers().clone();
     let bytes = response.bytes().await.map_err(OpenAIError::Reqwest)?;
+    let request_id = request_id(&headers).map(Box::from);
 
     if status.is_server_error() {
         // OpenAI does not guarantee server errors are returned as JSON so we cannot deserialize them.
@@ -710,20 +1237,205 @@ async fn read_response(response: Response) -> Result<(Bytes, HeaderMap), OpenAIE
             r#type: None,
             param: None,
             code: None,
+            status: Some(status.as_u16()),
+            request_id,
         }));
     }
 
     // Deserialize response body from either error object or actual response object
     if !status.is_success() {
-        let wrapped_error: WrappedError = serde_json::from_slice(bytes.as_ref())
-            .map_err(|e| map_deserialization_error(e, bytes.as_ref()))?;
+        // proxies, gateways and compatible servers may return errors that are not JSON, the
+        // status is kept so that e.g. a 429 is still retried
+        let error = match serde_json::from_slice::<WrappedError>(bytes.as_ref()) {
+            Ok(wrapped_error) => wrapped_error.error,
+            Err(_) => ApiError {
+                message: String::from_utf8_lossy(&bytes).into_owned(),
+                r#type: None,
+                param: None,
+                code: None,
+                status: None,
+                request_id: None,
+            },
+        };
 
-        return Err(OpenAIError::ApiError(wrapped_error.error));
+        return Err(OpenAIError::ApiError(ApiError {
+            status: Some(status.as_u16()),
+            request_id,
+            ..error
+        }));
     }
 
     Ok((bytes, headers))
 }
 
+#[cfg(not(target_family = "wasm"))]
+tokio::task_local! {
+    /// Whether the request being made reads the stream of an
+    /// [InputSource::Stream](crate::types::InputSource::Stream).
+    static STREAMED_UPLOAD: std::cell::Cell<bool>;
+}
+
+/// Marks the request being made by [Client::execute_raw] as not retryable, as it reads a stream.
+#[cfg(not(target_family = "wasm"))]
+#[allow(unused)]
+pub(crate) fn mark_streamed_upload() {
+    let _ = STREAMED_UPLOAD.try_with(|streamed| streamed.set(true));
+}
+
+/// The span of a request sent with [Client::execute_raw], with the fields recorded by [record_request]
+/// and [record_outcome].
+#[cfg(all(feature = "tracing", not(target_family = "wasm")))]
+fn request_span() -> tracing::Span {
+    tracing::info_span!(
+        "openai.request",
+        method = tracing::field::Empty,
+        endpoint = tracing::field::Empty,
+        model = tracing::field::Empty,
+        status = tracing::field::Empty,
+        request_id = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+        prompt_tokens = tracing::field::Empty,
+        completion_tokens = tracing::field::Empty,
+        total_tokens = tracing::field::Empty,
+        error = tracing::field::Empty,
+    )
+}
+
+/// The `x-request-id` of a response, to quote when contacting OpenAI support.
+pub(crate) fn request_id(headers: &HeaderMap) -> Option<&str> {
+    headers.get("x-request-id")?.to_str().ok()
+}
+
+/// Records the method, path and model of `request` on `span`.
+#[cfg(all(feature = "tracing", not(target_family = "wasm")))]
+fn record_request(span: &tracing::Span, request: &reqwest::Request) {
+    if span.is_disabled() {
+        return;
+    }
+    span.record("method", request.method().as_str());
+    span.record("endpoint", request.url().path());
+
+    #[derive(serde::Deserialize)]
+    struct Model {
+        model: Option<String>,
+    }
+    let model = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .and_then(|body| serde_json::from_slice::<Model>(body).ok())
+        .and_then(|body| body.model);
+    if let Some(model) = model {
+        span.record("model", model);
+    }
+}
+
+/// Records the latency, and the token usage of a successful response or the error, on `span`.
+#[cfg(all(feature = "tracing", not(target_family = "wasm")))]
+fn record_outcome(
+    span: &tracing::Span,
+    latency: Duration,
+    result: &Result<(Bytes, HeaderMap), OpenAIError>,
+) {
+    if span.is_disabled() {
+        return;
+    }
+    span.record("latency_ms", latency.as_millis() as u64);
+
+    // chat completions and embeddings count `prompt` and `completion` tokens, responses count
+    // `input` and `output` tokens
+    #[derive(serde::Deserialize)]
+    struct Usage {
+        #[serde(alias = "input_tokens")]
+        prompt_tokens: Option<u64>,
+        #[serde(alias = "output_tokens")]
+        completion_tokens: Option<u64>,
+        total_tokens: Option<u64>,
+    }
+    #[derive(serde::Deserialize)]
+    struct Body {
+        usage: Option<Usage>,
+    }
+
+    match result {
+        Ok((bytes, _)) => {
+            let usage = serde_json::from_slice::<Body>(bytes)
+                .ok()
+                .and_then(|body| body.usage);
+            if let Some(usage) = usage {
+                span.record("prompt_tokens", usage.prompt_tokens);
+                span.record("completion_tokens", usage.completion_tokens);
+                span.record("total_tokens", usage.total_tokens);
+            }
+        }
+        Err(e) => {
+            span.record("error", tracing::field::display(e));
+        }
+    }
+}
+
+/// Returns `true` when the connection was reset or aborted by the peer while the request was in flight.
+#[cfg(not(target_family = "wasm"))]
+fn is_connection_reset(e: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(e);
+    while let Some(err) = source {
+        if let Some(io_error) = err.downcast_ref::<std::io::Error>() {
+            return matches!(
+                io_error.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+            );
+        }
+        source = err.source();
+    }
+
+    false
+}
+
+/// Delay requested by the server via `retry-after-ms` or `retry-after` (in seconds) headers.
+#[cfg(not(target_family = "wasm"))]
+fn retry_after(headers: &HeaderMap) -> Option<std::time::Duration> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+
+    if let Some(ms) = header("retry-after-ms").and_then(|v| v.trim().parse::<f64>().ok()) {
+        return std::time::Duration::try_from_secs_f64(ms / 1000.0).ok();
+    }
+
+    header("retry-after")
+        .and_then(|v| v.trim().parse::<f64>().ok())
+        .and_then(|secs| std::time::Duration::try_from_secs_f64(secs).ok())
+}
+
+/// Next item of a SSE stream, or [StreamError::IdleTimeout] when none arrives within `idle_timeout`.
+/// `None` once the stream ends or the receiver of `tx` is dropped.
+#[cfg(not(target_family = "wasm"))]
+async fn next_event<S, T>(
+    stream: &mut S,
+    idle_timeout: Option<Duration>,
+    tx: &tokio::sync::mpsc::UnboundedSender<T>,
+) -> Result<Option<S::Item>, OpenAIError>
+where
+    S: Stream + Unpin,
+{
+    let next = async {
+        match idle_timeout {
+            Some(idle_timeout) => tokio::time::timeout(idle_timeout, stream.next())
+                .await
+                .map_err(|_| {
+                    OpenAIError::StreamError(Box::new(StreamError::IdleTimeout(idle_timeout)))
+                }),
+            None => Ok(stream.next().await),
+        }
+    };
+
+    tokio::select! {
+        // The receiving stream was dropped: stop waiting for the next event so the
+        // connection is closed right away, and the server stops generating tokens.
+        _ = tx.closed() => Ok(None),
+        event = next => event,
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 async fn map_stream_error(value: EventSourceError) -> OpenAIError {
     match value {
@@ -736,21 +1448,110 @@ async fn map_stream_error(value: EventSourceError) -> OpenAIError {
     }
 }
 
+/// Reconnection policy of SSE streams.
+///
+/// Streaming requests are not idempotent, so a stream is only reconnected when the server sent
+/// event ids, which the [EventSource] sends back in the `Last-Event-ID` header to resume after
+/// the last received event.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Clone)]
+struct ResumePolicy {
+    backoff: Option<reqwest_eventsource::retry::ExponentialBackoff>,
+    resumable: Arc<std::sync::atomic::AtomicBool>,
+    reconnecting: Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl ResumePolicy {
+    fn new(max_retries: Option<usize>) -> Self {
+        Self {
+            backoff: max_retries.map(|max_retries| {
+                reqwest_eventsource::retry::ExponentialBackoff::new(
+                    Duration::from_millis(300),
+                    2.,
+                    Some(Duration::from_secs(5)),
+                    Some(max_retries),
+                )
+            }),
+            resumable: Default::default(),
+            reconnecting: Default::default(),
+        }
+    }
+
+    fn received(&self, message: &eventsource_stream::Event) {
+        if !message.id.is_empty() {
+            self.resumable
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    /// Whether the last error is followed by a reconnection, and should not end the stream.
+    fn reconnecting(&self) -> bool {
+        self.reconnecting
+            .swap(false, std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl reqwest_eventsource::retry::RetryPolicy for ResumePolicy {
+    fn retry(
+        &self,
+        error: &EventSourceError,
+        last_retry: Option<(usize, Duration)>,
+    ) -> Option<Duration> {
+        let backoff = self.backoff.as_ref()?;
+        if matches!(error, EventSourceError::StreamEnded)
+            || !self.resumable.load(std::sync::atomic::Ordering::Relaxed)
+        {
+            return None;
+        }
+        let delay = backoff.retry(error, last_retry)?;
+        self.reconnecting
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+        Some(delay)
+    }
+
+    fn set_reconnection_time(&mut self, duration: Duration) {
+        if let Some(backoff) = &mut self.backoff {
+            backoff.set_reconnection_time(duration);
+        }
+    }
+}
+
 /// Request which responds with SSE.
 /// [server-sent events](https://developer.mozilla.org/en-US/docs/Web/API/Server-sent_events/Using_server-sent_events#event_stream_format)
 #[cfg(not(target_family = "wasm"))]
 pub(crate) async fn stream<O>(
     mut event_source: EventSource,
+    idle_timeout: Option<Duration>,
+    reconnect: Option<usize>,
 ) -> Pin<Box<dyn Stream<Item = Result<O, OpenAIError>> + Send>>
 where
     O: DeserializeOwned + std::marker::Send + 'static,
 {
+    let resume = ResumePolicy::new(reconnect);
+    event_source.set_retry_policy(Box::new(resume.clone()));
+
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
 
     tokio::spawn(async move {
-        while let Some(ev) = event_source.next().await {
+        loop {
+            let ev = match next_event(&mut event_source, idle_timeout, &tx).await {
+                Ok(Some(ev)) => ev,
+                Ok(None) => break,
+                Err(e) => {
+                    let _ = tx.send(Err(e));
+                    break;
+                }
+            };
+
             match ev {
                 Err(e) => {
+                    if resume.reconnecting() {
+                        tracing::warn!("Stream interrupted, resuming: {e}");
+                        continue;
+                    }
+
                     // Handle StreamEnded gracefully - it's a normal end of stream, not an error
                     // https://github.com/64bit/async-openai/issues/456
                     match &e {
@@ -767,6 +1568,8 @@ where
                 }
                 Ok(event) => match event {
                     Event::Message(message) => {
+                        resume.received(&message);
+
                         if message.data == "[DONE]" {
                             break;
                         }
@@ -799,17 +1602,36 @@ where
 #[cfg(not(target_family = "wasm"))]
 pub(crate) async fn stream_mapped_raw_events<O>(
     mut event_source: EventSource,
+    idle_timeout: Option<Duration>,
+    reconnect: Option<usize>,
     event_mapper: impl Fn(eventsource_stream::Event) -> Result<O, OpenAIError> + Send + 'static,
 ) -> Pin<Box<dyn Stream<Item = Result<O, OpenAIError>> + Send>>
 where
     O: DeserializeOwned + std::marker::Send + 'static,
 {
+    let resume = ResumePolicy::new(reconnect);
+    event_source.set_retry_policy(Box::new(resume.clone()));
+
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
 
     tokio::spawn(async move {
-        while let Some(ev) = event_source.next().await {
+        loop {
+            let ev = match next_event(&mut event_source, idle_timeout, &tx).await {
+                Ok(Some(ev)) => ev,
+                Ok(None) => break,
+                Err(e) => {
+                    let _ = tx.send(Err(e));
+                    break;
+                }
+            };
+
             match ev {
                 Err(e) => {
+                    if resume.reconnecting() {
+                        tracing::warn!("Stream interrupted, resuming: {e}");
+                        continue;
+                    }
+
                     // Handle StreamEnded gracefully - it's a normal end of stream, not an error
                     // https://github.com/64bit/async-openai/issues/456
                     match &e {
@@ -826,6 +1648,8 @@ where
                 }
                 Ok(event) => match event {
                     Event::Message(message) => {
+                        resume.received(&message);
+
                         let mut done = false;
 
                         if message.data == "[DONE]" {
@@ -0,0 +1,123 @@
+use crate::{
+    config::{Config, OpenAIConfig},
+    error::OpenAIError,
+    Client,
+};
+
+/// Builds a [Client] with the HTTP client settings needed behind corporate networks: a proxy,
+/// optionally with basic authentication, and additional root certificates for TLS.
+///
+/// These settings are applied to the [reqwest::ClientBuilder] of the client. For any other
+/// setting, build the [reqwest::Client] yourself and pass it to [Client::with_http_client].
+///
+/// ```
+/// # fn example() -> Result<(), async_openai::error::OpenAIError> {
+/// use async_openai::ClientBuilder;
+///
+/// let client = ClientBuilder::default()
+///     .proxy("http://proxy.internal:3128")
+///     .proxy_basic_auth("user", "password")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientBuilder<C: Config> {
+    config: C,
+    proxy: Option<String>,
+    proxy_basic_auth: Option<(String, String)>,
+    #[cfg(any(
+        feature = "rustls",
+        feature = "rustls-webpki-roots",
+        feature = "native-tls",
+        feature = "native-tls-vendored"
+    ))]
+    root_certificates: Vec<Vec<u8>>,
+}
+
+impl Default for ClientBuilder<OpenAIConfig> {
+    fn default() -> Self {
+        Self::new(OpenAIConfig::default())
+    }
+}
+
+impl<C: Config> ClientBuilder<C> {
+    pub fn new(config: C) -> Self {
+        Self {
+            config,
+            proxy: None,
+            proxy_basic_auth: None,
+            #[cfg(any(
+                feature = "rustls",
+                feature = "rustls-webpki-roots",
+                feature = "native-tls",
+                feature = "native-tls-vendored"
+            ))]
+            root_certificates: vec![],
+        }
+    }
+
+    /// Send all requests, HTTP and HTTPS, through the proxy at `url`, e.g.
+    /// `http://proxy.internal:3128`.
+    ///
+    /// Without it, the proxy of the `HTTP_PROXY`, `HTTPS_PROXY` and `ALL_PROXY` environment
+    /// variables is used, if any.
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Authenticate to the [proxy](Self::proxy) with `username` and `password`.
+    pub fn proxy_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.proxy_basic_auth = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Trust the PEM encoded certificates of `pem`, e.g. the CA bundle of a TLS inspecting proxy,
+    /// in addition to the root certificates of the TLS feature.
+    #[cfg(any(
+        feature = "rustls",
+        feature = "rustls-webpki-roots",
+        feature = "native-tls",
+        feature = "native-tls-vendored"
+    ))]
+    pub fn add_root_certificate_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Builds the client, failing when the proxy URL or a certificate is invalid.
+    pub fn build(self) -> Result<Client<C>, OpenAIError> {
+        let mut http_client = reqwest::Client::builder();
+
+        if let Some(url) = &self.proxy {
+            let mut proxy = reqwest::Proxy::all(url)?;
+            if let Some((username, password)) = &self.proxy_basic_auth {
+                proxy = proxy.basic_auth(username, password);
+            }
+            http_client = http_client.proxy(proxy);
+        } else if self.proxy_basic_auth.is_some() {
+            return Err(OpenAIError::InvalidArgument(
+                "proxy_basic_auth requires a proxy".into(),
+            ));
+        }
+
+        #[cfg(any(
+            feature = "rustls",
+            feature = "rustls-webpki-roots",
+            feature = "native-tls",
+            feature = "native-tls-vendored"
+        ))]
+        for pem in &self.root_certificates {
+            for certificate in reqwest::Certificate::from_pem_bundle(pem)? {
+                http_client = http_client.add_root_certificate(certificate);
+            }
+        }
+
+        Ok(Client::with_config(self.config).with_http_client(http_client.build()?))
+    }
+}
@@ -1,4 +1,7 @@
-//! Client configurations: [OpenAIConfig] for OpenAI, [AzureConfig] for Azure OpenAI Service.
+//! Client configurations: [OpenAIConfig] for OpenAI, [AzureConfig] for Azure OpenAI Service,
+//! [CompatConfig] for OpenAI-compatible servers.
+use std::sync::Arc;
+
 use reqwest::header::{HeaderMap, AUTHORIZATION};
 use secrecy::{ExposeSecret, SecretString};
 use serde::Deserialize;
@@ -25,6 +28,10 @@ pub trait Config: Send + Sync {
     fn api_base(&self) -> &str;
 
     fn api_key(&self) -> &SecretString;
+
+    /// Removes the headers not to send from the final `headers` of a request, which include
+    /// those set with [RequestOptions](crate::RequestOptions)
+    fn filter_headers(&self, _headers: &mut HeaderMap) {}
 }
 
 /// Macro to implement Config trait for pointer types with dyn objects
@@ -46,6 +53,9 @@ macro_rules! impl_config_for_ptr {
             fn api_key(&self) -> &SecretString {
                 self.as_ref().api_key()
             }
+            fn filter_headers(&self, headers: &mut HeaderMap) {
+                self.as_ref().filter_headers(headers)
+            }
         }
     };
 }
@@ -54,29 +64,15 @@ impl_config_for_ptr!(Box<dyn Config>);
 impl_config_for_ptr!(std::sync::Arc<dyn Config>);
 
 // Private helper functions for default values
-fn default_api_base() -> String {
-    #[cfg(not(target_family = "wasm"))]
-    {
-        std::env::var("OPENAI_BASE_URL").unwrap_or_else(|_| OPENAI_API_BASE.to_string())
-    }
-
-    #[cfg(target_family = "wasm")]
-    {
-        OPENAI_API_BASE.to_string()
-    }
+#[cfg(not(target_family = "wasm"))]
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
 }
 
 fn default_api_key() -> String {
     #[cfg(not(target_family = "wasm"))]
     {
-        std::env::var("OPENAI_API_KEY")
-            .or_else(|_| {
-                std::env::var("OPENAI_ADMIN_KEY").map(|admin_key| {
-                    tracing::warn!("Using OPENAI_ADMIN_KEY, OPENAI_API_KEY not set");
-                    admin_key
-                })
-            })
-            .unwrap_or_default()
+        api_key_from_vars(&env_var)
     }
 
     #[cfg(target_family = "wasm")]
@@ -85,27 +81,26 @@ fn default_api_key() -> String {
     }
 }
 
-fn default_org_id() -> String {
-    #[cfg(not(target_family = "wasm"))]
-    {
-        std::env::var("OPENAI_ORG_ID").unwrap_or_default()
-    }
-
-    #[cfg(target_family = "wasm")]
-    {
-        String::new()
-    }
+#[cfg(not(target_family = "wasm"))]
+fn api_key_from_vars(var: &impl Fn(&str) -> Option<String>) -> String {
+    var("OPENAI_API_KEY")
+        .or_else(|| {
+            var("OPENAI_ADMIN_KEY").map(|admin_key| {
+                tracing::warn!("Using OPENAI_ADMIN_KEY, OPENAI_API_KEY not set");
+                admin_key
+            })
+        })
+        .unwrap_or_default()
 }
 
-fn default_project_id() -> String {
-    #[cfg(not(target_family = "wasm"))]
-    {
-        std::env::var("OPENAI_PROJECT_ID").unwrap_or_default()
-    }
-
-    #[cfg(target_family = "wasm")]
-    {
-        String::new()
+/// Joins `api_base` and `path` with exactly one `/` between them, keeping any path prefix of `api_base`.
+fn join_url(api_base: &str, path: &str) -> String {
+    let api_base = api_base.trim_end_matches('/');
+    let path = path.trim_start_matches('/');
+    if path.is_empty() {
+        api_base.to_string()
+    } else {
+        format!("{api_base}/{path}")
     }
 }
 
@@ -123,11 +118,33 @@ pub struct OpenAIConfig {
 
 impl Default for OpenAIConfig {
     fn default() -> Self {
+        #[cfg(not(target_family = "wasm"))]
+        {
+            Self::from_vars(env_var)
+        }
+
+        #[cfg(target_family = "wasm")]
+        {
+            Self {
+                api_base: OPENAI_API_BASE.to_string(),
+                api_key: String::new().into(),
+                org_id: String::new(),
+                project_id: String::new(),
+                custom_headers: HeaderMap::new(),
+            }
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl OpenAIConfig {
+    /// The default configuration, with the environment variables read by `var`.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
         Self {
-            api_base: default_api_base(),
-            api_key: default_api_key().into(),
-            org_id: default_org_id(),
-            project_id: default_project_id(),
+            api_base: var("OPENAI_BASE_URL").unwrap_or_else(|| OPENAI_API_BASE.to_string()),
+            api_key: api_key_from_vars(&var).into(),
+            org_id: var("OPENAI_ORG_ID").unwrap_or_default(),
+            project_id: var("OPENAI_PROJECT_ID").unwrap_or_default(),
             custom_headers: HeaderMap::new(),
         }
     }
@@ -139,13 +156,15 @@ impl OpenAIConfig {
         Default::default()
     }
 
-    /// To use a different organization id other than default
+    /// To use a different organization id than the default `OPENAI_ORG_ID` env var, sent in the
+    /// [OPENAI_ORGANIZATION_HEADER]. An empty id is not sent.
     pub fn with_org_id<S: Into<String>>(mut self, org_id: S) -> Self {
         self.org_id = org_id.into();
         self
     }
 
-    /// Non default project id
+    /// To use a different project id than the default `OPENAI_PROJECT_ID` env var, sent in the
+    /// [OPENAI_PROJECT_HEADER] to attribute usage to the project. An empty id is not sent.
     pub fn with_project_id<S: Into<String>>(mut self, project_id: S) -> Self {
         self.project_id = project_id.into();
         self
@@ -181,6 +200,10 @@ impl OpenAIConfig {
     pub fn org_id(&self) -> &str {
         &self.org_id
     }
+
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
 }
 
 impl Config for OpenAIConfig {
@@ -217,7 +240,7 @@ impl Config for OpenAIConfig {
     }
 
     fn url(&self, path: &str) -> String {
-        format!("{}{}", self.api_base, path)
+        join_url(&self.api_base, path)
     }
 
     fn api_base(&self) -> &str {
@@ -233,6 +256,16 @@ impl Config for OpenAIConfig {
     }
 }
 
+/// Provides Azure AD (Microsoft Entra ID) bearer tokens for [AzureConfig]
+#[derive(Clone)]
+pub struct AzureTokenProvider(Arc<dyn Fn() -> String + Send + Sync>);
+
+impl std::fmt::Debug for AzureTokenProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AzureTokenProvider")
+    }
+}
+
 /// Configuration for Azure OpenAI Service
 #[derive(Clone, Debug, Deserialize)]
 #[serde(default)]
@@ -241,6 +274,8 @@ pub struct AzureConfig {
     deployment_id: String,
     api_base: String,
     api_key: SecretString,
+    #[serde(skip)]
+    token_provider: Option<AzureTokenProvider>,
 }
 
 impl Default for AzureConfig {
@@ -250,6 +285,7 @@ impl Default for AzureConfig {
             api_key: default_api_key().into(),
             deployment_id: Default::default(),
             api_version: Default::default(),
+            token_provider: None,
         }
     }
 }
@@ -280,21 +316,87 @@ impl AzureConfig {
         self.api_base = api_base.into();
         self
     }
+
+    /// Authenticate with Azure AD (Microsoft Entra ID) instead of an API key.
+    ///
+    /// `token_provider` is called for every request to build the `Authorization: Bearer` header,
+    /// so it can hand out refreshed tokens. It is called from synchronous code and should return
+    /// a cached token rather than block on fetching a new one.
+    pub fn with_token_provider<F>(mut self, token_provider: F) -> Self
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {
+        self.token_provider = Some(AzureTokenProvider(Arc::new(token_provider)));
+        self
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl AzureConfig {
+    /// Configuration from the environment variables of the official Azure OpenAI SDKs:
+    /// `AZURE_OPENAI_ENDPOINT` for the API base, `AZURE_OPENAI_API_KEY` for the API key (falling
+    /// back to `OPENAI_API_KEY`) and `OPENAI_API_VERSION`, along with `AZURE_OPENAI_DEPLOYMENT`
+    /// for the deployment.
+    pub fn from_env() -> Self {
+        Self::from_vars(env_var)
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        Self {
+            api_base: var("AZURE_OPENAI_ENDPOINT").unwrap_or_default(),
+            api_key: var("AZURE_OPENAI_API_KEY")
+                .or_else(|| var("OPENAI_API_KEY"))
+                .unwrap_or_default()
+                .into(),
+            deployment_id: var("AZURE_OPENAI_DEPLOYMENT").unwrap_or_default(),
+            api_version: var("OPENAI_API_VERSION").unwrap_or_default(),
+            token_provider: None,
+        }
+    }
+}
+
+/// An [AzureConfig] when `AZURE_OPENAI_ENDPOINT` is set, an [OpenAIConfig] otherwise, see
+/// [Client::from_env](crate::Client::from_env).
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn config_from_env() -> Box<dyn Config> {
+    config_from_vars(env_var)
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn config_from_vars(var: impl Fn(&str) -> Option<String>) -> Box<dyn Config> {
+    // without an endpoint there is no Azure resource to send requests to
+    match var("AZURE_OPENAI_ENDPOINT") {
+        Some(_) => Box::new(AzureConfig::from_vars(var)),
+        None => Box::new(OpenAIConfig::from_vars(var)),
+    }
 }
 
 impl Config for AzureConfig {
     fn headers(&self) -> HeaderMap {
         let mut headers = HeaderMap::new();
 
-        headers.insert("api-key", self.api_key.expose_secret().parse().unwrap());
+        if let Some(token_provider) = &self.token_provider {
+            headers.insert(
+                AUTHORIZATION,
+                format!("Bearer {}", (token_provider.0)())
+                    .as_str()
+                    .parse()
+                    .unwrap(),
+            );
+        } else {
+            headers.insert("api-key", self.api_key.expose_secret().parse().unwrap());
+        }
 
         headers
     }
 
     fn url(&self, path: &str) -> String {
-        format!(
-            "{}/openai/deployments/{}{}",
-            self.api_base, self.deployment_id, path
+        join_url(
+            &join_url(
+                &self.api_base,
+                &format!("/openai/deployments/{}", self.deployment_id),
+            ),
+            path,
         )
     }
 
@@ -311,6 +413,115 @@ impl Config for AzureConfig {
     }
 }
 
+/// Default API base url of [CompatConfig], the address vLLM serves on
+pub const COMPAT_API_BASE: &str = "http://localhost:8000/v1";
+
+/// Configuration for OpenAI-compatible servers such as Ollama, LM Studio or vLLM
+///
+/// Unlike [OpenAIConfig] no API key is read from the environment, and the `Authorization`
+/// header is only sent when an API key is set.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct CompatConfig {
+    api_base: String,
+    api_key: SecretString,
+    beta_headers: bool,
+    #[serde(skip)]
+    custom_headers: HeaderMap,
+}
+
+impl Default for CompatConfig {
+    fn default() -> Self {
+        Self {
+            api_base: COMPAT_API_BASE.to_string(),
+            api_key: SecretString::from(String::new()),
+            beta_headers: true,
+            custom_headers: HeaderMap::new(),
+        }
+    }
+}
+
+impl CompatConfig {
+    /// Create config with default [COMPAT_API_BASE] url and no API key
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// API base url of the server, for example `http://localhost:11434/v1` for Ollama
+    pub fn with_api_base<S: Into<String>>(mut self, api_base: S) -> Self {
+        self.api_base = api_base.into();
+        self
+    }
+
+    /// Send `Authorization: Bearer {api_key}` with every request
+    pub fn with_api_key<S: Into<String>>(mut self, api_key: S) -> Self {
+        self.api_key = SecretString::from(api_key.into());
+        self
+    }
+
+    /// Do not send [OPENAI_BETA_HEADER] headers, which some servers reject, including those
+    /// set on individual requests
+    pub fn without_beta_headers(mut self) -> Self {
+        self.beta_headers = false;
+        self
+    }
+
+    /// Add a custom header that will be included in all requests.
+    pub fn with_header<K, V>(mut self, key: K, value: V) -> Result<Self, OpenAIError>
+    where
+        K: reqwest::header::IntoHeaderName,
+        V: TryInto<reqwest::header::HeaderValue>,
+        V::Error: Into<reqwest::header::InvalidHeaderValue>,
+    {
+        let header_value = value.try_into().map_err(|e| {
+            OpenAIError::InvalidArgument(format!("Invalid header value: {}", e.into()))
+        })?;
+        self.custom_headers.insert(key, header_value);
+        Ok(self)
+    }
+}
+
+impl Config for CompatConfig {
+    fn headers(&self) -> HeaderMap {
+        let mut headers = self.custom_headers.clone();
+
+        if !self.api_key.expose_secret().is_empty() {
+            headers.insert(
+                AUTHORIZATION,
+                format!("Bearer {}", self.api_key.expose_secret())
+                    .as_str()
+                    .parse()
+                    .unwrap(),
+            );
+        }
+
+        self.filter_headers(&mut headers);
+        headers
+    }
+
+    fn url(&self, path: &str) -> String {
+        join_url(&self.api_base, path)
+    }
+
+    fn api_base(&self) -> &str {
+        &self.api_base
+    }
+
+    fn api_key(&self) -> &SecretString {
+        &self.api_key
+    }
+
+    fn query(&self) -> Vec<(&str, &str)> {
+        vec![]
+    }
+
+    fn filter_headers(&self, headers: &mut HeaderMap) {
+        if !self.beta_headers {
+            headers.remove(OPENAI_BETA_HEADER);
+        }
+    }
+}
+
 #[cfg(all(test, feature = "chat-completion"))]
 mod test {
     use super::*;
@@ -318,7 +529,7 @@ mod test {
         ChatCompletionRequestMessage, ChatCompletionRequestUserMessage, CreateChatCompletionRequest,
     };
     use crate::Client;
-    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     #[test]
     fn test_client_creation() {
         unsafe { std::env::set_var("OPENAI_API_KEY", "test") }
@@ -335,7 +546,7 @@ mod test {
     }
 
     async fn dynamic_dispatch_compiles(client: &Client<Box<dyn Config>>) {
-        let _ = client.chat().create(CreateChatCompletionRequest {
+        drop(client.chat().create(CreateChatCompletionRequest {
             model: "gpt-4o".to_string(),
             messages: vec![ChatCompletionRequestMessage::User(
                 ChatCompletionRequestUserMessage {
@@ -344,21 +555,190 @@ mod test {
                 },
             )],
             ..Default::default()
-        });
+        }));
     }
 
     #[tokio::test]
     async fn test_dynamic_dispatch() {
         let openai_config = OpenAIConfig::default();
         let azure_config = AzureConfig::default();
+        let compat_config = CompatConfig::default();
 
         let azure_client = Client::with_config(Box::new(azure_config.clone()) as Box<dyn Config>);
         let oai_client = Client::with_config(Box::new(openai_config.clone()) as Box<dyn Config>);
+        let compat_client = Client::with_config(Box::new(compat_config) as Box<dyn Config>);
 
         let _ = dynamic_dispatch_compiles(&azure_client).await;
         let _ = dynamic_dispatch_compiles(&oai_client).await;
+        let _ = dynamic_dispatch_compiles(&compat_client).await;
+
+        drop(tokio::spawn(async move {
+            dynamic_dispatch_compiles(&azure_client).await
+        }));
+        drop(tokio::spawn(async move {
+            dynamic_dispatch_compiles(&oai_client).await
+        }));
+    }
+
+    #[test]
+    fn test_org_and_project_headers() {
+        let config = OpenAIConfig::new()
+            .with_api_key("key")
+            .with_org_id("")
+            .with_project_id("");
+        let headers = config.headers();
+        assert!(headers.get(OPENAI_ORGANIZATION_HEADER).is_none());
+        assert!(headers.get(OPENAI_PROJECT_HEADER).is_none());
+
+        let config = config.with_org_id("org-123").with_project_id("proj_456");
+        let headers = config.headers();
+        assert_eq!(headers[OPENAI_ORGANIZATION_HEADER], "org-123");
+        assert_eq!(headers[OPENAI_PROJECT_HEADER], "proj_456");
+        assert_eq!(
+            (config.org_id(), config.project_id()),
+            ("org-123", "proj_456")
+        );
+
+        let headers = config.with_project_id("").headers();
+        assert_eq!(headers[OPENAI_ORGANIZATION_HEADER], "org-123");
+        assert!(headers.get(OPENAI_PROJECT_HEADER).is_none());
+    }
+
+    #[test]
+    fn test_config_from_vars() {
+        let vars = std::collections::HashMap::from([
+            ("AZURE_OPENAI_ENDPOINT", "https://example.openai.azure.com"),
+            ("AZURE_OPENAI_API_KEY", "azure-key"),
+            ("AZURE_OPENAI_DEPLOYMENT", "gpt-4o"),
+            ("OPENAI_API_VERSION", "2024-10-21"),
+            ("OPENAI_API_KEY", "sk-openai"),
+        ]);
+        let config = config_from_vars(|name| vars.get(name).map(|value| value.to_string()));
+        assert_eq!(
+            config.url("/chat/completions"),
+            "https://example.openai.azure.com/openai/deployments/gpt-4o/chat/completions"
+        );
+        assert_eq!(config.query(), [("api-version", "2024-10-21")]);
+        assert_eq!(config.headers()["api-key"], "azure-key");
+
+        // the OpenAI key is used by Azure without an Azure key
+        let config = config_from_vars(|name| match name {
+            "AZURE_OPENAI_ENDPOINT" => Some("https://example.openai.azure.com".into()),
+            "OPENAI_API_KEY" => Some("sk-openai".into()),
+            _ => None,
+        });
+        assert_eq!(config.headers()["api-key"], "sk-openai");
+
+        let config = config_from_vars(|name| match name {
+            "OPENAI_API_KEY" => Some("sk-openai".into()),
+            "OPENAI_BASE_URL" => Some("http://localhost:8080/v1".into()),
+            _ => None,
+        });
+        assert!(config.query().is_empty());
+        assert!(config.headers().get("api-key").is_none());
+        assert_eq!(config.headers()[AUTHORIZATION], "Bearer sk-openai");
+        assert_eq!(config.url("/models"), "http://localhost:8080/v1/models");
+
+        // an Azure key alone has no endpoint to send requests to
+        let config = config_from_vars(|name| match name {
+            "AZURE_OPENAI_API_KEY" => Some("azure-key".into()),
+            _ => None,
+        });
+        assert!(config.headers().get("api-key").is_none());
+        assert_eq!(config.url("/models"), "https://api.openai.com/v1/models");
+    }
+
+    #[test]
+    fn test_azure_api_key_header() {
+        let config = AzureConfig::new().with_api_key("azure-key");
+        let headers = config.headers();
+
+        assert_eq!(headers["api-key"], "azure-key");
+        assert!(headers.get(AUTHORIZATION).is_none());
+    }
+
+    #[test]
+    fn test_azure_token_provider_header() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let config = AzureConfig::new()
+            .with_api_key("azure-key")
+            .with_token_provider(move || {
+                format!("token-{}", counter.fetch_add(1, Ordering::SeqCst))
+            });
+
+        let headers = config.headers();
+        assert_eq!(headers[AUTHORIZATION], "Bearer token-0");
+        assert!(headers.get("api-key").is_none());
+
+        // the token is fetched again for every request
+        assert_eq!(config.clone().headers()[AUTHORIZATION], "Bearer token-1");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_url_normalization() {
+        for api_base in ["https://proxy.local/v1", "https://proxy.local/v1/"] {
+            let config = OpenAIConfig::new().with_api_base(api_base);
+            assert_eq!(
+                config.url("/chat/completions"),
+                "https://proxy.local/v1/chat/completions"
+            );
+            assert_eq!(
+                config.url("/embeddings"),
+                "https://proxy.local/v1/embeddings"
+            );
+            assert_eq!(config.url(""), "https://proxy.local/v1");
+        }
+
+        let config = OpenAIConfig::new().with_api_base("https://gw/openai");
+        assert_eq!(
+            config.url("/chat/completions"),
+            "https://gw/openai/chat/completions"
+        );
+        assert_eq!(
+            config.url("models/gpt-4o"),
+            "https://gw/openai/models/gpt-4o"
+        );
+
+        let config = OpenAIConfig::new().with_api_base("https://gw/team/openai/v1/");
+        assert_eq!(
+            config.url("/embeddings"),
+            "https://gw/team/openai/v1/embeddings"
+        );
+    }
+
+    #[test]
+    fn test_azure_url_normalization() {
+        for api_base in [
+            "https://my-resource.openai.azure.com",
+            "https://my-resource.openai.azure.com/",
+        ] {
+            let config = AzureConfig::new()
+                .with_api_base(api_base)
+                .with_deployment_id("gpt-4o");
+            assert_eq!(
+                config.url("/chat/completions"),
+                "https://my-resource.openai.azure.com/openai/deployments/gpt-4o/chat/completions"
+            );
+        }
+    }
+
+    #[test]
+    fn test_compat_config_headers() {
+        let config = CompatConfig::new()
+            .with_api_base("http://localhost:11434/v1/")
+            .with_header(OPENAI_BETA_HEADER, "assistants=v2")
+            .unwrap();
+        assert_eq!(
+            config.url("/chat/completions"),
+            "http://localhost:11434/v1/chat/completions"
+        );
+        assert!(config.headers().get(AUTHORIZATION).is_none());
+        assert_eq!(config.headers()[OPENAI_BETA_HEADER], "assistants=v2");
 
-        let _ = tokio::spawn(async move { dynamic_dispatch_compiles(&azure_client).await });
-        let _ = tokio::spawn(async move { dynamic_dispatch_compiles(&oai_client).await });
+        let config = config.with_api_key("sk-local").without_beta_headers();
+        assert_eq!(config.headers()[AUTHORIZATION], "Bearer sk-local");
+        assert!(config.headers().get(OPENAI_BETA_HEADER).is_none());
     }
 }
@@ -25,11 +25,7 @@ fn create_paths<P: AsRef<Path>>(url: &Url, base_dir: P) -> (PathBuf, PathBuf) {
     (dir, path)
 }
 
-pub(crate) async fn download_url<P: AsRef<Path>>(
-    url: &str,
-    dir: P,
-) -> Result<PathBuf, OpenAIError> {
-    let parsed_url = Url::parse(url).map_err(|e| OpenAIError::FileSaveError(e.to_string()))?;
+pub(crate) async fn download_bytes(url: &str) -> Result<bytes::Bytes, OpenAIError> {
     let response = reqwest::get(url)
         .await
         .map_err(|e| OpenAIError::FileSaveError(e.to_string()))?;
@@ -41,43 +37,63 @@ pub(crate) async fn download_url<P: AsRef<Path>>(
         )));
     }
 
+    response
+        .bytes()
+        .await
+        .map_err(|e| OpenAIError::FileSaveError(format!("{e}, url: {url}")))
+}
+
+pub(crate) async fn download_url<P: AsRef<Path>>(
+    url: &str,
+    dir: P,
+) -> Result<PathBuf, OpenAIError> {
+    let parsed_url = Url::parse(url).map_err(|e| OpenAIError::FileSaveError(e.to_string()))?;
+    let bytes = download_bytes(url).await?;
+
     let (dir, file_path) = create_paths(&parsed_url, dir);
 
     tokio::fs::create_dir_all(dir.as_path())
         .await
         .map_err(|e| OpenAIError::FileSaveError(format!("{}, dir: {}", e, dir.display())))?;
 
-    tokio::fs::write(
-        file_path.as_path(),
-        response.bytes().await.map_err(|e| {
-            OpenAIError::FileSaveError(format!("{}, file path: {}", e, file_path.display()))
-        })?,
-    )
-    .await
-    .map_err(|e| OpenAIError::FileSaveError(e.to_string()))?;
+    tokio::fs::write(file_path.as_path(), bytes)
+        .await
+        .map_err(|e| OpenAIError::FileSaveError(e.to_string()))?;
 
     Ok(file_path)
 }
 
+pub(crate) fn decode_b64(b64: &str) -> Result<Vec<u8>, OpenAIError> {
+    general_purpose::STANDARD
+        .decode(b64)
+        .map_err(|e| OpenAIError::FileSaveError(e.to_string()))
+}
+
+/// Extension of the image format of `bytes`, `png` when not recognized.
+fn image_extension(bytes: &[u8]) -> &'static str {
+    match bytes {
+        [0xFF, 0xD8, 0xFF, ..] => "jpeg",
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => "webp",
+        _ => "png",
+    }
+}
+
 pub(crate) async fn save_b64<P: AsRef<Path>>(b64: &str, dir: P) -> Result<PathBuf, OpenAIError> {
+    let bytes = decode_b64(b64)?;
+
     let filename: String = rand::rng()
         .sample_iter(&Alphanumeric)
         .take(10)
         .map(char::from)
         .collect();
 
-    let filename = format!("{filename}.png");
+    let filename = format!("{filename}.{}", image_extension(&bytes));
 
     let path = PathBuf::from(dir.as_ref()).join(filename);
 
-    tokio::fs::write(
-        path.as_path(),
-        general_purpose::STANDARD
-            .decode(b64)
-            .map_err(|e| OpenAIError::FileSaveError(e.to_string()))?,
-    )
-    .await
-    .map_err(|e| OpenAIError::FileSaveError(format!("{}, path: {}", e, path.display())))?;
+    tokio::fs::write(path.as_path(), bytes)
+        .await
+        .map_err(|e| OpenAIError::FileSaveError(format!("{}, path: {}", e, path.display())))?;
 
     Ok(path)
 }
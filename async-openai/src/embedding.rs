@@ -1,8 +1,13 @@
+use std::collections::HashMap;
+
+use futures::{stream, StreamExt, TryStreamExt};
+
 use crate::{
     config::Config,
     error::OpenAIError,
     types::embeddings::{
         CreateBase64EmbeddingResponse, CreateEmbeddingRequest, CreateEmbeddingResponse,
+        CreateEncodedEmbeddingResponse, Embedding, EmbeddingInput,
     },
     Client, RequestOptions,
 };
@@ -48,9 +53,123 @@ impl<'c, C: Config> Embeddings<'c, C> {
             .await
     }
 
+    /// Embeds `inputs` in sub-requests of at most `max_batch` inputs each, and returns the
+    /// embeddings in the order of `inputs`.
+    ///
+    /// The model and other parameters of the sub-requests are taken from `request`, its `input`
+    /// is ignored. Up to `parallelism` sub-requests are in flight at once, use `1` to send them
+    /// one after another. The [index](Embedding::index) of each embedding is its position in
+    /// `inputs`. The first error in input order is returned, including a
+    /// [JSONDeserialize](OpenAIError::JSONDeserialize) error when a response does not have one
+    /// embedding per input of its sub-request.
+    ///
+    /// The API accepts up to 2048 inputs and 300,000 tokens summed across inputs per request, so
+    /// pick `max_batch` such that a batch of your inputs stays under both limits.
+    pub async fn create_batched(
+        &self,
+        request: CreateEmbeddingRequest,
+        inputs: Vec<String>,
+        max_batch: usize,
+        parallelism: usize,
+    ) -> Result<Vec<Embedding>, OpenAIError> {
+        if max_batch == 0 || parallelism == 0 {
+            return Err(OpenAIError::InvalidArgument(
+                "max_batch and parallelism must be greater than 0".into(),
+            ));
+        }
+
+        let requests: Vec<_> = inputs
+            .chunks(max_batch)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let mut request = request.clone();
+                request.input = EmbeddingInput::StringArray(chunk.to_vec());
+                (i * max_batch, chunk.len(), request)
+            })
+            .collect();
+
+        let responses: Vec<_> = stream::iter(requests)
+            .map(|(offset, len, request)| async move {
+                let response = self.create(request).await?;
+                // a short response would shift the embeddings of all later inputs
+                if response.data.len() != len {
+                    let message = format!(
+                        "expected {len} embeddings for inputs {offset}..{}, got {}",
+                        offset + len,
+                        response.data.len()
+                    );
+                    return Err(OpenAIError::JSONDeserialize(
+                        serde::de::Error::custom(message),
+                        String::new(),
+                    ));
+                }
+                Ok((offset, response))
+            })
+            .buffered(parallelism)
+            .try_collect()
+            .await?;
+
+        let mut embeddings = Vec::with_capacity(inputs.len());
+        for (offset, response) in responses {
+            let mut data = response.data;
+            data.sort_by_key(|embedding| embedding.index);
+            embeddings.extend(data.into_iter().map(|mut embedding| {
+                embedding.index += offset as u32;
+                embedding
+            }));
+        }
+        Ok(embeddings)
+    }
+
+    /// Like [create_batched](Self::create_batched), but embeds each distinct input once: the
+    /// embeddings are returned in the order of `inputs`, and duplicates share the same embedding.
+    ///
+    /// Empty strings are rejected before any request is sent, as the API does not embed them.
+    pub async fn create_deduped(
+        &self,
+        request: CreateEmbeddingRequest,
+        inputs: Vec<String>,
+        max_batch: usize,
+        parallelism: usize,
+    ) -> Result<Vec<Embedding>, OpenAIError> {
+        if let Some(index) = inputs.iter().position(|input| input.is_empty()) {
+            return Err(OpenAIError::InvalidArgument(format!(
+                "input {index} is an empty string, which cannot be embedded"
+            )));
+        }
+
+        // position in `unique` of each input
+        let mut unique = Vec::new();
+        let mut positions = HashMap::new();
+        let slots: Vec<usize> = inputs
+            .iter()
+            .map(|input| {
+                *positions.entry(input.as_str()).or_insert_with(|| {
+                    unique.push(input.clone());
+                    unique.len() - 1
+                })
+            })
+            .collect();
+
+        let embeddings = self
+            .create_batched(request, unique, max_batch, parallelism)
+            .await?;
+
+        Ok(slots
+            .into_iter()
+            .enumerate()
+            .map(|(index, slot)| Embedding {
+                index: index as u32,
+                ..embeddings[slot].clone()
+            })
+            .collect())
+    }
+
     /// Creates an embedding vector representing the input text.
     ///
-    /// The response will contain the embedding in base64 format.
+    /// The response will contain the embedding in base64 format, which is smaller on the wire,
+    /// use [Base64EmbeddingVector::to_f32](crate::types::embeddings::Base64EmbeddingVector::to_f32)
+    /// to decode it.
     ///
     /// byot: In serialized `request` you must ensure "encoding_format" is "base64"
     #[crate::byot(T0 = serde::Serialize, R = serde::de::DeserializeOwned)]
@@ -70,10 +189,26 @@ impl<'c, C: Config> Embeddings<'c, C> {
             .post("/embeddings", request, &self.request_options)
             .await
     }
+
+    /// Creates an embedding vector representing the input text, in either `encoding_format`.
+    ///
+    /// Each embedding is an [EmbeddingVector](crate::types::embeddings::EmbeddingVector) of
+    /// floats or base64, use its [to_f32](crate::types::embeddings::EmbeddingVector::to_f32) to
+    /// get the floats of either.
+    #[crate::byot(T0 = serde::Serialize, R = serde::de::DeserializeOwned)]
+    pub async fn create_encoded(
+        &self,
+        request: CreateEmbeddingRequest,
+    ) -> Result<CreateEncodedEmbeddingResponse, OpenAIError> {
+        self.client
+            .post("/embeddings", request, &self.request_options)
+            .await
+    }
 }
 
 #[cfg(all(test, feature = "embedding"))]
 mod tests {
+    #[cfg(not(feature = "byot"))]
     use crate::error::OpenAIError;
     use crate::types::embeddings::{CreateEmbeddingResponse, Embedding, EncodingFormat};
     use crate::{types::embeddings::CreateEmbeddingRequestArgs, Client};
@@ -181,6 +316,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[cfg(not(feature = "byot"))]
     async fn test_cannot_use_base64_encoding_with_normal_create_request() {
         let client = Client::new();
 
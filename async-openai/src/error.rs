@@ -11,7 +11,8 @@ pub enum OpenAIError {
     /// OpenAI returns error object with details of API call failure
     #[error("{0}")]
     ApiError(ApiError),
-    /// Error when a response cannot be deserialized into a Rust type
+    /// Error when a response cannot be deserialized into a Rust type, with the body of the
+    /// response, truncated when longer than 8 KiB
     #[error("failed to deserialize api response: error:{0} content:{1}")]
     JSONDeserialize(serde_json::Error, String),
     /// Error on the client side when saving file to file system
@@ -27,6 +28,25 @@ pub enum OpenAIError {
     /// or when builder fails to build request before making API call
     #[error("invalid args: {0}")]
     InvalidArgument(String),
+    /// The model refused to respond to a structured output request
+    #[error("model refused to respond: {0}")]
+    Refusal(String),
+    /// A long running operation did not finish within the timeout, see [PollOptions::timeout](crate::PollOptions::timeout)
+    #[error("timed out after {0:?}")]
+    Timeout(std::time::Duration),
+}
+
+#[cfg(feature = "_api")]
+impl OpenAIError {
+    /// The `x-request-id` of the response for [OpenAIError::ApiError], to quote when contacting
+    /// OpenAI support. Successful responses carry it in their headers, e.g. from
+    /// [Chat::create_with_headers](crate::Chat::create_with_headers).
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            OpenAIError::ApiError(api_error) => api_error.request_id.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 // no streaming support for wasm yet
@@ -39,13 +59,17 @@ pub enum OpenAIError {
     /// OpenAI returns error object with details of API call failure
     #[error("{0}")]
     ApiError(ApiError),
-    /// Error when a response cannot be deserialized into a Rust type
+    /// Error when a response cannot be deserialized into a Rust type, with the body of the
+    /// response, truncated when longer than 8 KiB
     #[error("failed to deserialize api response: error:{0} content:{1}")]
     JSONDeserialize(serde_json::Error, String),
     /// Error from client side validation
     /// or when builder fails to build request before making API call
     #[error("invalid args: {0}")]
     InvalidArgument(String),
+    /// The model refused to respond to a structured output request
+    #[error("model refused to respond: {0}")]
+    Refusal(String),
 }
 
 #[cfg(not(feature = "_api"))]
@@ -80,22 +104,104 @@ pub enum StreamError {
     /// Error from eventsource_stream when parsing SSE
     #[error("EventStream error: {0}")]
     EventStream(String),
+    /// No event was received within the stream idle timeout
+    #[error("no event received for {0:?}")]
+    IdleTimeout(std::time::Duration),
+    /// Error on the WebSocket of a Realtime session
+    #[cfg(feature = "realtime")]
+    #[error("WebSocket error: {0}")]
+    WebSocket(#[from] tokio_tungstenite::tungstenite::Error),
 }
 
 /// OpenAI API returns error object on failure
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
-#[schema(as = Error)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "utoipa", schema(as = Error))]
 pub struct ApiError {
     pub message: String,
     pub r#type: Option<String>,
+    /// The request parameter the error relates to, if any.
     pub param: Option<String>,
+    /// Machine readable error code, e.g. `context_length_exceeded`, see [error_code](Self::error_code).
+    #[serde(default, deserialize_with = "deserialize_code")]
     pub code: Option<String>,
+    /// HTTP status code of the response, set when the error was returned for an API call.
+    #[serde(skip)]
+    pub status: Option<u16>,
+    /// The `x-request-id` header of the response, to quote when contacting OpenAI support.
+    // boxed to keep `OpenAIError` small
+    #[serde(skip)]
+    pub request_id: Option<Box<str>>,
+}
+
+impl ApiError {
+    /// The [code](Self::code) as an [ApiErrorCode].
+    pub fn error_code(&self) -> Option<ApiErrorCode> {
+        self.code.as_deref().map(ApiErrorCode::from)
+    }
+}
+
+/// Accepts numeric codes too, as returned by some OpenAI compatible servers.
+fn deserialize_code<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::String(code)) => Some(code),
+            Some(other) => Some(other.to_string()),
+        },
+    )
+}
+
+/// Common values of [ApiError::code].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ApiErrorCode {
+    /// The messages exceed the context window of the model.
+    ContextLengthExceeded,
+    /// Too many requests or tokens, retry after a while.
+    RateLimitExceeded,
+    /// The API key is invalid, revoked or missing.
+    InvalidApiKey,
+    /// The quota or credit of the account has run out.
+    InsufficientQuota,
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl From<&str> for ApiErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "context_length_exceeded" => Self::ContextLengthExceeded,
+            "rate_limit_exceeded" => Self::RateLimitExceeded,
+            "invalid_api_key" => Self::InvalidApiKey,
+            "insufficient_quota" => Self::InsufficientQuota,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = match self {
+            Self::ContextLengthExceeded => "context_length_exceeded",
+            Self::RateLimitExceeded => "rate_limit_exceeded",
+            Self::InvalidApiKey => "invalid_api_key",
+            Self::InsufficientQuota => "insufficient_quota",
+            Self::Other(code) => code,
+        };
+        f.write_str(code)
+    }
 }
 
 impl std::fmt::Display for ApiError {
     /// If all fields are available, `ApiError` is formatted as:
-    /// `{type}: {message} (param: {param}) (code: {code})`
+    /// `{type}: {message} (param: {param}) (code: {code}) (status: {status})`
     /// Otherwise, missing fields will be ignored.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut parts = Vec::new();
@@ -114,6 +220,10 @@ impl std::fmt::Display for ApiError {
             parts.push(format!("(code: {code})"));
         }
 
+        if let Some(status) = &self.status {
+            parts.push(format!("(status: {status})"));
+        }
+
         write!(f, "{}", parts.join(" "))
     }
 }
@@ -122,16 +232,30 @@ impl std::error::Error for ApiError {}
 
 /// Wrapper to deserialize the error object nested in "error" JSON key
 #[derive(Debug, Deserialize, Serialize)]
-#[derive(utoipa::ToSchema)]
-#[schema(as = ErrorResponse)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "utoipa", schema(as = ErrorResponse))]
 pub struct WrappedError {
     pub error: ApiError,
 }
 
+/// Longest content of the body kept in [OpenAIError::JSONDeserialize], in bytes.
+#[cfg(feature = "_api")]
+const MAX_DESERIALIZATION_ERROR_CONTENT: usize = 8 * 1024;
+
 #[cfg(feature = "_api")]
 pub(crate) fn map_deserialization_error(e: serde_json::Error, bytes: &[u8]) -> OpenAIError {
-    let json_content = String::from_utf8_lossy(bytes);
+    let mut json_content = String::from_utf8_lossy(bytes).into_owned();
+    if json_content.len() > MAX_DESERIALIZATION_ERROR_CONTENT {
+        let mut end = MAX_DESERIALIZATION_ERROR_CONTENT;
+        while !json_content.is_char_boundary(end) {
+            end -= 1;
+        }
+        let truncated = json_content.len() - end;
+        json_content.truncate(end);
+        json_content.push_str(&format!("... ({truncated} more bytes)"));
+    }
     tracing::error!("failed deserialization of: {}", json_content);
 
-    OpenAIError::JSONDeserialize(e, json_content.to_string())
+    OpenAIError::JSONDeserialize(e, json_content)
 }
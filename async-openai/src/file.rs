@@ -1,12 +1,16 @@
 use bytes::Bytes;
+use futures::Stream;
 
 use crate::{
     config::Config,
     error::OpenAIError,
+    pagination::{impl_list_page, paginate},
     types::files::{CreateFileRequest, DeleteFileResponse, ListFilesResponse, OpenAIFile},
     Client, RequestOptions,
 };
 
+impl_list_page!(ListFilesResponse => OpenAIFile);
+
 /// Files are used to upload documents that can be used with features like Assistants and Fine-tuning.
 pub struct Files<'c, C: Config> {
     client: &'c Client<C>,
@@ -29,6 +33,9 @@ impl<'c, C: Config> Files<'c, C> {
     /// The Fine-tuning API only supports `.jsonl` files. The input also has certain required formats for fine-tuning [chat](https://platform.openai.com/docs/api-reference/fine-tuning/chat-input) or [completions](https://platform.openai.com/docs/api-reference/fine-tuning/completions-input) models.
     ///
     /// The Batch API only supports `.jsonl` files up to 200 MB in size. The input also has a specific required [format](https://platform.openai.com/docs/api-reference/batch/request-input).
+    ///
+    /// Large files can be streamed from disk with [FileInput::from_reader](crate::types::files::FileInput::from_reader)
+    /// instead of being read into memory first.
     #[crate::byot(
         T0 = Clone,
         R = serde::de::DeserializeOwned,
@@ -46,6 +53,15 @@ impl<'c, C: Config> Files<'c, C> {
         self.client.get("/files", &self.request_options).await
     }
 
+    /// All the files that belong to the user's organization, following the `after` cursor of [list](Self::list) page by page.
+    pub fn list_stream(&self) -> impl Stream<Item = Result<OpenAIFile, OpenAIError>> + 'c {
+        paginate::<_, ListFilesResponse>(
+            self.client,
+            "/files".to_string(),
+            self.request_options.clone(),
+        )
+    }
+
     /// Returns information about a specific file.
     #[crate::byot(T0 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn retrieve(&self, file_id: &str) -> Result<OpenAIFile, OpenAIError> {
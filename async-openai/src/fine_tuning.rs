@@ -1,15 +1,31 @@
+#[cfg(not(target_family = "wasm"))]
+use std::{collections::HashSet, time::Duration};
+
+use futures::Stream;
+#[cfg(not(target_family = "wasm"))]
+use futures::{stream, TryStreamExt};
+
+#[cfg(not(target_family = "wasm"))]
+use crate::types::finetuning::FineTuningJobEvent;
 use crate::{
     config::Config,
     error::OpenAIError,
+    pagination::{impl_list_page, paginate},
     types::finetuning::{
         CreateFineTuningCheckpointPermissionRequest, CreateFineTuningJobRequest,
-        DeleteFineTuningCheckpointPermissionResponse, FineTuningJob,
+        DeleteFineTuningCheckpointPermissionResponse, FineTuningJob, FineTuningJobCheckpoint,
         ListFineTuningCheckpointPermissionResponse, ListFineTuningJobCheckpointsResponse,
         ListFineTuningJobEventsResponse, ListPaginatedFineTuningJobsResponse,
     },
     Client, RequestOptions,
 };
 
+impl_list_page!(ListFineTuningJobCheckpointsResponse => FineTuningJobCheckpoint);
+
+/// The largest page of [FineTuning::list_events], servers may return smaller ones.
+#[cfg(not(target_family = "wasm"))]
+const EVENTS_PAGE_SIZE: usize = 100;
+
 /// Manage fine-tuning jobs to tailor a model to your specific training data.
 ///
 /// Related guide: [Fine-tune models](https://platform.openai.com/docs/guides/fine-tuning)
@@ -113,7 +129,93 @@ impl<'c, C: Config> FineTuning<'c, C> {
             .await
     }
 
+    /// The events of a fine-tuning job as they happen, oldest first, polling the events every
+    /// `interval` until the job [is terminal](crate::types::finetuning::FineTuningJobStatus::is_terminal).
+    ///
+    /// Each event is yielded once, the last poll happens after the job finished so that its final
+    /// events are included. A poll pages back with `after` through the events newer than those
+    /// yielded so far, so the first poll yields all the past events of a long-running job and
+    /// polls never skip events however many arrived since the previous one. Training progress is
+    /// reported by `metrics` events, see [FineTuningJobEvent::metrics].
+    #[cfg(not(target_family = "wasm"))]
+    pub fn job_events_stream(
+        &self,
+        fine_tuning_job_id: &str,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<FineTuningJobEvent, OpenAIError>> + 'c {
+        let client = self.client;
+        let job_path = format!("/fine_tuning/jobs/{fine_tuning_job_id}");
+        let events_path = format!("{job_path}/events");
+        let job_options = self.request_options.clone();
+        let mut events_options = self.request_options.clone();
+        // the largest page, to page back as little as possible
+        events_options.with_query_param("limit", EVENTS_PAGE_SIZE.to_string());
+
+        // ids of the events yielded so far, whether the job is terminal, and whether this is the
+        // first poll, which does not wait for `interval`
+        let state = (HashSet::<String>::new(), false, true);
+
+        stream::try_unfold(state, move |(mut seen, finished, first)| {
+            let job_path = job_path.clone();
+            let events_path = events_path.clone();
+            let job_options = job_options.clone();
+            let events_options = events_options.clone();
+            async move {
+                if finished {
+                    return Ok::<_, OpenAIError>(None);
+                }
+                if !first {
+                    tokio::time::sleep(interval).await;
+                }
+
+                // the job is retrieved before its events, so that no events are missed after it finished
+                let job: FineTuningJob = client.get(&job_path, &job_options).await?;
+
+                // events are listed newest first, page back until an event yielded before or the
+                // last page
+                let mut new_events = Vec::new();
+                let mut page_options = events_options.clone();
+                loop {
+                    let page: ListFineTuningJobEventsResponse =
+                        client.get(&events_path, &page_options).await?;
+                    let len = page.data.len();
+                    let after = page.data.last().map(|event| event.id.clone());
+                    let before = new_events.len();
+                    new_events.extend(
+                        page.data
+                            .into_iter()
+                            .take_while(|event| !seen.contains(&event.id)),
+                    );
+                    let all_new = new_events.len() - before == len;
+                    match after {
+                        Some(after) if page.has_more && all_new => {
+                            page_options.with_query_param("after", after)
+                        }
+                        _ => break,
+                    }
+                }
+
+                let events: Vec<_> = new_events
+                    .into_iter()
+                    .rev()
+                    .filter(|event| seen.insert(event.id.clone()))
+                    .map(Ok::<_, OpenAIError>)
+                    .collect();
+
+                Ok(Some((
+                    stream::iter(events),
+                    (seen, job.status.is_terminal(), false),
+                )))
+            }
+        })
+        .try_flatten()
+    }
+
     /// List checkpoints for a fine-tuning job.
+    ///
+    /// Pages are selected with the `after` and `limit` parameters of
+    /// [ListFineTuningJobCheckpointsQuery](crate::types::finetuning::ListFineTuningJobCheckpointsQuery),
+    /// set with `.query()`.
     #[crate::byot(T0 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn list_checkpoints(
         &self,
@@ -127,6 +229,22 @@ impl<'c, C: Config> FineTuning<'c, C> {
             .await
     }
 
+    /// All the checkpoints of a fine-tuning job, following the `after` cursor of
+    /// [list_checkpoints](Self::list_checkpoints) page by page.
+    pub fn list_checkpoints_stream(
+        &self,
+        fine_tuning_job_id: &str,
+    ) -> impl Stream<Item = Result<FineTuningJobCheckpoint, OpenAIError>> + 'c {
+        paginate::<_, ListFineTuningJobCheckpointsResponse>(
+            self.client,
+            format!("/fine_tuning/jobs/{fine_tuning_job_id}/checkpoints"),
+            self.request_options.clone(),
+        )
+    }
+
+    /// Grants the projects of `request` access to a fine-tuned model checkpoint.
+    ///
+    /// Requires an [admin API key](https://platform.openai.com/docs/api-reference/admin-api-keys).
     #[crate::byot(T0 = std::fmt::Display, T1 = serde::Serialize, R = serde::de::DeserializeOwned)]
     pub async fn create_checkpoint_permissions(
         &self,
@@ -143,6 +261,11 @@ impl<'c, C: Config> FineTuning<'c, C> {
             .await
     }
 
+    /// Lists the permissions of a fine-tuned model checkpoint, filtered and paginated with
+    /// [ListFineTuningCheckpointPermissionsQuery](crate::types::finetuning::ListFineTuningCheckpointPermissionsQuery)
+    /// set with `.query()`.
+    ///
+    /// Requires an [admin API key](https://platform.openai.com/docs/api-reference/admin-api-keys).
     #[crate::byot(T0 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn list_checkpoint_permissions(
         &self,
@@ -157,6 +280,9 @@ impl<'c, C: Config> FineTuning<'c, C> {
             .await
     }
 
+    /// Revokes the permission `permission_id` of a fine-tuned model checkpoint.
+    ///
+    /// Requires an [admin API key](https://platform.openai.com/docs/api-reference/admin-api-keys).
     #[crate::byot(T0 = std::fmt::Display, T1 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn delete_checkpoint_permissions(
         &self,
@@ -50,11 +50,24 @@
 //!     .create(request)  // Make the API call in that "group"
 //!     .await?;
 //!
-//! println!("{:?}", response.output_text());
+//! println!("{}", response.output_text());
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! # });
 //!```
 //!
+//! Builders implement `Default` and `Clone`, so common parameters can be set once on a base
+//! builder and copied for each request:
+//!
+//! ```
+//! # use async_openai::types::responses::CreateResponseArgs;
+//! let mut base = CreateResponseArgs::default();
+//! base.model("gpt-5-mini").instructions("Answer in one sentence.");
+//!
+//! let first = base.clone().input("What is Rust?").build()?;
+//! let second = base.clone().input("What is Cargo?").build()?;
+//! # Ok::<(), async_openai::error::OpenAIError>(())
+//! ```
+//!
 //! ## Bring Your Own Types
 //!
 //! To use custom types for inputs and outputs, enable `byot` feature which provides additional generic methods with same name and `_byot` suffix.
@@ -119,7 +132,13 @@
 //! These granular types are enabled when the corresponding API feature is enabled - for example `responses` will enable `response-types`.
 //!
 //! ## WASM
-//! For WASM targets streaming, retries, file operations are not implemented yet.
+//! On `wasm32-unknown-unknown` requests are sent with the `fetch` API of the browser, through the
+//! wasm backend of `reqwest`, and the TLS features have no effect. Non-streaming requests of the API
+//! features work, for example `features = ["chat-completion", "embedding"]`, with the rustflags
+//! `--cfg getrandom_backend="wasm_js"`.
+//!
+//! For WASM targets streaming, retries, rate limits, custom transports and file operations are not
+//! implemented yet.
 //! See [examples/wasm-responses](https://github.com/64bit/async-openai/tree/main/examples/wasm-responses) for a working example.
 //!
 //! ## Configurable Requests
@@ -214,6 +233,13 @@
 //!
 //! let client = Client::with_config(config);
 //!
+//! // Azure AD (Microsoft Entra ID) tokens are fetched for every request
+//! let config = AzureConfig::new()
+//!     .with_api_base("https://my-resource-name.openai.azure.com")
+//!     .with_api_version("2023-03-15-preview")
+//!     .with_deployment_id("deployment-id")
+//!     .with_token_provider(|| cached_token());
+//!
 //! // Note that `async-openai` only implements OpenAI spec
 //! // and doesn't maintain parity with the spec of Azure OpenAI service.
 //!
@@ -253,6 +279,8 @@ mod chat;
 mod chatkit;
 #[cfg(feature = "_api")]
 mod client;
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+mod client_builder;
 #[cfg(feature = "completions")]
 mod completion;
 #[cfg(feature = "_api")]
@@ -278,6 +306,18 @@ mod impls;
 mod model;
 #[cfg(feature = "moderation")]
 mod moderation;
+#[cfg(any(
+    feature = "assistant",
+    feature = "batch",
+    feature = "file",
+    feature = "finetuning",
+    feature = "vectorstore"
+))]
+mod pagination;
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+mod poll;
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+mod rate_limiter;
 #[cfg(feature = "realtime")]
 mod realtime;
 #[cfg(feature = "_api")]
@@ -286,8 +326,12 @@ mod request_options;
 mod responses;
 #[cfg(feature = "skill")]
 mod skills;
+#[cfg(feature = "tokenizer")]
+pub mod tokenizer;
 #[cfg(feature = "_api")]
 pub mod traits;
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+pub mod transport;
 pub mod types;
 #[cfg(feature = "upload")]
 mod uploads;
@@ -331,7 +375,9 @@ pub use chat::Chat;
 #[cfg(feature = "chatkit")]
 pub use chatkit::Chatkit;
 #[cfg(feature = "_api")]
-pub use client::Client;
+pub use client::{Client, PreparedRequest};
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+pub use client_builder::ClientBuilder;
 #[cfg(feature = "completions")]
 pub use completion::Completions;
 #[cfg(feature = "container")]
@@ -350,12 +396,18 @@ pub use image::Images;
 pub use model::Models;
 #[cfg(feature = "moderation")]
 pub use moderation::Moderations;
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+pub use poll::PollOptions;
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+pub use rate_limiter::RateLimits;
 #[cfg(feature = "realtime")]
 pub use realtime::Realtime;
+#[cfg(all(feature = "realtime", not(target_family = "wasm")))]
+pub use realtime::{RealtimeClient, RealtimeReceiver, RealtimeSender};
 #[cfg(feature = "_api")]
 pub use request_options::RequestOptions;
 #[cfg(feature = "responses")]
-pub use responses::{ConversationItems, Conversations, Responses};
+pub use responses::{ConversationItems, Conversations, ResponseChain, ResponseTurn, Responses};
 #[cfg(feature = "skill")]
 pub use skills::{SkillVersions, Skills};
 #[cfg(feature = "upload")]
@@ -1,3 +1,16 @@
+#[cfg(not(target_family = "wasm"))]
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+#[cfg(not(target_family = "wasm"))]
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+
+#[cfg(not(target_family = "wasm"))]
+use crate::error::map_deserialization_error;
 use crate::{
     config::Config,
     error::OpenAIError,
@@ -8,6 +21,8 @@ use crate::{
 /// List and describe the various models available in the API.
 /// You can refer to the [Models](https://platform.openai.com/docs/models) documentation to understand what
 /// models are available and the differences between them.
+///
+/// With [Client::with_model_cache], `list` and `retrieve` return cached responses.
 pub struct Models<'c, C: Config> {
     client: &'c Client<C>,
     pub(crate) request_options: RequestOptions,
@@ -25,23 +40,120 @@ impl<'c, C: Config> Models<'c, C> {
     /// about each one such as the owner and availability.
     #[crate::byot(R = serde::de::DeserializeOwned)]
     pub async fn list(&self) -> Result<ListModelResponse, OpenAIError> {
-        self.client.get("/models", &self.request_options).await
+        self.get_cached("/models").await
+    }
+
+    /// Lists the models like [list](Self::list), replacing the cached responses of
+    /// [Client::with_model_cache] even if they have not expired.
+    #[crate::byot(R = serde::de::DeserializeOwned)]
+    pub async fn refresh(&self) -> Result<ListModelResponse, OpenAIError> {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(cache) = &self.client.model_cache {
+            cache.clear();
+        }
+        self.get_cached("/models").await
     }
 
     /// Retrieves a model instance, providing basic information about the model
     /// such as the owner and permissioning.
     #[crate::byot(T0 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn retrieve(&self, model: &str) -> Result<Model, OpenAIError> {
-        self.client
-            .get(format!("/models/{model}").as_str(), &self.request_options)
-            .await
+        self.get_cached(format!("/models/{model}").as_str()).await
     }
 
     /// Delete a fine-tuned model. You must have the Owner role in your organization.
     #[crate::byot(T0 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn delete(&self, model: &str) -> Result<DeleteModelResponse, OpenAIError> {
-        self.client
+        let response = self
+            .client
             .delete(format!("/models/{model}").as_str(), &self.request_options)
-            .await
+            .await?;
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(cache) = &self.client.model_cache {
+            cache.clear();
+        }
+        Ok(response)
+    }
+
+    async fn get_cached<O: DeserializeOwned>(&self, path: &str) -> Result<O, OpenAIError> {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(cache) = &self.client.model_cache {
+            let key = self.cache_key(path);
+            if let Some(bytes) = cache.get(&key) {
+                return serde_json::from_slice(&bytes)
+                    .map_err(|e| map_deserialization_error(e, &bytes));
+            }
+            let (bytes, _) = self.client.get_raw(path, &self.request_options).await?;
+            let response =
+                serde_json::from_slice(&bytes).map_err(|e| map_deserialization_error(e, &bytes))?;
+            cache.insert(&key, bytes);
+            return Ok(response);
+        }
+        self.client.get(path, &self.request_options).await
+    }
+
+    /// `path` with the path, query and headers of the request options, which can change the
+    /// response, e.g. the models of another `OpenAI-Project`.
+    #[cfg(not(target_family = "wasm"))]
+    fn cache_key(&self, path: &str) -> String {
+        let options = &self.request_options;
+        let mut key = options.path().map_or(path, String::as_str).to_string();
+        for (name, value) in options.query() {
+            key.push_str(&format!("&{name}={value}"));
+        }
+        if let Some(headers) = options.headers() {
+            let mut headers: Vec<String> = headers
+                .iter()
+                .map(|(name, value)| {
+                    format!("\n{name}: {}", String::from_utf8_lossy(value.as_bytes()))
+                })
+                .collect();
+            headers.sort();
+            key.extend(headers);
+        }
+        key
+    }
+}
+
+/// Response bodies of the models endpoints by path and request options, for
+/// [Client::with_model_cache].
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub(crate) struct ModelCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Bytes)>>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl ModelCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The body cached for `key`, unless it expired.
+    fn get(&self, key: &str) -> Option<Bytes> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((cached_at, bytes)) if cached_at.elapsed() < self.ttl => Some(bytes.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: &str, bytes: Bytes) {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), (Instant::now(), bytes));
+    }
+
+    fn clear(&self) {
+        self.entries.lock().unwrap().clear();
     }
 }
@@ -1,7 +1,7 @@
 use crate::{
     config::Config,
     error::OpenAIError,
-    types::moderations::{CreateModerationRequest, CreateModerationResponse},
+    types::moderations::{CreateModerationRequest, CreateModerationResponse, ModerationInput},
     Client, RequestOptions,
 };
 
@@ -32,4 +32,26 @@ impl<'c, C: Config> Moderations<'c, C> {
             .post("/moderations", request, &self.request_options)
             .await
     }
+
+    /// Classifies `input`, a string or an array of strings, with the default moderation model,
+    /// and returns whether any of it is flagged along with the names of the flagged categories,
+    /// e.g. `harassment` or `self-harm/intent`.
+    ///
+    /// Use [create](Self::create) for the scores of each category and per input results.
+    pub async fn is_flagged(
+        &self,
+        input: impl Into<ModerationInput>,
+    ) -> Result<(bool, Vec<String>), OpenAIError> {
+        let request = CreateModerationRequest {
+            input: input.into(),
+            model: None,
+        };
+        let response = self.create(request).await?;
+        let categories = response
+            .flagged_categories()
+            .into_iter()
+            .map(String::from)
+            .collect();
+        Ok((response.is_flagged(), categories))
+    }
 }
@@ -0,0 +1,82 @@
+//! Iteration over all the pages of list endpoints, for the `list_stream` methods of API groups.
+use futures::{stream, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+use crate::{config::Config, error::OpenAIError, Client, RequestOptions};
+
+/// A page of a cursor paginated list endpoint.
+pub(crate) trait ListPage: DeserializeOwned {
+    type Item;
+
+    fn has_more(&self) -> bool;
+
+    /// The cursor to request the next page with as `after`.
+    fn last_id(&self) -> Option<String>;
+
+    fn into_data(self) -> Vec<Self::Item>;
+}
+
+/// Implements [ListPage] for list responses with `data`, `last_id` and `has_more` fields.
+macro_rules! impl_list_page {
+    ($($page:ty => $item:ty),* $(,)?) => {
+        $(
+            impl $crate::pagination::ListPage for $page {
+                type Item = $item;
+
+                fn has_more(&self) -> bool {
+                    self.has_more
+                }
+
+                fn last_id(&self) -> Option<String> {
+                    self.last_id.clone()
+                }
+
+                fn into_data(self) -> Vec<Self::Item> {
+                    self.data
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_list_page;
+
+/// The items of all the pages of `GET path`, requesting each next page with `after` set to the
+/// `last_id` of the previous one. Other query parameters of `options`, such as `limit` and
+/// `order`, are sent with every request.
+pub(crate) fn paginate<'c, C, P>(
+    client: &'c Client<C>,
+    path: String,
+    options: RequestOptions,
+) -> impl Stream<Item = Result<P::Item, OpenAIError>> + 'c
+where
+    C: Config,
+    P: ListPage + 'c,
+{
+    // `None` once the last page is fetched
+    let cursor = Some(options.query_param("after"));
+
+    stream::try_unfold(cursor, move |cursor| {
+        let path = path.clone();
+        let mut options = options.clone();
+        async move {
+            let Some(after) = cursor else {
+                return Ok::<_, OpenAIError>(None);
+            };
+            if let Some(after) = after {
+                options.with_query_param("after", after);
+            }
+
+            let page: P = client.get(&path, &options).await?;
+            let next = match (page.has_more(), page.last_id()) {
+                (true, Some(last_id)) => Some(Some(last_id)),
+                _ => None,
+            };
+            Ok(Some((
+                stream::iter(page.into_data().into_iter().map(Ok::<_, OpenAIError>)),
+                next,
+            )))
+        }
+    })
+    .try_flatten()
+}
@@ -0,0 +1,117 @@
+//! Polling of long running operations, such as batches, until they finish.
+use std::{fmt::Debug, future::Future, time::Duration};
+
+use crate::error::OpenAIError;
+
+type ProgressCallback<T> = Box<dyn FnMut(&T) + Send>;
+
+/// How to poll a long running operation until it finishes, e.g. with [Batches::wait_for_completion](crate::Batches::wait_for_completion).
+///
+/// The first poll happens right away. The delay between polls starts at the interval
+/// and grows by half after each poll, up to the max interval.
+///
+/// ```
+/// # #[cfg(feature = "batch")]
+/// # {
+/// # use std::time::Duration;
+/// # use async_openai::{types::batches::Batch, PollOptions};
+/// let options = PollOptions::<Batch>::new(Duration::from_secs(5))
+///     .max_interval(Duration::from_secs(60))
+///     .timeout(Duration::from_secs(24 * 60 * 60))
+///     .on_progress(|batch| {
+///         if let Some(counts) = &batch.request_counts {
+///             println!("{}/{} requests done", counts.completed + counts.failed, counts.total);
+///         }
+///     });
+/// # }
+/// ```
+pub struct PollOptions<T> {
+    interval: Duration,
+    max_interval: Duration,
+    timeout: Option<Duration>,
+    on_progress: Option<ProgressCallback<T>>,
+}
+
+impl<T> PollOptions<T> {
+    /// Polls every `interval` at first, and at most every 4 times `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            max_interval: interval * 4,
+            timeout: None,
+            on_progress: None,
+        }
+    }
+
+    /// The maximum delay between polls.
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Gives up with [OpenAIError::Timeout] when the operation did not finish within `timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Called with the state of the operation after every poll, including the last one.
+    pub fn on_progress(mut self, on_progress: impl FnMut(&T) + Send + 'static) -> Self {
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
+}
+
+impl<T> From<Duration> for PollOptions<T> {
+    fn from(interval: Duration) -> Self {
+        Self::new(interval)
+    }
+}
+
+impl<T> Debug for PollOptions<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollOptions")
+            .field("interval", &self.interval)
+            .field("max_interval", &self.max_interval)
+            .field("timeout", &self.timeout)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+/// Calls `fetch` until `is_done` returns `true` for its output, and returns that output.
+#[allow(unused)]
+pub(crate) async fn poll_until<T, F, Fut>(
+    mut options: PollOptions<T>,
+    mut fetch: F,
+    is_done: impl Fn(&T) -> bool,
+) -> Result<T, OpenAIError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, OpenAIError>>,
+{
+    let timeout = options.timeout;
+    let poll = async move {
+        let mut delay = options.interval;
+        loop {
+            let state = fetch().await?;
+            if let Some(on_progress) = options.on_progress.as_mut() {
+                on_progress(&state);
+            }
+            if is_done(&state) {
+                return Ok(state);
+            }
+            tokio::time::sleep(delay).await;
+            delay = delay
+                .mul_f64(1.5)
+                .min(options.max_interval.max(options.interval));
+        }
+    };
+
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, poll)
+            .await
+            .map_err(|_| OpenAIError::Timeout(timeout))?,
+        None => poll.await,
+    }
+}
@@ -0,0 +1,208 @@
+//! Client side limits on the requests of a [Client](crate::Client), see
+//! [Client::with_concurrency_limit](crate::Client::with_concurrency_limit) and
+//! [Client::with_rate_limits](crate::Client::with_rate_limits).
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use futures::{Stream, StreamExt};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::{error::OpenAIError, types::RateLimitInfo};
+
+/// Requests and tokens per minute of an account tier, for
+/// [Client::with_rate_limits](crate::Client::with_rate_limits).
+///
+/// See the limits of your organization in the [rate limits guide](https://platform.openai.com/docs/guides/rate-limits).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimits {
+    pub requests_per_minute: Option<u64>,
+    /// Tokens per minute, estimated from the size of the request bodies.
+    pub tokens_per_minute: Option<u64>,
+}
+
+impl RateLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn requests_per_minute(mut self, requests_per_minute: u64) -> Self {
+        self.requests_per_minute = Some(requests_per_minute);
+        self
+    }
+
+    pub fn tokens_per_minute(mut self, tokens_per_minute: u64) -> Self {
+        self.tokens_per_minute = Some(tokens_per_minute);
+        self
+    }
+}
+
+/// The concurrency limit and token buckets of a client, shared by its clones.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Limiter {
+    concurrency: Option<Arc<Semaphore>>,
+    buckets: Option<Arc<Mutex<Buckets>>>,
+}
+
+#[derive(Debug, Default)]
+struct Buckets {
+    requests: Option<Bucket>,
+    tokens: Option<Bucket>,
+}
+
+/// A token bucket refilled continuously with `capacity` per minute.
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    available: f64,
+    refilled_at: Instant,
+}
+
+impl Bucket {
+    fn new(per_minute: u64) -> Self {
+        Self {
+            capacity: per_minute as f64,
+            available: per_minute as f64,
+            refilled_at: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.available = (self.available + elapsed * self.capacity / 60.0).min(self.capacity);
+        self.refilled_at = now;
+    }
+
+    /// How long until `amount` is available, `Duration::ZERO` when it is. Amounts above the
+    /// capacity only wait for a full bucket.
+    fn wait_for(&mut self, amount: f64) -> Duration {
+        self.refill();
+        let missing = amount.min(self.capacity) - self.available;
+        if missing <= 0.0 || self.capacity <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(missing * 60.0 / self.capacity)
+        }
+    }
+
+    fn take(&mut self, amount: f64) {
+        self.available -= amount.min(self.capacity);
+    }
+
+    fn update(&mut self, limit: u64, remaining: u64) {
+        self.capacity = limit as f64;
+        self.available = (remaining as f64).min(self.capacity);
+        self.refilled_at = Instant::now();
+    }
+}
+
+/// Bytes of JSON per token, to estimate the tokens of a request from its body.
+const BYTES_PER_TOKEN: usize = 4;
+
+impl Limiter {
+    /// A limit of 0 would make every request wait forever, it is raised to 1.
+    pub(crate) fn set_concurrency_limit(&mut self, limit: usize) {
+        self.concurrency = Some(Arc::new(Semaphore::new(limit.max(1))));
+    }
+
+    pub(crate) fn set_rate_limits(&mut self, limits: RateLimits) {
+        self.buckets = Some(Arc::new(Mutex::new(Buckets {
+            requests: limits.requests_per_minute.map(Bucket::new),
+            tokens: limits.tokens_per_minute.map(Bucket::new),
+        })));
+    }
+
+    pub(crate) fn update(&self, info: &RateLimitInfo) {
+        if let Some(buckets) = &self.buckets {
+            let mut buckets = buckets.lock().unwrap();
+            if let Some(requests) = &mut buckets.requests {
+                requests.update(info.limit_requests, info.remaining_requests);
+            }
+            if let Some(tokens) = &mut buckets.tokens {
+                tokens.update(info.limit_tokens, info.remaining_tokens);
+            }
+        }
+    }
+
+    /// Waits until `request` can be sent within the limits, or until its timeout elapses.
+    ///
+    /// The request counts against the concurrency limit until the returned permit is dropped.
+    pub(crate) async fn acquire(
+        &self,
+        request: &reqwest::Request,
+    ) -> Result<Option<OwnedSemaphorePermit>, OpenAIError> {
+        let acquire = async {
+            self.wait_for_buckets(request).await;
+            match &self.concurrency {
+                // the semaphore is never closed
+                Some(semaphore) => semaphore.clone().acquire_owned().await.ok(),
+                None => None,
+            }
+        };
+        match request.timeout() {
+            Some(timeout) => tokio::time::timeout(*timeout, acquire)
+                .await
+                .map_err(|_| OpenAIError::Timeout(*timeout)),
+            None => Ok(acquire.await),
+        }
+    }
+
+    async fn wait_for_buckets(&self, request: &reqwest::Request) {
+        let Some(buckets) = &self.buckets else {
+            return;
+        };
+        let tokens = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map_or(1, |body| (body.len() / BYTES_PER_TOKEN).max(1)) as f64;
+
+        loop {
+            let wait = {
+                let mut buckets = buckets.lock().unwrap();
+                let Buckets {
+                    requests,
+                    tokens: token_bucket,
+                } = &mut *buckets;
+                let wait = requests
+                    .as_mut()
+                    .map_or(Duration::ZERO, |bucket| bucket.wait_for(1.0))
+                    .max(
+                        token_bucket
+                            .as_mut()
+                            .map_or(Duration::ZERO, |bucket| bucket.wait_for(tokens)),
+                    );
+                if wait.is_zero() {
+                    if let Some(bucket) = requests {
+                        bucket.take(1.0);
+                    }
+                    if let Some(bucket) = token_bucket {
+                        bucket.take(tokens);
+                    }
+                }
+                wait
+            };
+            if wait.is_zero() {
+                return;
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Keeps `permit` until `stream` ends or is dropped, so that a streamed response counts against
+/// the concurrency limit while it is read.
+pub(crate) fn hold_permit<S>(
+    stream: S,
+    permit: Option<OwnedSemaphorePermit>,
+) -> impl Stream<Item = S::Item> + Send
+where
+    S: Stream + Unpin + Send,
+    S::Item: Send,
+{
+    futures::stream::unfold((stream, permit), |(mut stream, permit)| async move {
+        let item = stream.next().await?;
+        Some((item, (stream, permit)))
+    })
+}
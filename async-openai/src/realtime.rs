@@ -1,3 +1,26 @@
+#[cfg(not(target_family = "wasm"))]
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(not(target_family = "wasm"))]
+use futures::{
+    stream::{SplitSink, SplitStream},
+    SinkExt, Stream, StreamExt,
+};
+#[cfg(not(target_family = "wasm"))]
+use tokio_tungstenite::{
+    tungstenite::{client::IntoClientRequest, Message},
+    MaybeTlsStream, WebSocketStream,
+};
+
+#[cfg(not(target_family = "wasm"))]
+use crate::{
+    client::request_id,
+    error::{map_deserialization_error, ApiError, StreamError, WrappedError},
+    types::realtime::{RealtimeClientEvent, RealtimeServerEvent},
+};
 use crate::{
     config::Config,
     error::OpenAIError,
@@ -114,6 +137,43 @@ impl<'c, C: Config> Realtime<'c, C> {
             .await
     }
 
+    /// Opens a Realtime session with `model` over a WebSocket, e.g. `wss://api.openai.com/v1/realtime?model=gpt-realtime`.
+    ///
+    /// The URL is derived from the api base of the config, and the headers and query of the
+    /// config and of this API group are sent with the handshake.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn connect(&self, model: &str) -> Result<RealtimeClient, OpenAIError> {
+        let config = self.client.config();
+        let mut url = reqwest::Url::parse(&config.url("/realtime"))
+            .map_err(|e| OpenAIError::InvalidArgument(format!("invalid realtime url: {e}")))?;
+        let scheme = if url.scheme() == "http" { "ws" } else { "wss" };
+        url.set_scheme(scheme)
+            .map_err(|_| OpenAIError::InvalidArgument(format!("invalid realtime url: {url}")))?;
+        url.query_pairs_mut()
+            .append_pair("model", model)
+            .extend_pairs(config.query())
+            .extend_pairs(self.request_options.query());
+
+        let mut request = url
+            .as_str()
+            .into_client_request()
+            .map_err(websocket_error)?;
+        request.headers_mut().extend(config.headers());
+        if let Some(headers) = self.request_options.headers() {
+            request.headers_mut().extend(headers.clone());
+        }
+        config.filter_headers(request.headers_mut());
+
+        let (ws, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|e| match e {
+                tokio_tungstenite::tungstenite::Error::Http(response) => handshake_error(*response),
+                e => websocket_error(e),
+            })?;
+
+        Ok(RealtimeClient { ws })
+    }
+
     /// Create a Realtime client secret with an associated session configuration.
     #[crate::byot(T0 = serde::Serialize, R = serde::de::DeserializeOwned)]
     pub async fn create_client_secret(
@@ -125,3 +185,168 @@ impl<'c, C: Config> Realtime<'c, C> {
             .await
     }
 }
+
+#[cfg(not(target_family = "wasm"))]
+type WebSocket = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// A Realtime session over a WebSocket, opened with [Realtime::connect].
+///
+/// Client events are sent with [send](Self::send), and server events are received by polling
+/// the client as a [Stream]. The stream ends when the server closes the connection. Use
+/// [split](Self::split) to send and receive from different tasks.
+///
+/// ```no_run
+/// # async fn example() -> Result<(), async_openai::error::OpenAIError> {
+/// use async_openai::{
+///     types::realtime::{RealtimeClientEventResponseCreate, RealtimeServerEvent},
+///     Client,
+/// };
+/// use futures::StreamExt;
+///
+/// let client = Client::new();
+/// let mut session = client.realtime().connect("gpt-realtime").await?;
+///
+/// session.send(RealtimeClientEventResponseCreate::default()).await?;
+/// while let Some(event) = session.next().await {
+///     if let RealtimeServerEvent::ResponseDone(_) = event? {
+///         break;
+///     }
+/// }
+/// session.close().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub struct RealtimeClient {
+    ws: WebSocket,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RealtimeClient {
+    /// Sends a client event, such as `session.update`, `input_audio_buffer.append` or `response.create`.
+    pub async fn send(&mut self, event: impl Into<RealtimeClientEvent>) -> Result<(), OpenAIError> {
+        self.ws
+            .send(Message::from(event.into()))
+            .await
+            .map_err(websocket_error)
+    }
+
+    /// Closes the WebSocket connection.
+    pub async fn close(&mut self) -> Result<(), OpenAIError> {
+        self.ws.close(None).await.map_err(websocket_error)
+    }
+
+    /// Splits the session into halves which send client events and receive server events.
+    pub fn split(self) -> (RealtimeSender, RealtimeReceiver) {
+        let (sink, stream) = self.ws.split();
+        (RealtimeSender { sink }, RealtimeReceiver { stream })
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Stream for RealtimeClient {
+    type Item = Result<RealtimeServerEvent, OpenAIError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        poll_server_event(&mut self.ws, cx)
+    }
+}
+
+/// The sending half of a [RealtimeClient].
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub struct RealtimeSender {
+    sink: SplitSink<WebSocket, Message>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RealtimeSender {
+    /// Sends a client event, see [RealtimeClient::send].
+    pub async fn send(&mut self, event: impl Into<RealtimeClientEvent>) -> Result<(), OpenAIError> {
+        self.sink
+            .send(Message::from(event.into()))
+            .await
+            .map_err(websocket_error)
+    }
+
+    /// Closes the WebSocket connection.
+    pub async fn close(&mut self) -> Result<(), OpenAIError> {
+        self.sink.close().await.map_err(websocket_error)
+    }
+}
+
+/// The receiving half of a [RealtimeClient], a [Stream] of server events.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub struct RealtimeReceiver {
+    stream: SplitStream<WebSocket>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Stream for RealtimeReceiver {
+    type Item = Result<RealtimeServerEvent, OpenAIError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        poll_server_event(&mut self.stream, cx)
+    }
+}
+
+/// Polls `messages` for the next server event, skipping control frames.
+#[cfg(not(target_family = "wasm"))]
+fn poll_server_event<S>(
+    messages: &mut S,
+    cx: &mut Context<'_>,
+) -> Poll<Option<Result<RealtimeServerEvent, OpenAIError>>>
+where
+    S: Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    loop {
+        let message = match futures::ready!(messages.poll_next_unpin(cx)) {
+            None => return Poll::Ready(None),
+            Some(Err(e)) => return Poll::Ready(Some(Err(websocket_error(e)))),
+            Some(Ok(message)) => message,
+        };
+        let data = match &message {
+            Message::Text(text) => text.as_bytes(),
+            Message::Binary(data) => data.as_ref(),
+            Message::Close(_) => return Poll::Ready(None),
+            // pings are answered by tungstenite
+            Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => continue,
+        };
+        return Poll::Ready(Some(
+            serde_json::from_slice(data).map_err(|e| map_deserialization_error(e, data)),
+        ));
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn websocket_error(e: tokio_tungstenite::tungstenite::Error) -> OpenAIError {
+    OpenAIError::StreamError(Box::new(StreamError::WebSocket(e)))
+}
+
+/// The server rejected the WebSocket handshake, e.g. because of an invalid API key.
+#[cfg(not(target_family = "wasm"))]
+fn handshake_error(
+    response: tokio_tungstenite::tungstenite::http::Response<Option<Vec<u8>>>,
+) -> OpenAIError {
+    let status = response.status().as_u16();
+    let request_id = request_id(response.headers()).map(Box::from);
+    let body = response.into_body().unwrap_or_default();
+    let error = match serde_json::from_slice::<WrappedError>(&body) {
+        Ok(wrapped) => wrapped.error,
+        Err(_) => ApiError {
+            message: String::from_utf8_lossy(&body).into_owned(),
+            r#type: None,
+            param: None,
+            code: None,
+            status: None,
+            request_id: None,
+        },
+    };
+    OpenAIError::ApiError(ApiError {
+        status: Some(status),
+        request_id,
+        ..error
+    })
+}
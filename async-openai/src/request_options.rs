@@ -1,14 +1,23 @@
+use std::{collections::HashMap, time::Duration};
+
 use reqwest::header::HeaderMap;
 use serde::Serialize;
 use url::Url;
 
 use crate::{config::OPENAI_API_BASE, error::OpenAIError};
 
+/// The header of [RequestOptionsBuilder::idempotency_key](crate::traits::RequestOptionsBuilder::idempotency_key).
+pub(crate) const IDEMPOTENCY_KEY: &str = "idempotency-key";
+
 #[derive(Clone, Debug, Default)]
 pub struct RequestOptions {
     query: Option<Vec<(String, String)>>,
     headers: Option<HeaderMap>,
     path: Option<String>,
+    timeout: Option<Duration>,
+    stream_idle_timeout: Option<Duration>,
+    stream_reconnect: Option<usize>,
+    extra_body: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 impl RequestOptions {
@@ -17,6 +26,10 @@ impl RequestOptions {
             query: None,
             headers: None,
             path: None,
+            timeout: None,
+            stream_idle_timeout: None,
+            stream_reconnect: None,
+            extra_body: None,
         }
     }
 
@@ -30,6 +43,54 @@ impl RequestOptions {
         Ok(())
     }
 
+    pub(crate) fn with_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    pub(crate) fn with_stream_idle_timeout(&mut self, timeout: Duration) {
+        self.stream_idle_timeout = Some(timeout);
+    }
+
+    pub(crate) fn with_stream_reconnect(&mut self, max_retries: usize) {
+        self.stream_reconnect = Some(max_retries);
+    }
+
+    pub(crate) fn with_extra_body(&mut self, extra_body: HashMap<String, serde_json::Value>) {
+        self.extra_body
+            .get_or_insert_with(serde_json::Map::new)
+            .extend(extra_body);
+    }
+
+    /// `request` serialized with the fields of `extra_body` merged at its top level,
+    /// or `None` when there are no extra fields to send.
+    pub(crate) fn merge_extra_body<I: Serialize + ?Sized>(
+        &self,
+        request: &I,
+    ) -> Result<Option<serde_json::Value>, OpenAIError> {
+        let Some(extra_body) = self.extra_body.as_ref().filter(|extra| !extra.is_empty()) else {
+            return Ok(None);
+        };
+
+        let mut body = serde_json::to_value(request).map_err(|e| {
+            OpenAIError::InvalidArgument(format!("Failed to serialize request: {}", e))
+        })?;
+        let Some(fields) = body.as_object_mut() else {
+            return Err(OpenAIError::InvalidArgument(
+                "extra_body requires a JSON object request body".to_string(),
+            ));
+        };
+        for (key, value) in extra_body {
+            if fields.contains_key(key) {
+                return Err(OpenAIError::InvalidArgument(format!(
+                    "extra_body field `{}` is already set by the request",
+                    key
+                )));
+            }
+            fields.insert(key.clone(), value.clone());
+        }
+        Ok(Some(body))
+    }
+
     pub(crate) fn with_headers(&mut self, headers: HeaderMap) {
         // merge with existing headers or update with new headers
         if let Some(existing_headers) = &mut self.headers {
@@ -86,6 +147,24 @@ impl RequestOptions {
         Ok(())
     }
 
+    /// Sets the query parameter `key`, replacing previous values of it.
+    #[allow(unused)]
+    pub(crate) fn with_query_param(&mut self, key: &str, value: String) {
+        let query = self.query.get_or_insert_with(Vec::new);
+        query.retain(|(k, _)| k != key);
+        query.push((key.to_string(), value));
+    }
+
+    /// The last value of the query parameter `key`.
+    #[allow(unused)]
+    pub(crate) fn query_param(&self, key: &str) -> Option<String> {
+        self.query()
+            .iter()
+            .rev()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+    }
+
     pub(crate) fn query(&self) -> &[(String, String)] {
         self.query.as_deref().unwrap_or(&[])
     }
@@ -94,7 +173,45 @@ impl RequestOptions {
         self.headers.as_ref()
     }
 
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn has_header(&self, key: &str) -> bool {
+        self.headers
+            .as_ref()
+            .is_some_and(|headers| headers.contains_key(key))
+    }
+
     pub(crate) fn path(&self) -> Option<&String> {
         self.path.as_ref()
     }
+
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn stream_idle_timeout(&self) -> Option<Duration> {
+        self.stream_idle_timeout
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn stream_reconnect(&self) -> Option<usize> {
+        self.stream_reconnect
+    }
+}
+
+/// A random (version 4) UUID, such as `3b241101-e2bb-4255-8caf-4136c566a962`.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) fn uuid_v4() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
 }
@@ -0,0 +1,176 @@
+use crate::{
+    config::Config,
+    error::OpenAIError,
+    types::responses::{
+        CreateResponse, EasyInputMessage, InputItem, InputParam, Item, MessageItem, OutputItem,
+        Response,
+    },
+    Responses,
+};
+
+/// A turn of a [ResponseChain]: the input sent and the response of the model to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseTurn {
+    pub input: InputParam,
+    pub response: Response,
+}
+
+/// A multi-turn conversation whose state is kept by the API, threading `previous_response_id`
+/// through successive calls to [send](Self::send) so that the history is not resent.
+///
+/// Every request is built from the template passed to [Responses::chain], e.g. with the model,
+/// `instructions` and `tools`, with the `input` of the turn and the ID of the last response.
+/// Note that `instructions` are not carried over by `previous_response_id`, so they are sent
+/// with every turn.
+///
+/// Stored responses expire. When the API no longer finds the previous response and the chain
+/// holds all the turns since its start, the request is retried once with the messages and
+/// function calls of those turns as input instead, and the chain continues from the new
+/// response. Chains [forked](Self::fork) from a response they did not create return the error.
+///
+/// ```no_run
+/// # async fn example() -> Result<(), async_openai::error::OpenAIError> {
+/// use async_openai::{types::responses::CreateResponseArgs, Client};
+///
+/// let client = Client::new();
+/// let template = CreateResponseArgs::default()
+///     .model("gpt-4.1-mini")
+///     .instructions("Answer in one sentence.")
+///     .build()?;
+///
+/// let mut chain = client.responses().chain(template);
+/// chain.send("What is the capital of France?").await?;
+/// let answer = chain.send("And its population?").await?;
+/// println!("{}", answer.output_text());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ResponseChain<'c, C: Config> {
+    responses: Responses<'c, C>,
+    template: CreateResponse,
+    previous_response_id: Option<String>,
+    turns: Vec<ResponseTurn>,
+    /// Whether `turns` hold the entire history, so that it can be resent.
+    complete: bool,
+}
+
+impl<'c, C: Config> ResponseChain<'c, C> {
+    pub(crate) fn new(responses: Responses<'c, C>, mut template: CreateResponse) -> Self {
+        let previous_response_id = template.previous_response_id.take();
+        Self {
+            responses,
+            template,
+            complete: previous_response_id.is_none(),
+            previous_response_id,
+            turns: vec![],
+        }
+    }
+
+    /// Sends `input` as the next turn and returns the response of the model.
+    pub async fn send(&mut self, input: impl Into<InputParam>) -> Result<&Response, OpenAIError> {
+        let input = input.into();
+
+        let mut request = self.template.clone();
+        request.input = input.clone();
+        request.previous_response_id = self.previous_response_id.clone();
+
+        let response = match self.responses.create(request.clone()).await {
+            Err(e)
+                if self.complete && !self.turns.is_empty() && is_previous_response_missing(&e) =>
+            {
+                request.previous_response_id = None;
+                request.input = InputParam::Items(self.history(&input));
+                self.responses.create(request).await?
+            }
+            result => result?,
+        };
+
+        self.previous_response_id = Some(response.id.clone());
+        self.turns.push(ResponseTurn { input, response });
+        Ok(&self.turns.last().unwrap().response)
+    }
+
+    /// The turns sent with this chain, in order.
+    pub fn turns(&self) -> &[ResponseTurn] {
+        &self.turns
+    }
+
+    /// The ID of the response the next turn follows, if any.
+    pub fn previous_response_id(&self) -> Option<&str> {
+        self.previous_response_id.as_deref()
+    }
+
+    /// A new chain with the same template continuing from `response_id`, leaving this one as is.
+    ///
+    /// When `response_id` is one of the [turns](Self::turns), the new chain keeps the turns up to
+    /// it. Otherwise it starts without turns.
+    pub fn fork(&self, response_id: impl Into<String>) -> ResponseChain<'c, C> {
+        let response_id = response_id.into();
+        let turns = match self
+            .turns
+            .iter()
+            .position(|turn| turn.response.id == response_id)
+        {
+            Some(index) => self.turns[..=index].to_vec(),
+            None => vec![],
+        };
+        ResponseChain {
+            responses: Responses {
+                client: self.responses.client,
+                request_options: self.responses.request_options.clone(),
+            },
+            template: self.template.clone(),
+            complete: self.complete && !turns.is_empty(),
+            previous_response_id: Some(response_id),
+            turns,
+        }
+    }
+
+    /// The inputs and outputs of all the turns followed by `input`, to resend the history.
+    fn history(&self, input: &InputParam) -> Vec<InputItem> {
+        let mut items = vec![];
+        for turn in &self.turns {
+            items.extend(input_items(&turn.input));
+            items.extend(turn.response.output.iter().filter_map(|item| match item {
+                OutputItem::Message(message) => Some(InputItem::Item(Item::Message(
+                    MessageItem::Output(message.clone()),
+                ))),
+                OutputItem::FunctionCall(call) => {
+                    Some(InputItem::Item(Item::FunctionCall(call.clone())))
+                }
+                _ => None,
+            }));
+        }
+        items.extend(input_items(input));
+        items
+    }
+}
+
+fn input_items(input: &InputParam) -> Vec<InputItem> {
+    match input {
+        InputParam::Text(text) => vec![EasyInputMessage::from(text.as_str()).into()],
+        InputParam::Items(items) => items.clone(),
+    }
+}
+
+/// Whether `error` is the API reporting that the `previous_response_id` was not found, e.g.
+/// because the response expired or was deleted.
+fn is_previous_response_missing(error: &OpenAIError) -> bool {
+    match error {
+        OpenAIError::ApiError(e) => {
+            e.param.as_deref() == Some("previous_response_id")
+                || e.code.as_deref() == Some("previous_response_not_found")
+        }
+        _ => false,
+    }
+}
+
+impl<C: Config> std::fmt::Debug for ResponseChain<'_, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseChain")
+            .field("template", &self.template)
+            .field("previous_response_id", &self.previous_response_id)
+            .field("turns", &self.turns)
+            .finish()
+    }
+}
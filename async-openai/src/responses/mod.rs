@@ -1,7 +1,9 @@
+mod chain;
 mod conversation_items;
 mod conversations;
 mod responses_;
 
+pub use chain::*;
 pub use conversation_items::*;
 pub use conversations::*;
 pub use responses_::*;
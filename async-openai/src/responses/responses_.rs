@@ -8,11 +8,13 @@ use crate::{
     Client, RequestOptions,
 };
 
+use super::ResponseChain;
+
 #[cfg(not(target_family = "wasm"))]
 use crate::types::responses::ResponseStream;
 
 pub struct Responses<'c, C: Config> {
-    client: &'c Client<C>,
+    pub(crate) client: &'c Client<C>,
     pub(crate) request_options: RequestOptions,
 }
 
@@ -44,6 +46,11 @@ impl<'c, C: Config> Responses<'c, C> {
             .await
     }
 
+    /// A multi-turn conversation creating responses from `template`, see [ResponseChain].
+    pub fn chain(self, template: CreateResponse) -> ResponseChain<'c, C> {
+        ResponseChain::new(self, template)
+    }
+
     /// Creates a model response for the given input with streaming.
     ///
     /// Response events will be sent as server-sent events as they become available,
@@ -0,0 +1,343 @@
+//! Client side token counting for chat messages, using [tiktoken-rs](https://docs.rs/tiktoken-rs).
+//!
+//! Counts follow the message framing rules from the
+//! [OpenAI cookbook](https://cookbook.openai.com/examples/how_to_count_tokens_with_tiktoken),
+//! so they can be used to check a conversation against the context window of a model before sending it.
+//! Only text is counted: images, audio and files attached to a message are ignored.
+
+use tiktoken_rs::CoreBPE;
+
+use crate::error::OpenAIError;
+use crate::types::chat::{
+    ChatCompletionMessageToolCalls, ChatCompletionRequestAssistantMessageContent,
+    ChatCompletionRequestAssistantMessageContentPart, ChatCompletionRequestDeveloperMessageContent,
+    ChatCompletionRequestDeveloperMessageContentPart, ChatCompletionRequestMessage,
+    ChatCompletionRequestSystemMessageContent, ChatCompletionRequestSystemMessageContentPart,
+    ChatCompletionRequestToolMessageContent, ChatCompletionRequestToolMessageContentPart,
+    ChatCompletionRequestUserMessageContent, ChatCompletionRequestUserMessageContentPart,
+};
+
+/// Tokens added to every reply, which is primed with `<|start|>assistant<|message|>`.
+const REPLY_PRIMING_TOKENS: usize = 3;
+
+/// The token encoding used by a model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Used by `gpt-4o`, `gpt-4.1`, `gpt-4.5`, `gpt-5` and `o`-series models.
+    O200kBase,
+    /// Used by `gpt-4` and `gpt-3.5-turbo` models.
+    Cl100kBase,
+}
+
+impl Encoding {
+    /// Returns the encoding of `model`, or [OpenAIError::InvalidArgument] when the model is not known.
+    pub fn for_model(model: &str) -> Result<Self, OpenAIError> {
+        const O200K_BASE: [&str; 7] = ["gpt-4o", "gpt-4.1", "gpt-4.5", "gpt-5", "o1", "o3", "o4"];
+        const CL100K_BASE: [&str; 2] = ["gpt-4", "gpt-3.5-turbo"];
+
+        // fine-tuned models are named `ft:{base model}:{org}:{suffix}:{id}`
+        let model = model.strip_prefix("ft:").unwrap_or(model);
+
+        if O200K_BASE.iter().any(|prefix| model.starts_with(prefix)) {
+            Ok(Encoding::O200kBase)
+        } else if CL100K_BASE.iter().any(|prefix| model.starts_with(prefix)) {
+            Ok(Encoding::Cl100kBase)
+        } else {
+            Err(OpenAIError::InvalidArgument(format!(
+                "no token encoding known for model: {model}"
+            )))
+        }
+    }
+
+    fn bpe(self) -> &'static CoreBPE {
+        match self {
+            Encoding::O200kBase => tiktoken_rs::o200k_base_singleton(),
+            Encoding::Cl100kBase => tiktoken_rs::cl100k_base_singleton(),
+        }
+    }
+
+    /// Number of tokens in `text`.
+    pub fn count(self, text: &str) -> usize {
+        self.bpe().encode_with_special_tokens(text).len()
+    }
+}
+
+/// Counts the prompt tokens `messages` take up when sent to `model`, including the tokens
+/// priming the reply.
+///
+/// Returns [OpenAIError::InvalidArgument] when the encoding of `model` is not known.
+pub fn count_chat_tokens(
+    model: &str,
+    messages: &[ChatCompletionRequestMessage],
+) -> Result<usize, OpenAIError> {
+    let encoding = Encoding::for_model(model)?;
+
+    Ok(messages
+        .iter()
+        .map(|message| count_message_tokens(model, encoding, message))
+        .sum::<usize>()
+        + REPLY_PRIMING_TOKENS)
+}
+
+/// Number of tokens of a single message, including the framing overhead.
+pub(crate) fn count_message_tokens(
+    model: &str,
+    encoding: Encoding,
+    message: &ChatCompletionRequestMessage,
+) -> usize {
+    // gpt-3.5-turbo-0301 frames messages as `<|start|>{role/name}\n{content}<|end|>\n`
+    // and omits the role when a name is present.
+    let legacy = model == "gpt-3.5-turbo-0301";
+    let (tokens_per_message, tokens_per_name) = if legacy { (4, 0) } else { (3, 1) };
+
+    let mut texts: Vec<&str> = Vec::new();
+    let mut name = None;
+
+    match message {
+        ChatCompletionRequestMessage::Developer(message) => {
+            texts.push("developer");
+            name = message.name.as_deref();
+            match &message.content {
+                ChatCompletionRequestDeveloperMessageContent::Text(text) => texts.push(text),
+                ChatCompletionRequestDeveloperMessageContent::Array(parts) => {
+                    texts.extend(parts.iter().map(|part| match part {
+                        ChatCompletionRequestDeveloperMessageContentPart::Text(part) => {
+                            part.text.as_str()
+                        }
+                    }))
+                }
+            }
+        }
+        ChatCompletionRequestMessage::System(message) => {
+            texts.push("system");
+            name = message.name.as_deref();
+            match &message.content {
+                ChatCompletionRequestSystemMessageContent::Text(text) => texts.push(text),
+                ChatCompletionRequestSystemMessageContent::Array(parts) => {
+                    texts.extend(parts.iter().map(|part| match part {
+                        ChatCompletionRequestSystemMessageContentPart::Text(part) => {
+                            part.text.as_str()
+                        }
+                    }))
+                }
+            }
+        }
+        ChatCompletionRequestMessage::User(message) => {
+            texts.push("user");
+            name = message.name.as_deref();
+            match &message.content {
+                ChatCompletionRequestUserMessageContent::Text(text) => texts.push(text),
+                ChatCompletionRequestUserMessageContent::Array(parts) => {
+                    texts.extend(parts.iter().filter_map(|part| match part {
+                        ChatCompletionRequestUserMessageContentPart::Text(part) => {
+                            Some(part.text.as_str())
+                        }
+                        _ => None,
+                    }))
+                }
+            }
+        }
+        ChatCompletionRequestMessage::Assistant(message) => {
+            texts.push("assistant");
+            name = message.name.as_deref();
+            match &message.content {
+                Some(ChatCompletionRequestAssistantMessageContent::Text(text)) => texts.push(text),
+                Some(ChatCompletionRequestAssistantMessageContent::Array(parts)) => {
+                    texts.extend(parts.iter().map(|part| match part {
+                        ChatCompletionRequestAssistantMessageContentPart::Text(part) => {
+                            part.text.as_str()
+                        }
+                        ChatCompletionRequestAssistantMessageContentPart::Refusal(part) => {
+                            part.refusal.as_str()
+                        }
+                    }))
+                }
+                None => {}
+            }
+            if let Some(refusal) = &message.refusal {
+                texts.push(refusal);
+            }
+            for tool_call in message.tool_calls.iter().flatten() {
+                match tool_call {
+                    ChatCompletionMessageToolCalls::Function(tool_call) => {
+                        texts.push(&tool_call.function.name);
+                        texts.push(&tool_call.function.arguments);
+                    }
+                    ChatCompletionMessageToolCalls::Custom(tool_call) => {
+                        texts.push(&tool_call.custom_tool.name);
+                        texts.push(&tool_call.custom_tool.input);
+                    }
+                }
+            }
+            if let Some(function_call) = &message.function_call {
+                texts.push(&function_call.name);
+                texts.push(&function_call.arguments);
+            }
+        }
+        ChatCompletionRequestMessage::Tool(message) => {
+            texts.push("tool");
+            match &message.content {
+                ChatCompletionRequestToolMessageContent::Text(text) => texts.push(text),
+                ChatCompletionRequestToolMessageContent::Array(parts) => {
+                    texts.extend(parts.iter().map(|part| match part {
+                        ChatCompletionRequestToolMessageContentPart::Text(part) => {
+                            part.text.as_str()
+                        }
+                    }))
+                }
+            }
+        }
+        ChatCompletionRequestMessage::Function(message) => {
+            texts.push("function");
+            name = Some(message.name.as_str());
+            if let Some(content) = &message.content {
+                texts.push(content);
+            }
+        }
+    }
+
+    let mut tokens = tokens_per_message
+        + texts
+            .into_iter()
+            .map(|text| encoding.count(text))
+            .sum::<usize>();
+
+    if let Some(name) = name {
+        tokens += encoding.count(name) + tokens_per_name;
+        if legacy {
+            // the role is omitted when a name is present
+            tokens = tokens.saturating_sub(1);
+        }
+    }
+
+    tokens
+}
+
+/// Trims a conversation to fit a token budget by dropping its oldest messages.
+///
+/// The leading system (and developer) messages are always kept. An assistant message with
+/// `tool_calls` and the `tool` messages answering it are kept or dropped together, and `tool`
+/// messages without the assistant message that triggered them are removed.
+///
+/// ```
+/// # use async_openai::{error::OpenAIError, tokenizer::ChatHistoryTrimmer, types::chat::ChatCompletionRequestMessage};
+/// # fn trim(history: Vec<ChatCompletionRequestMessage>) -> Result<(), OpenAIError> {
+/// let (messages, tokens) = ChatHistoryTrimmer::new("gpt-4o", 8_000).trim(&history)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChatHistoryTrimmer {
+    model: String,
+    max_tokens: usize,
+}
+
+impl ChatHistoryTrimmer {
+    /// Trims conversations for `model` to at most `max_tokens` prompt tokens, as counted by [count_chat_tokens].
+    pub fn new<S: Into<String>>(model: S, max_tokens: usize) -> Self {
+        Self {
+            model: model.into(),
+            max_tokens,
+        }
+    }
+
+    /// Returns the most recent messages that fit the budget, along with their token count.
+    ///
+    /// Returns [OpenAIError::InvalidArgument] when the encoding of the model is not known, or when
+    /// the system prompt and the last message do not fit the budget on their own.
+    pub fn trim(
+        &self,
+        messages: &[ChatCompletionRequestMessage],
+    ) -> Result<(Vec<ChatCompletionRequestMessage>, usize), OpenAIError> {
+        let encoding = Encoding::for_model(&self.model)?;
+        let tokens = |messages: &[ChatCompletionRequestMessage]| -> usize {
+            messages
+                .iter()
+                .map(|message| count_message_tokens(&self.model, encoding, message))
+                .sum()
+        };
+
+        let system_len = messages
+            .iter()
+            .take_while(|message| {
+                matches!(
+                    message,
+                    ChatCompletionRequestMessage::System(_)
+                        | ChatCompletionRequestMessage::Developer(_)
+                )
+            })
+            .count();
+        let (system, history) = messages.split_at(system_len);
+
+        let groups = group_tool_calls(history);
+        let group_tokens: Vec<usize> = groups.iter().map(|group| tokens(group)).collect();
+
+        let mut used = tokens(system) + REPLY_PRIMING_TOKENS + group_tokens.iter().sum::<usize>();
+        let mut first_kept = 0;
+        while used > self.max_tokens && first_kept < groups.len() {
+            used -= group_tokens[first_kept];
+            first_kept += 1;
+        }
+
+        // Never return a conversation without its last message.
+        if used > self.max_tokens || (first_kept == groups.len() && !groups.is_empty()) {
+            return Err(OpenAIError::InvalidArgument(format!(
+                "messages do not fit in {} tokens even after trimming the history",
+                self.max_tokens
+            )));
+        }
+
+        let mut trimmed = system.to_vec();
+        trimmed.extend(groups[first_kept..].iter().flatten().cloned());
+
+        Ok((trimmed, used))
+    }
+}
+
+/// Splits `messages` into groups which can be dropped independently: an assistant message
+/// with tool calls together with its tool results, or a single other message.
+/// Tool results without a matching tool call are discarded.
+fn group_tool_calls(
+    messages: &[ChatCompletionRequestMessage],
+) -> Vec<Vec<ChatCompletionRequestMessage>> {
+    let mut groups: Vec<Vec<ChatCompletionRequestMessage>> = Vec::new();
+    let mut pending_tool_call_ids: Vec<&str> = Vec::new();
+
+    for message in messages {
+        match message {
+            ChatCompletionRequestMessage::Tool(tool) => {
+                // orphaned tool results are dropped, the API rejects them without their tool call
+                if let Some(position) = pending_tool_call_ids
+                    .iter()
+                    .position(|id| *id == tool.tool_call_id)
+                {
+                    pending_tool_call_ids.remove(position);
+                    groups
+                        .last_mut()
+                        .expect("a tool call is pending")
+                        .push(message.clone());
+                }
+            }
+            ChatCompletionRequestMessage::Assistant(assistant) => {
+                pending_tool_call_ids = assistant
+                    .tool_calls
+                    .iter()
+                    .flatten()
+                    .map(|tool_call| match tool_call {
+                        ChatCompletionMessageToolCalls::Function(tool_call) => {
+                            tool_call.id.as_str()
+                        }
+                        ChatCompletionMessageToolCalls::Custom(tool_call) => tool_call.id.as_str(),
+                    })
+                    .collect();
+                groups.push(vec![message.clone()]);
+            }
+            _ => {
+                pending_tool_call_ids.clear();
+                groups.push(vec![message.clone()]);
+            }
+        }
+    }
+
+    groups
+}
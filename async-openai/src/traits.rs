@@ -1,3 +1,5 @@
+use std::{collections::HashMap, time::Duration};
+
 use reqwest::header::HeaderMap;
 
 use crate::{error::OpenAIError, RequestOptions};
@@ -32,6 +34,10 @@ pub trait RequestOptionsBuilder: Sized {
     fn options(&self) -> &RequestOptions;
 
     /// Add headers to RequestOptions
+    ///
+    /// Headers are sent in addition to the headers of the [Config](crate::config::Config), and
+    /// replace the config headers of the same name, e.g. to route a request through a gateway
+    /// with `x-portkey-provider` or to bill it to another `OpenAI-Project`.
     fn headers(mut self, headers: HeaderMap) -> Self {
         self.options_mut().with_headers(headers);
         self
@@ -48,7 +54,22 @@ pub trait RequestOptionsBuilder: Sized {
         Ok(self)
     }
 
+    /// Send `key` in the `Idempotency-Key` header, for servers to recognize retries of a request
+    /// they already processed, e.g. to not create a batch, upload or fine-tuning job twice.
+    ///
+    /// Retries of the request by the client send the same key, and
+    /// [Client::with_idempotency_keys](crate::Client::with_idempotency_keys) generates one for
+    /// requests without it. OpenAI does not document which endpoints deduplicate requests by this
+    /// header, and endpoints that do not support it ignore it, so it can be sent to any endpoint;
+    /// gateways and OpenAI-compatible providers supporting it honor it as well.
+    fn idempotency_key(self, key: impl Into<String>) -> Result<Self, OpenAIError> {
+        self.header(crate::request_options::IDEMPOTENCY_KEY, key.into())
+    }
+
     /// Add query parameters to RequestOptions
+    ///
+    /// Parameters are appended to the query of the [Config](crate::config::Config), such as the
+    /// `api-version` of Azure, which is always sent.
     fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Result<Self, OpenAIError> {
         self.options_mut().with_query(query)?;
         Ok(self)
@@ -59,4 +80,63 @@ pub trait RequestOptionsBuilder: Sized {
         self.options_mut().with_path(path.into().as_str())?;
         Ok(self)
     }
+
+    /// Send extra fields at the top level of JSON request bodies, e.g. parameters of providers
+    /// extending the OpenAI API, or of the API that are not available in the request types yet.
+    ///
+    /// Requests fail with [OpenAIError::InvalidArgument] when an extra field is also set by the
+    /// request itself. Fields set to `None` in the request are not sent, so they can be set
+    /// here instead. Multipart requests, such as file uploads, do not include extra fields.
+    fn extra_body(mut self, extra_body: HashMap<String, serde_json::Value>) -> Self {
+        self.options_mut().with_extra_body(extra_body);
+        self
+    }
+
+    /// Set a timeout for the request, overriding the timeout of the underlying `reqwest::Client`.
+    ///
+    /// For streaming requests the timeout covers the entire stream,
+    /// use [stream_idle_timeout](Self::stream_idle_timeout) to abort stalled streams instead.
+    fn timeout(mut self, timeout: Duration) -> Self {
+        self.options_mut().with_timeout(timeout);
+        self
+    }
+
+    /// Abort a streaming request with [StreamError::IdleTimeout](crate::error::StreamError::IdleTimeout)
+    /// when no event is received within `timeout`.
+    fn stream_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.options_mut().with_stream_idle_timeout(timeout);
+        self
+    }
+
+    /// Reconnect a streaming request up to `max_retries` times when the connection drops, resuming
+    /// the stream by sending the id of the last received event in the `Last-Event-ID` header.
+    ///
+    /// Only streams whose events carry an SSE `id` can be resumed, as resuming without one would
+    /// generate the response again. For streams without event ids, which includes chat
+    /// completions, this is a no-op and a dropped connection ends the stream with an error.
+    /// Multipart streaming requests, such as audio transcriptions, are never reconnected.
+    fn stream_reconnect(mut self, max_retries: usize) -> Self {
+        self.options_mut().with_stream_reconnect(max_retries);
+        self
+    }
+}
+
+/// Cancellation of in-flight streaming responses, as returned by the `create_stream` methods.
+///
+/// Dropping a stream cancels it too: the connection is closed as soon as the stream is
+/// dropped, without waiting for the next event from the server.
+#[cfg(not(target_family = "wasm"))]
+pub trait CancelStream {
+    /// Closes the connection, after which the stream yields no more items.
+    fn cancel(&mut self);
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl<T: Send + 'static> CancelStream
+    for std::pin::Pin<Box<dyn futures::Stream<Item = Result<T, OpenAIError>> + Send>>
+{
+    fn cancel(&mut self) {
+        // Dropping the receiving end stops the task reading the server-sent events.
+        *self = Box::pin(futures::stream::empty());
+    }
 }
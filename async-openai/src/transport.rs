@@ -0,0 +1,509 @@
+//! Pluggable HTTP transport for [Client](crate::Client), and a [MockTransport] and a
+//! [RecordingTransport] to test code using this crate without network.
+//!
+//! ```
+//! # #[cfg(feature = "chat-completion")]
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! use async_openai::{
+//!     transport::{MockResponse, MockTransport},
+//!     types::chat::{ChatCompletionRequestUserMessage, CreateChatCompletionRequestArgs},
+//!     Client,
+//! };
+//!
+//! let transport = MockTransport::new().on(
+//!     "/chat/completions",
+//!     MockResponse::json(
+//!         200,
+//!         serde_json::json!({
+//!             "id": "chatcmpl-123",
+//!             "object": "chat.completion",
+//!             "created": 1677652288,
+//!             "model": "gpt-4o-mini",
+//!             "choices": [{
+//!                 "index": 0,
+//!                 "message": { "role": "assistant", "content": "Hello there!" },
+//!                 "finish_reason": "stop"
+//!             }]
+//!         }),
+//!     ),
+//! );
+//! let client = Client::new().with_transport(transport.clone());
+//!
+//! let request = CreateChatCompletionRequestArgs::default()
+//!     .model("gpt-4o-mini")
+//!     .messages([ChatCompletionRequestUserMessage::from("Hello").into()])
+//!     .build()?;
+//! let response = client.chat().create(request).await?;
+//!
+//! assert_eq!(response.choices[0].message.content.as_deref(), Some("Hello there!"));
+//! assert_eq!(transport.requests()[0].json().unwrap()["model"], "gpt-4o-mini");
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use base64::{engine::general_purpose, Engine as _};
+use bytes::Bytes;
+use reqwest::{header::HeaderMap, Method, Request, Response};
+use serde::{Deserialize, Serialize};
+
+use crate::error::OpenAIError;
+
+/// Sends the requests built by a [Client](crate::Client).
+///
+/// Set one with [Client::with_transport](crate::Client::with_transport). The default transport
+/// sends requests with the `reqwest::Client` of the [Client](crate::Client). Requests are still
+/// retried by the [Client](crate::Client) according to its backoff, when the transport returns
+/// a retryable status code.
+///
+/// SSE streaming requests always go through the `reqwest::Client`.
+pub trait HttpTransport: Debug + Send + Sync {
+    /// Sends `request` and returns the response of the server.
+    fn execute(
+        &self,
+        request: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Response, OpenAIError>> + Send + '_>>;
+}
+
+/// A canned response of a [MockTransport].
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Bytes,
+}
+
+impl MockResponse {
+    /// Response with the given status code and raw body.
+    pub fn new(status: u16, body: impl Into<Bytes>) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: body.into(),
+        }
+    }
+
+    /// Response with the given status code and `body` serialized as JSON.
+    pub fn json(status: u16, body: impl serde::Serialize) -> Self {
+        Self::new(
+            status,
+            serde_json::to_vec(&body).expect("mock response body must serialize to JSON"),
+        )
+        .header("content-type", "application/json")
+    }
+
+    /// Response in the format of API errors, e.g. `MockResponse::error(429, "rate_limit_exceeded", "Rate limit reached")`.
+    pub fn error(status: u16, code: &str, message: &str) -> Self {
+        Self::json(
+            status,
+            serde_json::json!({
+                "error": {
+                    "message": message,
+                    "type": code,
+                    "param": null,
+                    "code": code,
+                }
+            }),
+        )
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    fn to_response(&self) -> Result<Response, OpenAIError> {
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(self.body.clone())
+            .map_err(|e| OpenAIError::InvalidArgument(format!("invalid mock response: {e}")))?;
+        Ok(Response::from(response))
+    }
+}
+
+/// A request received by a [MockTransport].
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    pub method: Method,
+    pub url: reqwest::Url,
+    pub headers: HeaderMap,
+    /// The body of the request, `None` for streamed bodies such as multipart uploads.
+    pub body: Option<Bytes>,
+}
+
+impl MockRequest {
+    /// The body of the request parsed as JSON.
+    pub fn json(&self) -> Option<serde_json::Value> {
+        serde_json::from_slice(self.body.as_ref()?).ok()
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<(String, VecDeque<MockResponse>)>,
+    requests: Vec<MockRequest>,
+}
+
+/// An [HttpTransport] serving canned responses per endpoint, and recording the requests it receives.
+///
+/// Responses registered for the same path are served in order, and the last one repeats.
+/// Requests to paths without responses fail with [OpenAIError::InvalidArgument].
+/// Clones share their responses and recorded requests.
+///
+/// Retryable responses (`429` and `5xx`) are retried by the [Client](crate::Client), use
+/// [Client::with_max_retries](crate::Client::with_max_retries) to control how many times.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `response` for requests whose path ends with `path`, e.g. `/chat/completions`.
+    pub fn on(self, path: impl Into<String>, response: MockResponse) -> Self {
+        let path = path.into();
+        {
+            let mut state = self.state.lock().unwrap();
+            match state.routes.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, responses)) => responses.push_back(response),
+                None => state.routes.push((path, VecDeque::from([response]))),
+            }
+        }
+        self
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    fn respond(&self, request: Request) -> Result<Response, OpenAIError> {
+        let mut state = self.state.lock().unwrap();
+        let path = request.url().path().to_string();
+
+        state.requests.push(MockRequest {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(Bytes::copy_from_slice),
+        });
+
+        // the longest matching path wins, so `/completions` does not shadow `/chat/completions`
+        let responses = state
+            .routes
+            .iter_mut()
+            .filter(|(p, _)| path.ends_with(p.as_str()))
+            .max_by_key(|(p, _)| p.len())
+            .map(|(_, responses)| responses)
+            .ok_or_else(|| {
+                OpenAIError::InvalidArgument(format!(
+                    "no mock response for {} {path}",
+                    request.method()
+                ))
+            })?;
+
+        let response = if responses.len() > 1 {
+            responses.pop_front().unwrap()
+        } else {
+            responses[0].clone()
+        };
+        response.to_response()
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn execute(
+        &self,
+        request: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Response, OpenAIError>> + Send + '_>> {
+        let response = self.respond(request);
+        Box::pin(async move { response })
+    }
+}
+
+/// How a [RecordingTransport] uses its recordings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingMode {
+    /// Replays the recording of a request when there is one, sends the request and records its
+    /// response otherwise.
+    RecordOrReplay,
+    /// Only replays recordings, requests without one fail with [OpenAIError::InvalidArgument].
+    /// For CI without an API key.
+    Replay,
+    /// Always sends requests, and overwrites their recordings.
+    Record,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Recording {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    url: String,
+    headers: Vec<(String, String)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encoding: Option<BodyEncoding>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encoding: Option<BodyEncoding>,
+}
+
+/// How a body that is neither JSON nor text is recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum BodyEncoding {
+    Base64,
+}
+
+/// Headers with credentials or identifying the account, replaced by `REDACTED` in recordings.
+const REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "api-key",
+    "cookie",
+    "set-cookie",
+    "openai-organization",
+    "openai-project",
+];
+
+fn recorded_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = match REDACTED_HEADERS.contains(&name.as_str()) {
+                true => "REDACTED",
+                false => value.to_str().unwrap_or_default(),
+            };
+            (name.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+/// JSON objects and arrays are recorded as JSON, for readable recordings, other UTF-8 bodies as
+/// text, and binary bodies such as audio as base64.
+fn recorded_body(body: &[u8]) -> (serde_json::Value, Option<BodyEncoding>) {
+    match serde_json::from_slice(body) {
+        Ok(value @ (serde_json::Value::Object(_) | serde_json::Value::Array(_))) => (value, None),
+        _ => match std::str::from_utf8(body) {
+            Ok(text) => (text.into(), None),
+            Err(_) => (
+                general_purpose::STANDARD.encode(body).into(),
+                Some(BodyEncoding::Base64),
+            ),
+        },
+    }
+}
+
+fn replayed_body(
+    body: serde_json::Value,
+    encoding: Option<BodyEncoding>,
+) -> Result<Bytes, OpenAIError> {
+    Ok(match (body, encoding) {
+        (serde_json::Value::String(body), Some(BodyEncoding::Base64)) => general_purpose::STANDARD
+            .decode(body)
+            .map_err(|e| OpenAIError::InvalidArgument(format!("invalid base64 body: {e}")))?
+            .into(),
+        (serde_json::Value::String(body), _) => body.into(),
+        (body, _) => serde_json::to_vec(&body).unwrap().into(),
+    })
+}
+
+/// FNV-1a, which unlike the hashers of `std` is stable across Rust versions and platforms.
+fn fnv1a(parts: &[&[u8]]) -> u64 {
+    parts
+        .iter()
+        .flat_map(|part| part.iter().chain(b"\n"))
+        .fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+/// An [HttpTransport] recording the responses of the API to JSON files on the first run, and
+/// replaying them afterwards without network, e.g. to run tests in CI without an API key.
+///
+/// Recordings are named after the path of the request and a hash of its method, path, query and
+/// body, such as `v1_chat_completions-8c2d5e0f1a3b4c7d.json`. Credentials and account headers
+/// (`Authorization`, `api-key`, `Cookie`, `Set-Cookie`, `OpenAI-Organization` and
+/// `OpenAI-Project`) are redacted from them, so that they can be committed. Bodies other than
+/// JSON are recorded as text, or as base64 when they are binary, and multipart uploads are
+/// matched without their body.
+///
+/// SSE streaming requests do not go through transports, and are not recorded.
+///
+/// ```no_run
+/// # #[cfg(feature = "model")]
+/// # async fn example() -> Result<(), async_openai::error::OpenAIError> {
+/// use async_openai::{
+///     transport::{RecordingMode, RecordingTransport},
+///     Client,
+/// };
+///
+/// let transport = RecordingTransport::new("tests/recordings", RecordingMode::RecordOrReplay);
+/// let client = Client::new().with_transport(transport);
+/// let models = client.models().list().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RecordingTransport {
+    dir: PathBuf,
+    mode: RecordingMode,
+    http_client: reqwest::Client,
+}
+
+impl RecordingTransport {
+    /// Records to and replays from the files in `dir`, which is created on the first recording.
+    pub fn new(dir: impl Into<PathBuf>, mode: RecordingMode) -> Self {
+        Self {
+            dir: dir.into(),
+            mode,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// The HTTP client sending the requests to record.
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = http_client;
+        self
+    }
+
+    fn recording_path(&self, request: &Request) -> PathBuf {
+        let url = request.url();
+        let body = request.body().and_then(|body| body.as_bytes());
+        let hash = fnv1a(&[
+            request.method().as_str().as_bytes(),
+            url.path().as_bytes(),
+            url.query().unwrap_or_default().as_bytes(),
+            body.unwrap_or_default(),
+        ]);
+        let name = url.path().trim_matches('/').replace('/', "_");
+        self.dir.join(format!("{name}-{hash:016x}.json"))
+    }
+
+    async fn replay(&self, path: &Path) -> Result<Option<Response>, OpenAIError> {
+        let bytes = match tokio::fs::read(path).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(OpenAIError::FileReadError(format!(
+                    "{}: {e}",
+                    path.display()
+                )))
+            }
+        };
+        let recording: Recording = serde_json::from_slice(&bytes).map_err(|e| {
+            OpenAIError::JSONDeserialize(e, String::from_utf8_lossy(&bytes).into_owned())
+        })?;
+
+        let response = MockResponse {
+            status: recording.response.status,
+            headers: recording.response.headers,
+            body: replayed_body(recording.response.body, recording.response.encoding)?,
+        };
+        response.to_response().map(Some)
+    }
+
+    async fn record(&self, path: &Path, request: Request) -> Result<Response, OpenAIError> {
+        let (request_body, request_encoding) = match request.body().and_then(|b| b.as_bytes()) {
+            Some(body) => {
+                let (body, encoding) = recorded_body(body);
+                (Some(body), encoding)
+            }
+            None => (None, None),
+        };
+        let recorded_request = RecordedRequest {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            headers: recorded_headers(request.headers()),
+            body: request_body,
+            encoding: request_encoding,
+        };
+
+        let response = self.http_client.execute(request).await?;
+        let status = response.status().as_u16();
+        let headers = recorded_headers(response.headers());
+        let body = response.bytes().await?;
+
+        let (recorded, encoding) = recorded_body(&body);
+        let recording = Recording {
+            request: recorded_request,
+            response: RecordedResponse {
+                status,
+                headers: headers.clone(),
+                body: recorded,
+                encoding,
+            },
+        };
+        let save_error =
+            |e: std::io::Error| OpenAIError::FileSaveError(format!("{}: {e}", path.display()));
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(save_error)?;
+        tokio::fs::write(path, serde_json::to_vec_pretty(&recording).unwrap())
+            .await
+            .map_err(save_error)?;
+
+        MockResponse {
+            status,
+            headers,
+            body,
+        }
+        .to_response()
+    }
+
+    async fn record_or_replay(&self, request: Request) -> Result<Response, OpenAIError> {
+        let path = self.recording_path(&request);
+
+        if self.mode != RecordingMode::Record {
+            if let Some(response) = self.replay(&path).await? {
+                return Ok(response);
+            }
+            if self.mode == RecordingMode::Replay {
+                return Err(OpenAIError::InvalidArgument(format!(
+                    "no recording for {} {} at {}",
+                    request.method(),
+                    request.url().path(),
+                    path.display()
+                )));
+            }
+        }
+
+        self.record(&path, request).await
+    }
+}
+
+impl HttpTransport for RecordingTransport {
+    fn execute(
+        &self,
+        request: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Response, OpenAIError>> + Send + '_>> {
+        Box::pin(self.record_or_replay(request))
+    }
+}
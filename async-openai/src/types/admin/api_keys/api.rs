@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 
 /// Sort order for listing admin API keys.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ListAdminApiKeysOrder {
     /// Ascending order
@@ -15,7 +16,8 @@ pub enum ListAdminApiKeysOrder {
 
 /// Query parameters for listing admin API keys.
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ListAdminApiKeysQueryArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 
 /// Represents an individual Admin API key in an org.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AdminApiKey {
     /// The object type, which is always `organization.admin_api_key`.
     pub object: String,
@@ -27,7 +28,8 @@ pub struct AdminApiKey {
 
 /// Represents the owner of an admin API key.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AdminApiKeyOwner {
     pub r#type: String,
 
@@ -44,7 +46,8 @@ pub struct AdminApiKeyOwner {
 
 /// Represents the response object for listing admin API keys.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApiKeyList {
     /// The object type, which is always `list`.
     pub object: String,
@@ -60,7 +63,8 @@ pub struct ApiKeyList {
 
 /// Represents the request object for creating an admin API key.
 #[derive(Debug, Serialize, Deserialize, Builder, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateAdminApiKeyRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -73,7 +77,8 @@ pub struct CreateAdminApiKeyRequest {
 
 /// Represents the response object for deleting an admin API key.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AdminApiKeyDeleteResponse {
     /// The object type, which is always `organization.admin_api_key.deleted`.
     pub object: String,
@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 
 /// Sort order for listing organization certificates.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ListOrganizationCertificatesOrder {
     /// Ascending order
@@ -15,7 +16,8 @@ pub enum ListOrganizationCertificatesOrder {
 
 /// Query parameters for listing organization certificates.
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ListOrganizationCertificatesQueryArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -35,7 +37,8 @@ pub struct ListOrganizationCertificatesQuery {
 
 /// Sort order for listing project certificates.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ListProjectCertificatesOrder {
     /// Ascending order
@@ -46,7 +49,8 @@ pub enum ListProjectCertificatesOrder {
 
 /// Query parameters for listing project certificates.
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ListProjectCertificatesQueryArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -66,7 +70,8 @@ pub struct ListProjectCertificatesQuery {
 
 /// Query parameters for getting a certificate.
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "GetCertificateQueryArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 
 /// Represents an individual certificate uploaded to the organization.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Certificate {
     /// The object type. Can be `certificate`, `organization.certificate`, or `organization.project.certificate`.
     pub object: String,
@@ -24,7 +25,8 @@ pub struct Certificate {
 
 /// Details about a certificate.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CertificateDetails {
     /// The Unix timestamp (in seconds) of when the certificate becomes valid.
     pub valid_at: u64,
@@ -38,7 +40,8 @@ pub struct CertificateDetails {
 
 /// Response for listing certificates.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListCertificatesResponse {
     /// The object type, which is always `list`.
     pub object: String,
@@ -56,7 +59,8 @@ pub struct ListCertificatesResponse {
 
 /// Request for uploading a certificate.
 #[derive(Debug, Serialize, Deserialize, Builder, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "UploadCertificateRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -72,7 +76,8 @@ pub struct UploadCertificateRequest {
 
 /// Request for modifying a certificate.
 #[derive(Debug, Serialize, Deserialize, Builder, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ModifyCertificateRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -85,7 +90,8 @@ pub struct ModifyCertificateRequest {
 
 /// Request for toggling (activating/deactivating) certificates.
 #[derive(Debug, Serialize, Deserialize, Builder, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ToggleCertificatesRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -98,7 +104,8 @@ pub struct ToggleCertificatesRequest {
 
 /// Response for deleting a certificate.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteCertificateResponse {
     /// The object type, which is always `certificate.deleted`.
     pub object: String,
@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 
 /// Sort order for listing groups.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ListGroupsOrder {
     /// Ascending order
@@ -15,7 +16,8 @@ pub enum ListGroupsOrder {
 
 /// Query parameters for listing groups.
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ListGroupsQueryArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
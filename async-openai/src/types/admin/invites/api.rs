@@ -4,7 +4,8 @@ use serde::Serialize;
 
 /// Query parameters for listing invites.
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ListInvitesQueryArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -4,7 +4,8 @@ use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum InviteStatus {
     Accepted,
@@ -13,7 +14,8 @@ pub enum InviteStatus {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "InviteRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -25,7 +27,8 @@ pub struct InviteRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InviteListResponse {
     pub object: String,
     pub data: Vec<Invite>,
@@ -35,7 +38,8 @@ pub struct InviteListResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InviteDeleteResponse {
     /// The object type, which is always `organization.invite.deleted`
     pub object: String,
@@ -45,7 +49,8 @@ pub struct InviteDeleteResponse {
 
 /// Represents an individual `invite` to the organization.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Invite {
     /// The object type, which is always `organization.invite`
     pub object: String,
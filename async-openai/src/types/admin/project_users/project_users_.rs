@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 
 /// Represents an individual user in a project.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ProjectUser {
     /// The object type, which is always `organization.project.user`
     pub object: String,
@@ -22,7 +23,8 @@ pub struct ProjectUser {
 
 /// `owner` or `member`
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ProjectUserRole {
     Owner,
@@ -30,7 +32,8 @@ pub enum ProjectUserRole {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ProjectUserListResponse {
     pub object: String,
     pub data: Vec<ProjectUser>,
@@ -41,7 +44,8 @@ pub struct ProjectUserListResponse {
 
 /// The project user create request payload.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ProjectUserCreateRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -55,7 +59,8 @@ pub struct ProjectUserCreateRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ProjectUserUpdateRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -67,7 +72,8 @@ pub struct ProjectUserUpdateRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ProjectUserDeleteResponse {
     pub object: String,
     pub id: String,
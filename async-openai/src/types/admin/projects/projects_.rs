@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 
 /// `active` or `archived`
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ProjectStatus {
     Active,
@@ -13,7 +14,8 @@ pub enum ProjectStatus {
 
 /// Represents an individual project.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Project {
     /// The identifier, which can be referenced in API endpoints
     pub id: String,
@@ -31,7 +33,8 @@ pub struct Project {
 
 /// A list of Project objects.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ProjectListResponse {
     pub object: String,
     pub data: Vec<Project>,
@@ -42,7 +45,8 @@ pub struct ProjectListResponse {
 
 /// The project create request payload.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ProjectCreateRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -55,7 +59,8 @@ pub struct ProjectCreateRequest {
 
 /// The project update request payload.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ProjectUpdateRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -68,7 +73,8 @@ pub struct ProjectUpdateRequest {
 
 /// Details about a group's membership in a project.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ProjectGroup {
     /// The object type, which is always `project.group`.
     pub object: String,
@@ -84,7 +90,8 @@ pub struct ProjectGroup {
 
 /// Paginated list of groups that have access to a project.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ProjectGroupListResource {
     /// The object type, which is always `list`.
     pub object: String,
@@ -98,7 +105,8 @@ pub struct ProjectGroupListResource {
 
 /// Confirmation payload returned after removing a group from a project.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ProjectGroupDeletedResource {
     /// The object type, which is always `project.group.deleted`.
     pub object: String,
@@ -108,7 +116,8 @@ pub struct ProjectGroupDeletedResource {
 
 /// Request payload for granting a group access to a project.
 #[derive(Debug, Serialize, Deserialize, Builder, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "InviteProjectGroupRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
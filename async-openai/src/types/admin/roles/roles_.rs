@@ -3,7 +3,8 @@ use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum OrganizationRole {
     Owner,
@@ -12,7 +13,8 @@ pub enum OrganizationRole {
 
 /// Details about a role that can be assigned through the public Roles API.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Role {
     /// The object type, which is always `role`.
     pub object: String,
@@ -32,7 +34,8 @@ pub struct Role {
 
 /// Paginated list of roles available on an organization or project.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct PublicRoleListResource {
     /// The object type, which is always `list`.
     pub object: String,
@@ -46,7 +49,8 @@ pub struct PublicRoleListResource {
 
 /// Request payload for creating a custom role.
 #[derive(Debug, Serialize, Deserialize, Builder, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateOrganizationRoleRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -64,7 +68,8 @@ pub struct PublicCreateOrganizationRoleBody {
 
 /// Request payload for updating an existing role.
 #[derive(Debug, Serialize, Deserialize, Builder, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "UpdateOrganizationRoleRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -84,7 +89,8 @@ pub struct PublicUpdateOrganizationRoleBody {
 
 /// Confirmation payload returned after deleting a role.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RoleDeletedResource {
     /// The object type, which is always `role.deleted`.
     pub object: String,
@@ -96,7 +102,8 @@ pub struct RoleDeletedResource {
 
 /// Detailed information about a role assignment entry returned when listing assignments.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssignedRoleDetails {
     /// Identifier for the role.
     pub id: String,
@@ -118,17 +125,18 @@ pub struct AssignedRoleDetails {
     pub created_by: Option<String>,
     /// User details for the actor that created the role, when available.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub created_by_user_obj: Option<serde_json::Value>,
     /// Arbitrary metadata stored on the role.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub metadata: Option<serde_json::Value>,
 }
 
 /// Paginated list of roles assigned to a principal.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RoleListResource {
     /// The object type, which is always `list`.
     pub object: String,
@@ -142,7 +150,8 @@ pub struct RoleListResource {
 
 /// Confirmation payload returned after unassigning a role.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeletedRoleAssignmentResource {
     /// Identifier for the deleted assignment, such as `group.role.deleted` or `user.role.deleted`.
     pub object: String,
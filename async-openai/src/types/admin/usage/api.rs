@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 
 /// Query parameters for organization usage endpoints.
 #[derive(Debug, Clone, Serialize, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "UsageQueryParamsArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -47,7 +48,8 @@ pub struct UsageQueryParams {
 
 /// Width of each time bucket in response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum UsageBucketWidth {
     #[serde(rename = "1m")]
@@ -60,7 +62,8 @@ pub enum UsageBucketWidth {
 
 /// Fields to group usage data by.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum UsageGroupBy {
     ProjectId,
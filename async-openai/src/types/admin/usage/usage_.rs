@@ -2,7 +2,8 @@ use serde::Deserialize;
 
 /// Response structure for organization usage endpoints.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageResponse {
     /// The object type, which is always `page`.
     pub object: String,
@@ -16,7 +17,8 @@ pub struct UsageResponse {
 
 /// A time bucket containing usage results.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageTimeBucket {
     /// The object type, which is always `bucket`.
     pub object: String,
@@ -36,7 +38,8 @@ pub struct UsageTimeBucket {
 
 /// Discriminated union of all possible usage result types.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum UsageResult {
     AudioSpeeches(UsageAudioSpeechesResult),
@@ -52,7 +55,8 @@ pub enum UsageResult {
 
 /// The aggregated audio speeches usage details of the specific time bucket.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageAudioSpeechesResult {
     /// The object type, which is always `organization.usage.audio_speeches.result`.
     pub object: String,
@@ -72,7 +76,8 @@ pub struct UsageAudioSpeechesResult {
 
 /// The aggregated audio transcriptions usage details of the specific time bucket.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageAudioTranscriptionsResult {
     /// The object type, which is always `organization.usage.audio_transcriptions.result`.
     pub object: String,
@@ -92,7 +97,8 @@ pub struct UsageAudioTranscriptionsResult {
 
 /// The aggregated code interpreter sessions usage details of the specific time bucket.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageCodeInterpreterSessionsResult {
     /// The object type, which is always `organization.usage.code_interpreter_sessions.result`.
     pub object: String,
@@ -106,7 +112,8 @@ pub struct UsageCodeInterpreterSessionsResult {
 
 /// The aggregated completions usage details of the specific time bucket.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageCompletionsResult {
     /// The object type, which is always `organization.usage.completions.result`.
     pub object: String,
@@ -165,7 +172,8 @@ pub struct UsageCompletionsResult {
 
 /// The aggregated embeddings usage details of the specific time bucket.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageEmbeddingsResult {
     /// The object type, which is always `organization.usage.embeddings.result`.
     pub object: String,
@@ -185,7 +193,8 @@ pub struct UsageEmbeddingsResult {
 
 /// The aggregated images usage details of the specific time bucket.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageImagesResult {
     /// The object type, which is always `organization.usage.images.result`.
     pub object: String,
@@ -209,7 +218,8 @@ pub struct UsageImagesResult {
 
 /// The aggregated moderations usage details of the specific time bucket.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageModerationsResult {
     /// The object type, which is always `organization.usage.moderations.result`.
     pub object: String,
@@ -229,7 +239,8 @@ pub struct UsageModerationsResult {
 
 /// The aggregated vector stores usage details of the specific time bucket.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UsageVectorStoresResult {
     /// The object type, which is always `organization.usage.vector_stores.result`.
     pub object: String,
@@ -241,7 +252,8 @@ pub struct UsageVectorStoresResult {
 
 /// The aggregated costs details of the specific time bucket.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CostsResult {
     /// The object type, which is always `organization.costs.result`.
     pub object: String,
@@ -258,7 +270,8 @@ pub struct CostsResult {
 
 /// The monetary value in its associated currency.
 #[derive(Debug, Clone, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CostsAmount {
     /// The numeric value of the cost.
     pub value: f64,
@@ -6,7 +6,8 @@ use serde::{Deserialize, Serialize};
 
 /// Represents an individual `user` within an organization.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct User {
     /// The object type, which is always `organization.user`
     pub object: String,
@@ -24,7 +25,8 @@ pub struct User {
 
 /// A list of `User` objects.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UserListResponse {
     pub object: String,
     pub data: Vec<User>,
@@ -34,7 +36,8 @@ pub struct UserListResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "UserRoleUpdateRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -47,7 +50,8 @@ pub struct UserRoleUpdateRequest {
 
 /// Confirmation of the deleted user
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UserDeleteResponse {
     pub object: String,
     pub id: String,
@@ -56,7 +60,8 @@ pub struct UserDeleteResponse {
 
 /// Role assignment linking a user to a role.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UserRoleAssignment {
     /// The object type, which is always `user.role`.
     pub object: String,
@@ -68,7 +73,8 @@ pub struct UserRoleAssignment {
 
 /// Paginated list of role assignments for a user.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UserRoleAssignmentListResource {
     /// The object type, which is always `list`.
     pub object: String,
@@ -82,7 +88,8 @@ pub struct UserRoleAssignmentListResource {
 
 /// Request payload for assigning a role to a user.
 #[derive(Debug, Serialize, Deserialize, Builder, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "AssignUserRoleRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option))]
@@ -4,27 +4,31 @@ use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 use crate::error::OpenAIError;
+use crate::types::validation::check_range;
 
 use crate::types::assistants::{
     FunctionName, FunctionObject, ResponseFormat, StaticChunkingStrategy,
 };
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantToolCodeInterpreterResources {
     ///A list of [file](https://platform.openai.com/docs/api-reference/files) IDs made available to the `code_interpreter`` tool. There can be a maximum of 20 files associated with the tool.
     pub file_ids: Vec<String>, // maxItems: 20
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantToolFileSearchResources {
     /// The ID of the [vector store](https://platform.openai.com/docs/api-reference/vector-stores/object) attached to this assistant. There can be a maximum of 1 vector store attached to the assistant.
     pub vector_store_ids: Vec<String>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantToolResources {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code_interpreter: Option<AssistantToolCodeInterpreterResources>,
@@ -33,7 +37,8 @@ pub struct AssistantToolResources {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateAssistantToolResources {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code_interpreter: Option<AssistantToolCodeInterpreterResources>,
@@ -42,7 +47,8 @@ pub struct CreateAssistantToolResources {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateAssistantToolFileSearchResources {
     ///  The [vector store](https://platform.openai.com/docs/api-reference/vector-stores/object) attached to this assistant. There can be a maximum of 1 vector store attached to the assistant.
     pub vector_store_ids: Option<Vec<String>>,
@@ -51,7 +57,8 @@ pub struct CreateAssistantToolFileSearchResources {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantVectorStore {
     /// A list of [file](https://platform.openai.com/docs/api-reference/files) IDs to add to the vector store. There can be a maximum of 10000 files in a vector store.
     pub file_ids: Vec<String>,
@@ -64,7 +71,8 @@ pub struct AssistantVectorStore {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum AssistantVectorStoreChunkingStrategy {
     /// The default strategy. This strategy currently uses a `max_chunk_size_tokens` of `800` and `chunk_overlap_tokens` of `400`.
@@ -80,7 +88,8 @@ pub enum AssistantVectorStoreChunkingStrategy {
     note = "Assistants API is deprecated and will be removed in August 2026. Use the Responses API."
 )]
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantObject {
     /// The identifier, which can be referenced in API endpoints.
     pub id: String,
@@ -120,7 +129,8 @@ pub struct AssistantObject {
 ///
 /// **Important:** when using JSON mode, you **must** also instruct the model to produce JSON yourself via a system or user message. Without this, the model may generate an unending stream of whitespace until the generation reaches the token limit, resulting in a long-running and seemingly "stuck" request. Also note that the message content may be partially cut off if `finish_reason="length"`, which indicates the generation exceeded `max_tokens` or the conversation exceeded the max context length.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum AssistantsApiResponseFormatOption {
     #[default]
     #[serde(rename = "auto")]
@@ -131,7 +141,8 @@ pub enum AssistantsApiResponseFormatOption {
 
 /// Retrieval tool
 #[derive(Clone, Serialize, Debug, Default, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantToolsFileSearch {
     /// Overrides for the file search tool.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -139,7 +150,8 @@ pub struct AssistantToolsFileSearch {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantToolsFileSearchOverrides {
     ///  The maximum number of results the file search tool should output. The default is 20 for gpt-4* models and 5 for gpt-3.5-turbo. This number should be between 1 and 50 inclusive.
     ///
@@ -149,7 +161,8 @@ pub struct AssistantToolsFileSearchOverrides {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum FileSearchRanker {
     #[serde(rename = "auto")]
     Auto,
@@ -161,7 +174,8 @@ pub enum FileSearchRanker {
 ///
 /// See the [file search tool documentation](https://platform.openai.com/docs/assistants/tools/file-search#customizing-file-search-settings) for more information.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FileSearchRankingOptions {
     /// The ranker to use for the file search. If not specified will use the `auto` ranker.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -173,13 +187,15 @@ pub struct FileSearchRankingOptions {
 
 /// Function tool
 #[derive(Clone, Serialize, Debug, Default, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantToolsFunction {
     pub function: FunctionObject,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum AssistantTools {
@@ -192,12 +208,13 @@ pub enum AssistantTools {
     note = "Assistants API is deprecated and will be removed in August 2026. Use the Responses API."
 )]
 #[derive(Clone, Serialize, Default, Debug, Deserialize, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateAssistantRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(error = "OpenAIError", validate = "Self::validate"))]
 pub struct CreateAssistantRequest {
     /// ID of the model to use. You can use the [List models](https://platform.openai.com/docs/api-reference/models/list) API to see all of your available models, or see our [Model overview](https://platform.openai.com/docs/models/overview) for descriptions of them.
     pub model: String,
@@ -240,16 +257,25 @@ pub struct CreateAssistantRequest {
     pub response_format: Option<AssistantsApiResponseFormatOption>,
 }
 
+impl CreateAssistantRequestArgs {
+    /// Checks the documented ranges of the parameters set so far, called by [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        check_range("temperature", self.temperature.flatten(), 0.0, 2.0)?;
+        check_range("top_p", self.top_p.flatten(), 0.0, 1.0)
+    }
+}
+
 #[deprecated(
     note = "Assistants API is deprecated and will be removed in August 2026. Use the Responses API."
 )]
 #[derive(Clone, Serialize, Default, Debug, Deserialize, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ModifyAssistantRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(error = "OpenAIError", validate = "Self::validate"))]
 pub struct ModifyAssistantRequest {
     /// ID of the model to use. You can use the [List models](https://platform.openai.com/docs/api-reference/models/list) API to see all of your available models, or see our [Model overview](https://platform.openai.com/docs/models/overview) for descriptions of them.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -292,11 +318,20 @@ pub struct ModifyAssistantRequest {
     pub response_format: Option<AssistantsApiResponseFormatOption>,
 }
 
+impl ModifyAssistantRequestArgs {
+    /// Checks the documented ranges of the parameters set so far, called by [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        check_range("temperature", self.temperature.flatten(), 0.0, 2.0)?;
+        check_range("top_p", self.top_p.flatten(), 0.0, 1.0)
+    }
+}
+
 #[deprecated(
     note = "Assistants API is deprecated and will be removed in August 2026. Use the Responses API."
 )]
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteAssistantResponse {
     pub id: String,
     pub deleted: bool,
@@ -307,7 +342,8 @@ pub struct DeleteAssistantResponse {
     note = "Assistants API is deprecated and will be removed in August 2026. Use the Responses API."
 )]
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListAssistantsResponse {
     pub object: String,
     pub data: Vec<AssistantObject>,
@@ -322,7 +358,8 @@ pub struct ListAssistantsResponse {
 /// `required` means the model must call one or more tools before responding to the user.
 /// Specifying a particular tool like `{"type": "file_search"}` or `{"type": "function", "function": {"name": "my_function"}}` forces the model to call that tool.
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum AssistantsApiToolChoiceOption {
     #[default]
@@ -335,7 +372,8 @@ pub enum AssistantsApiToolChoiceOption {
 
 /// Specifies a tool the model should use. Use to force the model to call a specific tool.
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantsNamedToolChoice {
     /// The type of the tool. If type is `function`, the function name must be set
     pub r#type: AssistantToolType,
@@ -344,7 +382,8 @@ pub struct AssistantsNamedToolChoice {
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum AssistantToolType {
     #[default]
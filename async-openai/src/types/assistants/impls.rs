@@ -2,7 +2,8 @@ use crate::types::assistants::{
     AssistantToolCodeInterpreterResources, AssistantToolFileSearchResources,
     AssistantToolResources, AssistantTools, AssistantToolsFileSearch, AssistantToolsFunction,
     CreateAssistantToolFileSearchResources, CreateAssistantToolResources,
-    CreateMessageRequestContent, FunctionObject,
+    CreateMessageRequestContent, FunctionObject, MessageDeltaContent, MessageDeltaObject,
+    RunObject, RunStatus, RunToolCallObject,
 };
 
 impl From<AssistantToolsFileSearch> for AssistantTools {
@@ -82,3 +83,43 @@ impl Default for CreateMessageRequestContent {
         Self::Content("".into())
     }
 }
+
+impl RunStatus {
+    /// Returns `true` when the run is `cancelled`, `failed`, `completed`, `incomplete` or `expired` and will not change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            RunStatus::Cancelled
+                | RunStatus::Failed
+                | RunStatus::Completed
+                | RunStatus::Incomplete
+                | RunStatus::Expired
+        )
+    }
+}
+
+impl RunObject {
+    /// The tool calls to answer with [Runs::submit_tool_outputs](crate::Runs::submit_tool_outputs)
+    /// when the status is `requires_action`, empty otherwise.
+    pub fn required_tool_calls(&self) -> &[RunToolCallObject] {
+        self.required_action
+            .as_ref()
+            .map(|action| action.submit_tool_outputs.tool_calls.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl MessageDeltaObject {
+    /// The text added to the message by this delta, e.g. to print tokens as they arrive.
+    pub fn text(&self) -> String {
+        self.delta
+            .content
+            .iter()
+            .flatten()
+            .filter_map(|content| match content {
+                MessageDeltaContent::Text(text) => text.text.as_ref()?.value.as_deref(),
+                _ => None,
+            })
+            .collect()
+    }
+}
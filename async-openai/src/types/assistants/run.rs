@@ -4,6 +4,7 @@ use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 use crate::error::OpenAIError;
+use crate::types::validation::check_range;
 
 use crate::types::assistants::{
     AssistantTools, AssistantsApiResponseFormatOption, AssistantsApiToolChoiceOption,
@@ -12,7 +13,8 @@ use crate::types::assistants::{
 
 /// Represents an execution run on a [thread](https://platform.openai.com/docs/api-reference/threads).
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunObject {
     /// The identifier, which can be referenced in API endpoints.
     pub id: String,
@@ -87,7 +89,8 @@ pub struct RunObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum TruncationObjectType {
     #[default]
@@ -97,7 +100,8 @@ pub enum TruncationObjectType {
 
 /// Thread Truncation Controls
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TruncationObject {
     /// The truncation strategy to use for the thread. The default is `auto`. If set to `last_messages`, the thread will be truncated to the n most recent messages in the thread. When set to `auto`, messages in the middle of the thread will be dropped to fit the context length of the model, `max_prompt_tokens`.
     pub r#type: TruncationObjectType,
@@ -106,14 +110,16 @@ pub struct TruncationObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunObjectIncompleteDetails {
     /// The reason why the run is incomplete. This will point to which specific token limit was reached over the course of the run.
     pub reason: RunObjectIncompleteDetailsReason,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum RunObjectIncompleteDetailsReason {
     MaxCompletionTokens,
@@ -121,7 +127,8 @@ pub enum RunObjectIncompleteDetailsReason {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum RunStatus {
     Queued,
@@ -136,7 +143,8 @@ pub enum RunStatus {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RequiredAction {
     /// For now, this is always `submit_tool_outputs`.
     pub r#type: String,
@@ -145,13 +153,15 @@ pub struct RequiredAction {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SubmitToolOutputs {
     pub tool_calls: Vec<RunToolCallObject>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunToolCallObject {
     /// The ID of the tool call. This ID must be referenced when you submit the tool outputs in using the [Submit tool outputs to run](https://platform.openai.com/docs/api-reference/runs/submitToolOutputs) endpoint.
     pub id: String,
@@ -162,7 +172,8 @@ pub struct RunToolCallObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct LastError {
     /// One of `server_error`, `rate_limit_exceeded`, or `invalid_prompt`.
     pub code: LastErrorCode,
@@ -171,7 +182,8 @@ pub struct LastError {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum LastErrorCode {
     ServerError,
@@ -180,7 +192,8 @@ pub enum LastErrorCode {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunCompletionUsage {
     /// Number of completion tokens used over the course of the run.
     pub completion_tokens: u32,
@@ -191,12 +204,13 @@ pub struct RunCompletionUsage {
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateRunRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(error = "OpenAIError", validate = "Self::validate"))]
 pub struct CreateRunRequest {
     /// The ID of the [assistant](https://platform.openai.com/docs/api-reference/assistants) to use to execute this run.
     pub assistant_id: String,
@@ -261,15 +275,25 @@ pub struct CreateRunRequest {
     pub response_format: Option<AssistantsApiResponseFormatOption>,
 }
 
+impl CreateRunRequestArgs {
+    /// Checks the documented ranges of the parameters set so far, called by [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        check_range("temperature", self.temperature.flatten(), 0.0, 2.0)?;
+        check_range("top_p", self.top_p.flatten(), 0.0, 1.0)
+    }
+}
+
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ModifyRunRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<HashMap<String, serde_json::Value>>,
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListRunsResponse {
     pub object: String,
     pub data: Vec<RunObject>,
@@ -279,7 +303,8 @@ pub struct ListRunsResponse {
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SubmitToolOutputsRunRequest {
     /// A list of tools for which the outputs are being submitted.
     pub tool_outputs: Vec<ToolsOutputs>,
@@ -288,7 +313,8 @@ pub struct SubmitToolOutputsRunRequest {
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ToolsOutputsArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -5,7 +5,8 @@ use serde::{Deserialize, Serialize};
 use crate::types::assistants::{FileSearchRankingOptions, ImageFile, LastError, RunStatus};
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum RunStepType {
     MessageCreation,
@@ -14,7 +15,8 @@ pub enum RunStepType {
 
 /// Represents a step in execution of a run.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepObject {
     /// The identifier, which can be referenced in API endpoints.
     pub id: String,
@@ -63,7 +65,8 @@ pub struct RunStepObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepCompletionUsage {
     /// Number of completion tokens used over the course of the run step.
     pub completion_tokens: u32,
@@ -74,7 +77,8 @@ pub struct RunStepCompletionUsage {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum StepDetails {
@@ -84,13 +88,15 @@ pub enum StepDetails {
 
 /// Details of the message creation by the run step.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsMessageCreationObject {
     pub message_creation: MessageCreation,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct MessageCreation {
     /// The ID of the message that was created by this run step.
     pub message_id: String,
@@ -98,14 +104,16 @@ pub struct MessageCreation {
 
 /// Details of the tool call.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsToolCallsObject {
     /// An array of tool calls the run step was involved in. These can be associated with one of three types of tools: `code_interpreter`, `file_search`, or `function`.
     pub tool_calls: Vec<RunStepDetailsToolCalls>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum RunStepDetailsToolCalls {
@@ -117,7 +125,8 @@ pub enum RunStepDetailsToolCalls {
 
 /// Code interpreter tool call
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsToolCallsCodeObject {
     /// The ID of the tool call.
     pub id: String,
@@ -127,7 +136,8 @@ pub struct RunStepDetailsToolCallsCodeObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CodeInterpreter {
     /// The input to the Code Interpreter tool call.
     pub input: String,
@@ -136,7 +146,8 @@ pub struct CodeInterpreter {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "lowercase")]
 pub enum CodeInterpreterOutput {
@@ -148,14 +159,16 @@ pub enum CodeInterpreterOutput {
 
 /// Text output from the Code Interpreter tool call as part of a run step.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsToolCallsCodeOutputLogsObject {
     /// The text output from the Code Interpreter tool call.
     pub logs: String,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsToolCallsCodeOutputImageObject {
     /// The [file](https://platform.openai.com/docs/api-reference/files) ID of the image.
     pub image: ImageFile,
@@ -163,7 +176,8 @@ pub struct RunStepDetailsToolCallsCodeOutputImageObject {
 
 /// File search tool call
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsToolCallsFileSearchObject {
     /// The ID of the tool call object.
     pub id: String,
@@ -172,7 +186,8 @@ pub struct RunStepDetailsToolCallsFileSearchObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsToolCallsFileSearchObjectFileSearch {
     pub ranking_options: Option<FileSearchRankingOptions>,
     /// The results of the file search.
@@ -181,7 +196,8 @@ pub struct RunStepDetailsToolCallsFileSearchObjectFileSearch {
 
 /// A result instance of the file search.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsToolCallsFileSearchResultObject {
     /// The ID of the file that result was found in.
     pub file_id: String,
@@ -194,7 +210,8 @@ pub struct RunStepDetailsToolCallsFileSearchResultObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsToolCallsFileSearchResultObjectContent {
     // note: type is text hence omitted from struct
     /// The text content of the file.
@@ -202,7 +219,8 @@ pub struct RunStepDetailsToolCallsFileSearchResultObjectContent {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDetailsToolCallsFunctionObject {
     /// The ID of the tool call object.
     pub id: String,
@@ -211,7 +229,8 @@ pub struct RunStepDetailsToolCallsFunctionObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepFunctionObject {
     /// The name of the function.
     pub name: String,
@@ -222,7 +241,8 @@ pub struct RunStepFunctionObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepFunctionObjectDelta {
     /// The name of the function.
     pub name: Option<String>,
@@ -233,7 +253,8 @@ pub struct RunStepFunctionObjectDelta {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListRunStepsResponse {
     pub object: String,
     pub data: Vec<RunStepObject>,
@@ -244,7 +265,8 @@ pub struct ListRunStepsResponse {
 
 /// Represents a run step delta i.e. any changed fields on a run step during streaming.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDeltaObject {
     /// The identifier of the run step, which can be referenced in API endpoints.
     pub id: String,
@@ -255,13 +277,15 @@ pub struct RunStepDeltaObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDelta {
     pub step_details: DeltaStepDetails,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum DeltaStepDetails {
@@ -271,21 +295,24 @@ pub enum DeltaStepDetails {
 
 /// Details of the message creation by the run step.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDeltaStepDetailsMessageCreationObject {
     pub message_creation: Option<MessageCreation>,
 }
 
 /// Details of the tool call.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDeltaStepDetailsToolCallsObject {
     /// An array of tool calls the run step was involved in. These can be associated with one of three types of tools: `code_interpreter`, `file_search`, or `function`.
     pub tool_calls: Option<Vec<RunStepDeltaStepDetailsToolCalls>>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum RunStepDeltaStepDetailsToolCalls {
@@ -296,7 +323,8 @@ pub enum RunStepDeltaStepDetailsToolCalls {
 
 /// Details of the Code Interpreter tool call the run step was involved in.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDeltaStepDetailsToolCallsCodeObject {
     /// The index of the tool call in the tool calls array.
     pub index: u32,
@@ -307,7 +335,8 @@ pub struct RunStepDeltaStepDetailsToolCallsCodeObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeltaCodeInterpreter {
     /// The input to the Code Interpreter tool call.
     pub input: Option<String>,
@@ -316,7 +345,8 @@ pub struct DeltaCodeInterpreter {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "lowercase")]
 pub enum DeltaCodeInterpreterOutput {
@@ -326,7 +356,8 @@ pub enum DeltaCodeInterpreterOutput {
 
 /// Text output from the Code Interpreter tool call as part of a run step.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDeltaStepDetailsToolCallsCodeOutputLogsObject {
     /// The index of the output in the outputs array.
     pub index: u32,
@@ -336,7 +367,8 @@ pub struct RunStepDeltaStepDetailsToolCallsCodeOutputLogsObject {
 
 /// Code interpreter image output
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDeltaStepDetailsToolCallsCodeOutputImageObject {
     /// The index of the output in the outputs array.
     pub index: u32,
@@ -345,20 +377,22 @@ pub struct RunStepDeltaStepDetailsToolCallsCodeOutputImageObject {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDeltaStepDetailsToolCallsFileSearchObject {
     /// The index of the tool call in the tool calls array.
     pub index: u32,
     /// The ID of the tool call object.
     pub id: Option<String>,
     /// For now, this is always going to be an empty object.
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub file_search: Option<serde_json::Value>,
 }
 
 /// Function tool call
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RunStepDeltaStepDetailsToolCallsFunctionObject {
     /// The index of the tool call in the tool calls array.
     pub index: u32,
@@ -26,7 +26,8 @@ use crate::types::assistants::{
 /// integrate the Assistants API with streaming.
 
 #[derive(Debug, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "event", content = "data")]
 pub enum AssistantStreamEvent {
     /// Occurs when a new [thread](https://platform.openai.com/docs/api-reference/threads/object) is created.
@@ -201,7 +202,10 @@ impl TryFrom<eventsource_stream::Event> for AssistantStreamEvent {
                     .map_err(|e| crate::error::map_deserialization_error(e, value.data.as_bytes()))
                     .map(AssistantStreamEvent::ThreadMessageIncomplete)
             }
-            "error" => serde_json::from_str::<ApiError>(value.data.as_str())
+            // the error is either bare or wrapped in `{"error": ...}` as in API responses
+            "error" => serde_json::from_str::<crate::error::WrappedError>(value.data.as_str())
+                .map(|wrapped| wrapped.error)
+                .or_else(|_| serde_json::from_str::<ApiError>(value.data.as_str()))
                 .map_err(|e| crate::error::map_deserialization_error(e, value.data.as_bytes()))
                 .map(AssistantStreamEvent::ErrorEvent),
             "done" => Ok(AssistantStreamEvent::Done(value.data)),
@@ -8,7 +8,8 @@ use crate::types::InputSource;
 
 /// A custom voice reference, identified by its voice ID.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CustomVoiceRef {
     /// The custom voice ID, e.g. `voice_1234`.
     pub id: String,
@@ -17,7 +18,8 @@ pub struct CustomVoiceRef {
 // openapi spec type: VoiceIdsOrCustomVoice
 // anyOf[VoiceIdsShared, CustomVoiceRef]
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Voice {
     #[default]
@@ -47,7 +49,8 @@ pub struct AudioInput {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum AudioResponseFormat {
     #[default]
@@ -60,7 +63,8 @@ pub enum AudioResponseFormat {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum TranslationResponseFormat {
     #[default]
@@ -72,7 +76,8 @@ pub enum TranslationResponseFormat {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum SpeechResponseFormat {
     #[default]
@@ -85,7 +90,8 @@ pub enum SpeechResponseFormat {
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum SpeechModel {
     #[default]
     #[serde(rename = "tts-1")]
@@ -99,7 +105,8 @@ pub enum SpeechModel {
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum TimestampGranularity {
     Word,
@@ -112,7 +119,7 @@ pub enum TimestampGranularity {
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(error = "OpenAIError", validate = "Self::validate"))]
 pub struct CreateTranscriptionRequest {
     /// The audio file object (not file name) to transcribe, in one of these formats:
     /// flac, mp3, mp4, mpeg, mpga, m4a, ogg, wav, or webm.
@@ -185,8 +192,30 @@ pub struct CreateTranscriptionRequest {
     pub known_speaker_references: Option<Vec<String>>,
 }
 
+impl CreateTranscriptionRequestArgs {
+    /// Timestamp granularities are only returned in the `verbose_json` response format, which
+    /// [create_verbose_json](crate::Transcriptions::create_verbose_json) requests when no format is set.
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        let has_granularities = self
+            .timestamp_granularities
+            .as_ref()
+            .and_then(Option::as_ref)
+            .is_some_and(|granularities| !granularities.is_empty());
+
+        match self.response_format.flatten() {
+            Some(format) if has_granularities && format != AudioResponseFormat::VerboseJson => {
+                Err(OpenAIError::InvalidArgument(format!(
+                    "timestamp_granularities require the verbose_json response_format, got {format}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum TranscriptionChunkingStrategy {
     #[default]
@@ -196,7 +225,8 @@ pub enum TranscriptionChunkingStrategy {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum VadConfigType {
     #[default]
@@ -204,7 +234,8 @@ pub enum VadConfigType {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VadConfig {
     pub r#type: VadConfigType,
     /// Amount of audio to include before the VAD detected speech (in milliseconds). Default: 300.
@@ -225,7 +256,8 @@ pub struct VadConfig {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum TranscriptionInclude {
     Logprobs,
@@ -234,7 +266,8 @@ pub enum TranscriptionInclude {
 /// Represents a transcription response returned by model, based on the provided
 /// input.
 #[derive(Debug, Deserialize, Clone, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateTranscriptionResponseJson {
     /// The transcribed text.
     pub text: String,
@@ -248,14 +281,16 @@ pub struct CreateTranscriptionResponseJson {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum CreateTranscriptionResponseDiarizedJsonTask {
     Transcribe,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateTranscriptionResponseDiarizedJson {
     /// The type of task that was run. Always `transcribe`.
     pub task: Option<CreateTranscriptionResponseDiarizedJsonTask>,
@@ -276,7 +311,8 @@ pub struct CreateTranscriptionResponseDiarizedJson {
 /// Represents a verbose json transcription response returned by model, based on
 /// the provided input.
 #[derive(Debug, Deserialize, Clone, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateTranscriptionResponseVerboseJson {
     /// The language of the input audio.
     pub language: String,
@@ -300,7 +336,8 @@ pub struct CreateTranscriptionResponseVerboseJson {
 }
 
 #[derive(Debug, Deserialize, Clone, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TranscriptionWord {
     /// The text content of the word.
     pub word: String,
@@ -313,14 +350,16 @@ pub struct TranscriptionWord {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum TranscriptionDiarizedSegmentType {
     #[serde(rename = "transcript.text.segment")]
     TranscriptTextSegment,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TranscriptionDiarizedSegment {
     /// The type of the segment. Always `transcript.text.segment`.
     pub r#type: TranscriptionDiarizedSegmentType,
@@ -344,7 +383,8 @@ pub struct TranscriptionDiarizedSegment {
 }
 
 #[derive(Debug, Deserialize, Clone, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TranscriptionSegment {
     /// Unique identifier of the segment.
     pub id: u32,
@@ -381,7 +421,8 @@ pub struct TranscriptionSegment {
 }
 
 #[derive(Clone, Default, Debug, Builder, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateSpeechRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -421,7 +462,8 @@ pub struct CreateSpeechRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum StreamFormat {
     #[default]
@@ -458,18 +500,20 @@ pub struct CreateTranslationRequest {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateTranslationResponseJson {
     pub text: String,
 }
 
 #[derive(Debug, Deserialize, Clone, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateTranslationResponseVerboseJson {
     /// The language of the output translation (always `english`).
     pub language: String,
     /// The duration of the input audio.
-    pub duration: String,
+    pub duration: f32,
     /// The translated text.
     pub text: String,
     /// Segments of the translated text and their corresponding details.
@@ -484,7 +528,8 @@ pub struct CreateSpeechResponse {
 
 /// A consent recording used to authorize creation of a custom voice.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VoiceConsentResource {
     /// The object type, which is always `audio.voice_consent`.
     pub object: String,
@@ -518,7 +563,8 @@ pub struct CreateVoiceConsentRequest {
 
 /// Request to update a voice consent recording (metadata only).
 #[derive(Clone, Serialize, Default, Debug, Deserialize, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "UpdateVoiceConsentRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -531,7 +577,8 @@ pub struct UpdateVoiceConsentRequest {
 
 /// The voice consent deletion object.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VoiceConsentDeletedResource {
     /// The consent recording identifier.
     pub id: String,
@@ -543,7 +590,8 @@ pub struct VoiceConsentDeletedResource {
 
 /// The voice consent list object.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VoiceConsentListResource {
     /// The object type, which is always `list`.
     pub object: String,
@@ -561,7 +609,8 @@ pub struct VoiceConsentListResource {
 
 /// A custom voice that can be used for audio output.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VoiceResource {
     /// The object type, which is always `audio.voice`.
     pub object: String,
@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use crate::error::OpenAIError;
+
+/// A cue of the subtitles returned for the `srt` and `vtt` response formats of transcriptions and
+/// translations.
+///
+/// ```
+/// use std::time::Duration;
+/// use async_openai::types::audio::Caption;
+///
+/// let srt = "1\n00:00:00,000 --> 00:00:01,500\nHello\nworld\n\n2\n00:00:01,500 --> 00:00:03,000\nBye\n";
+/// let captions = Caption::parse_srt(srt).unwrap();
+/// assert_eq!(captions[0].end, Duration::from_millis(1500));
+/// assert_eq!(captions[0].text, "Hello\nworld");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Caption {
+    pub start: Duration,
+    pub end: Duration,
+    /// The text of the cue, with its lines separated by `\n`.
+    pub text: String,
+}
+
+impl Caption {
+    /// Parses the cues of [SubRip](https://en.wikipedia.org/wiki/SubRip) subtitles.
+    pub fn parse_srt(srt: &str) -> Result<Vec<Caption>, OpenAIError> {
+        parse_cues(srt, "srt")
+    }
+
+    /// Parses the cues of [WebVTT](https://www.w3.org/TR/webvtt1/) subtitles. Cue settings,
+    /// notes, styles and regions are ignored.
+    pub fn parse_vtt(vtt: &str) -> Result<Vec<Caption>, OpenAIError> {
+        if !vtt.trim_start_matches('\u{feff}').starts_with("WEBVTT") {
+            return Err(OpenAIError::InvalidArgument(
+                "vtt subtitles must start with WEBVTT".into(),
+            ));
+        }
+        parse_cues(vtt, "vtt")
+    }
+}
+
+/// Parses the blocks of lines separated by blank lines, whose cues are made of an optional
+/// identifier, the timings and the lines of text.
+fn parse_cues(input: &str, format: &str) -> Result<Vec<Caption>, OpenAIError> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut captions = Vec::new();
+    let mut block = Vec::new();
+
+    // the trailing blank line ends the last block
+    for (index, line) in input.lines().chain([""]).enumerate() {
+        if !line.trim().is_empty() {
+            block.push((index + 1, line));
+            continue;
+        }
+        if block.is_empty() {
+            continue;
+        }
+        if let Some(caption) = parse_cue(&block, format)? {
+            captions.push(caption);
+        }
+        block.clear();
+    }
+
+    Ok(captions)
+}
+
+fn parse_cue(block: &[(usize, &str)], format: &str) -> Result<Option<Caption>, OpenAIError> {
+    let invalid = |(number, line): (usize, &str)| {
+        OpenAIError::InvalidArgument(format!("invalid {format} cue at line {number}: {line}"))
+    };
+
+    let Some(timings) = block.iter().position(|(_, line)| line.contains("-->")) else {
+        let (_, first) = block[0];
+        let is_vtt_block = ["WEBVTT", "NOTE", "STYLE", "REGION"]
+            .iter()
+            .any(|keyword| first.starts_with(keyword));
+        return match format == "vtt" && is_vtt_block {
+            true => Ok(None),
+            false => Err(invalid(block[0])),
+        };
+    };
+    // at most one identifier precedes the timings
+    if timings > 1 {
+        return Err(invalid(block[0]));
+    }
+
+    let (_, line) = block[timings];
+    let (start, end) = line
+        .split_once("-->")
+        .ok_or_else(|| invalid(block[timings]))?;
+    // the end of vtt timings can be followed by cue settings
+    let end = end.split_whitespace().next().unwrap_or_default();
+    let (Some(start), Some(end)) = (parse_timestamp(start.trim()), parse_timestamp(end)) else {
+        return Err(invalid(block[timings]));
+    };
+
+    let text = block[timings + 1..]
+        .iter()
+        .map(|(_, line)| *line)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    Ok(Some(Caption { start, end, text }))
+}
+
+/// Parses `hh:mm:ss,mmm` in srt, and `[hh:]mm:ss.mmm` in vtt.
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    let (clock, millis) = timestamp.rsplit_once([',', '.'])?;
+    let parts: Vec<&str> = clock.split(':').collect();
+    if !(2..=3).contains(&parts.len()) || millis.len() != 3 {
+        return None;
+    }
+
+    let mut seconds = 0;
+    for part in parts {
+        seconds = seconds * 60 + parse_digits(part)?;
+    }
+    Some(Duration::from_millis(
+        seconds * 1000 + parse_digits(millis)?,
+    ))
+}
+
+fn parse_digits(digits: &str) -> Option<u64> {
+    match !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        true => digits.parse().ok(),
+        false => None,
+    }
+}
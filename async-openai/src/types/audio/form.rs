@@ -1,39 +1,61 @@
+use std::fmt::Display;
+
 use crate::{
     error::OpenAIError,
     traits::AsyncTryFrom,
     types::audio::{
-        CreateTranscriptionRequest, CreateTranslationRequest, CreateVoiceConsentRequest,
-        CreateVoiceRequest, TranscriptionChunkingStrategy,
+        AudioInput, CreateTranscriptionRequest, CreateTranslationRequest,
+        CreateVoiceConsentRequest, CreateVoiceRequest, TranscriptionChunkingStrategy,
     },
     util::create_file_part,
 };
 
+/// The form fields shared by transcriptions and translations.
+async fn audio_form(
+    file: AudioInput,
+    model: String,
+    prompt: Option<String>,
+    response_format: Option<impl Display>,
+    temperature: Option<f32>,
+) -> Result<reqwest::multipart::Form, OpenAIError> {
+    let audio_part = create_file_part(file.source).await?;
+
+    let mut form = reqwest::multipart::Form::new()
+        .part("file", audio_part)
+        .text("model", model);
+
+    if let Some(prompt) = prompt {
+        form = form.text("prompt", prompt);
+    }
+
+    if let Some(response_format) = response_format {
+        form = form.text("response_format", response_format.to_string())
+    }
+
+    if let Some(temperature) = temperature {
+        form = form.text("temperature", temperature.to_string())
+    }
+
+    Ok(form)
+}
+
 impl AsyncTryFrom<CreateTranscriptionRequest> for reqwest::multipart::Form {
     type Error = OpenAIError;
 
     async fn try_from(request: CreateTranscriptionRequest) -> Result<Self, Self::Error> {
-        let audio_part = create_file_part(request.file.source).await?;
-
-        let mut form = reqwest::multipart::Form::new()
-            .part("file", audio_part)
-            .text("model", request.model);
+        let mut form = audio_form(
+            request.file,
+            request.model,
+            request.prompt,
+            request.response_format,
+            request.temperature,
+        )
+        .await?;
 
         if let Some(language) = request.language {
             form = form.text("language", language);
         }
 
-        if let Some(prompt) = request.prompt {
-            form = form.text("prompt", prompt);
-        }
-
-        if let Some(response_format) = request.response_format {
-            form = form.text("response_format", response_format.to_string())
-        }
-
-        if let Some(temperature) = request.temperature {
-            form = form.text("temperature", temperature.to_string())
-        }
-
         if let Some(include) = request.include {
             for inc in include {
                 form = form.text("include[]", inc.to_string());
@@ -84,24 +106,14 @@ impl AsyncTryFrom<CreateTranslationRequest> for reqwest::multipart::Form {
     type Error = OpenAIError;
 
     async fn try_from(request: CreateTranslationRequest) -> Result<Self, Self::Error> {
-        let audio_part = create_file_part(request.file.source).await?;
-
-        let mut form = reqwest::multipart::Form::new()
-            .part("file", audio_part)
-            .text("model", request.model);
-
-        if let Some(prompt) = request.prompt {
-            form = form.text("prompt", prompt);
-        }
-
-        if let Some(response_format) = request.response_format {
-            form = form.text("response_format", response_format.to_string())
-        }
-
-        if let Some(temperature) = request.temperature {
-            form = form.text("temperature", temperature.to_string())
-        }
-        Ok(form)
+        audio_form(
+            request.file,
+            request.model,
+            request.prompt,
+            request.response_format,
+            request.temperature,
+        )
+        .await
     }
 }
 
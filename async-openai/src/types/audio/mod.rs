@@ -1,4 +1,5 @@
 mod audio_;
+mod caption;
 #[cfg(feature = "_api")]
 mod form;
 mod impls;
@@ -7,6 +8,7 @@ mod sdk;
 mod stream;
 
 pub use audio_::*;
+pub use caption::*;
 pub use stream::*;
 
 // Re-export shared types that are used in audio
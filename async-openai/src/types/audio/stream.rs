@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 use crate::types::audio::{LogProbProperties, TranscriptTextUsageTokens};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CreateSpeechResponseStreamEvent {
     /// Emitted for each chunk of audio data generated during speech synthesis.
@@ -15,14 +16,16 @@ pub enum CreateSpeechResponseStreamEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SpeechAudioDeltaEvent {
     /// A chunk of Base64-encoded audio data.
     pub audio: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SpeechUsage {
     /// Number of input tokens in the prompt.
     pub input_tokens: u32,
@@ -33,14 +36,16 @@ pub struct SpeechUsage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SpeechAudioDoneEvent {
     /// Token usage statistics for the request.
     pub usage: SpeechUsage,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TranscriptionTextSegmentEvent {
     /// Unique identifier for the segment.
     pub id: String,
@@ -55,7 +60,8 @@ pub struct TranscriptionTextSegmentEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TranscriptionTextDeltaEvent {
     /// The text delta that was additionally transcribed.
     pub delta: String,
@@ -69,7 +75,8 @@ pub struct TranscriptionTextDeltaEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TranscriptionTextDoneEvent {
     /// The text that was transcribed.
     pub text: String,
@@ -82,7 +89,8 @@ pub struct TranscriptionTextDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum CreateTranscriptionResponseStreamEvent {
     /// Emitted when a diarized transcription returns a completed segment with speaker information. Only
@@ -118,6 +126,12 @@ pub type SpeechResponseStream = std::pin::Pin<
     >,
 >;
 
+/// Stream of the bytes of the audio generated by [Speech::create_audio_stream](crate::Speech::create_audio_stream)
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+pub type SpeechAudioStream = std::pin::Pin<
+    Box<dyn futures::Stream<Item = Result<bytes::Bytes, crate::error::OpenAIError>> + Send>,
+>;
+
 #[cfg(feature = "_api")]
 macro_rules! impl_event_type {
     ($($ty:ty => $event_type:expr),* $(,)?) => {
@@ -8,7 +8,8 @@ use crate::types::batches::ResponseUsage;
 use crate::types::Metadata;
 
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "BatchRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -39,7 +40,8 @@ pub struct BatchRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum BatchEndpoint {
     #[default]
     #[serde(rename = "/v1/responses")]
@@ -55,7 +57,8 @@ pub enum BatchEndpoint {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Default, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum BatchCompletionWindow {
     #[default]
     #[serde(rename = "24h")]
@@ -66,7 +69,8 @@ pub enum BatchCompletionWindow {
 ///
 /// The expiration policy for the output and/or error file that are generated for a batch.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct BatchFileExpirationAfter {
     /// Anchor timestamp after which the expiration policy applies. Supported anchors: `created_at`. Note that the anchor is the file creation time, not the time the batch is created.
     pub anchor: BatchFileExpirationAnchor,
@@ -75,14 +79,16 @@ pub struct BatchFileExpirationAfter {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum BatchFileExpirationAnchor {
     CreatedAt,
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Batch {
     pub id: String,
     /// The object type, which is always `batch`.
@@ -129,7 +135,8 @@ pub struct Batch {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct BatchErrors {
     /// The object type, which is always `list`.
     pub object: String,
@@ -137,7 +144,8 @@ pub struct BatchErrors {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct BatchError {
     /// An error code identifying the error type.
     pub code: String,
@@ -150,7 +158,8 @@ pub struct BatchError {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum BatchStatus {
     Validating,
@@ -163,8 +172,22 @@ pub enum BatchStatus {
     Cancelled,
 }
 
+impl BatchStatus {
+    /// Returns `true` when the batch is `completed`, `failed`, `expired` or `cancelled` and will not change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            BatchStatus::Completed
+                | BatchStatus::Failed
+                | BatchStatus::Expired
+                | BatchStatus::Cancelled
+        )
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct BatchRequestCounts {
     /// Total number of requests in the batch.
     pub total: u32,
@@ -175,7 +198,8 @@ pub struct BatchRequestCounts {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListBatchesResponse {
     pub data: Vec<Batch>,
     pub first_id: Option<String>,
@@ -185,16 +209,22 @@ pub struct ListBatchesResponse {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "UPPERCASE")]
 pub enum BatchRequestInputMethod {
     POST,
 }
 
 /// The per-line object of the batch input file
+///
+/// The body is typed with `T`, e.g. [CreateChatCompletionRequest](crate::types::chat::CreateChatCompletionRequest),
+/// see [BatchRequestInput::new] and [BatchRequestInput::to_jsonl].
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
-pub struct BatchRequestInput {
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "utoipa", schema(bound = "T: utoipa::ToSchema"))]
+pub struct BatchRequestInput<T = serde_json::Value> {
     /// A developer-provided per-request id that will be used to match outputs to inputs. Must be unique for each request in a batch.
     pub custom_id: String,
     /// The HTTP method to be used for the request. Currently only `POST` is supported.
@@ -202,24 +232,26 @@ pub struct BatchRequestInput {
     /// The OpenAI API relative URL to be used for the request. Currently `/v1/responses`,
     /// `/v1/chat/completions`, `/v1/embeddings`, `/v1/completions`, and `/v1/moderations` are supported.
     pub url: BatchEndpoint,
-    #[schema(value_type = Option<Object>)]
-    pub body: Option<serde_json::Value>,
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
+    pub body: Option<T>,
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct BatchRequestOutputResponse {
     /// The HTTP status code of the response
     pub status_code: u16,
     /// An unique identifier for the OpenAI API request. Please include this request ID when contacting support.
     pub request_id: String,
     /// The JSON body of the response
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub body: serde_json::Value,
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct BatchRequestOutputError {
     /// A machine-readable error code.
     pub code: String,
@@ -229,7 +261,8 @@ pub struct BatchRequestOutputError {
 
 /// The per-line object of the batch output and error files
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct BatchRequestOutput {
     pub id: String,
     /// A developer-provided per-request id that will be used to match outputs to inputs.
@@ -0,0 +1,132 @@
+use std::fmt::Display;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::{ApiError, OpenAIError, WrappedError};
+use crate::types::batches::{
+    BatchEndpoint, BatchRequestInput, BatchRequestInputMethod, BatchRequestOutput,
+    BatchRequestOutputError,
+};
+
+impl<T> BatchRequestInput<T> {
+    /// A `POST` request to `url` with `body`, matched to its output by `custom_id`.
+    pub fn new(custom_id: impl Into<String>, url: BatchEndpoint, body: T) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            method: BatchRequestInputMethod::POST,
+            url,
+            body: Some(body),
+        }
+    }
+}
+
+impl<T: Serialize> BatchRequestInput<T> {
+    /// Writes `inputs` as the lines of a batch input file, to upload with the purpose `batch`.
+    pub fn to_jsonl<'a>(
+        inputs: impl IntoIterator<Item = &'a BatchRequestInput<T>>,
+    ) -> Result<String, OpenAIError>
+    where
+        T: 'a,
+    {
+        let mut jsonl = String::new();
+        for input in inputs {
+            let line = serde_json::to_string(input).map_err(|e| {
+                OpenAIError::InvalidArgument(format!(
+                    "failed to serialize batch request {}: {e}",
+                    input.custom_id
+                ))
+            })?;
+            jsonl.push_str(&line);
+            jsonl.push('\n');
+        }
+        Ok(jsonl)
+    }
+}
+
+impl BatchRequestOutput {
+    /// Parses the lines of a batch output or error file. Blank lines are skipped.
+    pub fn from_jsonl(jsonl: impl AsRef<[u8]>) -> Result<Vec<Self>, OpenAIError> {
+        jsonl
+            .as_ref()
+            .split(|b| *b == b'\n')
+            .enumerate()
+            .filter(|(_, line)| !line.iter().all(u8::is_ascii_whitespace))
+            .map(|(index, line)| {
+                serde_json::from_slice(line).map_err(|e| {
+                    OpenAIError::InvalidArgument(format!(
+                        "invalid batch output on line {}: {e}",
+                        index + 1
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// The `custom_id` of the request, and either the body of its response as `T`, or why it failed.
+    pub fn into_result<T: DeserializeOwned>(self) -> BatchResult<T> {
+        let result = match (self.error, self.response) {
+            (Some(error), _) => Err(BatchOutputError::Request(error)),
+            (None, None) => Err(BatchOutputError::MissingResponse),
+            (None, Some(response)) if !(200..300).contains(&response.status_code) => {
+                let error = match serde_json::from_value::<WrappedError>(response.body) {
+                    Ok(wrapped) => wrapped.error,
+                    Err(_) => ApiError {
+                        message: format!("request failed with status {}", response.status_code),
+                        r#type: None,
+                        param: None,
+                        code: None,
+                        status: None,
+                        request_id: None,
+                    },
+                };
+                Err(BatchOutputError::Api(ApiError {
+                    status: Some(response.status_code),
+                    ..error
+                }))
+            }
+            (None, Some(response)) => {
+                serde_json::from_value(response.body).map_err(BatchOutputError::Deserialize)
+            }
+        };
+        (self.custom_id, result)
+    }
+
+    /// Parses a batch output or error file into the `custom_id` and result of each request, see [into_result](Self::into_result).
+    pub fn results_from_jsonl<T: DeserializeOwned>(
+        jsonl: impl AsRef<[u8]>,
+    ) -> Result<Vec<BatchResult<T>>, OpenAIError> {
+        Ok(Self::from_jsonl(jsonl)?
+            .into_iter()
+            .map(Self::into_result)
+            .collect())
+    }
+}
+
+/// The `custom_id` of a request of a batch, and its result.
+pub type BatchResult<T> = (String, Result<T, BatchOutputError>);
+
+/// Why a request of a batch has no result, see [BatchRequestOutput::into_result].
+#[derive(Debug)]
+pub enum BatchOutputError {
+    /// The request failed with a non-HTTP error.
+    Request(BatchRequestOutputError),
+    /// The API responded with an error, its [status](ApiError::status) is the HTTP status code of the response.
+    Api(ApiError),
+    /// The body of the response does not deserialize into the expected type.
+    Deserialize(serde_json::Error),
+    /// The output line has neither a response nor an error.
+    MissingResponse,
+}
+
+impl Display for BatchOutputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchOutputError::Request(error) => write!(f, "{}: {}", error.code, error.message),
+            BatchOutputError::Api(error) => write!(f, "{error}"),
+            BatchOutputError::Deserialize(e) => write!(f, "failed to deserialize response: {e}"),
+            BatchOutputError::MissingResponse => write!(f, "no response and no error"),
+        }
+    }
+}
+
+impl std::error::Error for BatchOutputError {}
@@ -1,8 +1,10 @@
 mod api;
 mod batch;
+mod impls;
 
 pub use api::*;
 pub use batch::*;
+pub use impls::*;
 
 // Re-export shared types
 pub use crate::types::shared::InputTokenDetails;
@@ -7,15 +7,18 @@ use crate::{
     error::OpenAIError,
     types::{
         chat::{
-            CompletionTokensDetails, CustomGrammarFormatParam, FunctionCall, FunctionName,
-            FunctionObject, ImageUrl, PromptTokensDetails, ReasoningEffort, ResponseFormat,
+            CompletionTokensDetails, ContentFilterResults, CustomGrammarFormatParam, FunctionCall,
+            FunctionName, FunctionObject, ImageUrl, PromptFilterResult, PromptTokensDetails,
+            ReasoningEffort, ResponseFormat,
         },
-        Metadata,
+        validation::{check_min, check_range},
+        Metadata, ModelCapabilities,
     },
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum Prompt {
     String(String),
@@ -26,25 +29,54 @@ pub enum Prompt {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum StopConfiguration {
     String(String),           // nullable: true
     StringArray(Vec<String>), // minItems: 1; maxItems: 4
 }
 
+/// Up to 4 sequences where the API will stop generating further tokens, built from a string or
+/// an array or `Vec` of strings.
+pub type Stop = StopConfiguration;
+
+impl StopConfiguration {
+    /// The maximum number of stop sequences accepted by the API.
+    pub const MAX_SEQUENCES: usize = 4;
+
+    /// Checks that there are 1 to [MAX_SEQUENCES](Self::MAX_SEQUENCES) stop sequences.
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        match self {
+            Self::String(_) => Ok(()),
+            Self::StringArray(sequences)
+                if (1..=Self::MAX_SEQUENCES).contains(&sequences.len()) =>
+            {
+                Ok(())
+            }
+            Self::StringArray(sequences) => Err(OpenAIError::InvalidArgument(format!(
+                "stop must have between 1 and {} sequences, got {}",
+                Self::MAX_SEQUENCES,
+                sequences.len()
+            ))),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Logprobs {
     pub tokens: Vec<String>,
     pub token_logprobs: Vec<Option<f32>>, // Option is to account for null value in the list
-    #[schema(value_type = Vec<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<Object>))]
     pub top_logprobs: Vec<serde_json::Value>,
     pub text_offset: Vec<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum CompletionFinishReason {
     Stop,
@@ -53,7 +85,8 @@ pub enum CompletionFinishReason {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Choice {
     pub text: String,
     pub index: u32,
@@ -64,7 +97,8 @@ pub struct Choice {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum ChatCompletionFunctionCall {
     /// The model does not call a function, and responds to the end-user.
     #[serde(rename = "none")]
@@ -82,7 +116,8 @@ pub enum ChatCompletionFunctionCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Role {
     System,
@@ -95,7 +130,8 @@ pub enum Role {
 
 /// Usage statistics for the completion request.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CompletionUsage {
     /// Number of tokens in the prompt.
     pub prompt_tokens: u32,
@@ -112,7 +148,8 @@ pub struct CompletionUsage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionRequestDeveloperMessageArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -128,7 +165,8 @@ pub struct ChatCompletionRequestDeveloperMessage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum ChatCompletionRequestDeveloperMessageContent {
     Text(String),
@@ -136,7 +174,8 @@ pub enum ChatCompletionRequestDeveloperMessageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum ChatCompletionRequestDeveloperMessageContentPart {
@@ -144,7 +183,8 @@ pub enum ChatCompletionRequestDeveloperMessageContentPart {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionRequestSystemMessageArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -159,7 +199,8 @@ pub struct ChatCompletionRequestSystemMessage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionRequestMessageContentPartTextArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -170,14 +211,16 @@ pub struct ChatCompletionRequestMessageContentPartText {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionRequestMessageContentPartRefusal {
     /// The refusal message generated by the model.
     pub refusal: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionRequestMessageContentPartImageArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -188,7 +231,8 @@ pub struct ChatCompletionRequestMessageContentPartImage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum InputAudioFormat {
     Wav,
@@ -197,7 +241,8 @@ pub enum InputAudioFormat {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InputAudio {
     /// Base64 encoded audio data.
     pub data: String,
@@ -207,7 +252,8 @@ pub struct InputAudio {
 
 /// Learn about [audio inputs](https://platform.openai.com/docs/guides/audio).
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionRequestMessageContentPartAudioArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -218,29 +264,32 @@ pub struct ChatCompletionRequestMessageContentPartAudio {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FileObject {
     /// The base64 encoded file data, used when passing the file to the model
     /// as a string.
     #[serde(skip_serializing_if = "Option::is_none")]
-    file_data: Option<String>,
+    pub file_data: Option<String>,
     /// The ID of an uploaded file to use as input.
     #[serde(skip_serializing_if = "Option::is_none")]
-    file_id: Option<String>,
+    pub file_id: Option<String>,
     /// The name of the file, used when passing the file to the model as a
     /// string.
     #[serde(skip_serializing_if = "Option::is_none")]
-    filename: Option<String>,
+    pub filename: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionRequestMessageContentPartFile {
     pub file: FileObject,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum ChatCompletionRequestUserMessageContentPart {
@@ -251,7 +300,8 @@ pub enum ChatCompletionRequestUserMessageContentPart {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum ChatCompletionRequestSystemMessageContentPart {
@@ -259,7 +309,8 @@ pub enum ChatCompletionRequestSystemMessageContentPart {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum ChatCompletionRequestAssistantMessageContentPart {
@@ -268,7 +319,8 @@ pub enum ChatCompletionRequestAssistantMessageContentPart {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum ChatCompletionRequestToolMessageContentPart {
@@ -276,7 +328,8 @@ pub enum ChatCompletionRequestToolMessageContentPart {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum ChatCompletionRequestSystemMessageContent {
     /// The text contents of the system message.
@@ -286,7 +339,8 @@ pub enum ChatCompletionRequestSystemMessageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum ChatCompletionRequestUserMessageContent {
     /// The text contents of the message.
@@ -296,7 +350,8 @@ pub enum ChatCompletionRequestUserMessageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum ChatCompletionRequestAssistantMessageContent {
     /// The text contents of the message.
@@ -306,7 +361,8 @@ pub enum ChatCompletionRequestAssistantMessageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum ChatCompletionRequestToolMessageContent {
     /// The text contents of the tool message.
@@ -316,7 +372,8 @@ pub enum ChatCompletionRequestToolMessageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionRequestUserMessageArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -331,14 +388,16 @@ pub struct ChatCompletionRequestUserMessage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionRequestAssistantMessageAudio {
     /// Unique identifier for a previous audio response from the model.
     pub id: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionRequestAssistantMessageArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -368,7 +427,8 @@ pub struct ChatCompletionRequestAssistantMessage {
 
 /// Tool message
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionRequestToolMessageArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -381,7 +441,8 @@ pub struct ChatCompletionRequestToolMessage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionRequestFunctionMessageArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -395,7 +456,8 @@ pub struct ChatCompletionRequestFunctionMessage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "role")]
 #[serde(rename_all = "lowercase")]
 pub enum ChatCompletionRequestMessage {
@@ -408,7 +470,8 @@ pub enum ChatCompletionRequestMessage {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum ChatCompletionMessageToolCalls {
@@ -417,7 +480,8 @@ pub enum ChatCompletionMessageToolCalls {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionMessageToolCall {
     /// The ID of the tool call.
     pub id: String,
@@ -426,7 +490,8 @@ pub struct ChatCompletionMessageToolCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionMessageCustomToolCall {
     /// The ID of the tool call.
     pub id: String,
@@ -435,7 +500,8 @@ pub struct ChatCompletionMessageCustomToolCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CustomTool {
     /// The name of the custom tool to call.
     pub name: String,
@@ -444,7 +510,8 @@ pub struct CustomTool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionResponseMessageAudio {
     /// Unique identifier for this audio response.
     pub id: String,
@@ -457,14 +524,16 @@ pub struct ChatCompletionResponseMessageAudio {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ChatCompletionResponseMessageAnnotation {
     UrlCitation { url_citation: UrlCitation },
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UrlCitation {
     /// The index of the last character of the URL citation in the message.
     pub end_index: u32,
@@ -478,7 +547,8 @@ pub struct UrlCitation {
 
 /// A chat completion message generated by the model.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionResponseMessage {
     /// The contents of the message.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -508,7 +578,8 @@ pub struct ChatCompletionResponseMessage {
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ChatCompletionFunctionsArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -523,12 +594,13 @@ pub struct ChatCompletionFunctions {
     /// The parameters the functions accepts, described as a JSON Schema object. See the [guide](https://platform.openai.com/docs/guides/text-generation/function-calling) for examples, and the [JSON Schema reference](https://json-schema.org/understanding-json-schema/) for documentation about the format.
     ///
     /// Omitting `parameters` defines a function with an empty parameter list.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub parameters: serde_json::Value,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ChatCompletionTools {
     /// A function tool that can be used to generate a response.
@@ -538,19 +610,22 @@ pub enum ChatCompletionTools {
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionTool {
     pub function: FunctionObject,
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CustomToolChatCompletions {
     pub custom: CustomToolProperties,
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CustomToolProperties {
     /// The name of the custom tool, used to identify it in tool calls.
     pub name: String,
@@ -563,7 +638,8 @@ pub struct CustomToolProperties {
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CustomToolPropertiesFormat {
     /// Unconstrained free-form text.
@@ -575,19 +651,22 @@ pub enum CustomToolPropertiesFormat {
 
 /// Specifies a tool the model should use. Use to force the model to call a specific function.
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionNamedToolChoice {
     pub function: FunctionName,
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionNamedToolChoiceCustom {
     pub custom: CustomName,
 }
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CustomName {
     /// The name of the custom tool to call.
     pub name: String,
@@ -601,7 +680,8 @@ pub struct CustomName {
 ///
 /// `none` is the default when no tools are present. `auto` is the default if tools are present.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ChatCompletionToolChoiceOption {
     AllowedTools(ChatCompletionAllowedToolsChoice),
@@ -612,22 +692,41 @@ pub enum ChatCompletionToolChoiceOption {
     Mode(ToolChoiceOptions),
 }
 
+/// The tool choice of the chat completions API. Hosted tools such as file search are chosen in the
+/// [Responses API](crate::types::responses::ToolChoiceParam).
+///
+/// ```
+/// use async_openai::types::chat::ToolChoice;
+///
+/// let choice = ToolChoice::function("get_weather");
+/// assert_eq!(
+///     serde_json::to_value(&choice).unwrap(),
+///     serde_json::json!({"type": "function", "function": {"name": "get_weather"}})
+/// );
+/// assert_eq!(serde_json::to_value(ToolChoice::required()).unwrap(), "required");
+/// ```
+pub type ToolChoice = ChatCompletionToolChoiceOption;
+
 #[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionAllowedToolsChoice {
-    pub allowed_tools: Vec<ChatCompletionAllowedTools>,
+    pub allowed_tools: ChatCompletionAllowedTools,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ToolChoiceAllowedMode {
+    #[default]
     Auto,
     Required,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionAllowedTools {
     /// Constrains the tools available to the model to a pre-defined set.
     ///
@@ -645,12 +744,13 @@ pub struct ChatCompletionAllowedTools {
     ///   { "type": "function", "function": { "name": "get_time" } }
     /// ]
     /// ```
-    #[schema(value_type = Vec<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<Object>))]
     pub tools: Vec<serde_json::Value>,
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ToolChoiceOptions {
     #[default]
@@ -660,7 +760,8 @@ pub enum ToolChoiceOptions {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 /// The amount of context window space to use for the search.
 pub enum WebSearchContextSize {
@@ -671,7 +772,8 @@ pub enum WebSearchContextSize {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum WebSearchUserLocationType {
     Approximate,
@@ -679,7 +781,8 @@ pub enum WebSearchUserLocationType {
 
 /// Approximate location parameters for the search.
 #[derive(Clone, Serialize, Debug, Default, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WebSearchLocation {
     ///  The two-letter [ISO country code](https://en.wikipedia.org/wiki/ISO_3166-1) of the user, e.g. `US`.
     pub country: Option<String>,
@@ -692,7 +795,8 @@ pub struct WebSearchLocation {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WebSearchUserLocation {
     //  The type of location approximation. Always `approximate`.
     pub r#type: WebSearchUserLocationType,
@@ -702,7 +806,8 @@ pub struct WebSearchUserLocation {
 
 /// Options for the web search tool.
 #[derive(Clone, Serialize, Debug, Default, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WebSearchOptions {
     /// High level guidance for the amount of context window space to use for the search. One of `low`, `medium`, or `high`. `medium` is the default.
     pub search_context_size: Option<WebSearchContextSize>,
@@ -712,7 +817,8 @@ pub struct WebSearchOptions {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ServiceTier {
     Auto,
@@ -720,11 +826,15 @@ pub enum ServiceTier {
     Flex,
     Scale,
     Priority,
+    /// A tier not known to this version of the crate.
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Constrains the verbosity of the model's response. Lower values will result in more concise responses, while higher values will result in more verbose responses. Currently supported values are `low`, `medium`, and `high`.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Verbosity {
     Low,
@@ -740,7 +850,8 @@ pub enum Verbosity {
 /// The `gpt-4o-audio-preview` model can also be used to [generate
 /// audio](https://platform.openai.com/docs/guides/audio). To request that this model generate both text and audio responses, you can use: `["text", "audio"]`
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ResponseModalities {
     Text,
@@ -749,7 +860,8 @@ pub enum ResponseModalities {
 
 /// The content that should be matched when generating a model response. If generated tokens would match this content, the entire model response can be returned much more quickly.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum PredictionContentContent {
     /// The content used for a Predicted Output. This is often the text of a file you are regenerating with minor changes.
@@ -760,7 +872,8 @@ pub enum PredictionContentContent {
 
 /// Static predicted output content, such as the content of a text file that is being regenerated.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "lowercase", content = "content")]
 pub enum PredictionContent {
     /// The type of the predicted content you want to provide. This type is
@@ -769,7 +882,8 @@ pub enum PredictionContent {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ChatCompletionAudioVoice {
     Alloy,
@@ -782,12 +896,15 @@ pub enum ChatCompletionAudioVoice {
     Onyx,
     Sage,
     Shimmer,
+    Marin,
+    Cedar,
     #[serde(untagged)]
     Other(String),
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ChatCompletionAudioFormat {
     Wav,
@@ -799,7 +916,8 @@ pub enum ChatCompletionAudioFormat {
 }
 
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionAudio {
     /// The voice the model uses to respond. Supported built-in voices are `alloy`, `ash`,
     /// `ballad`, `coral`, `echo`, `fable`, `nova`, `onyx`, `sage`, `shimmer`, `marin`, and `cedar`.
@@ -808,13 +926,61 @@ pub struct ChatCompletionAudio {
     pub format: ChatCompletionAudioFormat,
 }
 
+/// Bias values added to the logits of tokens, keyed by token ID.
+///
+/// Serialized as the JSON object the API expects, with token IDs as string keys, e.g. `{"50256": -100}`.
+/// Weights must be from -100 to 100, see [validate](Self::validate).
+#[derive(Clone, Serialize, Default, Debug, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(transparent)]
+pub struct LogitBias(pub HashMap<u32, i8>);
+
+impl LogitBias {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the bias of `token` to `weight`, replacing any previous value.
+    pub fn insert(&mut self, token: u32, weight: i8) -> &mut Self {
+        self.0.insert(token, weight);
+        self
+    }
+
+    /// Checks that all weights are from -100 to 100.
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        for (token, weight) in &self.0 {
+            check_range(
+                &format!("logit_bias of token {token}"),
+                Some(*weight),
+                -100,
+                100,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl From<HashMap<u32, i8>> for LogitBias {
+    fn from(value: HashMap<u32, i8>) -> Self {
+        Self(value)
+    }
+}
+
+impl FromIterator<(u32, i8)> for LogitBias {
+    fn from_iter<I: IntoIterator<Item = (u32, i8)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 #[derive(Clone, Serialize, Default, Debug, Builder, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateChatCompletionRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(name = "build_unchecked", error = "OpenAIError"))]
 pub struct CreateChatCompletionRequest {
     /// A list of messages comprising the conversation so far. Depending on the
     /// [model](https://platform.openai.com/docs/models) you use, different message types (modalities)
@@ -931,8 +1097,10 @@ pub struct CreateChatCompletionRequest {
     /// Mathematically, the bias is added to the logits generated by the model prior to sampling.
     /// The exact effect will vary per model, but values between -1 and 1 should decrease or increase likelihood of selection;
     /// values like -100 or 100 should result in a ban or exclusive selection of the relevant token.
+    ///
+    /// Use [bias_token](CreateChatCompletionRequestArgs::bias_token) to add tokens one at a time.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub logit_bias: Option<HashMap<String, i8>>, // default: null
+    pub logit_bias: Option<LogitBias>, // default: null
 
     /// Whether to return log probabilities of the output tokens or not. If true,
     /// returns the log probabilities of each output token returned in the `content` of `message`.
@@ -1012,11 +1180,15 @@ pub struct CreateChatCompletionRequest {
     /// Specifying a particular tool via `{"type": "function", "function": {"name": "my_function"}}` forces
     /// the model to call that tool.
     /// `none` is the default when no tools are present. `auto` is the default if tools are present.
+    ///
+    /// The builder accepts a [ToolChoice], a [ToolChoiceOptions] mode, or the name of the function
+    /// to call.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_choice: Option<ChatCompletionToolChoiceOption>,
 
     /// Whether to enable [parallel function calling](https://platform.openai.com/docs/guides/function-calling#configuring-parallel-function-calling)
-    /// during tool use.
+    /// during tool use. Only allowed with [tools](Self::tools), see
+    /// [sequential_tool_calls](CreateChatCompletionRequestArgs::sequential_tool_calls).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parallel_tool_calls: Option<bool>,
 
@@ -1067,9 +1239,131 @@ pub struct CreateChatCompletionRequest {
     pub metadata: Option<Metadata>, // nullable: true
 }
 
+impl CreateChatCompletionRequestArgs {
+    /// Appends `tool` to the [tools](CreateChatCompletionRequest::tools), e.g. a [FunctionObject]
+    /// or a [CustomToolChatCompletions].
+    pub fn tool(&mut self, tool: impl Into<ChatCompletionTools>) -> &mut Self {
+        self.tools
+            .get_or_insert(None)
+            .get_or_insert_with(Vec::new)
+            .push(tool.into());
+        self
+    }
+
+    /// Appends each of `functions` to the [tools](CreateChatCompletionRequest::tools) as function tools.
+    pub fn function_tools(
+        &mut self,
+        functions: impl IntoIterator<Item = FunctionObject>,
+    ) -> &mut Self {
+        for function in functions {
+            self.tool(function);
+        }
+        self
+    }
+
+    /// Disables [parallel_tool_calls](CreateChatCompletionRequest::parallel_tool_calls), so that
+    /// the model calls at most one tool per response, for tools that must run in order.
+    pub fn sequential_tool_calls(&mut self) -> &mut Self {
+        self.parallel_tool_calls(false)
+    }
+
+    /// Adds `weight` (from -100 to 100) to the [logit_bias](CreateChatCompletionRequest::logit_bias) of `token`.
+    pub fn bias_token(&mut self, token: u32, weight: i8) -> &mut Self {
+        self.logit_bias
+            .get_or_insert(None)
+            .get_or_insert_with(LogitBias::default)
+            .insert(token, weight);
+        self
+    }
+
+    /// Limits the number of generated tokens with the field supported by the [model](Self::model)
+    /// set so far: the legacy `max_tokens` for the known families of non-reasoning models such as
+    /// `gpt-4o`, and `max_completion_tokens`, which also counts reasoning tokens, otherwise.
+    ///
+    /// Reasoning models such as `o3` reject `max_tokens`.
+    pub fn max_output_tokens(&mut self, tokens: u32) -> &mut Self {
+        let legacy = self
+            .model
+            .as_deref()
+            .and_then(ModelCapabilities::for_model)
+            .is_some_and(|capabilities| !capabilities.reasoning);
+        #[allow(deprecated)]
+        if legacy {
+            self.max_tokens(tokens)
+        } else {
+            self.max_completion_tokens(tokens)
+        }
+    }
+
+    /// Builds the request after checking its parameters with [validate](Self::validate).
+    ///
+    /// Use [build_unchecked](Self::build_unchecked) for OpenAI compatible servers accepting values
+    /// that the OpenAI API does not.
+    pub fn build(&self) -> Result<CreateChatCompletionRequest, OpenAIError> {
+        self.validate()?;
+        self.build_unchecked()
+    }
+
+    /// Checks the documented ranges of the parameters set so far, called by [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        #[allow(deprecated)]
+        if let (Some(Some(_)), Some(model)) = (self.max_tokens, &self.model) {
+            if ModelCapabilities::for_model(model).is_some_and(|c| c.reasoning) {
+                return Err(OpenAIError::InvalidArgument(format!(
+                    "max_tokens is not supported by the reasoning model {model}, use max_completion_tokens"
+                )));
+            }
+        }
+        let has_tools = matches!(&self.tools, Some(Some(tools)) if !tools.is_empty());
+        if self.parallel_tool_calls.flatten().is_some() && !has_tools {
+            return Err(OpenAIError::InvalidArgument(
+                "parallel_tool_calls is only allowed when tools are specified".into(),
+            ));
+        }
+        if let Some(Some(logit_bias)) = &self.logit_bias {
+            logit_bias.validate()?;
+        }
+        if let Some(Some(stop)) = &self.stop {
+            stop.validate()?;
+        }
+        check_range("temperature", self.temperature.flatten(), 0.0, 2.0)?;
+        check_range("top_p", self.top_p.flatten(), 0.0, 1.0)?;
+        check_range("n", self.n.flatten(), 1, 128)?;
+        check_range("top_logprobs", self.top_logprobs.flatten(), 0, 20)?;
+        check_range(
+            "presence_penalty",
+            self.presence_penalty.flatten(),
+            -2.0,
+            2.0,
+        )?;
+        check_range(
+            "frequency_penalty",
+            self.frequency_penalty.flatten(),
+            -2.0,
+            2.0,
+        )?;
+        #[allow(deprecated)]
+        check_min("max_tokens", self.max_tokens.flatten(), 1)?;
+        check_min(
+            "max_completion_tokens",
+            self.max_completion_tokens.flatten(),
+            1,
+        )?;
+        Ok(())
+    }
+}
+
 /// Options for streaming response. Only set this when you set `stream: true`.
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+///
+/// ```
+/// # use async_openai::types::chat::ChatCompletionStreamOptions;
+/// let stream_options = ChatCompletionStreamOptions::default()
+///     .with_include_usage(true)
+///     .with_include_obfuscation(false);
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionStreamOptions {
     /// If set, an additional chunk will be streamed before the `data: [DONE]`
     /// message. The `usage` field on this chunk shows the token usage statistics
@@ -1093,19 +1387,31 @@ pub struct ChatCompletionStreamOptions {
     pub include_obfuscation: Option<bool>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+/// Why the model stopped generating tokens.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum FinishReason {
+    /// A natural stop point or a provided stop sequence was reached.
     Stop,
+    /// The maximum number of tokens of the request was reached.
     Length,
+    /// The model called tools.
     ToolCalls,
+    /// Content was omitted by a content filter.
     ContentFilter,
+    /// The model called a function, deprecated in favor of `ToolCalls`.
     FunctionCall,
+    /// A reason added to the API after this version of the crate, or specific to an OpenAI
+    /// compatible server.
+    #[serde(untagged)]
+    Other(String),
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TopLogprobs {
     /// The token.
     pub token: String,
@@ -1116,7 +1422,8 @@ pub struct TopLogprobs {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionTokenLogprob {
     /// The token.
     pub token: String,
@@ -1125,19 +1432,23 @@ pub struct ChatCompletionTokenLogprob {
     /// A list of integers representing the UTF-8 bytes representation of the token. Useful in instances where characters are represented by multiple tokens and their byte representations must be combined to generate the correct text representation. Can be `null` if there is no bytes representation for the token.
     pub bytes: Option<Vec<u8>>,
     ///  List of the most likely tokens and their log probability, at this token position. In rare cases, there may be fewer than the number of requested `top_logprobs` returned.
+    #[serde(default)]
     pub top_logprobs: Vec<TopLogprobs>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatChoiceLogprobs {
     /// A list of message content tokens with log probability information.
     pub content: Option<Vec<ChatCompletionTokenLogprob>>,
+    /// A list of message refusal tokens with log probability information.
     pub refusal: Option<Vec<ChatCompletionTokenLogprob>>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatChoice {
     /// The index of the choice in the list of choices.
     pub index: u32,
@@ -1151,11 +1462,15 @@ pub struct ChatChoice {
     /// Log probability information for the choice.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub logprobs: Option<ChatChoiceLogprobs>,
+    /// Results of the content filters of Azure OpenAI for the content of the choice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_filter_results: Option<ContentFilterResults>,
 }
 
 /// Represents a chat completion response returned by model, based on the provided input.
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateChatCompletionResponse {
     /// A unique identifier for the chat completion.
     pub id: String,
@@ -1178,6 +1493,13 @@ pub struct CreateChatCompletionResponse {
     /// The object type, which is always `chat.completion`.
     pub object: String,
     pub usage: Option<CompletionUsage>,
+    /// The `metadata` of the request, returned for [stored](CreateChatCompletionRequest::store)
+    /// completions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Results of the content filters of Azure OpenAI for the prompts of the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_filter_results: Option<Vec<PromptFilterResult>>,
 }
 
 /// Parsed server side events stream until an \[DONE\] is received from server.
@@ -1187,7 +1509,8 @@ pub type ChatCompletionResponseStream = std::pin::Pin<
 >;
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionCallStream {
     /// The name of the function to call.
     pub name: Option<String>,
@@ -1199,7 +1522,8 @@ pub struct FunctionCallStream {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionMessageToolCallChunk {
     pub index: u32,
     /// The ID of the tool call.
@@ -1210,7 +1534,8 @@ pub struct ChatCompletionMessageToolCallChunk {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum FunctionType {
     Function,
@@ -1218,7 +1543,8 @@ pub enum FunctionType {
 
 /// A chat completion delta generated by streamed model responses.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionStreamResponseDelta {
     /// The contents of the chunk message.
     pub content: Option<String>,
@@ -1234,7 +1560,8 @@ pub struct ChatCompletionStreamResponseDelta {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatChoiceStream {
     /// The index of the choice in the list of choices.
     pub index: u32,
@@ -1254,11 +1581,15 @@ pub struct ChatChoiceStream {
     /// Log probability information for the choice.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub logprobs: Option<ChatChoiceLogprobs>,
+    /// Results of the content filters of Azure OpenAI for the content of the choice so far.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_filter_results: Option<ContentFilterResults>,
 }
 
 /// Represents a streamed chunk of a chat completion response returned by the model, based on the provided input. [Learn more](https://platform.openai.com/docs/guides/streaming-responses).
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateChatCompletionStreamResponse {
     /// A unique identifier for the chat completion. Each chunk has the same ID.
     pub id: String,
@@ -1281,11 +1612,16 @@ pub struct CreateChatCompletionStreamResponse {
     /// An optional field that will only be present when you set `stream_options: {"include_usage": true}` in your request.
     /// When present, it contains a null value except for the last chunk which contains the token usage statistics for the entire request.
     pub usage: Option<CompletionUsage>,
+    /// Results of the content filters of Azure OpenAI for the prompts of the request, sent in
+    /// the first chunk.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_filter_results: Option<Vec<PromptFilterResult>>,
 }
 
 /// An object representing a list of Chat Completions.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionList {
     /// The type of this object. It is always set to "list".
     pub object: String,
@@ -1301,7 +1637,8 @@ pub struct ChatCompletionList {
 
 /// Response when deleting a chat completion.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionDeleted {
     /// The type of object being deleted.
     pub object: String,
@@ -1312,7 +1649,8 @@ pub struct ChatCompletionDeleted {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum ContentPart {
@@ -1322,7 +1660,8 @@ pub enum ContentPart {
 
 /// A chat completion message with additional fields for listing.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionMessageListItem {
     /// The identifier of the chat message.
     pub id: String,
@@ -1336,7 +1675,8 @@ pub struct ChatCompletionMessageListItem {
 
 /// An object representing a list of chat completion messages.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ChatCompletionMessageList {
     /// The type of this object. It is always set to "list".
     pub object: String,
@@ -1352,7 +1692,8 @@ pub struct ChatCompletionMessageList {
 
 /// Request to update a chat completion.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "UpdateChatCompletionRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -0,0 +1,146 @@
+use base64::{engine::general_purpose, Engine as _};
+
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+use crate::error::OpenAIError;
+use crate::types::chat::{
+    ChatCompletionRequestMessage, ChatCompletionRequestMessageContentPartAudio,
+    ChatCompletionRequestMessageContentPartFile, ChatCompletionRequestMessageContentPartImage,
+    ChatCompletionRequestMessageContentPartText, ChatCompletionRequestUserMessage,
+    ChatCompletionRequestUserMessageContent, ChatCompletionRequestUserMessageContentPart,
+    FileObject, ImageUrl, InputAudio, InputAudioFormat,
+};
+
+/// The parts of a multimodal user message, in order.
+///
+/// ```
+/// # use async_openai::types::chat::{ChatCompletionRequestUserMessage, InputAudioFormat, UserContent};
+/// # let wav = vec![];
+/// let message: ChatCompletionRequestUserMessage = UserContent::new()
+///     .text("What is in this image, and what does the recording say about it?")
+///     .image_url("https://example.com/image.png")
+///     .audio(wav, InputAudioFormat::Wav)
+///     .file("file-abc123")
+///     .into();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserContent {
+    parts: Vec<ChatCompletionRequestUserMessageContentPart>,
+}
+
+impl UserContent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.part(ChatCompletionRequestMessageContentPartText { text: text.into() })
+    }
+
+    /// An image by URL, or as a `data:` URL, see [ImageUrl::from_bytes].
+    pub fn image_url(self, image_url: impl Into<ImageUrl>) -> Self {
+        self.part(ChatCompletionRequestMessageContentPartImage {
+            image_url: image_url.into(),
+        })
+    }
+
+    /// An image read from a file, see [ImageUrl::from_path].
+    #[cfg(all(feature = "_api", not(target_family = "wasm")))]
+    pub async fn image_path<P: AsRef<std::path::Path>>(self, path: P) -> Result<Self, OpenAIError> {
+        Ok(self.image_url(ImageUrl::from_path(path).await?))
+    }
+
+    /// Audio from its encoded bytes.
+    pub fn audio(self, bytes: impl AsRef<[u8]>, format: InputAudioFormat) -> Self {
+        self.part(ChatCompletionRequestMessageContentPartAudio {
+            input_audio: InputAudio {
+                data: general_purpose::STANDARD.encode(bytes),
+                format,
+            },
+        })
+    }
+
+    /// An uploaded file, by its ID.
+    pub fn file(self, file_id: impl Into<String>) -> Self {
+        self.part(ChatCompletionRequestMessageContentPartFile {
+            file: FileObject::from_id(file_id),
+        })
+    }
+
+    /// A file sent inline, see [FileObject::from_bytes].
+    pub fn file_data(
+        self,
+        filename: impl Into<String>,
+        bytes: impl AsRef<[u8]>,
+        mime: &str,
+    ) -> Self {
+        self.part(ChatCompletionRequestMessageContentPartFile {
+            file: FileObject::from_bytes(filename, bytes, mime),
+        })
+    }
+
+    pub fn part(mut self, part: impl Into<ChatCompletionRequestUserMessageContentPart>) -> Self {
+        self.parts.push(part.into());
+        self
+    }
+
+    pub fn into_parts(self) -> Vec<ChatCompletionRequestUserMessageContentPart> {
+        self.parts
+    }
+}
+
+impl FileObject {
+    /// A file uploaded with the Files API, by its ID.
+    pub fn from_id(file_id: impl Into<String>) -> Self {
+        Self {
+            file_id: Some(file_id.into()),
+            ..Default::default()
+        }
+    }
+
+    /// A file from its bytes, sent as a `data:` URL, e.g. `FileObject::from_bytes("report.pdf", pdf, "application/pdf")`.
+    pub fn from_bytes(filename: impl Into<String>, bytes: impl AsRef<[u8]>, mime: &str) -> Self {
+        Self {
+            file_data: Some(format!(
+                "data:{mime};base64,{}",
+                general_purpose::STANDARD.encode(bytes)
+            )),
+            filename: Some(filename.into()),
+            file_id: None,
+        }
+    }
+}
+
+impl From<ChatCompletionRequestMessageContentPartFile>
+    for ChatCompletionRequestUserMessageContentPart
+{
+    fn from(value: ChatCompletionRequestMessageContentPartFile) -> Self {
+        ChatCompletionRequestUserMessageContentPart::File(value)
+    }
+}
+
+impl From<UserContent> for Vec<ChatCompletionRequestUserMessageContentPart> {
+    fn from(value: UserContent) -> Self {
+        value.parts
+    }
+}
+
+impl From<UserContent> for ChatCompletionRequestUserMessageContent {
+    fn from(value: UserContent) -> Self {
+        ChatCompletionRequestUserMessageContent::Array(value.parts)
+    }
+}
+
+impl From<UserContent> for ChatCompletionRequestUserMessage {
+    fn from(value: UserContent) -> Self {
+        ChatCompletionRequestUserMessage {
+            content: value.into(),
+            name: None,
+        }
+    }
+}
+
+impl From<UserContent> for ChatCompletionRequestMessage {
+    fn from(value: UserContent) -> Self {
+        ChatCompletionRequestMessage::User(value.into())
+    }
+}
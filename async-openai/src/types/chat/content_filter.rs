@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+/// Results of the content filters of [Azure OpenAI](https://learn.microsoft.com/en-us/azure/ai-services/openai/concepts/content-filter)
+/// for a choice or a prompt. OpenAI does not return them.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ContentFilterResults {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hate: Option<ContentFilterSeverityResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_harm: Option<ContentFilterSeverityResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sexual: Option<ContentFilterSeverityResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub violence: Option<ContentFilterSeverityResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profanity: Option<ContentFilterDetectedResult>,
+    /// User prompt attacks, only on prompts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jailbreak: Option<ContentFilterDetectedResult>,
+    /// Known text content, like song lyrics, only on completions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected_material_text: Option<ContentFilterDetectedResult>,
+    /// Source code from public repositories, only on completions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected_material_code: Option<ContentFilterCodeResult>,
+    /// Set when the content filters could not run.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ContentFilterError>,
+}
+
+impl ContentFilterResults {
+    /// Whether the content was filtered for any category.
+    pub fn is_filtered(&self) -> bool {
+        [&self.hate, &self.self_harm, &self.sexual, &self.violence]
+            .into_iter()
+            .flatten()
+            .any(|result| result.filtered)
+            || [
+                &self.profanity,
+                &self.jailbreak,
+                &self.protected_material_text,
+            ]
+            .into_iter()
+            .flatten()
+            .any(|result| result.filtered)
+            || self
+                .protected_material_code
+                .as_ref()
+                .is_some_and(|result| result.filtered)
+    }
+}
+
+/// Severity of the content of a harm category.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum ContentFilterSeverity {
+    Safe,
+    Low,
+    Medium,
+    High,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Result of a harm category rated by severity.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ContentFilterSeverityResult {
+    pub filtered: bool,
+    pub severity: ContentFilterSeverity,
+}
+
+/// Result of a category detected or not, such as jailbreak attempts.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ContentFilterDetectedResult {
+    pub filtered: bool,
+    pub detected: bool,
+}
+
+/// Result of the protected material code filter, with the repository the code comes from.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ContentFilterCodeResult {
+    pub filtered: bool,
+    pub detected: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub citation: Option<ContentFilterCitation>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ContentFilterCitation {
+    #[serde(rename = "URL", skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct ContentFilterError {
+    pub code: String,
+    pub message: String,
+}
+
+/// Results of the content filters for a prompt of the request.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct PromptFilterResult {
+    pub prompt_index: u32,
+    pub content_filter_results: ContentFilterResults,
+}
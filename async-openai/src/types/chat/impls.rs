@@ -1,9 +1,15 @@
 use std::fmt::Display;
 
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::error::OpenAIError;
 use crate::types::chat::{
+    ChatChoiceLogprobs, ChatCompletionAllowedTools, ChatCompletionAllowedToolsChoice,
+    ChatCompletionAudio, ChatCompletionAudioFormat, ChatCompletionAudioVoice,
     ChatCompletionFunctionCall, ChatCompletionMessageCustomToolCall, ChatCompletionMessageToolCall,
     ChatCompletionMessageToolCalls, ChatCompletionNamedToolChoice,
-    ChatCompletionRequestAssistantMessage, ChatCompletionRequestAssistantMessageContent,
+    ChatCompletionNamedToolChoiceCustom, ChatCompletionRequestAssistantMessage,
+    ChatCompletionRequestAssistantMessageAudio, ChatCompletionRequestAssistantMessageContent,
     ChatCompletionRequestDeveloperMessage, ChatCompletionRequestDeveloperMessageContent,
     ChatCompletionRequestFunctionMessage, ChatCompletionRequestMessage,
     ChatCompletionRequestMessageContentPartAudio, ChatCompletionRequestMessageContentPartImage,
@@ -11,8 +17,11 @@ use crate::types::chat::{
     ChatCompletionRequestSystemMessageContent, ChatCompletionRequestToolMessage,
     ChatCompletionRequestToolMessageContent, ChatCompletionRequestUserMessage,
     ChatCompletionRequestUserMessageContent, ChatCompletionRequestUserMessageContentPart,
-    ChatCompletionTool, ChatCompletionTools, CustomToolChatCompletions, FunctionName, ImageUrl,
-    Role,
+    ChatCompletionResponseMessage, ChatCompletionResponseMessageAudio, ChatCompletionStreamOptions,
+    ChatCompletionStreamResponseDelta, ChatCompletionTokenLogprob, ChatCompletionTool,
+    ChatCompletionToolChoiceOption, ChatCompletionTools, CreateChatCompletionRequest, CustomName,
+    CustomToolChatCompletions, FunctionName, FunctionObject, ImageUrl, PredictionContent,
+    PredictionContentContent, Role, ToolChoiceAllowedMode, ToolChoiceOptions,
 };
 
 impl From<ChatCompletionRequestUserMessage> for ChatCompletionRequestMessage {
@@ -239,6 +248,18 @@ impl From<String> for ChatCompletionRequestMessageContentPartText {
     }
 }
 
+impl From<&str> for PredictionContent {
+    fn from(value: &str) -> Self {
+        PredictionContent::Content(PredictionContentContent::Text(value.into()))
+    }
+}
+
+impl From<String> for PredictionContent {
+    fn from(value: String) -> Self {
+        PredictionContent::Content(PredictionContentContent::Text(value))
+    }
+}
+
 impl From<&str> for ChatCompletionFunctionCall {
     fn from(value: &str) -> Self {
         match value {
@@ -277,6 +298,90 @@ impl From<String> for ChatCompletionNamedToolChoice {
     }
 }
 
+// ChatCompletionToolChoiceOption ergonomics
+
+impl From<ToolChoiceOptions> for ChatCompletionToolChoiceOption {
+    fn from(mode: ToolChoiceOptions) -> Self {
+        ChatCompletionToolChoiceOption::Mode(mode)
+    }
+}
+
+/// `"none"`, `"auto"` and `"required"` are modes, any other string is the name of a function.
+impl<S: Into<String>> From<S> for ChatCompletionToolChoiceOption {
+    fn from(value: S) -> Self {
+        let value = value.into();
+        match value.as_str() {
+            "none" => ChatCompletionToolChoiceOption::Mode(ToolChoiceOptions::None),
+            "auto" => ChatCompletionToolChoiceOption::Mode(ToolChoiceOptions::Auto),
+            "required" => ChatCompletionToolChoiceOption::Mode(ToolChoiceOptions::Required),
+            _ => ChatCompletionToolChoiceOption::Function(value.into()),
+        }
+    }
+}
+
+impl From<ChatCompletionNamedToolChoice> for ChatCompletionToolChoiceOption {
+    fn from(function: ChatCompletionNamedToolChoice) -> Self {
+        ChatCompletionToolChoiceOption::Function(function)
+    }
+}
+
+impl From<ChatCompletionNamedToolChoiceCustom> for ChatCompletionToolChoiceOption {
+    fn from(custom: ChatCompletionNamedToolChoiceCustom) -> Self {
+        ChatCompletionToolChoiceOption::Custom(custom)
+    }
+}
+
+impl From<ChatCompletionAllowedToolsChoice> for ChatCompletionToolChoiceOption {
+    fn from(allowed_tools: ChatCompletionAllowedToolsChoice) -> Self {
+        ChatCompletionToolChoiceOption::AllowedTools(allowed_tools)
+    }
+}
+
+impl ChatCompletionToolChoiceOption {
+    /// The model does not call any tool.
+    pub fn none() -> Self {
+        ToolChoiceOptions::None.into()
+    }
+
+    /// The model picks between generating a message and calling tools.
+    pub fn auto() -> Self {
+        ToolChoiceOptions::Auto.into()
+    }
+
+    /// The model calls one or more tools.
+    pub fn required() -> Self {
+        ToolChoiceOptions::Required.into()
+    }
+
+    /// The model calls the function tool `name`.
+    pub fn function(name: impl Into<String>) -> Self {
+        ChatCompletionNamedToolChoice::from(name.into()).into()
+    }
+
+    /// The model calls the custom tool `name`.
+    pub fn custom(name: impl Into<String>) -> Self {
+        ChatCompletionNamedToolChoiceCustom {
+            custom: CustomName { name: name.into() },
+        }
+        .into()
+    }
+
+    /// The model only picks among the function tools of `names`, depending on `mode`.
+    pub fn allowed_functions<S: Into<String>>(
+        mode: ToolChoiceAllowedMode,
+        names: impl IntoIterator<Item = S>,
+    ) -> Self {
+        let tools = names
+            .into_iter()
+            .map(|name| serde_json::json!({"type": "function", "function": {"name": name.into()}}))
+            .collect();
+        ChatCompletionAllowedToolsChoice {
+            allowed_tools: ChatCompletionAllowedTools { mode, tools },
+        }
+        .into()
+    }
+}
+
 impl Default for ChatCompletionRequestDeveloperMessageContent {
     fn default() -> Self {
         ChatCompletionRequestDeveloperMessageContent::Text("".into())
@@ -301,6 +406,21 @@ impl Default for ChatCompletionRequestUserMessageContent {
     }
 }
 
+impl ChatCompletionStreamOptions {
+    /// Streams a final chunk with the usage of the whole request.
+    pub fn with_include_usage(mut self, include_usage: bool) -> Self {
+        self.include_usage = Some(include_usage);
+        self
+    }
+
+    /// Set to `false` to leave out the `obfuscation` padding of stream events, e.g. for proxies
+    /// that reject or buffer it.
+    pub fn with_include_obfuscation(mut self, include_obfuscation: bool) -> Self {
+        self.include_obfuscation = Some(include_obfuscation);
+        self
+    }
+}
+
 impl From<&str> for ImageUrl {
     fn from(value: &str) -> Self {
         Self {
@@ -335,6 +455,30 @@ impl Display for Role {
     }
 }
 
+impl From<FunctionObject> for ChatCompletionTool {
+    fn from(function: FunctionObject) -> Self {
+        ChatCompletionTool { function }
+    }
+}
+
+impl From<FunctionObject> for ChatCompletionTools {
+    fn from(function: FunctionObject) -> Self {
+        ChatCompletionTools::Function(function.into())
+    }
+}
+
+impl From<ChatCompletionTool> for ChatCompletionTools {
+    fn from(value: ChatCompletionTool) -> Self {
+        ChatCompletionTools::Function(value)
+    }
+}
+
+impl From<CustomToolChatCompletions> for ChatCompletionTools {
+    fn from(value: CustomToolChatCompletions) -> Self {
+        ChatCompletionTools::Custom(value)
+    }
+}
+
 impl From<ChatCompletionTool> for Vec<ChatCompletionTools> {
     fn from(value: ChatCompletionTool) -> Self {
         vec![ChatCompletionTools::Function(value)]
@@ -400,3 +544,148 @@ impl From<ImageUrl> for ChatCompletionRequestMessageContentPartImage {
         ChatCompletionRequestMessageContentPartImage { image_url: value }
     }
 }
+
+/// The assistant message to send back in the next request of a multi-turn conversation, keeping
+/// the content, refusal, tool calls and audio of the response.
+impl From<ChatCompletionResponseMessage> for ChatCompletionRequestAssistantMessage {
+    #[allow(deprecated)]
+    fn from(value: ChatCompletionResponseMessage) -> Self {
+        ChatCompletionRequestAssistantMessage {
+            content: value
+                .content
+                .map(ChatCompletionRequestAssistantMessageContent::Text),
+            refusal: value.refusal,
+            name: None,
+            audio: value
+                .audio
+                .map(|audio| ChatCompletionRequestAssistantMessageAudio { id: audio.id }),
+            tool_calls: value.tool_calls,
+            function_call: value.function_call,
+        }
+    }
+}
+
+impl From<ChatCompletionResponseMessage> for ChatCompletionRequestMessage {
+    fn from(value: ChatCompletionResponseMessage) -> Self {
+        ChatCompletionRequestMessage::Assistant(value.into())
+    }
+}
+
+/// Append messages to a request, e.g. to send the next turn of a conversation with the same
+/// request.
+///
+/// ```
+/// # use async_openai::types::chat::CreateChatCompletionRequest;
+/// let mut request = CreateChatCompletionRequest {
+///     model: "gpt-4o-mini".into(),
+///     ..Default::default()
+/// };
+/// request
+///     .push_system("You are a helpful assistant.")
+///     .push_user("What is the capital of France?")
+///     .push_assistant("Paris.")
+///     .push_user("And of Italy?");
+/// assert_eq!(request.messages.len(), 4);
+/// ```
+impl CreateChatCompletionRequest {
+    /// Appends a user message, from its text or a [ChatCompletionRequestUserMessage].
+    pub fn push_user(&mut self, message: impl Into<ChatCompletionRequestUserMessage>) -> &mut Self {
+        self.messages.push(message.into().into());
+        self
+    }
+
+    /// Appends an assistant message, from its text or the
+    /// [message](ChatCompletionResponseMessage) of a previous response, keeping its tool calls.
+    pub fn push_assistant(
+        &mut self,
+        message: impl Into<ChatCompletionRequestAssistantMessage>,
+    ) -> &mut Self {
+        self.messages.push(message.into().into());
+        self
+    }
+
+    /// Appends a system message, from its text or a [ChatCompletionRequestSystemMessage].
+    pub fn push_system(
+        &mut self,
+        message: impl Into<ChatCompletionRequestSystemMessage>,
+    ) -> &mut Self {
+        self.messages.push(message.into().into());
+        self
+    }
+
+    /// Appends the result of the tool call `tool_call_id` of the previous assistant message.
+    pub fn push_tool_result(
+        &mut self,
+        tool_call_id: impl Into<String>,
+        content: impl Into<ChatCompletionRequestToolMessageContent>,
+    ) -> &mut Self {
+        self.messages.push(
+            ChatCompletionRequestToolMessage {
+                content: content.into(),
+                tool_call_id: tool_call_id.into(),
+            }
+            .into(),
+        );
+        self
+    }
+}
+
+impl ChatCompletionResponseMessage {
+    /// Returns `true` when the model refused to respond instead of generating content.
+    pub fn is_refusal(&self) -> bool {
+        self.refusal.is_some()
+    }
+
+    /// The refusal message generated by the model, if any.
+    pub fn refusal(&self) -> Option<&str> {
+        self.refusal.as_deref()
+    }
+}
+
+impl ChatCompletionAudio {
+    pub fn new(voice: ChatCompletionAudioVoice, format: ChatCompletionAudioFormat) -> Self {
+        Self { voice, format }
+    }
+}
+
+impl ChatCompletionResponseMessageAudio {
+    /// Decodes the base64 [data](Self::data) into the bytes of the audio, in the format of the request.
+    pub fn decode(&self) -> Result<Vec<u8>, OpenAIError> {
+        general_purpose::STANDARD
+            .decode(&self.data)
+            .map_err(|e| OpenAIError::InvalidArgument(format!("invalid base64 audio: {e}")))
+    }
+}
+
+impl ChatCompletionStreamResponseDelta {
+    /// Returns `true` when this chunk carries a fragment of a refusal message.
+    pub fn is_refusal(&self) -> bool {
+        self.refusal.is_some()
+    }
+
+    /// The fragment of the refusal message in this chunk, if any.
+    pub fn refusal(&self) -> Option<&str> {
+        self.refusal.as_deref()
+    }
+}
+
+impl ChatCompletionTokenLogprob {
+    /// The probability of the token, between 0 and 1.
+    pub fn probability(&self) -> f32 {
+        self.logprob.exp()
+    }
+}
+
+impl ChatChoiceLogprobs {
+    /// The [perplexity](https://en.wikipedia.org/wiki/Perplexity) of the content tokens, the
+    /// exponential of their mean negative log probability: 1 when the model was certain of every
+    /// token, and higher the less confident it was. `None` without content tokens.
+    pub fn perplexity(&self) -> Option<f32> {
+        let tokens = self
+            .content
+            .as_deref()
+            .filter(|tokens| !tokens.is_empty())?;
+        let sum: f64 = tokens.iter().map(|token| token.logprob as f64).sum();
+        Some((-sum / tokens.len() as f64).exp() as f32)
+    }
+}
@@ -1,9 +1,21 @@
 mod api;
 mod chat_;
+mod content;
+mod content_filter;
 mod impls;
+mod stream;
+#[cfg(feature = "schema")]
+mod tool_registry;
+mod usage;
 
 pub use api::*;
 pub use chat_::*;
+pub use content::*;
+pub use content_filter::*;
+pub use stream::*;
+#[cfg(feature = "schema")]
+pub use tool_registry::*;
+pub use usage::*;
 
 // Re-export shared types that are used in chat
 pub use crate::types::shared::CompletionTokensDetails;
@@ -16,6 +28,7 @@ pub use crate::types::shared::GrammarSyntax;
 pub use crate::types::shared::ImageDetail;
 pub use crate::types::shared::ImageUrl;
 pub use crate::types::shared::ImageUrlArgs;
+pub use crate::types::shared::PartialJsonAccumulator;
 pub use crate::types::shared::PromptTokensDetails;
 pub use crate::types::shared::ReasoningEffort;
 pub use crate::types::shared::ResponseFormat;
@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::types::chat::{
+    ChatChoice, ChatChoiceLogprobs, ChatChoiceStream, ChatCompletionMessageToolCall,
+    ChatCompletionMessageToolCallChunk, ChatCompletionMessageToolCalls,
+    ChatCompletionResponseMessage, ChatCompletionStreamResponseDelta, CompletionRecord,
+    CompletionUsage, ContentFilterResults, CreateChatCompletionResponse,
+    CreateChatCompletionStreamResponse, FinishReason, FunctionCall, PromptFilterResult, Role,
+    ServiceTier,
+};
+
+/// Assembles [ChatCompletionMessageToolCall]s from the partial `tool_calls` of streamed
+/// [ChatCompletionStreamResponseDelta]s.
+///
+/// The `id` and function `name` of a tool call only appear in its first chunk, while the
+/// `arguments` arrive in fragments. Chunks are grouped by their `index`, and a tool call is
+/// considered complete once a chunk for another `index` is received and its `arguments` are a
+/// complete JSON value, so that the chunks of concurrent tool calls can be interleaved. Call
+/// [finish](Self::finish) when the stream ends to flush the remaining tool calls.
+///
+/// ```
+/// # use async_openai::types::chat::{ChatCompletionStreamResponseDelta, ToolCallStreamAccumulator};
+/// # fn handle(deltas: Vec<ChatCompletionStreamResponseDelta>) {
+/// let mut accumulator = ToolCallStreamAccumulator::new();
+/// for delta in &deltas {
+///     for tool_call in accumulator.push(delta) {
+///         println!("{}({})", tool_call.function.name, tool_call.function.arguments);
+///     }
+/// }
+/// for tool_call in accumulator.finish() {
+///     println!("{}({})", tool_call.function.name, tool_call.function.arguments);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ToolCallStreamAccumulator {
+    in_progress: BTreeMap<u32, ChatCompletionMessageToolCall>,
+}
+
+impl ToolCallStreamAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `tool_calls` of `delta` and returns the tool calls completed by it, in `index` order.
+    pub fn push(
+        &mut self,
+        delta: &ChatCompletionStreamResponseDelta,
+    ) -> Vec<ChatCompletionMessageToolCall> {
+        let mut completed = Vec::new();
+        for chunk in delta.tool_calls.iter().flatten() {
+            completed.extend(self.push_chunk(chunk));
+        }
+        completed
+    }
+
+    /// Adds a single tool call chunk and returns the tool calls completed by it, in `index` order.
+    pub fn push_chunk(
+        &mut self,
+        chunk: &ChatCompletionMessageToolCallChunk,
+    ) -> Vec<ChatCompletionMessageToolCall> {
+        self.push_indexed(chunk)
+            .into_iter()
+            .map(|(_, tool_call)| tool_call)
+            .collect()
+    }
+
+    /// Like [push_chunk](Self::push_chunk), with the `index` of each completed tool call.
+    fn push_indexed(
+        &mut self,
+        chunk: &ChatCompletionMessageToolCallChunk,
+    ) -> Vec<(u32, ChatCompletionMessageToolCall)> {
+        // A chunk for another index ends the tool calls whose arguments are complete, those with
+        // partial arguments can still be interleaved with it.
+        let done: Vec<u32> = self
+            .in_progress
+            .iter()
+            .filter(|(index, tool_call)| {
+                **index != chunk.index && is_complete_json(&tool_call.function.arguments)
+            })
+            .map(|(index, _)| *index)
+            .collect();
+        let completed: Vec<(u32, ChatCompletionMessageToolCall)> = done
+            .into_iter()
+            .filter_map(|index| Some((index, self.in_progress.remove(&index)?)))
+            .collect();
+
+        let tool_call =
+            self.in_progress
+                .entry(chunk.index)
+                .or_insert_with(|| ChatCompletionMessageToolCall {
+                    id: String::new(),
+                    function: FunctionCall {
+                        name: String::new(),
+                        arguments: String::new(),
+                    },
+                });
+
+        // some compatible servers repeat the id and name in every chunk, only the arguments
+        // are split across chunks
+        if let Some(id) = chunk.id.as_ref().filter(|id| !id.is_empty()) {
+            tool_call.id.clone_from(id);
+        }
+        if let Some(function) = &chunk.function {
+            if let Some(name) = function.name.as_ref().filter(|name| !name.is_empty()) {
+                tool_call.function.name.clone_from(name);
+            }
+            if let Some(arguments) = &function.arguments {
+                tool_call.function.arguments.push_str(arguments);
+            }
+        }
+
+        completed
+    }
+
+    /// Returns `true` if no tool call is in progress.
+    pub fn is_empty(&self) -> bool {
+        self.in_progress.is_empty()
+    }
+
+    /// Flushes all the tool calls in progress, in `index` order.
+    pub fn finish(&mut self) -> Vec<ChatCompletionMessageToolCall> {
+        std::mem::take(&mut self.in_progress)
+            .into_values()
+            .collect()
+    }
+}
+
+fn is_complete_json(json: &str) -> bool {
+    serde_json::from_str::<serde::de::IgnoredAny>(json).is_ok()
+}
+
+/// Folds the chunks of a chat completion stream into the equivalent non-streaming
+/// [CreateChatCompletionResponse].
+///
+/// Content, refusal, tool calls and logprobs are concatenated per choice `index`, the last
+/// `finish_reason` of each choice is kept, and the trailing usage chunk (sent when
+/// `stream_options.include_usage` is set) is captured.
+///
+/// With `n` greater than 1, the chunks of the choices are interleaved and each choice is
+/// assembled separately: [finish](Self::finish) returns them in `index` order, and
+/// [contents](Self::contents) returns the text of each so far, e.g. to render them side by side.
+///
+/// ```
+/// # use async_openai::types::chat::{ChatCompletionStreamAccumulator, CreateChatCompletionResponse, CreateChatCompletionStreamResponse};
+/// # fn collect(chunks: Vec<CreateChatCompletionStreamResponse>) -> CreateChatCompletionResponse {
+/// let mut accumulator = ChatCompletionStreamAccumulator::new();
+/// for chunk in &chunks {
+///     accumulator.push(chunk);
+/// }
+/// accumulator.finish()
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ChatCompletionStreamAccumulator {
+    id: String,
+    created: u32,
+    model: String,
+    service_tier: Option<ServiceTier>,
+    system_fingerprint: Option<String>,
+    usage: Option<CompletionUsage>,
+    prompt_filter_results: Option<Vec<PromptFilterResult>>,
+    choices: BTreeMap<u32, ChoiceAccumulator>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ChoiceAccumulator {
+    role: Option<Role>,
+    content: Option<String>,
+    refusal: Option<String>,
+    function_call: Option<FunctionCall>,
+    // keyed by `index`, as tool calls complete out of order when they are interleaved
+    tool_calls: BTreeMap<u32, ChatCompletionMessageToolCall>,
+    tool_call_accumulator: ToolCallStreamAccumulator,
+    finish_reason: Option<FinishReason>,
+    logprobs: Option<ChatChoiceLogprobs>,
+    content_filter_results: Option<ContentFilterResults>,
+}
+
+impl ChatCompletionStreamAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Folds a single stream chunk into the response.
+    pub fn push(&mut self, chunk: &CreateChatCompletionStreamResponse) {
+        if self.id.is_empty() {
+            self.id.clone_from(&chunk.id);
+            self.created = chunk.created;
+            self.model.clone_from(&chunk.model);
+        }
+        if chunk.service_tier.is_some() {
+            self.service_tier.clone_from(&chunk.service_tier);
+        }
+        if chunk.system_fingerprint.is_some() {
+            self.system_fingerprint
+                .clone_from(&chunk.system_fingerprint);
+        }
+        if chunk.usage.is_some() {
+            self.usage.clone_from(&chunk.usage);
+        }
+        if chunk.prompt_filter_results.is_some() {
+            self.prompt_filter_results
+                .clone_from(&chunk.prompt_filter_results);
+        }
+
+        for choice in &chunk.choices {
+            self.choices.entry(choice.index).or_default().push(choice);
+        }
+    }
+
+    /// The content of the choice `index` so far, `None` if it has none yet.
+    pub fn content(&self, index: u32) -> Option<&str> {
+        self.choices.get(&index)?.content.as_deref()
+    }
+
+    /// The content of each choice so far, in `index` order.
+    pub fn contents(&self) -> Vec<String> {
+        self.choices
+            .values()
+            .map(|choice| choice.content.clone().unwrap_or_default())
+            .collect()
+    }
+
+    /// The [CompletionRecord] of the chunks pushed so far, for a stream whose last chunk was
+    /// received `latency` after its request was sent.
+    pub fn record(&self, latency: Duration) -> CompletionRecord {
+        CompletionRecord {
+            id: self.id.clone(),
+            model: self.model.clone(),
+            service_tier: self.service_tier.clone(),
+            system_fingerprint: self.system_fingerprint.clone(),
+            usage: self.usage.clone(),
+            latency_ms: latency.as_millis() as u64,
+        }
+    }
+
+    /// Returns the response assembled from all the chunks pushed so far.
+    pub fn finish(self) -> CreateChatCompletionResponse {
+        CreateChatCompletionResponse {
+            id: self.id,
+            choices: self
+                .choices
+                .into_iter()
+                .map(|(index, choice)| choice.finish(index))
+                .collect(),
+            created: self.created,
+            model: self.model,
+            service_tier: self.service_tier,
+            system_fingerprint: self.system_fingerprint,
+            object: "chat.completion".into(),
+            usage: self.usage,
+            metadata: None,
+            prompt_filter_results: self.prompt_filter_results,
+        }
+    }
+}
+
+impl ChoiceAccumulator {
+    fn push(&mut self, choice: &ChatChoiceStream) {
+        let delta = &choice.delta;
+
+        if delta.role.is_some() {
+            self.role = delta.role;
+        }
+        append(&mut self.content, delta.content.as_deref());
+        append(&mut self.refusal, delta.refusal.as_deref());
+
+        if let Some(function_call) = &delta.function_call {
+            let accumulated = self.function_call.get_or_insert_with(|| FunctionCall {
+                name: String::new(),
+                arguments: String::new(),
+            });
+            if let Some(name) = function_call.name.as_ref().filter(|name| !name.is_empty()) {
+                accumulated.name.clone_from(name);
+            }
+            if let Some(arguments) = &function_call.arguments {
+                accumulated.arguments.push_str(arguments);
+            }
+        }
+
+        for chunk in delta.tool_calls.iter().flatten() {
+            self.tool_calls
+                .extend(self.tool_call_accumulator.push_indexed(chunk));
+        }
+
+        if choice.finish_reason.is_some() {
+            self.finish_reason = choice.finish_reason.clone();
+        }
+        // the results of each chunk cover all the content so far
+        if choice.content_filter_results.is_some() {
+            self.content_filter_results
+                .clone_from(&choice.content_filter_results);
+        }
+
+        if let Some(logprobs) = &choice.logprobs {
+            let accumulated = self.logprobs.get_or_insert(ChatChoiceLogprobs {
+                content: None,
+                refusal: None,
+            });
+            if let Some(content) = &logprobs.content {
+                accumulated
+                    .content
+                    .get_or_insert_with(Vec::new)
+                    .extend_from_slice(content);
+            }
+            if let Some(refusal) = &logprobs.refusal {
+                accumulated
+                    .refusal
+                    .get_or_insert_with(Vec::new)
+                    .extend_from_slice(refusal);
+            }
+        }
+    }
+
+    fn finish(mut self, index: u32) -> ChatChoice {
+        self.tool_calls
+            .append(&mut self.tool_call_accumulator.in_progress);
+        let tool_calls = (!self.tool_calls.is_empty()).then(|| {
+            self.tool_calls
+                .into_values()
+                .map(ChatCompletionMessageToolCalls::Function)
+                .collect()
+        });
+
+        ChatChoice {
+            index,
+            message: ChatCompletionResponseMessage {
+                content: self.content,
+                refusal: self.refusal,
+                tool_calls,
+                annotations: None,
+                role: self.role.unwrap_or(Role::Assistant),
+                function_call: self.function_call,
+                audio: None,
+            },
+            finish_reason: self.finish_reason,
+            logprobs: self.logprobs,
+            content_filter_results: self.content_filter_results,
+        }
+    }
+}
+
+fn append(accumulated: &mut Option<String>, fragment: Option<&str>) {
+    if let Some(fragment) = fragment {
+        accumulated
+            .get_or_insert_with(String::new)
+            .push_str(fragment);
+    }
+}
@@ -0,0 +1,230 @@
+use std::fmt::{Debug, Display};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::types::chat::{
+    ChatCompletionMessageToolCall, ChatCompletionMessageToolCalls, ChatCompletionRequestMessage,
+    ChatCompletionRequestToolMessage, ChatCompletionTool, ChatCompletionTools, FunctionObject,
+};
+
+type Handler = Box<dyn Fn(&str) -> Result<String, ToolError> + Send + Sync>;
+
+struct RegisteredTool {
+    function: FunctionObject,
+    handler: Handler,
+}
+
+/// Function tools with typed parameters, and a dispatcher of the tool calls of the model to them.
+///
+/// The parameters of each function are derived from a type implementing `schemars::JsonSchema`
+/// as a strict JSON schema, see [FunctionObject::from_schema]. [dispatch](Self::dispatch)
+/// deserializes the `arguments` of a tool call into that type, calls the handler, and returns
+/// the tool message to send back to the model. Unknown tools and invalid arguments are reported
+/// to the model as `{"error": "..."}`, so that it can correct itself.
+///
+/// ```
+/// # use async_openai::types::chat::{ChatCompletionMessageToolCall, FunctionCall, ToolRegistry};
+/// #[derive(serde::Deserialize, schemars::JsonSchema)]
+/// struct GetWeather {
+///     location: String,
+/// }
+///
+/// let mut registry = ToolRegistry::new();
+/// registry.register("get_weather", "Current weather of a location", |params: GetWeather| {
+///     serde_json::json!({ "location": params.location, "temperature": 21 })
+/// });
+///
+/// // tools to include in the request
+/// let tools = registry.tools();
+///
+/// # let tool_call = ChatCompletionMessageToolCall {
+/// #     id: "call_1".into(),
+/// #     function: FunctionCall { name: "get_weather".into(), arguments: r#"{"location":"Paris"}"#.into() },
+/// # };
+/// // tool call from the response of the model
+/// let result = registry.dispatch(&tool_call);
+/// assert!(!result.is_error());
+/// ```
+#[derive(Default)]
+pub struct ToolRegistry {
+    tools: Vec<RegisteredTool>,
+}
+
+impl Debug for ToolRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToolRegistry")
+            .field(
+                "tools",
+                &self
+                    .tools
+                    .iter()
+                    .map(|tool| &tool.function)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl ToolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the function `name` with the parameters of `P`, handled by `handler`.
+    ///
+    /// The output of `handler` is sent to the model as is when it serializes to a JSON string,
+    /// and as JSON otherwise. Registering a `name` again replaces the previous function.
+    pub fn register<P, F, R>(
+        &mut self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        handler: F,
+    ) -> &mut Self
+    where
+        P: DeserializeOwned + schemars::JsonSchema,
+        F: Fn(P) -> R + Send + Sync + 'static,
+        R: Serialize,
+    {
+        let name = name.into();
+        let function = FunctionObject::from_schema::<P>(name.clone(), description);
+
+        let handler_name = name.clone();
+        let handler: Handler = Box::new(move |arguments| {
+            let params: P =
+                serde_json::from_str(arguments).map_err(|e| ToolError::InvalidArguments {
+                    name: handler_name.clone(),
+                    message: e.to_string(),
+                })?;
+            match serde_json::to_value(handler(params)) {
+                Ok(serde_json::Value::String(output)) => Ok(output),
+                Ok(output) => Ok(output.to_string()),
+                Err(e) => Err(ToolError::InvalidOutput {
+                    name: handler_name.clone(),
+                    message: e.to_string(),
+                }),
+            }
+        });
+
+        let tool = RegisteredTool { function, handler };
+        match self
+            .tools
+            .iter_mut()
+            .find(|tool| tool.function.name == name)
+        {
+            Some(existing) => *existing = tool,
+            None => self.tools.push(tool),
+        }
+        self
+    }
+
+    /// The definitions of the registered functions, in registration order, to include in
+    /// [CreateChatCompletionRequest::tools](crate::types::chat::CreateChatCompletionRequest::tools).
+    pub fn tools(&self) -> Vec<ChatCompletionTools> {
+        self.tools
+            .iter()
+            .map(|tool| {
+                ChatCompletionTools::Function(ChatCompletionTool {
+                    function: tool.function.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Calls the handler of the function called by `tool_call`.
+    pub fn dispatch(&self, tool_call: &ChatCompletionMessageToolCall) -> ToolResult {
+        let name = &tool_call.function.name;
+        let output = match self.tools.iter().find(|tool| tool.function.name == *name) {
+            Some(tool) => (tool.handler)(&tool_call.function.arguments),
+            None => Err(ToolError::UnknownTool { name: name.clone() }),
+        };
+        ToolResult::new(tool_call.id.clone(), output)
+    }
+
+    /// Dispatches each of `tool_calls`, in order. Custom tool calls are reported as unknown tools.
+    pub fn dispatch_all(&self, tool_calls: &[ChatCompletionMessageToolCalls]) -> Vec<ToolResult> {
+        tool_calls
+            .iter()
+            .map(|tool_call| match tool_call {
+                ChatCompletionMessageToolCalls::Function(tool_call) => self.dispatch(tool_call),
+                ChatCompletionMessageToolCalls::Custom(tool_call) => ToolResult::new(
+                    tool_call.id.clone(),
+                    Err(ToolError::UnknownTool {
+                        name: tool_call.custom_tool.name.clone(),
+                    }),
+                ),
+            })
+            .collect()
+    }
+}
+
+/// Why a tool call could not be handled by a [ToolRegistry].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolError {
+    /// No function with this name is registered.
+    UnknownTool { name: String },
+    /// The arguments do not deserialize into the parameters of the function.
+    InvalidArguments { name: String, message: String },
+    /// The output of the handler does not serialize to JSON.
+    InvalidOutput { name: String, message: String },
+}
+
+impl Display for ToolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToolError::UnknownTool { name } => write!(f, "unknown tool: {name}"),
+            ToolError::InvalidArguments { name, message } => {
+                write!(f, "invalid arguments for {name}: {message}")
+            }
+            ToolError::InvalidOutput { name, message } => {
+                write!(f, "invalid output of {name}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ToolError {}
+
+/// The outcome of dispatching a tool call with a [ToolRegistry].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolResult {
+    /// The tool message answering the tool call. On error, its content is `{"error": "..."}`.
+    pub message: ChatCompletionRequestToolMessage,
+    /// Why the tool call could not be handled, if it could not.
+    pub error: Option<ToolError>,
+}
+
+impl ToolResult {
+    fn new(tool_call_id: String, output: Result<String, ToolError>) -> Self {
+        let (content, error) = match output {
+            Ok(content) => (content, None),
+            Err(error) => (
+                serde_json::json!({ "error": error.to_string() }).to_string(),
+                Some(error),
+            ),
+        };
+        Self {
+            message: ChatCompletionRequestToolMessage {
+                content: content.into(),
+                tool_call_id,
+            },
+            error,
+        }
+    }
+
+    /// Returns `true` if the tool call could not be handled.
+    pub fn is_error(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+impl From<ToolResult> for ChatCompletionRequestToolMessage {
+    fn from(value: ToolResult) -> Self {
+        value.message
+    }
+}
+
+impl From<ToolResult> for ChatCompletionRequestMessage {
+    fn from(value: ToolResult) -> Self {
+        ChatCompletionRequestMessage::Tool(value.message)
+    }
+}
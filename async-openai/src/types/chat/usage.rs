@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::types::chat::{
+    CompletionUsage, CreateChatCompletionResponse, CreateChatCompletionStreamResponse, ServiceTier,
+};
+
+/// Token totals of [CompletionUsage] accumulated across many chat completions, e.g. over the
+/// calls of a pipeline.
+///
+/// Streamed completions only report usage when `stream_options.include_usage` is set, in a final
+/// chunk without choices. Pass every chunk to [add_chunk](Self::add_chunk): chunks without usage
+/// are ignored.
+///
+/// ```
+/// # use async_openai::types::chat::{CompletionUsage, TokenPrices, UsageTracker};
+/// let mut tracker = UsageTracker::new();
+/// tracker.add(&CompletionUsage {
+///     prompt_tokens: 2000,
+///     completion_tokens: 500,
+///     total_tokens: 2500,
+///     ..Default::default()
+/// });
+///
+/// let prices = TokenPrices::new(0.00015, 0.0006);
+/// assert!((tracker.cost_estimate(&prices) - 0.0006).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UsageTracker {
+    /// Number of usages added.
+    pub requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+    /// Prompt tokens served from the prompt cache, included in `prompt_tokens`.
+    pub cached_tokens: u64,
+    /// Tokens generated for reasoning, included in `completion_tokens`.
+    pub reasoning_tokens: u64,
+}
+
+impl UsageTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, usage: &CompletionUsage) {
+        self.requests += 1;
+        self.prompt_tokens += u64::from(usage.prompt_tokens);
+        self.completion_tokens += u64::from(usage.completion_tokens);
+        self.total_tokens += u64::from(usage.total_tokens);
+        if let Some(cached_tokens) = usage
+            .prompt_tokens_details
+            .as_ref()
+            .and_then(|details| details.cached_tokens)
+        {
+            self.cached_tokens += u64::from(cached_tokens);
+        }
+        if let Some(reasoning_tokens) = usage
+            .completion_tokens_details
+            .as_ref()
+            .and_then(|details| details.reasoning_tokens)
+        {
+            self.reasoning_tokens += u64::from(reasoning_tokens);
+        }
+    }
+
+    /// Adds the usage of `response`, if any.
+    pub fn add_response(&mut self, response: &CreateChatCompletionResponse) {
+        if let Some(usage) = &response.usage {
+            self.add(usage);
+        }
+    }
+
+    /// Adds the usage of `chunk`, which is only present in the final chunk of a stream.
+    pub fn add_chunk(&mut self, chunk: &CreateChatCompletionStreamResponse) {
+        if let Some(usage) = &chunk.usage {
+            self.add(usage);
+        }
+    }
+
+    /// Prompt tokens not served from the prompt cache.
+    pub fn uncached_prompt_tokens(&self) -> u64 {
+        self.prompt_tokens.saturating_sub(self.cached_tokens)
+    }
+
+    /// Estimated cost of the tokens so far, in the currency of `prices`.
+    ///
+    /// Reasoning tokens are billed as completion tokens, and cached tokens at
+    /// [TokenPrices::cached_prompt] when set, or at the prompt price otherwise.
+    pub fn cost_estimate(&self, prices: &TokenPrices) -> f64 {
+        let cached_prompt = prices.cached_prompt.unwrap_or(prices.prompt);
+        (self.uncached_prompt_tokens() as f64 * prices.prompt
+            + self.cached_tokens as f64 * cached_prompt
+            + self.completion_tokens as f64 * prices.completion)
+            / 1000.0
+    }
+}
+
+impl std::ops::AddAssign for UsageTracker {
+    fn add_assign(&mut self, other: Self) {
+        self.requests += other.requests;
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.total_tokens += other.total_tokens;
+        self.cached_tokens += other.cached_tokens;
+        self.reasoning_tokens += other.reasoning_tokens;
+    }
+}
+
+impl<'a> Extend<&'a CompletionUsage> for UsageTracker {
+    fn extend<I: IntoIterator<Item = &'a CompletionUsage>>(&mut self, usages: I) {
+        for usage in usages {
+            self.add(usage);
+        }
+    }
+}
+
+/// Prices of a model per 1K tokens, for [UsageTracker::cost_estimate].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TokenPrices {
+    pub prompt: f64,
+    /// Price of cached prompt tokens, the prompt price when `None`.
+    pub cached_prompt: Option<f64>,
+    pub completion: f64,
+}
+
+impl TokenPrices {
+    pub fn new(prompt: f64, completion: f64) -> Self {
+        Self {
+            prompt,
+            cached_prompt: None,
+            completion,
+        }
+    }
+
+    pub fn with_cached_prompt(mut self, cached_prompt: f64) -> Self {
+        self.cached_prompt = Some(cached_prompt);
+        self
+    }
+}
+
+/// The facts of a chat completion worth logging once per call, e.g. for billing dashboards,
+/// built by [ChatCompletionStreamAccumulator::record] after a stream ends or
+/// [from_response](Self::from_response) for a call that was not streamed.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use async_openai::types::chat::{ChatCompletionStreamAccumulator, CreateChatCompletionStreamResponse};
+/// # fn log(chunks: Vec<CreateChatCompletionStreamResponse>, latency: Duration) {
+/// let mut accumulator = ChatCompletionStreamAccumulator::new();
+/// for chunk in &chunks {
+///     accumulator.push(chunk);
+/// }
+/// let record = accumulator.record(latency);
+/// println!("{}", serde_json::to_string(&record).unwrap());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CompletionRecord {
+    pub id: String,
+    pub model: String,
+    /// The service tier that served the request, only reported when the request sets one.
+    pub service_tier: Option<ServiceTier>,
+    pub system_fingerprint: Option<String>,
+    /// Only reported by streams when `stream_options.include_usage` is set.
+    pub usage: Option<CompletionUsage>,
+    /// Time from sending the request to receiving the end of the response, in milliseconds.
+    pub latency_ms: u64,
+}
+
+impl CompletionRecord {
+    /// The record of `response`, received `latency` after its request was sent.
+    pub fn from_response(response: &CreateChatCompletionResponse, latency: Duration) -> Self {
+        Self {
+            id: response.id.clone(),
+            model: response.model.clone(),
+            service_tier: response.service_tier.clone(),
+            system_fingerprint: response.system_fingerprint.clone(),
+            usage: response.usage.clone(),
+            latency_ms: latency.as_millis() as u64,
+        }
+    }
+}
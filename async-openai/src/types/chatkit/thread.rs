@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 
 /// Represents a ChatKit thread and its current status.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ThreadResource {
     /// Identifier of the thread.
     pub id: String,
@@ -29,7 +30,8 @@ fn default_thread_object() -> String {
 
 /// Current status for the thread.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ThreadStatus {
     /// Indicates that a thread is active.
@@ -42,7 +44,8 @@ pub enum ThreadStatus {
 
 /// A paginated list of ChatKit threads.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ThreadListResource {
     /// The type of object returned, must be `list`.
     #[serde(default = "default_list_object")]
@@ -63,7 +66,8 @@ fn default_list_object() -> String {
 
 /// Confirmation payload returned after deleting a thread.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeletedThreadResource {
     /// Identifier of the deleted thread.
     pub id: String,
@@ -80,7 +84,8 @@ fn default_deleted_object() -> String {
 
 /// A paginated list of thread items rendered for the ChatKit API.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ThreadItemListResource {
     /// The type of object returned, must be `list`.
     #[serde(default = "default_list_object")]
@@ -97,7 +102,8 @@ pub struct ThreadItemListResource {
 
 /// The thread item - discriminated union based on type field.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ThreadItem {
     /// User-authored messages within a thread.
@@ -122,7 +128,8 @@ pub enum ThreadItem {
 
 /// User-authored messages within a thread.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UserMessageItem {
     /// Identifier of the thread item.
     pub id: String,
@@ -149,7 +156,8 @@ fn default_thread_item_object() -> String {
 
 /// Content blocks that comprise a user message.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum UserMessageContent {
     /// Text block that a user contributed to the thread.
@@ -162,7 +170,8 @@ pub enum UserMessageContent {
 
 /// Attachment metadata included on thread items.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Attachment {
     /// Attachment discriminator.
     #[serde(rename = "type")]
@@ -179,7 +188,8 @@ pub struct Attachment {
 
 /// Attachment discriminator.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum AttachmentType {
     Image,
@@ -188,7 +198,8 @@ pub enum AttachmentType {
 
 /// Model and tool overrides applied when generating the assistant response.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InferenceOptions {
     /// Preferred tool to invoke. Defaults to null when ChatKit should auto-select.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -200,7 +211,8 @@ pub struct InferenceOptions {
 
 /// Tool selection that the assistant should honor when executing the item.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ToolChoice {
     /// Identifier of the requested tool.
     pub id: String,
@@ -208,7 +220,8 @@ pub struct ToolChoice {
 
 /// Assistant-authored message within a thread.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantMessageItem {
     /// Identifier of the thread item.
     pub id: String,
@@ -225,7 +238,8 @@ pub struct AssistantMessageItem {
 
 /// Assistant response text accompanied by optional annotations.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseOutputText {
     /// Type discriminator that is always `output_text`.
     #[serde(default = "default_output_text_type")]
@@ -243,7 +257,8 @@ fn default_output_text_type() -> String {
 
 /// Annotation object describing a cited source.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Annotation {
     /// Annotation that references an uploaded file.
@@ -256,7 +271,8 @@ pub enum Annotation {
 
 /// Annotation that references an uploaded file.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FileAnnotation {
     /// Type discriminator that is always `file` for this annotation.
     #[serde(default = "default_file_annotation_type")]
@@ -271,7 +287,8 @@ fn default_file_annotation_type() -> String {
 
 /// Attachment source referenced by an annotation.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FileAnnotationSource {
     /// Type discriminator that is always `file`.
     #[serde(default = "default_file_source_type")]
@@ -286,7 +303,8 @@ fn default_file_source_type() -> String {
 
 /// Annotation that references a URL.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UrlAnnotation {
     /// Type discriminator that is always `url` for this annotation.
     #[serde(default = "default_url_annotation_type")]
@@ -301,7 +319,8 @@ fn default_url_annotation_type() -> String {
 
 /// URL backing an annotation entry.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UrlAnnotationSource {
     /// Type discriminator that is always `url`.
     #[serde(default = "default_url_source_type")]
@@ -316,7 +335,8 @@ fn default_url_source_type() -> String {
 
 /// Thread item that renders a widget payload.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WidgetMessageItem {
     /// Identifier of the thread item.
     pub id: String,
@@ -333,7 +353,8 @@ pub struct WidgetMessageItem {
 
 /// Record of a client side tool invocation initiated by the assistant.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ClientToolCallItem {
     /// Identifier of the thread item.
     pub id: String,
@@ -358,7 +379,8 @@ pub struct ClientToolCallItem {
 
 /// Execution status for the tool call.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ClientToolCallStatus {
     InProgress,
@@ -367,7 +389,8 @@ pub enum ClientToolCallStatus {
 
 /// Task emitted by the workflow to show progress and status updates.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TaskItem {
     /// Identifier of the thread item.
     pub id: String,
@@ -388,7 +411,8 @@ pub struct TaskItem {
 
 /// Subtype for the task.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum TaskType {
     Custom,
@@ -397,7 +421,8 @@ pub enum TaskType {
 
 /// Collection of workflow tasks grouped together in the thread.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TaskGroupItem {
     /// Identifier of the thread item.
     pub id: String,
@@ -414,7 +439,8 @@ pub struct TaskGroupItem {
 
 /// Task entry that appears within a TaskGroup.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TaskGroupTask {
     /// Subtype for the grouped task.
     pub task_type: TaskType,
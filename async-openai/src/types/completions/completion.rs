@@ -4,18 +4,20 @@ use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 use crate::error::OpenAIError;
+use crate::types::validation::check_range;
 
 use crate::types::chat::{
     ChatCompletionStreamOptions, Choice, CompletionUsage, Prompt, StopConfiguration,
 };
 
 #[derive(Clone, Serialize, Deserialize, Default, Debug, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateCompletionRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(name = "build_unchecked", error = "OpenAIError"))]
 pub struct CreateCompletionRequest {
     /// ID of the model to use. You can use the [List models](https://platform.openai.com/docs/api-reference/models/list) API to see all of your available models, or see our [Model overview](https://platform.openai.com/docs/models/overview) for descriptions of them.
     pub model: String,
@@ -117,8 +119,45 @@ pub struct CreateCompletionRequest {
     pub seed: Option<i64>,
 }
 
+impl CreateCompletionRequestArgs {
+    /// Builds the request after checking its parameters with [validate](Self::validate).
+    ///
+    /// Use [build_unchecked](Self::build_unchecked) for OpenAI compatible servers accepting values
+    /// that the OpenAI API does not.
+    pub fn build(&self) -> Result<CreateCompletionRequest, OpenAIError> {
+        self.validate()?;
+        self.build_unchecked()
+    }
+
+    /// Checks the documented ranges of the parameters set so far, called by [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        if let Some(Some(stop)) = &self.stop {
+            stop.validate()?;
+        }
+        check_range("temperature", self.temperature.flatten(), 0.0, 2.0)?;
+        check_range("top_p", self.top_p.flatten(), 0.0, 1.0)?;
+        check_range("n", self.n.flatten(), 1, 128)?;
+        check_range("logprobs", self.logprobs.flatten(), 0, 5)?;
+        check_range(
+            "presence_penalty",
+            self.presence_penalty.flatten(),
+            -2.0,
+            2.0,
+        )?;
+        check_range(
+            "frequency_penalty",
+            self.frequency_penalty.flatten(),
+            -2.0,
+            2.0,
+        )?;
+        check_range("best_of", self.best_of.flatten(), 0, 20)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateCompletionResponse {
     /// A unique identifier for the completion.
     pub id: String,
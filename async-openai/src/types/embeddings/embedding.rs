@@ -2,9 +2,11 @@ use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
 use crate::error::OpenAIError;
+use crate::types::validation::{check_min, check_range};
 
 #[derive(Debug, Serialize, Clone, PartialEq, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum EmbeddingInput {
     String(String),
@@ -15,7 +17,8 @@ pub enum EmbeddingInput {
 }
 
 #[derive(Debug, Serialize, Default, Clone, PartialEq, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum EncodingFormat {
     #[default]
@@ -24,7 +27,8 @@ pub enum EncodingFormat {
 }
 
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateEmbeddingRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -55,13 +59,44 @@ pub struct CreateEmbeddingRequest {
     pub user: Option<String>,
 
     /// The number of dimensions the resulting output embeddings should have. Only supported in `text-embedding-3` and later models.
+    ///
+    /// `text-embedding-ada-002` does not support it, `text-embedding-3-small` supports up to 1536
+    /// dimensions and `text-embedding-3-large` up to 3072. The builder does not enforce this, as
+    /// compatible servers can serve other models under these names: call
+    /// [validate](CreateEmbeddingRequestArgs::validate) to check it before sending the request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dimensions: Option<u32>,
 }
 
+impl CreateEmbeddingRequestArgs {
+    /// Checks [dimensions](CreateEmbeddingRequest::dimensions) against the [model](Self::model),
+    /// for the OpenAI embedding models. Not called by [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        let Some(dimensions) = self.dimensions.flatten() else {
+            return Ok(());
+        };
+        let model = self.model.as_deref().unwrap_or_default();
+        if model.starts_with("text-embedding-ada") {
+            return Err(OpenAIError::InvalidArgument(format!(
+                "dimensions is not supported by {model}, only by text-embedding-3 and later models"
+            )));
+        }
+        match model {
+            model if model.starts_with("text-embedding-3-small") => {
+                check_range("dimensions", Some(dimensions), 1, 1536)
+            }
+            model if model.starts_with("text-embedding-3-large") => {
+                check_range("dimensions", Some(dimensions), 1, 3072)
+            }
+            _ => check_min("dimensions", Some(dimensions), 1),
+        }
+    }
+}
+
 /// Represents an embedding vector returned by embedding endpoint.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Embedding {
     /// The index of the embedding in the list of embeddings.
     pub index: u32,
@@ -73,12 +108,27 @@ pub struct Embedding {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Base64EmbeddingVector(pub String);
 
+/// An embedding vector in either of the encodings of [EncodingFormat].
+///
+/// Deserializes from a list of floats or from a base64 string, use
+/// [to_f32](EmbeddingVector::to_f32) to get the floats of either.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(untagged)]
+pub enum EmbeddingVector {
+    Float(Vec<f32>),
+    Base64(Base64EmbeddingVector),
+}
+
 /// Represents an base64-encoded embedding vector returned by embedding endpoint.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Base64Embedding {
     /// The index of the embedding in the list of embeddings.
     pub index: u32,
@@ -88,8 +138,22 @@ pub struct Base64Embedding {
     pub embedding: Base64EmbeddingVector,
 }
 
+/// Represents an embedding vector in the [EncodingFormat] of the request.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct EncodedEmbedding {
+    /// The index of the embedding in the list of embeddings.
+    pub index: u32,
+    /// The object type, which is always "embedding".
+    pub object: String,
+    /// The embedding vector, a list of floats or a base64 string.
+    pub embedding: EmbeddingVector,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EmbeddingUsage {
     /// The number of tokens used by the prompt.
     pub prompt_tokens: u32,
@@ -98,7 +162,8 @@ pub struct EmbeddingUsage {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateEmbeddingResponse {
     pub object: String,
     /// The name of the model used to generate the embedding.
@@ -110,7 +175,8 @@ pub struct CreateEmbeddingResponse {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateBase64EmbeddingResponse {
     pub object: String,
     /// The name of the model used to generate the embedding.
@@ -120,3 +186,16 @@ pub struct CreateBase64EmbeddingResponse {
     /// The usage information for the request.
     pub usage: EmbeddingUsage,
 }
+
+#[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct CreateEncodedEmbeddingResponse {
+    pub object: String,
+    /// The name of the model used to generate the embedding.
+    pub model: String,
+    /// The list of embeddings generated by the model.
+    pub data: Vec<EncodedEmbedding>,
+    /// The usage information for the request.
+    pub usage: EmbeddingUsage,
+}
@@ -1,15 +1,58 @@
-use base64::engine::{general_purpose, Engine};
+use base64::{engine::general_purpose, Engine as _};
 
-use crate::types::embeddings::Base64EmbeddingVector;
+use crate::error::OpenAIError;
+use crate::types::embeddings::{Base64EmbeddingVector, EmbeddingVector};
 
-impl From<Base64EmbeddingVector> for Vec<f32> {
-    fn from(value: Base64EmbeddingVector) -> Self {
+impl Base64EmbeddingVector {
+    /// Encodes `embedding` the way the API does for `encoding_format=base64`: the little-endian
+    /// bytes of each float, base64 encoded.
+    pub fn encode(embedding: &[f32]) -> Self {
+        let bytes: Vec<u8> = embedding.iter().flat_map(|f| f.to_le_bytes()).collect();
+        Self(general_purpose::STANDARD.encode(bytes))
+    }
+
+    /// Decodes the floats of the embedding.
+    pub fn to_f32(&self) -> Result<Vec<f32>, OpenAIError> {
         let bytes = general_purpose::STANDARD
-            .decode(value.0)
-            .expect("openai base64 encoding to be valid");
-        let chunks = bytes.chunks_exact(4);
-        chunks
+            .decode(&self.0)
+            .map_err(|e| OpenAIError::InvalidArgument(format!("invalid base64 embedding: {e}")))?;
+        if bytes.len() % 4 != 0 {
+            return Err(OpenAIError::InvalidArgument(format!(
+                "invalid base64 embedding: {} bytes is not a whole number of f32",
+                bytes.len()
+            )));
+        }
+        Ok(bytes
+            .chunks_exact(4)
             .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
-            .collect()
+            .collect())
+    }
+}
+
+impl From<Base64EmbeddingVector> for Vec<f32> {
+    fn from(value: Base64EmbeddingVector) -> Self {
+        value.to_f32().expect("openai base64 encoding to be valid")
+    }
+}
+
+impl EmbeddingVector {
+    /// The floats of the embedding, decoding them in the base64 case.
+    pub fn to_f32(&self) -> Result<Vec<f32>, OpenAIError> {
+        match self {
+            EmbeddingVector::Float(embedding) => Ok(embedding.clone()),
+            EmbeddingVector::Base64(embedding) => embedding.to_f32(),
+        }
+    }
+}
+
+impl From<Vec<f32>> for EmbeddingVector {
+    fn from(value: Vec<f32>) -> Self {
+        EmbeddingVector::Float(value)
+    }
+}
+
+impl From<Base64EmbeddingVector> for EmbeddingVector {
+    fn from(value: Base64EmbeddingVector) -> Self {
+        EmbeddingVector::Base64(value)
     }
 }
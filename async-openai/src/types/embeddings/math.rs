@@ -0,0 +1,64 @@
+//! Similarity of embeddings, for nearest neighbor search over a few thousand embeddings without
+//! a vector database.
+//!
+//! OpenAI embeddings are normalized to length 1, so their [dot] product equals their
+//! [cosine_similarity].
+//!
+//! ```
+//! use async_openai::types::embeddings::math::{cosine_similarity, top_k};
+//!
+//! let corpus = vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![0.6, 0.8]];
+//! let query = [0.8, 0.6];
+//!
+//! assert!((cosine_similarity(&query, &corpus[2]) - 0.96).abs() < 1e-6);
+//! let nearest: Vec<usize> = top_k(&query, &corpus, 2).iter().map(|(i, _)| *i).collect();
+//! assert_eq!(nearest, [2, 0]);
+//! ```
+
+/// The dot product of `a` and `b`.
+///
+/// # Panics
+///
+/// If `a` and `b` have different lengths.
+pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+    assert_eq!(a.len(), b.len(), "embeddings must have the same length");
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+/// The euclidean length of `a`.
+pub fn l2_norm(a: &[f32]) -> f32 {
+    a.iter().map(|a| a * a).sum::<f32>().sqrt()
+}
+
+/// The cosine of the angle between `a` and `b`, from -1 for opposite directions to 1 for the same
+/// direction, 0 when either is all zeros.
+///
+/// # Panics
+///
+/// If `a` and `b` have different lengths.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot = dot(a, b);
+    let norms = l2_norm(a) * l2_norm(b);
+    if norms == 0.0 {
+        0.0
+    } else {
+        dot / norms
+    }
+}
+
+/// The indices in `corpus` of the `k` embeddings most similar to `query` by
+/// [cosine_similarity], with their similarity, most similar first.
+///
+/// # Panics
+///
+/// If an embedding of `corpus` does not have the length of `query`.
+pub fn top_k<E: AsRef<[f32]>>(query: &[f32], corpus: &[E], k: usize) -> Vec<(usize, f32)> {
+    let mut scores: Vec<(usize, f32)> = corpus
+        .iter()
+        .map(|embedding| cosine_similarity(query, embedding.as_ref()))
+        .enumerate()
+        .collect();
+    scores.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    scores.truncate(k);
+    scores
+}
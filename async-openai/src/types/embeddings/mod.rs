@@ -1,5 +1,5 @@
 mod embedding;
-#[cfg(feature = "_api")]
 mod impls;
+pub mod math;
 
 pub use embedding::*;
@@ -19,7 +19,8 @@ pub use crate::types::responses::{EasyInputMessage, InputTextContent, ReasoningE
 /// - See how well my chatbot handles customer support
 /// - Check if o4-mini is better at my usecase than gpt-4o
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Eval {
     /// The object type, which is always "eval".
     pub object: String,
@@ -38,7 +39,8 @@ pub struct Eval {
 
 /// Configuration of data sources used in runs of the evaluation.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EvalDataSourceConfig {
     /// Custom data source config.
@@ -52,19 +54,21 @@ pub enum EvalDataSourceConfig {
 
 /// Custom data source config.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalCustomDataSourceConfig {
     /// The type of data source. Always "custom".
     #[serde(rename = "type")]
     pub r#type: String,
     /// The json schema for the run data source items.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub schema: serde_json::Value,
 }
 
 /// Logs data source config.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalLogsDataSourceConfig {
     /// The type of data source. Always "logs".
     #[serde(rename = "type")]
@@ -73,13 +77,14 @@ pub struct EvalLogsDataSourceConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     /// The json schema for the run data source items.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub schema: serde_json::Value,
 }
 
 /// Stored completions data source config (deprecated).
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalStoredCompletionsDataSourceConfig {
     /// The type of data source. Always "stored_completions".
     #[serde(rename = "type")]
@@ -88,13 +93,14 @@ pub struct EvalStoredCompletionsDataSourceConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     /// The json schema for the run data source items.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub schema: serde_json::Value,
 }
 
 /// A list of testing criteria.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EvalTestingCriterion {
     /// Label model grader.
@@ -111,19 +117,22 @@ pub enum EvalTestingCriterion {
 
 /// Label model grader.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(transparent)]
 pub struct EvalGraderLabelModel(pub GraderLabelModel);
 
 /// String check grader.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(transparent)]
 pub struct EvalGraderStringCheck(pub GraderStringCheck);
 
 /// Text similarity grader.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalGraderTextSimilarity {
     #[serde(flatten)]
     pub grader: GraderTextSimilarity,
@@ -132,7 +141,8 @@ pub struct EvalGraderTextSimilarity {
 
 /// Text similarity metric.
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum TextSimilarityMetric {
     /// Cosine similarity.
@@ -162,7 +172,8 @@ pub enum TextSimilarityMetric {
 /// Python grader.
 /// also in openapi spec: GraderPython
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalGraderPython {
     #[serde(flatten)]
     pub grader: GraderPython,
@@ -170,7 +181,8 @@ pub struct EvalGraderPython {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SamplingParams {
     /// A seed value to initialize the randomness, during sampling.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -192,7 +204,8 @@ pub struct SamplingParams {
 /// Score model grader.
 /// also in openapi spec: GraderScoreModel
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalGraderScoreModel {
     #[serde(flatten)]
     pub grader: GraderScoreModel,
@@ -201,7 +214,8 @@ pub struct EvalGraderScoreModel {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalItem {
     /// The role of the message input. One of `user`, `assistant`, `system`, or
     /// `developer`.
@@ -213,7 +227,8 @@ pub struct EvalItem {
 
 /// The role of the message input.
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum EvalItemRole {
     /// User role.
@@ -228,7 +243,8 @@ pub enum EvalItemRole {
 
 /// Output text from the model.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalItemContentOutputText {
     /// The text output from the model.
     pub text: String,
@@ -236,7 +252,8 @@ pub struct EvalItemContentOutputText {
 
 /// Input image block used within EvalItem content arrays.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalItemInputImage {
     /// The URL of the image input.
     pub image_url: String,
@@ -249,7 +266,8 @@ pub struct EvalItemInputImage {
 /// Inputs to the model - can contain template strings.
 /// Supports text, output text, input images, and input audio, either as a single item or an array of items.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum EvalItemContent {
     /// An array of Input text, Output text, Input image, and Input audio
@@ -260,7 +278,8 @@ pub enum EvalItemContent {
 
 /// A single content item: input text, output text, input image, or input audio.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EvalItemContentItem {
     /// An input text content object with type field.
@@ -278,7 +297,8 @@ pub enum EvalItemContentItem {
 
 /// List of evals.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalList {
     /// The object type, which is always "list".
     pub object: String,
@@ -293,7 +313,8 @@ pub struct EvalList {
 }
 
 #[derive(Debug, Serialize, Clone, Builder, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateEvalRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -314,7 +335,8 @@ pub struct CreateEvalRequest {
 }
 
 #[derive(Debug, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CreateEvalDataSourceConfig {
     /// A CustomDataSourceConfig object that defines the schema for the data source used for the evaluation
@@ -334,7 +356,8 @@ impl Default for CreateEvalDataSourceConfig {
 }
 
 #[derive(Debug, Serialize, Clone, PartialEq, Builder, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateEvalCustomDataSourceConfigArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -342,7 +365,7 @@ impl Default for CreateEvalDataSourceConfig {
 #[builder(build_fn(error = "OpenAIError"))]
 pub struct CreateEvalCustomDataSourceConfig {
     /// The json schema for each row in the data source.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub item_schema: serde_json::Value,
     /// Whether the eval should expect you to populate the sample namespace (ie, by generating responses
     /// off of your data source).
@@ -352,7 +375,8 @@ pub struct CreateEvalCustomDataSourceConfig {
 
 /// Logs data source config for creating an eval.
 #[derive(Debug, Serialize, Clone, PartialEq, Builder, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateEvalLogsDataSourceConfigArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -365,7 +389,8 @@ pub struct CreateEvalLogsDataSourceConfig {
 }
 
 #[derive(Debug, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CreateEvalTestingCriterion {
     /// A LabelModelGrader object which uses a model to assign labels to each item
@@ -384,7 +409,8 @@ pub enum CreateEvalTestingCriterion {
 
 /// Label model grader for creating an eval.
 #[derive(Debug, Serialize, Clone, PartialEq, Builder, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateEvalLabelModelGraderArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -405,7 +431,8 @@ pub struct CreateEvalLabelModelGrader {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SimpleInputMessage {
     /// The role of the message.
     pub role: String,
@@ -415,7 +442,8 @@ pub struct SimpleInputMessage {
 
 /// A chat message that makes up the prompt or context.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CreateEvalItem {
     /// A message input to the model with a role indicating instruction following
@@ -432,7 +460,8 @@ pub enum CreateEvalItem {
 
 /// Request to update an eval.
 #[derive(Debug, Serialize, Clone, Builder, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "UpdateEvalRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -449,7 +478,8 @@ pub struct UpdateEvalRequest {
 
 /// Response from deleting an eval.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteEvalResponse {
     /// The object type, which is always "eval.deleted".
     pub object: String,
@@ -463,7 +493,8 @@ pub struct DeleteEvalResponse {
 
 /// A schema representing an evaluation run.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRun {
     /// The object type, which is always "eval.run".
     pub object: String,
@@ -497,7 +528,8 @@ pub struct EvalRun {
 
 /// Status of an evaluation run.
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum EvalRunStatus {
     /// Queued.
@@ -514,7 +546,8 @@ pub enum EvalRunStatus {
 
 /// Counters summarizing the outcomes of the evaluation run.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRunResultCounts {
     /// Total number of executed output items.
     pub total: u32,
@@ -528,7 +561,8 @@ pub struct EvalRunResultCounts {
 
 /// Usage statistics for each model during the evaluation run.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRunModelUsage {
     /// The name of the model.
     pub model_name: String,
@@ -546,7 +580,8 @@ pub struct EvalRunModelUsage {
 
 /// Results per testing criteria applied during the evaluation run.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRunTestingCriteriaResult {
     /// A description of the testing criteria.
     pub testing_criteria: String,
@@ -558,7 +593,8 @@ pub struct EvalRunTestingCriteriaResult {
 
 /// Information about the run's data source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EvalRunDataSource {
     /// A JsonlRunDataSource object with that specifies a JSONL file that matches the eval
@@ -571,7 +607,8 @@ pub enum EvalRunDataSource {
 
 /// JSONL run data source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateEvalJsonlRunDataSource {
     /// Determines what populates the `item` namespace in the data source.
     pub source: EvalJsonlSource,
@@ -579,7 +616,8 @@ pub struct CreateEvalJsonlRunDataSource {
 
 /// JSONL source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EvalJsonlSource {
     /// File content source.
@@ -590,7 +628,8 @@ pub enum EvalJsonlSource {
 
 /// JSONL file content source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalJsonlFileContentSource {
     /// The content of the jsonl file.
     pub content: Vec<EvalJsonlContentItem>,
@@ -598,7 +637,8 @@ pub struct EvalJsonlFileContentSource {
 
 /// JSONL file ID source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalJsonlFileIdSource {
     /// The identifier of the file.
     pub id: String,
@@ -606,20 +646,22 @@ pub struct EvalJsonlFileIdSource {
 
 /// JSONL content item.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalJsonlContentItem {
     /// The item data.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub item: serde_json::Value,
     /// The sample data, if any.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub sample: Option<serde_json::Value>,
 }
 
 /// Completions run data source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateEvalCompletionsRunDataSource {
     /// Used when sampling from a model. Dictates the structure of the messages passed into the model. Can
     /// either be a reference to a prebuilt trajectory (ie, `item.input_trajectory`), or a template with
@@ -635,7 +677,8 @@ pub struct CreateEvalCompletionsRunDataSource {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TemplateInputMessages {
     /// A list of chat messages forming the prompt or context. May include variable references to
     /// the `item` namespace, ie {{item.name}}.
@@ -643,7 +686,8 @@ pub struct TemplateInputMessages {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ItemReference {
     /// A reference to a variable in the `item` namespace. Ie, "item.input_trajectory"
     pub item_reference: String,
@@ -651,7 +695,8 @@ pub struct ItemReference {
 
 /// Input messages for completions.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EvalInputMessages {
     /// Template input messages.
@@ -662,7 +707,8 @@ pub enum EvalInputMessages {
 
 /// Sampling parameters for the model.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalSamplingParams {
     /// A seed value to initialize the randomness, during sampling.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -688,7 +734,8 @@ pub struct EvalSamplingParams {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalResponsesSamplingParams {
     /// A seed value to initialize the randomness, during sampling.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -721,7 +768,8 @@ pub struct EvalResponsesSamplingParams {
 
 /// Completions source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EvalCompletionsSource {
     /// File content source.
@@ -734,7 +782,8 @@ pub enum EvalCompletionsSource {
 
 /// Stored completions source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalStoredCompletionsSource {
     /// Metadata filters for the stored completions.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -755,7 +804,8 @@ pub struct EvalStoredCompletionsSource {
 
 /// Responses run data source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateEvalResponsesRunDataSource {
     /// Used when sampling from a model. Dictates the structure of the messages passed into the model.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -771,7 +821,8 @@ pub struct CreateEvalResponsesRunDataSource {
 
 /// Responses source.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EvalResponsesRunSource {
     /// File content source.
@@ -784,11 +835,12 @@ pub enum EvalResponsesRunSource {
 
 /// A EvalResponsesSource object describing a run data source configuration.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalResponsesSource {
     /// Metadata filter for the responses. This is a query parameter used to select responses.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub metadata: Option<serde_json::Value>,
     /// The name of the model to find responses for. This is a query parameter used to select responses.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -821,7 +873,8 @@ pub struct EvalResponsesSource {
 
 /// List of eval runs.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRunList {
     /// The object type, which is always "list".
     pub object: String,
@@ -837,7 +890,8 @@ pub struct EvalRunList {
 
 /// Request to create an eval run.
 #[derive(Debug, Serialize, Clone, Builder, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateEvalRunRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -856,7 +910,8 @@ pub struct CreateEvalRunRequest {
 
 /// Details about the run's data source.
 #[derive(Debug, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CreateEvalRunDataSource {
     /// JSONL data source.
@@ -876,7 +931,8 @@ impl Default for CreateEvalRunDataSource {
 
 /// Response from deleting an eval run.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteEvalRunResponse {
     /// The object type, which is always "eval.run.deleted".
     pub object: String,
@@ -890,7 +946,8 @@ pub struct DeleteEvalRunResponse {
 
 /// A schema representing an evaluation run output item.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRunOutputItem {
     /// The object type, which is always "eval.run.output_item".
     pub object: String,
@@ -907,7 +964,7 @@ pub struct EvalRunOutputItem {
     /// The identifier for the data source item.
     pub datasource_item_id: u64,
     /// Details of the input data source item.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub datasource_item: serde_json::Value,
     /// A list of grader results for this output item.
     pub results: Vec<EvalRunOutputItemResult>,
@@ -917,7 +974,8 @@ pub struct EvalRunOutputItem {
 
 /// A single grader result for an evaluation run output item.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRunOutputItemResult {
     /// The name of the grader.
     pub name: String,
@@ -927,12 +985,13 @@ pub struct EvalRunOutputItemResult {
     pub passed: bool,
     /// Optional sample or intermediate data produced by the grader.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub sample: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SimpleOutputMessage {
     pub role: String,
     pub content: String,
@@ -940,7 +999,8 @@ pub struct SimpleOutputMessage {
 
 /// A sample containing the input and output of the evaluation run.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRunOutputItemSample {
     /// An array of input messages.
     pub input: Vec<SimpleInputMessage>,
@@ -966,7 +1026,8 @@ pub struct EvalRunOutputItemSample {
 
 /// Token usage details for the sample.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRunOutputItemUsage {
     /// The total number of tokens used.
     pub total_tokens: i32,
@@ -980,7 +1041,8 @@ pub struct EvalRunOutputItemUsage {
 
 /// List of eval run output items.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalRunOutputItemList {
     /// The object type, which is always "list".
     pub object: String,
@@ -996,7 +1058,8 @@ pub struct EvalRunOutputItemList {
 
 /// An object representing an error response from the Eval API.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct EvalApiError {
     /// The error code.
     pub code: String,
@@ -11,7 +11,7 @@ pub struct FileInput {
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum FilePurpose {
     Assistants,
     Batch,
@@ -23,7 +23,8 @@ pub enum FilePurpose {
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum FileExpirationAfterAnchor {
     #[default]
     #[serde(rename = "created_at")]
@@ -31,7 +32,8 @@ pub enum FileExpirationAfterAnchor {
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FileExpirationAfter {
     /// Anchor timestamp after which the expiration policy applies. Supported anchors: `created_at`.
     pub anchor: FileExpirationAfterAnchor,
@@ -64,7 +66,8 @@ pub struct CreateFileRequest {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListFilesResponse {
     pub object: String,
     pub data: Vec<OpenAIFile>,
@@ -74,7 +77,8 @@ pub struct ListFilesResponse {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteFileResponse {
     pub id: String,
     pub object: String,
@@ -82,7 +86,8 @@ pub struct DeleteFileResponse {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum OpenAIFilePurpose {
     #[serde(rename = "assistants")]
     Assistants,
@@ -104,7 +109,8 @@ pub enum OpenAIFilePurpose {
 
 /// The `File` object represents a document that has been uploaded to OpenAI.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct OpenAIFile {
     /// The file identifier, which can be referenced in the API endpoints.
     pub id: String,
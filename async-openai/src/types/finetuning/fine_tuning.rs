@@ -12,7 +12,8 @@ use crate::{
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum NEpochs {
     #[default]
     #[serde(rename = "auto")]
@@ -22,7 +23,8 @@ pub enum NEpochs {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum BatchSize {
     #[default]
     #[serde(rename = "auto")]
@@ -32,7 +34,8 @@ pub enum BatchSize {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum LearningRateMultiplier {
     #[default]
     #[serde(rename = "auto")]
@@ -42,7 +45,8 @@ pub enum LearningRateMultiplier {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuneSupervisedHyperparameters {
     /// Number of examples in each batch. A larger batch size means that model parameters
     /// are updated less frequently, but with lower variance.
@@ -55,7 +59,8 @@ pub struct FineTuneSupervisedHyperparameters {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum Beta {
     #[default]
     #[serde(rename = "auto")]
@@ -65,7 +70,8 @@ pub enum Beta {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuneDPOHyperparameters {
     /// The beta value for the DPO method. A higher beta value will increase the weight of the penalty between the policy and reference model.
     pub beta: Beta,
@@ -80,7 +86,8 @@ pub struct FineTuneDPOHyperparameters {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateFineTuningJobRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -144,7 +151,8 @@ pub struct CreateFineTuningJobRequest {
 
 /// The method used for fine-tuning.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum FineTuneMethod {
     Supervised {
@@ -160,19 +168,22 @@ pub enum FineTuneMethod {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuneSupervisedMethod {
     pub hyperparameters: FineTuneSupervisedHyperparameters,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuneDPOMethod {
     pub hyperparameters: FineTuneDPOHyperparameters,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FineTuneReinforcementMethodGrader {
     StringCheck(GraderStringCheck),
@@ -183,7 +194,8 @@ pub enum FineTuneReinforcementMethodGrader {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum FineTuneReasoningEffort {
     #[default]
@@ -194,7 +206,8 @@ pub enum FineTuneReasoningEffort {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum ComputeMultiplier {
     #[default]
     #[serde(rename = "auto")]
@@ -204,7 +217,8 @@ pub enum ComputeMultiplier {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum EvalInterval {
     #[default]
     #[serde(rename = "auto")]
@@ -214,7 +228,8 @@ pub enum EvalInterval {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum EvalSamples {
     #[default]
     #[serde(rename = "auto")]
@@ -224,7 +239,8 @@ pub enum EvalSamples {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuneReinforcementHyperparameters {
     /// Number of examples in each batch. A larger batch size means that model parameters
     /// are updated less frequently, but with lower variance.
@@ -245,14 +261,16 @@ pub struct FineTuneReinforcementHyperparameters {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuneReinforcementMethod {
     pub grader: FineTuneReinforcementMethodGrader,
     pub hyperparameters: FineTuneReinforcementHyperparameters,
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum FineTuningJobIntegrationType {
     #[default]
@@ -260,7 +278,8 @@ pub enum FineTuningJobIntegrationType {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuningIntegration {
     /// The type of integration to enable. Currently, only "wandb" (Weights and Biases) is supported.
     pub r#type: FineTuningJobIntegrationType,
@@ -272,7 +291,8 @@ pub struct FineTuningIntegration {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WandB {
     /// The name of the project that the new run will be created under.
     pub project: String,
@@ -291,7 +311,8 @@ pub struct WandB {
 
 /// For fine-tuning jobs that have `failed`, this will contain more information on the cause of the failure.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuneJobError {
     ///  A machine-readable error code.
     pub code: String,
@@ -303,7 +324,8 @@ pub struct FineTuneJobError {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum FineTuningJobStatus {
     ValidatingFiles,
@@ -314,8 +336,21 @@ pub enum FineTuningJobStatus {
     Cancelled,
 }
 
+impl FineTuningJobStatus {
+    /// Returns `true` when the job `succeeded`, `failed` or was `cancelled` and will not change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            FineTuningJobStatus::Succeeded
+                | FineTuningJobStatus::Failed
+                | FineTuningJobStatus::Cancelled
+        )
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Hyperparameters {
     pub batch_size: BatchSize,
     pub learning_rate_multiplier: LearningRateMultiplier,
@@ -324,7 +359,8 @@ pub struct Hyperparameters {
 
 /// The `fine_tuning.job` object represents a fine-tuning job that has been created through the API.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuningJob {
     /// The object identifier, which can be referenced in the API endpoints.
     pub id: String,
@@ -383,7 +419,8 @@ pub struct FineTuningJob {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListPaginatedFineTuningJobsResponse {
     pub data: Vec<FineTuningJob>,
     pub has_more: bool,
@@ -391,14 +428,17 @@ pub struct ListPaginatedFineTuningJobsResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListFineTuningJobEventsResponse {
     pub data: Vec<FineTuningJobEvent>,
+    pub has_more: bool,
     pub object: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListFineTuningJobCheckpointsResponse {
     pub data: Vec<FineTuningJobCheckpoint>,
     pub object: String,
@@ -408,7 +448,8 @@ pub struct ListFineTuningJobCheckpointsResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Level {
     Info,
@@ -418,7 +459,8 @@ pub enum Level {
 
 ///Fine-tuning job event object
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuningJobEvent {
     /// The object identifier.
     pub id: String,
@@ -433,12 +475,42 @@ pub struct FineTuningJobEvent {
     /// The type of event.
     pub r#type: Option<FineTuningJobEventType>,
     /// The data associated with the event.
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub data: Option<serde_json::Value>,
 }
 
+impl FineTuningJobEvent {
+    /// The training metrics of a `metrics` event, `None` for other events.
+    pub fn metrics(&self) -> Option<FineTuningJobEventMetrics> {
+        if self.r#type != Some(FineTuningJobEventType::Metrics) {
+            return None;
+        }
+        serde_json::from_value(self.data.clone()?).ok()
+    }
+}
+
+/// The [data](FineTuningJobEvent::data) of a `metrics` event, reported every few training steps.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+pub struct FineTuningJobEventMetrics {
+    /// The training step the metrics were computed at.
+    pub step: Option<u64>,
+    /// The total number of training steps of the job.
+    pub total_steps: Option<u64>,
+    pub train_loss: Option<f64>,
+    pub train_mean_token_accuracy: Option<f64>,
+    /// Loss on a sample of the validation file, if one was provided.
+    pub valid_loss: Option<f64>,
+    pub valid_mean_token_accuracy: Option<f64>,
+    /// Loss on the full validation file, reported at the end of each epoch.
+    pub full_valid_loss: Option<f64>,
+    pub full_valid_mean_token_accuracy: Option<f64>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum FineTuningJobEventType {
     Message,
@@ -447,7 +519,8 @@ pub enum FineTuningJobEventType {
 
 /// The `fine_tuning.job.checkpoint` object represents a model checkpoint for a fine-tuning job that is ready to use.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuningJobCheckpoint {
     /// The checkpoint identifier, which can be referenced in the API endpoints.
     pub id: String,
@@ -466,26 +539,30 @@ pub struct FineTuningJobCheckpoint {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuningJobCheckpointMetrics {
-    pub step: u32,
-    pub train_loss: f32,
-    pub train_mean_token_accuracy: f32,
-    pub valid_loss: f32,
-    pub valid_mean_token_accuracy: f32,
-    pub full_valid_loss: f32,
-    pub full_valid_mean_token_accuracy: f32,
+    pub step: Option<f32>,
+    pub train_loss: Option<f32>,
+    pub train_mean_token_accuracy: Option<f32>,
+    /// Only reported when the job has a validation file, like the other `valid` metrics.
+    pub valid_loss: Option<f32>,
+    pub valid_mean_token_accuracy: Option<f32>,
+    pub full_valid_loss: Option<f32>,
+    pub full_valid_mean_token_accuracy: Option<f32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CreateFineTuningCheckpointPermissionRequest {
     /// The project identifiers to grant access to.
     pub project_ids: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListFineTuningCheckpointPermissionResponse {
     pub data: Vec<FineTuningCheckpointPermission>,
     pub object: String,
@@ -496,7 +573,8 @@ pub struct ListFineTuningCheckpointPermissionResponse {
 
 /// The `checkpoint.permission` object represents a permission for a fine-tuned model checkpoint.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FineTuningCheckpointPermission {
     /// The permission identifier, which can be referenced in the API endpoints.
     pub id: String,
@@ -509,7 +587,8 @@ pub struct FineTuningCheckpointPermission {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteFineTuningCheckpointPermissionResponse {
     pub object: String,
     pub id: String,
@@ -5,7 +5,8 @@ use crate::types::graders::ReasoningEffort;
 
 /// String check operation.
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum GraderStringCheckOperation {
     /// Equal.
@@ -20,7 +21,8 @@ pub enum GraderStringCheckOperation {
 
 /// String check grader.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct GraderStringCheck {
     /// The name of the grader.
     pub name: String,
@@ -34,7 +36,8 @@ pub struct GraderStringCheck {
 
 /// Text similarity grader.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct GraderTextSimilarity {
     /// The name of the grader.
     pub name: String,
@@ -48,7 +51,8 @@ pub struct GraderTextSimilarity {
 
 /// Text similarity metric.
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum GraderTextSimilarityEvaluationMetric {
     Cosine,
@@ -66,7 +70,8 @@ pub enum GraderTextSimilarityEvaluationMetric {
 
 /// Python grader.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct GraderPython {
     /// The name of the grader.
     pub name: String,
@@ -78,7 +83,8 @@ pub struct GraderPython {
 
 /// Score model grader.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct GraderScoreModel {
     /// The name of the grader.
     pub name: String,
@@ -96,7 +102,8 @@ pub struct GraderScoreModel {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct GraderScoreModelSamplingParams {
     /// A seed value to initialize the randomness, during sampling.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,7 +123,8 @@ pub struct GraderScoreModelSamplingParams {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct GraderLabelModel {
     /// The name of the grader.
     pub name: String,
@@ -131,7 +139,8 @@ pub struct GraderLabelModel {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Graders {
     StringCheck(GraderStringCheck),
@@ -142,7 +151,8 @@ pub enum Graders {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct GraderMulti {
     /// The name of the grader.
     pub name: String,
@@ -57,10 +57,6 @@ impl AsyncTryFrom<CreateImageEditRequest> for reqwest::multipart::Form {
             form = form.text("output_compression", output_compression.to_string())
         }
 
-        if let Some(output_compression) = request.output_compression {
-            form = form.text("output_compression", output_compression.to_string())
-        }
-
         if let Some(user) = request.user {
             form = form.text("user", user)
         }
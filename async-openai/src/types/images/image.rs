@@ -3,9 +3,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::OpenAIError;
 use crate::types::images::ImageInput;
+use crate::types::{validation::check_range, InputSource};
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum ImageSize {
     #[default]
     #[serde(rename = "auto")]
@@ -27,7 +29,8 @@ pub enum ImageSize {
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum DallE2ImageSize {
     #[serde(rename = "256x256")]
     S256x256,
@@ -39,7 +42,8 @@ pub enum DallE2ImageSize {
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum DallE3ImageSize {
     #[default]
     #[serde(rename = "1024x1024")]
@@ -51,7 +55,8 @@ pub enum DallE3ImageSize {
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum GptImage1ImageSize {
     #[default]
     #[serde(rename = "auto")]
@@ -65,7 +70,8 @@ pub enum GptImage1ImageSize {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageResponseFormat {
     #[default]
@@ -75,7 +81,8 @@ pub enum ImageResponseFormat {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum ImageModel {
     #[serde(rename = "gpt-image-1")]
     GptImage1,
@@ -93,7 +100,8 @@ pub enum ImageModel {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageQuality {
     Standard,
@@ -106,7 +114,8 @@ pub enum ImageQuality {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageStyle {
     #[default]
@@ -115,7 +124,8 @@ pub enum ImageStyle {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageModeration {
     #[default]
@@ -124,7 +134,8 @@ pub enum ImageModeration {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageOutputFormat {
     #[default]
@@ -134,7 +145,8 @@ pub enum ImageOutputFormat {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageBackground {
     #[default]
@@ -144,7 +156,8 @@ pub enum ImageBackground {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateImageRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -252,7 +265,8 @@ pub enum Image {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageResponseBackground {
     Transparent,
@@ -260,7 +274,8 @@ pub enum ImageResponseBackground {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageGenInputUsageDetails {
     /// The number of text tokens in the input prompt.
     pub text_tokens: u32,
@@ -269,7 +284,8 @@ pub struct ImageGenInputUsageDetails {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageGenOutputTokensDetails {
     /// The number of text output tokens generated by the model.
     pub text_tokens: u32,
@@ -278,7 +294,8 @@ pub struct ImageGenOutputTokensDetails {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageGenUsage {
     /// The number of tokens (images and text) in the input prompt.
     pub input_tokens: u32,
@@ -311,7 +328,8 @@ pub struct ImagesResponse {
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum InputFidelity {
     High,
@@ -330,7 +348,7 @@ pub enum ImageEditInput {
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(error = "OpenAIError", validate = "Self::validate"))]
 pub struct CreateImageEditRequest {
     /// The image(s) to edit. Must be a supported image file or an array of images.
     ///
@@ -414,6 +432,78 @@ pub struct CreateImageEditRequest {
     pub quality: Option<ImageQuality>,
 }
 
+impl CreateImageEditRequestArgs {
+    /// Checks the documented limits of the parameters set so far, called by [build](Self::build).
+    ///
+    /// `dall-e-2` edits take a single image, and a mask with the same dimensions as the image.
+    /// Dimensions are only compared for PNG images and masks given as bytes, files are not read.
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        check_range("n", self.n.flatten(), 1, 10)?;
+        check_range(
+            "output_compression",
+            self.output_compression.flatten(),
+            0,
+            100,
+        )?;
+        check_range("partial_images", self.partial_images.flatten(), 0, 3)?;
+
+        let images = match &self.image {
+            Some(ImageEditInput::Image(image)) => std::slice::from_ref(image),
+            Some(ImageEditInput::Images(images)) => images.as_slice(),
+            None => &[],
+        };
+
+        if self.model != Some(Some(ImageModel::DallE2)) {
+            if images.len() > 16 {
+                return Err(OpenAIError::InvalidArgument(format!(
+                    "at most 16 images can be edited, got {}",
+                    images.len()
+                )));
+            }
+            return Ok(());
+        }
+
+        if images.len() > 1 {
+            return Err(OpenAIError::InvalidArgument(format!(
+                "dall-e-2 edits a single image, got {}",
+                images.len()
+            )));
+        }
+        if let (Some(image), Some(Some(mask))) = (images.first(), &self.mask) {
+            if let (Some(image), Some(mask)) =
+                (png_dimensions(&image.source), png_dimensions(&mask.source))
+            {
+                if image != mask {
+                    return Err(OpenAIError::InvalidArgument(format!(
+                        "the mask must have the dimensions of the image for dall-e-2, got {}x{} for a {}x{} image",
+                        mask.0, mask.1, image.0, image.1
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Width and height of a PNG given as bytes, from its `IHDR` chunk.
+fn png_dimensions(source: &InputSource) -> Option<(u32, u32)> {
+    let bytes: &[u8] = match source {
+        InputSource::Bytes { bytes, .. } => bytes,
+        InputSource::VecU8 { vec, .. } => vec,
+        #[allow(unreachable_patterns)]
+        _ => return None,
+    };
+    match bytes {
+        [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, _, _, _, _, b'I', b'H', b'D', b'R', w0, w1, w2, w3, h0, h1, h2, h3, ..] => {
+            Some((
+                u32::from_be_bytes([*w0, *w1, *w2, *w3]),
+                u32::from_be_bytes([*h0, *h1, *h2, *h3]),
+            ))
+        }
+        _ => None,
+    }
+}
+
 #[derive(Debug, Default, Clone, Builder, PartialEq)]
 #[builder(name = "CreateImageVariationRequestArgs")]
 #[builder(pattern = "mutable")]
@@ -446,7 +536,8 @@ pub struct CreateImageVariationRequest {
 /// Reference an input image by either URL or uploaded file ID.
 /// Provide exactly one of `image_url` or `file_id`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageRefParam {
     /// A fully qualified URL or base64-encoded data URL.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -462,7 +553,8 @@ pub struct ImageRefParam {
 /// You can reference images via external URLs, data URLs, or uploaded file IDs.
 /// JSON edits support GPT image models only; DALL-E edits require multipart (`dall-e-2` only).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Builder, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "EditImageJsonRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -4,10 +4,37 @@ use std::fmt::Display;
 use std::path::{Path, PathBuf};
 
 use crate::types::images::{
-    DallE2ImageSize, ImageBackground, ImageEditInput, ImageInput, ImageModel, ImageOutputFormat,
-    ImageQuality, ImageResponseFormat, ImageSize, InputFidelity,
+    DallE2ImageSize, Image, ImageBackground, ImageEditInput, ImageInput, ImageModel,
+    ImageOutputFormat, ImageQuality, ImageResponseFormat, ImageSize, InputFidelity,
 };
 
+impl Image {
+    /// The URL of the image, for `ResponseFormat::Url`.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Image::Url { url, .. } => Some(url),
+            Image::B64Json { .. } => None,
+        }
+    }
+
+    /// The base64-encoded image, for `ResponseFormat::B64Json`.
+    pub fn b64_json(&self) -> Option<&str> {
+        match self {
+            Image::Url { .. } => None,
+            Image::B64Json { b64_json, .. } => Some(b64_json),
+        }
+    }
+
+    /// The prompt used to generate the image, when the model revised the original prompt.
+    pub fn revised_prompt(&self) -> Option<&str> {
+        match self {
+            Image::Url { revised_prompt, .. } | Image::B64Json { revised_prompt, .. } => {
+                revised_prompt.as_deref()
+            }
+        }
+    }
+}
+
 impl Display for ImageSize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -1,6 +1,6 @@
 #![cfg(not(target_family = "wasm"))]
 use crate::{
-    download::{download_url, save_b64},
+    download::{decode_b64, download_bytes, download_url, save_b64},
     error::OpenAIError,
     types::images::{Image, ImagesResponse},
     util::create_all_dir,
@@ -48,7 +48,21 @@ impl ImagesResponse {
 }
 
 impl Image {
-    async fn save<P: AsRef<Path>>(&self, dir: P) -> Result<PathBuf, OpenAIError> {
+    /// The encoded image, downloaded for `ResponseFormat::Url` or decoded for `ResponseFormat::B64Json`.
+    pub async fn bytes(&self) -> Result<Vec<u8>, OpenAIError> {
+        match self {
+            Image::Url { url, .. } => Ok(download_bytes(url).await?.to_vec()),
+            Image::B64Json { b64_json, .. } => decode_b64(b64_json),
+        }
+    }
+
+    /// Save the image in `dir`, created if missing, and return the path to the saved file.
+    ///
+    /// Downloaded images are named after the path of their URL. Decoded images get a random
+    /// name, with the extension of their format: `png`, `jpeg` or `webp`.
+    pub async fn save<P: AsRef<Path>>(&self, dir: P) -> Result<PathBuf, OpenAIError> {
+        create_all_dir(dir.as_ref())?;
+
         match self {
             Image::Url { url, .. } => download_url(url, dir).await,
             Image::B64Json { b64_json, .. } => save_b64(b64_json, dir).await,
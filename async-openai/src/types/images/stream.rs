@@ -6,7 +6,8 @@ use crate::types::images::{
 
 /// Emitted when a partial image is available during image generation streaming.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageGenPartialImageEvent {
     /// Base64-encoded partial image data, suitable for rendering as an image.
     pub b64_json: String,
@@ -26,7 +27,8 @@ pub struct ImageGenPartialImageEvent {
 
 /// Emitted when image generation has completed and the final image is available.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageGenCompletedEvent {
     /// Base64-encoded image data, suitable for rendering as an image.
     pub b64_json: String,
@@ -45,7 +47,8 @@ pub struct ImageGenCompletedEvent {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum ImageGenStreamEvent {
     /// Emitted when a partial image is available during image generation streaming.
@@ -58,7 +61,8 @@ pub enum ImageGenStreamEvent {
 
 /// Emitted when a partial image is available during image editing streaming.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageEditPartialImageEvent {
     /// Base64-encoded partial image data, suitable for rendering as an image.
     pub b64_json: String,
@@ -78,7 +82,8 @@ pub struct ImageEditPartialImageEvent {
 
 /// Emitted when image editing has completed and the final image is available.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageEditCompletedEvent {
     /// Base64-encoded final image data, suitable for rendering as an image.
     pub b64_json: String,
@@ -97,7 +102,8 @@ pub struct ImageEditCompletedEvent {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum ImageEditStreamEvent {
     /// Emitted when a partial image is available during image editing streaming.
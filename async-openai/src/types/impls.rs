@@ -133,7 +133,7 @@ impl_default!(EmbeddingInput);
 ///     source: InputSource
 /// }
 /// ```
-/// implements methods `from_bytes` and `from_vec_u8`,
+/// implements methods `from_bytes`, `from_vec_u8`, `from_stream` and `from_reader`,
 /// and `From<P>` for `P: AsRef<Path>`
 #[cfg(any(
     feature = "audio-types",
@@ -155,6 +155,38 @@ macro_rules! impl_input {
                     source: InputSource::VecU8 { filename, vec },
                 }
             }
+
+            /// Streams the upload from `stream` instead of buffering it in memory.
+            /// `length` is the total size in bytes, when known.
+            #[cfg(all(feature = "_api", not(target_family = "wasm")))]
+            pub fn from_stream<S>(filename: String, stream: S, length: Option<u64>) -> Self
+            where
+                S: futures::Stream<Item = Result<bytes::Bytes, std::io::Error>> + Send + 'static,
+            {
+                Self {
+                    source: InputSource::Stream {
+                        filename,
+                        stream: crate::types::InputStream::new(stream),
+                        length,
+                    },
+                }
+            }
+
+            /// Streams the upload from `reader`, e.g. a [tokio::fs::File], instead of buffering it in memory.
+            /// `length` is the total size in bytes, when known.
+            #[cfg(all(feature = "_api", not(target_family = "wasm")))]
+            pub fn from_reader<R>(filename: String, reader: R, length: Option<u64>) -> Self
+            where
+                R: tokio::io::AsyncRead + Send + 'static,
+            {
+                Self {
+                    source: InputSource::Stream {
+                        filename,
+                        stream: crate::types::InputStream::from_reader(reader),
+                        length,
+                    },
+                }
+            }
         }
 
         #[cfg(not(target_family = "wasm"))]
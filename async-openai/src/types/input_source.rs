@@ -12,6 +12,69 @@ pub enum InputSource {
         filename: String,
         vec: Vec<u8>,
     },
+    /// Streamed to the server as it is read, without buffering it in memory.
+    /// `length` is the total number of bytes, when known.
+    #[cfg(all(feature = "_api", not(target_family = "wasm")))]
+    Stream {
+        filename: String,
+        stream: InputStream,
+        length: Option<u64>,
+    },
+}
+
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+type BoxedByteStream = std::pin::Pin<
+    Box<dyn futures::Stream<Item = Result<bytes::Bytes, std::io::Error>> + Send + 'static>,
+>;
+
+/// A byte stream for [InputSource::Stream].
+///
+/// A stream can only be read once: clones share the same underlying stream, and
+/// requests whose stream has already been consumed by a previous request fail
+/// with [OpenAIError::FileReadError](crate::error::OpenAIError::FileReadError).
+/// Requests reading a stream are not retried.
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+#[derive(Clone)]
+pub struct InputStream(std::sync::Arc<std::sync::Mutex<Option<BoxedByteStream>>>);
+
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+impl InputStream {
+    pub fn new<S>(stream: S) -> Self
+    where
+        S: futures::Stream<Item = Result<bytes::Bytes, std::io::Error>> + Send + 'static,
+    {
+        Self(std::sync::Arc::new(std::sync::Mutex::new(Some(Box::pin(
+            stream,
+        )))))
+    }
+
+    /// Reads the stream from `reader`, e.g. a [tokio::fs::File].
+    pub fn from_reader<R>(reader: R) -> Self
+    where
+        R: tokio::io::AsyncRead + Send + 'static,
+    {
+        Self::new(tokio_util::io::ReaderStream::new(reader))
+    }
+
+    /// Takes the stream out, leaving `None` behind for every clone.
+    #[allow(dead_code)] // unused when no API with multipart uploads is enabled
+    pub(crate) fn take(&self) -> Option<BoxedByteStream> {
+        self.0.lock().ok()?.take()
+    }
+}
+
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+impl std::fmt::Debug for InputStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("InputStream")
+    }
+}
+
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+impl PartialEq for InputStream {
+    fn eq(&self, other: &Self) -> bool {
+        std::sync::Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 #[cfg(not(target_family = "wasm"))]
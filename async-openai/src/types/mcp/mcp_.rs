@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 use crate::error::OpenAIError;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum McpToolConnectorId {
     ConnectorDropbox,
@@ -18,7 +19,8 @@ pub enum McpToolConnectorId {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "MCPToolArgs",
     pattern = "mutable",
@@ -57,7 +59,7 @@ pub struct MCPTool {
 
     /// Optional HTTP headers to send to the MCP server. Use for authentication or other purposes.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub headers: Option<serde_json::Value>,
 
     /// Specify which of the MCP server's tools require approval.
@@ -78,7 +80,8 @@ pub struct MCPTool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum MCPToolAllowedTools {
     /// A string array of allowed tool names
@@ -88,7 +91,8 @@ pub enum MCPToolAllowedTools {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct MCPToolFilter {
     /// Indicates whether or not a tool modifies data or is read-only.
     /// If an MCP server is annotated with [readOnlyHint](https://modelcontextprotocol.io/specification/2025-06-18/schema#toolannotations-readonlyhint),
@@ -102,7 +106,8 @@ pub struct MCPToolFilter {
 
 /// Approval policy or filter for MCP tools.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum MCPToolRequireApproval {
     /// Specify which of the MCP server's tools require approval. Can be
@@ -116,7 +121,8 @@ pub enum MCPToolRequireApproval {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum MCPToolApprovalSetting {
     Always,
@@ -124,7 +130,8 @@ pub enum MCPToolApprovalSetting {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct MCPToolApprovalFilter {
     /// A list of tools that always require approval.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -135,16 +142,17 @@ pub struct MCPToolApprovalFilter {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct MCPListToolsTool {
     /// The JSON schema describing the tool's input.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub input_schema: serde_json::Value,
     /// The name of the tool.
     pub name: String,
     /// Additional annotations about the tool.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub annotations: Option<serde_json::Value>,
     /// The description of the tool.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 /// Set of 16 key-value pairs that can be attached to an object.
 /// This can be useful for storing additional information about the
 /// object in a structured format, and querying for objects via API
@@ -5,7 +7,8 @@
 /// characters. Values are strings with a maximum length of 512
 /// characters.
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(transparent)]
 pub struct Metadata(serde_json::Value);
 
@@ -14,3 +17,26 @@ impl From<serde_json::Value> for Metadata {
         Self(value)
     }
 }
+
+impl From<HashMap<String, String>> for Metadata {
+    fn from(value: HashMap<String, String>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Metadata {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(serde_json::Value::Object(
+            iter.into_iter()
+                .map(|(key, value)| (key.into(), serde_json::Value::String(value.into())))
+                .collect(),
+        ))
+    }
+}
+
+impl Metadata {
+    /// The value of `key`, if it is set to a string.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(serde_json::Value::as_str)
+    }
+}
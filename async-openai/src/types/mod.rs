@@ -56,10 +56,14 @@ pub mod mcp;
     feature = "realtime-types"
 ))]
 mod metadata;
+mod model_capabilities;
+mod model_id;
 #[cfg(feature = "model-types")]
 pub mod models;
 #[cfg(feature = "moderation-types")]
 pub mod moderations;
+#[cfg(feature = "_api")]
+mod rate_limit;
 #[cfg_attr(docsrs, doc(cfg(feature = "realtime-types")))]
 #[cfg(feature = "realtime-types")]
 pub mod realtime;
@@ -117,6 +121,12 @@ pub use input_source::*;
 ))]
 pub use metadata::*;
 
+pub use model_capabilities::*;
+pub use model_id::*;
+
+#[cfg(feature = "_api")]
+pub use rate_limit::*;
+
 #[cfg(any(
     feature = "audio-types",
     feature = "file-types",
@@ -129,6 +139,17 @@ pub use metadata::*;
 ))]
 mod impls;
 
+#[cfg(any(
+    feature = "chat-completion-types",
+    feature = "completion-types",
+    feature = "embedding-types",
+    feature = "response-types",
+    feature = "image-types",
+    feature = "assistant-types",
+    feature = "vectorstore-types"
+))]
+mod validation;
+
 #[cfg(any(
     feature = "response-types",
     feature = "audio-types",
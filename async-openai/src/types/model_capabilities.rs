@@ -0,0 +1,181 @@
+use crate::types::ModelId;
+
+/// Context window and modalities of a model family, to validate requests client side.
+///
+/// Known for the model families of the [ModelId] constants, their dated snapshots such as
+/// `gpt-4o-2024-08-06`, and models fine-tuned from them. The values are those documented when
+/// this version of the crate was released.
+///
+/// ```
+/// use async_openai::types::{ModelCapabilities, ModelId};
+///
+/// let capabilities = ModelId::GPT_4O_MINI.capabilities().unwrap();
+/// assert_eq!(capabilities.context_window, 128_000);
+/// assert!(capabilities.image_input);
+///
+/// let snapshot = ModelCapabilities::for_model("ft:gpt-4o-mini-2024-07-18:org::abc123");
+/// assert_eq!(snapshot, Some(capabilities));
+/// assert_eq!(ModelCapabilities::for_model("my-local-model"), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelCapabilities {
+    /// Maximum number of tokens of the input and the output together.
+    pub context_window: u32,
+    /// Maximum number of output tokens, including reasoning tokens.
+    pub max_output_tokens: u32,
+    pub text_input: bool,
+    pub image_input: bool,
+    pub audio_input: bool,
+    pub text_output: bool,
+    pub audio_output: bool,
+    /// Whether the model generates reasoning tokens, and accepts `reasoning_effort`.
+    pub reasoning: bool,
+}
+
+impl ModelCapabilities {
+    const TEXT: Self = Self {
+        context_window: 0,
+        max_output_tokens: 0,
+        text_input: true,
+        image_input: false,
+        audio_input: false,
+        text_output: true,
+        audio_output: false,
+        reasoning: false,
+    };
+
+    const fn text(context_window: u32, max_output_tokens: u32) -> Self {
+        Self {
+            context_window,
+            max_output_tokens,
+            ..Self::TEXT
+        }
+    }
+
+    const fn vision(context_window: u32, max_output_tokens: u32) -> Self {
+        Self {
+            image_input: true,
+            ..Self::text(context_window, max_output_tokens)
+        }
+    }
+
+    const fn reasoning(self) -> Self {
+        Self {
+            reasoning: true,
+            ..self
+        }
+    }
+
+    const fn audio(context_window: u32, max_output_tokens: u32) -> Self {
+        Self {
+            audio_input: true,
+            audio_output: true,
+            ..Self::text(context_window, max_output_tokens)
+        }
+    }
+
+    const fn transcription(context_window: u32, max_output_tokens: u32) -> Self {
+        Self {
+            text_input: false,
+            audio_input: true,
+            ..Self::text(context_window, max_output_tokens)
+        }
+    }
+
+    /// The capabilities of `model`, `None` when its family is not known.
+    pub fn for_model(model: &str) -> Option<Self> {
+        // fine-tuned models are named `ft:{base model}:{org}:{suffix}:{id}`
+        let model = match model.strip_prefix("ft:") {
+            Some(model) => model.split(':').next().unwrap_or(model),
+            None => model,
+        };
+
+        FAMILIES
+            .iter()
+            .find(|(family, _)| is_snapshot_of(model, family))
+            .map(|(_, capabilities)| *capabilities)
+    }
+}
+
+/// Whether `model` is `family`, a dated snapshot of it like `gpt-4o-2024-08-06` or `gpt-4-0613`,
+/// or a preview of it.
+fn is_snapshot_of(model: &str, family: &str) -> bool {
+    match model.strip_prefix(family) {
+        Some("") | Some("-preview") => true,
+        Some(rest) => rest
+            .strip_prefix('-')
+            .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit())),
+        None => false,
+    }
+}
+
+const FAMILIES: &[(&str, ModelCapabilities)] = &[
+    (
+        "gpt-5",
+        ModelCapabilities::vision(400_000, 128_000).reasoning(),
+    ),
+    (
+        "gpt-5-mini",
+        ModelCapabilities::vision(400_000, 128_000).reasoning(),
+    ),
+    (
+        "gpt-5-nano",
+        ModelCapabilities::vision(400_000, 128_000).reasoning(),
+    ),
+    ("gpt-4.1", ModelCapabilities::vision(1_047_576, 32_768)),
+    ("gpt-4.1-mini", ModelCapabilities::vision(1_047_576, 32_768)),
+    ("gpt-4.1-nano", ModelCapabilities::vision(1_047_576, 32_768)),
+    ("gpt-4o", ModelCapabilities::vision(128_000, 16_384)),
+    ("gpt-4o-mini", ModelCapabilities::vision(128_000, 16_384)),
+    (
+        "gpt-4o-audio-preview",
+        ModelCapabilities::audio(128_000, 16_384),
+    ),
+    (
+        "gpt-4o-mini-audio-preview",
+        ModelCapabilities::audio(128_000, 16_384),
+    ),
+    ("gpt-realtime", ModelCapabilities::audio(32_000, 4_096)),
+    (
+        "gpt-4o-transcribe",
+        ModelCapabilities::transcription(16_000, 2_000),
+    ),
+    (
+        "gpt-4o-mini-transcribe",
+        ModelCapabilities::transcription(16_000, 2_000),
+    ),
+    ("gpt-4-turbo", ModelCapabilities::vision(128_000, 4_096)),
+    ("gpt-4", ModelCapabilities::text(8_192, 8_192)),
+    ("gpt-3.5-turbo", ModelCapabilities::text(16_385, 4_096)),
+    (
+        "gpt-3.5-turbo-instruct",
+        ModelCapabilities::text(4_096, 4_096),
+    ),
+    (
+        "o1",
+        ModelCapabilities::vision(200_000, 100_000).reasoning(),
+    ),
+    (
+        "o1-mini",
+        ModelCapabilities::text(128_000, 65_536).reasoning(),
+    ),
+    (
+        "o3",
+        ModelCapabilities::vision(200_000, 100_000).reasoning(),
+    ),
+    (
+        "o3-mini",
+        ModelCapabilities::text(200_000, 100_000).reasoning(),
+    ),
+    (
+        "o4-mini",
+        ModelCapabilities::vision(200_000, 100_000).reasoning(),
+    ),
+];
+
+impl ModelId {
+    /// The capabilities of this model, see [ModelCapabilities::for_model].
+    pub fn capabilities(&self) -> Option<ModelCapabilities> {
+        ModelCapabilities::for_model(self)
+    }
+}
@@ -0,0 +1,159 @@
+use std::{borrow::Cow, convert::Infallible, fmt::Display, ops::Deref, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// The name of a model, e.g. `gpt-4o-mini`, with constants for common models.
+///
+/// Request builders take model names as `impl Into<String>`, which `ModelId` implements, so the
+/// constants can be used in place of string literals. Any other model name, including models
+/// released after this version of the crate, converts with `From<&str>`, `From<String>` or
+/// [FromStr].
+///
+/// ```
+/// use async_openai::types::ModelId;
+///
+/// assert_eq!(ModelId::GPT_4O_MINI, "gpt-4o-mini");
+/// assert_eq!(ModelId::from("my-fine-tuned-model").len(), 19);
+///
+/// let model: ModelId = "gpt-4.1".parse().unwrap();
+/// assert_eq!(model, ModelId::GPT_4_1);
+/// assert_eq!(String::from(model), "gpt-4.1");
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize
+)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct ModelId(Cow<'static, str>);
+
+impl ModelId {
+    pub const GPT_5: Self = Self::from_static("gpt-5");
+    pub const GPT_5_MINI: Self = Self::from_static("gpt-5-mini");
+    pub const GPT_5_NANO: Self = Self::from_static("gpt-5-nano");
+    pub const GPT_4_1: Self = Self::from_static("gpt-4.1");
+    pub const GPT_4_1_MINI: Self = Self::from_static("gpt-4.1-mini");
+    pub const GPT_4_1_NANO: Self = Self::from_static("gpt-4.1-nano");
+    pub const GPT_4O: Self = Self::from_static("gpt-4o");
+    pub const GPT_4O_MINI: Self = Self::from_static("gpt-4o-mini");
+    pub const GPT_4O_AUDIO_PREVIEW: Self = Self::from_static("gpt-4o-audio-preview");
+    pub const GPT_3_5_TURBO: Self = Self::from_static("gpt-3.5-turbo");
+    pub const GPT_3_5_TURBO_INSTRUCT: Self = Self::from_static("gpt-3.5-turbo-instruct");
+    pub const O1: Self = Self::from_static("o1");
+    pub const O3: Self = Self::from_static("o3");
+    pub const O3_MINI: Self = Self::from_static("o3-mini");
+    pub const O4_MINI: Self = Self::from_static("o4-mini");
+    pub const GPT_REALTIME: Self = Self::from_static("gpt-realtime");
+    pub const TEXT_EMBEDDING_3_SMALL: Self = Self::from_static("text-embedding-3-small");
+    pub const TEXT_EMBEDDING_3_LARGE: Self = Self::from_static("text-embedding-3-large");
+    pub const TEXT_EMBEDDING_ADA_002: Self = Self::from_static("text-embedding-ada-002");
+    pub const WHISPER_1: Self = Self::from_static("whisper-1");
+    pub const GPT_4O_TRANSCRIBE: Self = Self::from_static("gpt-4o-transcribe");
+    pub const GPT_4O_MINI_TRANSCRIBE: Self = Self::from_static("gpt-4o-mini-transcribe");
+    pub const TTS_1: Self = Self::from_static("tts-1");
+    pub const TTS_1_HD: Self = Self::from_static("tts-1-hd");
+    pub const GPT_4O_MINI_TTS: Self = Self::from_static("gpt-4o-mini-tts");
+    pub const GPT_IMAGE_1: Self = Self::from_static("gpt-image-1");
+    pub const DALL_E_2: Self = Self::from_static("dall-e-2");
+    pub const DALL_E_3: Self = Self::from_static("dall-e-3");
+    pub const OMNI_MODERATION_LATEST: Self = Self::from_static("omni-moderation-latest");
+
+    /// A model name known at compile time.
+    pub const fn from_static(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for ModelId {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ModelId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for ModelId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for ModelId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl From<&str> for ModelId {
+    fn from(value: &str) -> Self {
+        Self(Cow::Owned(value.to_string()))
+    }
+}
+
+impl From<String> for ModelId {
+    fn from(value: String) -> Self {
+        Self(Cow::Owned(value))
+    }
+}
+
+impl From<ModelId> for String {
+    fn from(value: ModelId) -> Self {
+        value.0.into_owned()
+    }
+}
+
+impl From<&ModelId> for String {
+    fn from(value: &ModelId) -> Self {
+        value.0.to_string()
+    }
+}
+
+impl PartialEq<str> for ModelId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for ModelId {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for ModelId {
+    fn eq(&self, other: &String) -> bool {
+        self.0 == other.as_str()
+    }
+}
+
+impl PartialEq<ModelId> for str {
+    fn eq(&self, other: &ModelId) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<ModelId> for String {
+    fn eq(&self, other: &ModelId) -> bool {
+        self.as_str() == other.0
+    }
+}
@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::types::ModelCapabilities;
+
 /// Describes an OpenAI model offering that can be used with the API.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Model {
     /// The model identifier, which can be referenced in the API endpoints.
     pub id: String,
@@ -14,15 +17,24 @@ pub struct Model {
     pub owned_by: String,
 }
 
+impl Model {
+    /// The capabilities of the family of this model, see [ModelCapabilities::for_model].
+    pub fn capabilities(&self) -> Option<ModelCapabilities> {
+        ModelCapabilities::for_model(&self.id)
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListModelResponse {
     pub object: String,
     pub data: Vec<Model>,
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteModelResponse {
     pub id: String,
     pub object: String,
@@ -0,0 +1,70 @@
+use crate::types::moderations::{Categories, CreateModerationResponse};
+
+/// The names of the categories, as named by the API, in the order of [Categories::flags].
+const CATEGORY_NAMES: [&str; 13] = [
+    "hate",
+    "hate/threatening",
+    "harassment",
+    "harassment/threatening",
+    "illicit",
+    "illicit/violent",
+    "self-harm",
+    "self-harm/intent",
+    "self-harm/instructions",
+    "sexual",
+    "sexual/minors",
+    "violence",
+    "violence/graphic",
+];
+
+impl Categories {
+    /// The names of the flagged categories, as named by the API, e.g. `hate/threatening`.
+    pub fn flagged(&self) -> Vec<&'static str> {
+        flagged_names(self.flags())
+    }
+
+    fn flags(&self) -> [bool; 13] {
+        [
+            self.hate,
+            self.hate_threatening,
+            self.harassment,
+            self.harassment_threatening,
+            self.illicit,
+            self.illicit_violent,
+            self.self_harm,
+            self.self_harm_intent,
+            self.self_harm_instructions,
+            self.sexual,
+            self.sexual_minors,
+            self.violence,
+            self.violence_graphic,
+        ]
+    }
+}
+
+fn flagged_names(flags: [bool; 13]) -> Vec<&'static str> {
+    CATEGORY_NAMES
+        .into_iter()
+        .zip(flags)
+        .filter_map(|(name, flagged)| flagged.then_some(name))
+        .collect()
+}
+
+impl CreateModerationResponse {
+    /// Returns `true` if any of the inputs is flagged.
+    pub fn is_flagged(&self) -> bool {
+        self.results.iter().any(|result| result.flagged)
+    }
+
+    /// The categories flagged for any of the inputs, without duplicates, in the order of
+    /// [Categories::flagged].
+    pub fn flagged_categories(&self) -> Vec<&'static str> {
+        let mut flags = [false; 13];
+        for result in &self.results {
+            for (any, flagged) in flags.iter_mut().zip(result.categories.flags()) {
+                *any |= flagged;
+            }
+        }
+        flagged_names(flags)
+    }
+}
@@ -1,3 +1,4 @@
+mod impls;
 mod moderation;
 
 pub use moderation::*;
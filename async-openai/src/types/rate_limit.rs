@@ -0,0 +1,175 @@
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+
+use crate::error::OpenAIError;
+
+/// Rate limit state reported by the API in `x-ratelimit-*` response headers.
+///
+/// Obtain the headers with a `*_with_headers` method, for example
+/// [Chat::create_with_headers](crate::Chat::create_with_headers), and parse them
+/// with `RateLimitInfo::try_from(&headers)`.
+///
+/// Learn more in the [rate limits guide](https://platform.openai.com/docs/guides/rate-limits#rate-limits-in-headers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// The maximum number of requests that are permitted before exhausting the rate limit.
+    pub limit_requests: u64,
+    /// The maximum number of tokens that are permitted before exhausting the rate limit.
+    pub limit_tokens: u64,
+    /// The remaining number of requests that are permitted before exhausting the rate limit.
+    pub remaining_requests: u64,
+    /// The remaining number of tokens that are permitted before exhausting the rate limit.
+    pub remaining_tokens: u64,
+    /// The time until the rate limit (based on requests) resets to its initial state.
+    pub reset_requests: Duration,
+    /// The time until the rate limit (based on tokens) resets to its initial state.
+    pub reset_tokens: Duration,
+}
+
+impl TryFrom<&HeaderMap> for RateLimitInfo {
+    type Error = OpenAIError;
+
+    fn try_from(headers: &HeaderMap) -> Result<Self, Self::Error> {
+        Ok(Self {
+            limit_requests: parse_count(headers, "x-ratelimit-limit-requests")?,
+            limit_tokens: parse_count(headers, "x-ratelimit-limit-tokens")?,
+            remaining_requests: parse_count(headers, "x-ratelimit-remaining-requests")?,
+            remaining_tokens: parse_count(headers, "x-ratelimit-remaining-tokens")?,
+            reset_requests: parse_reset(headers, "x-ratelimit-reset-requests")?,
+            reset_tokens: parse_reset(headers, "x-ratelimit-reset-tokens")?,
+        })
+    }
+}
+
+fn header_str<'h>(headers: &'h HeaderMap, name: &str) -> Result<&'h str, OpenAIError> {
+    headers
+        .get(name)
+        .ok_or_else(|| OpenAIError::InvalidArgument(format!("missing header: {name}")))?
+        .to_str()
+        .map_err(|e| OpenAIError::InvalidArgument(format!("invalid header {name}: {e}")))
+}
+
+fn parse_count(headers: &HeaderMap, name: &str) -> Result<u64, OpenAIError> {
+    let value = header_str(headers, name)?;
+    value
+        .trim()
+        .parse()
+        .map_err(|e| OpenAIError::InvalidArgument(format!("invalid header {name}={value}: {e}")))
+}
+
+fn parse_reset(headers: &HeaderMap, name: &str) -> Result<Duration, OpenAIError> {
+    let value = header_str(headers, name)?;
+    parse_duration(value.trim()).ok_or_else(|| {
+        OpenAIError::InvalidArgument(format!("invalid header {name}={value}: not a duration"))
+    })
+}
+
+/// Parses Go style duration strings as sent by the API, e.g. `6m0s`, `1.5s`, `88ms` or `1h2m3s`.
+fn parse_duration(value: &str) -> Option<Duration> {
+    if value.is_empty() {
+        return None;
+    }
+
+    let mut total = 0f64;
+    let mut rest = value;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_len].parse().ok()?;
+        rest = &rest[number_len..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        let seconds_per_unit = match &rest[..unit_len] {
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 1e-3,
+            "us" | "µs" => 1e-6,
+            "ns" => 1e-9,
+            _ => return None,
+        };
+        rest = &rest[unit_len..];
+
+        total += number * seconds_per_unit;
+    }
+
+    Duration::try_from_secs_f64(total).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    const ALL: [(&str, &str); 6] = [
+        ("x-ratelimit-limit-requests", "60"),
+        ("x-ratelimit-limit-tokens", "150000"),
+        ("x-ratelimit-remaining-requests", "59"),
+        ("x-ratelimit-remaining-tokens", "149984"),
+        ("x-ratelimit-reset-requests", "1s"),
+        ("x-ratelimit-reset-tokens", "6m0s"),
+    ];
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("6m0s"), Some(Duration::from_secs(360)));
+        assert_eq!(parse_duration("88ms"), Some(Duration::from_millis(88)));
+        assert_eq!(parse_duration("1.5s"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("1h2m3s"), Some(Duration::from_secs(3723)));
+        assert_eq!(parse_duration("0s"), Some(Duration::ZERO));
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("10"), None);
+        assert_eq!(parse_duration("5d"), None);
+    }
+
+    #[test]
+    fn test_rate_limit_info_from_headers() {
+        let info = RateLimitInfo::try_from(&headers(&ALL)).unwrap();
+
+        assert_eq!(
+            info,
+            RateLimitInfo {
+                limit_requests: 60,
+                limit_tokens: 150000,
+                remaining_requests: 59,
+                remaining_tokens: 149984,
+                reset_requests: Duration::from_secs(1),
+                reset_tokens: Duration::from_secs(360),
+            }
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_info_missing_header() {
+        let pairs: Vec<_> = ALL
+            .into_iter()
+            .filter(|(name, _)| *name != "x-ratelimit-reset-tokens")
+            .collect();
+
+        let err = RateLimitInfo::try_from(&headers(&pairs)).unwrap_err();
+        assert!(
+            matches!(err, OpenAIError::InvalidArgument(ref msg) if msg.contains("x-ratelimit-reset-tokens"))
+        );
+
+        assert!(RateLimitInfo::try_from(&HeaderMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_info_invalid_header() {
+        let mut headers = headers(&ALL);
+        headers.insert("x-ratelimit-reset-requests", "soon".parse().unwrap());
+
+        assert!(RateLimitInfo::try_from(&headers).is_err());
+    }
+}
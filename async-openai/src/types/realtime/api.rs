@@ -4,7 +4,8 @@ use crate::types::realtime::{RealtimeSessionConfiguration, Session};
 
 /// Request to create a Realtime call.
 #[derive(Debug, Serialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeCallCreateRequest {
     /// WebRTC Session Description Protocol (SDP) offer generated by the caller.
     pub sdp: String,
@@ -15,7 +16,7 @@ pub struct RealtimeCallCreateRequest {
 
 /// Response from creating a Realtime call.
 #[derive(Debug, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeCallCreateResponse {
     /// SDP answer produced by OpenAI for the peer connection.
     pub sdp: String,
@@ -26,7 +27,8 @@ pub struct RealtimeCallCreateResponse {
 
 /// Request to accept a Realtime call.
 #[derive(Debug, Serialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeCallAcceptRequest {
     /// Realtime session configuration to apply before the caller is bridged to the model.
     pub session: RealtimeSessionConfiguration,
@@ -34,7 +36,8 @@ pub struct RealtimeCallAcceptRequest {
 
 /// Request to refer a Realtime call.
 #[derive(Debug, Serialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeCallReferRequest {
     /// URI that should appear in the SIP Refer-To header.
     /// Supports values like `tel:+14155550123` or `sip:agent@example.com`.
@@ -43,7 +46,8 @@ pub struct RealtimeCallReferRequest {
 
 /// Request to reject a Realtime call.
 #[derive(Debug, Serialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeCallRejectRequest {
     /// SIP response code to send back to the caller.
     /// Defaults to `603` (Decline) when omitted.
@@ -53,7 +57,8 @@ pub struct RealtimeCallRejectRequest {
 
 /// Request to create a Realtime client secret.
 #[derive(Debug, Serialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeCreateClientSecretRequest {
     /// Session configuration to use for the client secret.
     /// Choose either a realtime session or a transcription session.
@@ -65,7 +70,8 @@ pub struct RealtimeCreateClientSecretRequest {
 
 /// Client secret expiration configuration.
 #[derive(Debug, Serialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ClientSecretExpiration {
     /// The anchor point for the client secret expiration.
     /// Only `created_at` is currently supported.
@@ -79,7 +85,8 @@ pub struct ClientSecretExpiration {
 
 /// Response from creating a Realtime client secret.
 #[derive(Debug, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeCreateClientSecretResponse {
     /// The generated client secret value.
     pub value: String,
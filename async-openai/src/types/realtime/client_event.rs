@@ -1,9 +1,12 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::types::realtime::{RealtimeConversationItem, RealtimeResponseCreateParams, Session};
 
+use super::server_event::event_type_of;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventSessionUpdate {
     /// Optional client-generated ID used to identify this event.
     /// This is an arbitrary string that a client may assign. It will be passed
@@ -16,7 +19,8 @@ pub struct RealtimeClientEventSessionUpdate {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventInputAudioBufferAppend {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,7 +31,8 @@ pub struct RealtimeClientEventInputAudioBufferAppend {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventInputAudioBufferCommit {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -35,7 +40,8 @@ pub struct RealtimeClientEventInputAudioBufferCommit {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventInputAudioBufferClear {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -43,7 +49,8 @@ pub struct RealtimeClientEventInputAudioBufferClear {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventConversationItemCreate {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -62,7 +69,8 @@ pub struct RealtimeClientEventConversationItemCreate {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventConversationItemRetrieve {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,7 +81,8 @@ pub struct RealtimeClientEventConversationItemRetrieve {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventConversationItemTruncate {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -91,7 +100,8 @@ pub struct RealtimeClientEventConversationItemTruncate {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventConversationItemDelete {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -102,7 +112,8 @@ pub struct RealtimeClientEventConversationItemDelete {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventResponseCreate {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -113,7 +124,8 @@ pub struct RealtimeClientEventResponseCreate {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventResponseCancel {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -126,7 +138,8 @@ pub struct RealtimeClientEventResponseCancel {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeClientEventOutputAudioBufferClear {
     /// Optional client-generated ID used to identify this event.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -134,9 +147,13 @@ pub struct RealtimeClientEventOutputAudioBufferClear {
 }
 
 /// These are events that the OpenAI Realtime WebSocket server will accept from the client.
+///
+/// Events of a type not modeled here deserialize into [RealtimeClientEvent::Unknown], which
+/// can also be used to send them.
 #[derive(Debug, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
-#[serde(tag = "type")]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "type", remote = "Self")]
 pub enum RealtimeClientEvent {
     /// Send this event to update the session's configuration. The client may send this event at any time to update any field
     /// except for `voice` and `model`. `voice` can be updated only if there have been no other audio outputs yet.
@@ -241,6 +258,36 @@ pub enum RealtimeClientEvent {
     /// [Learn more](https://platform.openai.com/docs/guides/realtime-conversations#client-and-server-events-for-audio-in-webrtc)
     #[serde(rename = "output_audio_buffer.clear")]
     OutputAudioBufferClear(RealtimeClientEventOutputAudioBufferClear),
+
+    /// An event of a type not modeled by this crate. `raw` is the whole event, including its `type`,
+    /// and is sent as is.
+    #[serde(skip)]
+    Unknown {
+        r#type: String,
+        #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
+        raw: serde_json::Value,
+    },
+}
+
+impl Serialize for RealtimeClientEvent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            RealtimeClientEvent::Unknown { raw, .. } => raw.serialize(serializer),
+            event => RealtimeClientEvent::serialize(event, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RealtimeClientEvent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = serde_json::Value::deserialize(deserializer)?;
+        let r#type = event_type_of::<D::Error>(&raw)?;
+        if EVENT_TYPES.contains(&r#type.as_str()) {
+            RealtimeClientEvent::deserialize(raw).map_err(serde::de::Error::custom)
+        } else {
+            Ok(RealtimeClientEvent::Unknown { r#type, raw })
+        }
+    }
 }
 
 impl From<&RealtimeClientEvent> for String {
@@ -390,21 +437,23 @@ message_from_event!(
     RealtimeClientEvent
 );
 
-// Implement EventType trait for all event types in this file
-#[cfg(feature = "_api")]
+// Implement EventType trait for all event types in this file, and list their types
 macro_rules! impl_event_type {
     ($($ty:ty => $event_type:expr),* $(,)?) => {
         $(
+            #[cfg(feature = "_api")]
             impl crate::traits::EventType for $ty {
                 fn event_type(&self) -> &'static str {
                     $event_type
                 }
             }
         )*
+
+        /// The types of the events modeled by [RealtimeClientEvent].
+        const EVENT_TYPES: &[&str] = &[$($event_type),*];
     };
 }
 
-#[cfg(feature = "_api")]
 impl_event_type! {
     RealtimeClientEventSessionUpdate => "session.update",
     RealtimeClientEventInputAudioBufferAppend => "input_audio_buffer.append",
@@ -434,6 +483,8 @@ impl crate::traits::EventType for RealtimeClientEvent {
             RealtimeClientEvent::ResponseCreate(e) => e.event_type(),
             RealtimeClientEvent::ResponseCancel(e) => e.event_type(),
             RealtimeClientEvent::OutputAudioBufferClear(e) => e.event_type(),
+            // the actual type is in `RealtimeClientEvent::Unknown::type`
+            RealtimeClientEvent::Unknown { .. } => "unknown",
         }
     }
 }
@@ -6,7 +6,8 @@ use crate::types::{
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SystemMessageContent {
     /// The text content.
     pub text: String,
@@ -15,7 +16,8 @@ pub struct SystemMessageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeConversationItemMessageSystem {
     /// The content of the message.
     pub content: Vec<SystemMessageContent>,
@@ -32,14 +34,16 @@ pub struct RealtimeConversationItemMessageSystem {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UserMessageContentInputText {
     /// The text content (for `input_text`).
     pub text: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UserMessageContentInputAudio {
     /// Base64-encoded audio bytes (for `input_audio`), these will be parsed as the
     /// format specified in the session input audio type configuration.
@@ -51,7 +55,8 @@ pub struct UserMessageContentInputAudio {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ImageDetail {
     #[default]
@@ -61,7 +66,8 @@ pub enum ImageDetail {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UserMessageContentInputImage {
     /// Base64-encoded image bytes (for `input_image`) as a data URI.
     /// For example `data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAA...`.
@@ -72,7 +78,8 @@ pub struct UserMessageContentInputImage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum UserMessageContent {
@@ -82,7 +89,8 @@ pub enum UserMessageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeConversationItemMessageUser {
     /// The content of the message.
     pub content: Vec<UserMessageContent>,
@@ -102,14 +110,16 @@ pub struct RealtimeConversationItemMessageUser {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantMessageContentOutputText {
     /// The text content
     pub text: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AssistantMessageContentOutputAudio {
     /// Base64-encoded audio bytes, these will be parsed as the format specified
     /// in the session output audio type configuration. This defaults to PCM 16-bit
@@ -121,7 +131,8 @@ pub struct AssistantMessageContentOutputAudio {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum AssistantMessageContent {
@@ -130,7 +141,8 @@ pub enum AssistantMessageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeConversationItemMessageAssistant {
     /// The content of the message.
     pub content: Vec<AssistantMessageContent>,
@@ -150,7 +162,8 @@ pub struct RealtimeConversationItemMessageAssistant {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "role")]
 #[serde(rename_all = "lowercase")]
 pub enum RealtimeConversationItemMessage {
@@ -160,7 +173,8 @@ pub enum RealtimeConversationItemMessage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeConversationItemFunctionCall {
     /// The arguments of the function call. This is a JSON-encoded string representing
     /// the arguments passed to the function, for example {"arg1": "value1", "arg2": 42}.
@@ -186,7 +200,8 @@ pub struct RealtimeConversationItemFunctionCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeConversationItemFunctionCallOutput {
     /// The ID of the function call this output is for.
     pub call_id: String,
@@ -209,7 +224,8 @@ pub struct RealtimeConversationItemFunctionCallOutput {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeMCPApprovalResponse {
     /// The ID of the approval request being answered.
     pub approval_request_id: String,
@@ -225,7 +241,8 @@ pub struct RealtimeMCPApprovalResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeMCPListTools {
     /// The label of the MCP server.
     pub server_label: String,
@@ -238,7 +255,8 @@ pub struct RealtimeMCPListTools {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeMCPApprovalRequest {
     /// A JSON string of arguments for the tool.
     pub arguments: String,
@@ -254,11 +272,13 @@ pub struct RealtimeMCPApprovalRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeMCPProtocolError {}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum RealtimeMCPToolCallError {
     ProtocolError(ErrorCodeMessage),
@@ -267,7 +287,8 @@ pub enum RealtimeMCPToolCallError {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeMCPToolCall {
     /// A JSON string of the arguments passed to the tool.
     pub arguments: String,
@@ -292,7 +313,8 @@ pub struct RealtimeMCPToolCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum RealtimeConversationItem {
     Message(RealtimeConversationItemMessage),
@@ -0,0 +1,51 @@
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::error::OpenAIError;
+use crate::types::realtime::{
+    RealtimeClientEventInputAudioBufferAppend, RealtimeServerEventResponseAudioDelta,
+};
+
+/// Encodes PCM16 samples the way the Realtime API expects `pcm16` audio: the little-endian
+/// bytes of each sample, base64 encoded.
+fn encode_pcm16(samples: &[i16]) -> String {
+    let bytes: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+    general_purpose::STANDARD.encode(bytes)
+}
+
+fn decode_pcm16(audio: &str) -> Result<Vec<i16>, OpenAIError> {
+    let bytes = general_purpose::STANDARD
+        .decode(audio)
+        .map_err(|e| OpenAIError::InvalidArgument(format!("invalid base64 audio: {e}")))?;
+    if bytes.len() % 2 != 0 {
+        return Err(OpenAIError::InvalidArgument(format!(
+            "invalid pcm16 audio: {} bytes is not a whole number of samples",
+            bytes.len()
+        )));
+    }
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|chunk| i16::from_le_bytes([chunk[0], chunk[1]]))
+        .collect())
+}
+
+impl RealtimeServerEventResponseAudioDelta {
+    /// Decodes the `delta` as PCM16 samples, for sessions with `pcm16` output audio.
+    pub fn pcm16_samples(&self) -> Result<Vec<i16>, OpenAIError> {
+        decode_pcm16(&self.delta)
+    }
+}
+
+impl RealtimeClientEventInputAudioBufferAppend {
+    /// Appends PCM16 `samples`, for sessions with `pcm16` input audio.
+    pub fn from_pcm16(samples: &[i16]) -> Self {
+        Self {
+            event_id: None,
+            audio: encode_pcm16(samples),
+        }
+    }
+
+    /// Decodes the `audio` as PCM16 samples.
+    pub fn pcm16_samples(&self) -> Result<Vec<i16>, OpenAIError> {
+        decode_pcm16(&self.audio)
+    }
+}
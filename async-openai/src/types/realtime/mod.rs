@@ -4,6 +4,7 @@ mod conversation_item;
 mod error;
 #[cfg(feature = "_api")]
 mod form;
+mod impls;
 mod response;
 mod server_event;
 mod session;
@@ -11,7 +11,8 @@ use crate::types::{
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeResponseUsage {
     /// Details about the input tokens used in the Response. Cached tokens are tokens from previous
     /// turns in the conversation that are included as context for the current response. Cached tokens
@@ -34,7 +35,8 @@ pub struct RealtimeResponseUsage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InputTokenDetails {
     /// The number of audio tokens used as input for the Response.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -56,7 +58,8 @@ pub struct InputTokenDetails {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CachedTokenDetails {
     /// The number of cached audio tokens used as input for the Response.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -72,7 +75,8 @@ pub struct CachedTokenDetails {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct OutputTokenDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text_tokens: Option<u32>,
@@ -81,7 +85,8 @@ pub struct OutputTokenDetails {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum RealtimeResponseStatus {
     InProgress,
@@ -92,14 +97,16 @@ pub enum RealtimeResponseStatus {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Error {
     pub code: String,
     pub r#type: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum RealtimeResponseStatusDetailType {
     Completed,
@@ -109,7 +116,8 @@ pub enum RealtimeResponseStatusDetailType {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum RealtimeResponseStatusDetailReason {
     TurnDetected,
@@ -119,7 +127,8 @@ pub enum RealtimeResponseStatusDetailReason {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeResponseStatusDetail {
     /// A description of the error that caused the response to fail, populated when the status is failed.
     pub error: Option<Error>,
@@ -134,7 +143,8 @@ pub struct RealtimeResponseStatusDetail {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseAudioOutput {
     /// The format of the output audio.
     pub format: RealtimeAudioFormats,
@@ -147,14 +157,16 @@ pub struct ResponseAudioOutput {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseAudio {
     /// Configuration for audio output.
     pub output: ResponseAudioOutput,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Conversation {
     #[default]
@@ -164,7 +176,8 @@ pub enum Conversation {
 
 /// The response resource.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeResponseCreateParams {
     /// Configuration for audio input and output.
     pub audio: ResponseAudio,
@@ -204,7 +217,7 @@ pub struct RealtimeResponseCreateParams {
     /// Keys are strings with a maximum length of 64 characters. Values are strings with a
     /// maximum length of 512 characters.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub metadata: Option<serde_json::Value>,
 
     /// The set of modalities the model used to respond, currently the only possible values
@@ -228,7 +241,8 @@ pub struct RealtimeResponseCreateParams {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeResponse {
     /// Configuration for audio output.
     pub audio: Option<ResponseAudio>,
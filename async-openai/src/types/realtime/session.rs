@@ -6,7 +6,8 @@ use crate::types::{
 };
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AudioTranscription {
     /// The language of the input audio. Supplying the input language in
     /// [ISO-639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes) (e.g. `en`) format will improve accuracy and latency.
@@ -26,7 +27,8 @@ pub struct AudioTranscription {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum RealtimeTurnDetection {
     /// Server-side voice activity detection (VAD) which flips on when user speech is detected
@@ -101,7 +103,8 @@ pub enum RealtimeTurnDetection {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum MaxOutputTokens {
     #[serde(rename = "inf")]
     Inf,
@@ -110,7 +113,8 @@ pub enum MaxOutputTokens {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeFunctionTool {
     /// The name of the function.
     pub name: String,
@@ -118,12 +122,13 @@ pub struct RealtimeFunctionTool {
     /// and guidance about what to tell the user when calling (if anything).
     pub description: String,
     /// Parameters of the function in JSON Schema.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub parameters: serde_json::Value,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum RealtimeTool {
     #[serde(rename = "function")]
@@ -135,14 +140,16 @@ pub enum RealtimeTool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum FunctionType {
     Function,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ToolChoice {
     /// Use this option to force the model to call a specific function.
@@ -155,7 +162,8 @@ pub enum ToolChoice {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum RealtimeVoice {
     Alloy,
@@ -173,7 +181,8 @@ pub enum RealtimeVoice {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum RealtimeAudioFormats {
     /// The PCM audio format. Only a 24kHz sample rate is supported.
@@ -191,14 +200,16 @@ pub enum RealtimeAudioFormats {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct G711ULAWAudioFormat {
     pub sample_rate: u32,
     pub channels: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AudioInput {
     /// The format of the input audio.
     pub format: RealtimeAudioFormats,
@@ -232,7 +243,8 @@ pub struct AudioInput {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AudioOutput {
     /// The format of the output audio.
     pub format: RealtimeAudioFormats,
@@ -252,13 +264,16 @@ pub struct AudioOutput {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Audio {
     pub input: AudioInput,
     pub output: AudioOutput,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Tracing {
     /// Enables tracing and sets default values for tracing configuration options. Always `auto`.
@@ -269,12 +284,13 @@ pub enum Tracing {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TracingConfiguration {
     /// The group id to attach to this trace to enable filtering and grouping in the Traces Dashboard.
     pub group_id: String,
     /// The arbitrary metadata to attach to this trace to enable filtering in the Traces Dashboard.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub metadata: serde_json::Value,
     /// The name of the workflow to attach to this trace. This is used to name the trace in the Traces Dashboard.
     pub workflow_name: String,
@@ -282,7 +298,8 @@ pub struct TracingConfiguration {
 
 /// The truncation strategy to use for the session.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum RealtimeTruncation {
     /// `auto` is the default truncation strategy.
@@ -299,7 +316,8 @@ pub enum RealtimeTruncation {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RetentionRatioTruncation {
     /// Fraction of post-instruction conversation tokens to retain (0.0 - 1.0) when the conversation
     ///  exceeds the input token limit. Setting this to 0.8 means that messages will be dropped
@@ -317,7 +335,8 @@ pub struct RetentionRatioTruncation {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TokenLimits {
     /// Maximum tokens allowed in the conversation after instructions (which including tool
     /// definitions). For example, setting this to 5,000 would mean that truncation would occur
@@ -327,7 +346,8 @@ pub struct TokenLimits {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum Session {
     // Boxed as per clippy suggestion:
@@ -342,7 +362,8 @@ pub enum Session {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum RealtimeSessionConfiguration {
     Realtime(RealtimeSession),
@@ -357,7 +378,8 @@ impl Default for RealtimeSessionConfiguration {
 /// Realtime session object configuration.
 /// openapi spec type: RealtimeSessionCreateRequestGA
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeSession {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub audio: Option<Audio>,
@@ -440,7 +462,8 @@ pub struct RealtimeSession {
 /// headphones, `far_field` is for far-field microphones such as laptop or conference
 /// room microphones.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum NoiseReductionType {
     NearField,
@@ -448,6 +471,8 @@ pub enum NoiseReductionType {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TranscriptionAudio {
     pub input: AudioInput,
 }
@@ -455,7 +480,8 @@ pub struct TranscriptionAudio {
 /// Realtime transcription session object configuration.
 /// openapi spec type: RealtimeTranscriptionSessionCreateRequestGA
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RealtimeTranscriptionSession {
     /// Configuration for input and output audio.
     pub audio: TranscriptionAudio,
@@ -16,6 +16,7 @@ use crate::types::responses::{
     TextResponseFormatConfiguration, Tool, ToolChoiceCustom, ToolChoiceFunction, ToolChoiceMCP,
     ToolChoiceOptions, ToolChoiceParam, ToolChoiceTypes, ToolSearchCallItemParam,
     ToolSearchOutputItemParam, ToolSearchToolParam, WebSearchTool, WebSearchToolCall,
+    WebSearchToolFilters,
 };
 
 impl<S: Into<String>> From<S> for EasyInputMessage {
@@ -545,6 +546,24 @@ impl From<ToolSearchOutputItemParam> for Item {
 
 // Tool ergonomics
 
+#[cfg(feature = "schema")]
+impl FunctionTool {
+    /// A strict function whose parameters are the JSON schema of `T`, see
+    /// [ResponseFormatJsonSchema::for_type].
+    pub fn from_schema<T: schemars::JsonSchema>(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        FunctionTool {
+            name: name.into(),
+            parameters: ResponseFormatJsonSchema::for_type::<T>().schema,
+            strict: Some(true),
+            description: Some(description.into()),
+            defer_loading: None,
+        }
+    }
+}
+
 impl From<FunctionTool> for Tool {
     fn from(tool: FunctionTool) -> Self {
         Tool::Function(tool)
@@ -689,6 +708,49 @@ impl From<&str> for EasyInputContent {
 
 // Defaults
 
+impl FileSearchTool {
+    /// Searches the vector stores `vector_store_ids`.
+    pub fn new<I, S>(vector_store_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            vector_store_ids: vector_store_ids.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl WebSearchToolFilters {
+    /// Restricts the search to `domains` and their subdomains.
+    pub fn allowed_domains<I, S>(domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            allowed_domains: Some(domains.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl CodeInterpreterTool {
+    /// A code interpreter in a new container with access to the uploaded files `file_ids`.
+    pub fn with_files<I, S>(file_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            container: CodeInterpreterToolContainer::Auto(CodeInterpreterContainerAuto {
+                file_ids: Some(file_ids.into_iter().map(Into::into).collect()),
+                memory_limit: None,
+            }),
+        }
+    }
+}
+
 impl Default for CodeInterpreterToolContainer {
     fn default() -> Self {
         Self::Auto(CodeInterpreterContainerAuto::default())
@@ -22,6 +22,7 @@ pub use crate::types::shared::GrammarSyntax;
 pub use crate::types::shared::ImageDetail;
 pub use crate::types::shared::InputTokenDetails;
 pub use crate::types::shared::OutputTokenDetails;
+pub use crate::types::shared::PartialJsonAccumulator;
 pub use crate::types::shared::PromptTokensDetails;
 pub use crate::types::shared::ReasoningEffort;
 pub use crate::types::shared::ResponseFormat;
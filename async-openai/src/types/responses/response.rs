@@ -4,6 +4,7 @@ use crate::types::responses::{
     CustomGrammarFormatParam, Filter, ImageDetail, ReasoningEffort, ResponseFormatJsonSchema,
     ResponseUsage, SummaryTextContent,
 };
+use crate::types::validation::{check_min, check_range};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -12,7 +13,8 @@ use std::collections::HashMap;
 /// For models like `gpt-5.3-codex` and beyond, when sending follow-up requests, preserve and resend
 /// phase on all assistant messages — dropping it can degrade performance.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum MessagePhase {
     Commentary,
@@ -21,7 +23,8 @@ pub enum MessagePhase {
 
 /// Whether tool search was executed by the server or by the client.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ToolSearchExecutionType {
     Server,
@@ -30,7 +33,8 @@ pub enum ToolSearchExecutionType {
 
 /// The type of content to search for.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum SearchContentType {
     Text,
@@ -39,7 +43,8 @@ pub enum SearchContentType {
 
 /// The status of a function call.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum FunctionCallStatus {
     InProgress,
@@ -49,7 +54,8 @@ pub enum FunctionCallStatus {
 
 /// The status of a function call output.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum FunctionCallOutputStatusEnum {
     InProgress,
@@ -60,12 +66,14 @@ pub enum FunctionCallOutputStatusEnum {
 /// A tool that controls a virtual computer. Learn more about the
 /// [computer tool](https://platform.openai.com/docs/guides/tools-computer-use).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ComputerTool {}
 
 /// Groups function/custom tools under a shared namespace.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Builder, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "NamespaceToolParamArgs",
     pattern = "mutable",
@@ -84,7 +92,8 @@ pub struct NamespaceToolParam {
 
 /// A function or custom tool that belongs to a namespace.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum NamespaceToolParamTool {
     Function(FunctionToolParam),
@@ -93,7 +102,8 @@ pub enum NamespaceToolParamTool {
 
 /// A function tool that can be used within a namespace or with tool search.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "FunctionToolParamArgs",
     pattern = "mutable",
@@ -109,7 +119,7 @@ pub struct FunctionToolParam {
     pub description: Option<String>,
     /// A JSON schema object describing the parameters of the function.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub parameters: Option<serde_json::Value>,
     /// Whether to enforce strict parameter validation.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -121,7 +131,8 @@ pub struct FunctionToolParam {
 
 /// Hosted or BYOT tool search configuration for deferred tools.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "ToolSearchToolParamArgs",
     pattern = "mutable",
@@ -138,13 +149,14 @@ pub struct ToolSearchToolParam {
     pub description: Option<String>,
     /// Parameter schema for a client-executed tool search tool.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub parameters: Option<serde_json::Value>,
 }
 
 /// A tool search call output item.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ToolSearchCall {
     /// The unique ID of the tool search call item.
     pub id: String,
@@ -153,7 +165,7 @@ pub struct ToolSearchCall {
     /// Whether tool search was executed by the server or by the client.
     pub execution: ToolSearchExecutionType,
     /// Arguments used for the tool search call.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub arguments: serde_json::Value,
     /// The status of the tool search call item.
     pub status: FunctionCallStatus,
@@ -164,7 +176,8 @@ pub struct ToolSearchCall {
 
 /// A tool search call input item.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ToolSearchCallItemParam {
     /// The unique ID of this tool search call.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -177,7 +190,7 @@ pub struct ToolSearchCallItemParam {
     pub execution: Option<ToolSearchExecutionType>,
     /// The arguments supplied to the tool search call.
     #[serde(default)]
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub arguments: serde_json::Value,
     /// The status of the tool search call.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -186,7 +199,8 @@ pub struct ToolSearchCallItemParam {
 
 /// A tool search output item.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ToolSearchOutput {
     /// The unique ID of the tool search output item.
     pub id: String,
@@ -205,7 +219,8 @@ pub struct ToolSearchOutput {
 
 /// A tool search output input item.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ToolSearchOutputItemParam {
     /// The unique ID of this tool search output.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -225,7 +240,8 @@ pub struct ToolSearchOutputItemParam {
 
 /// Role of messages in the API.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Role {
     #[default]
@@ -237,7 +253,8 @@ pub enum Role {
 
 /// Status of input/output items.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum OutputStatus {
     InProgress,
@@ -246,7 +263,8 @@ pub enum OutputStatus {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum InputParam {
     ///  A text input to the model, equivalent to a text input with the
@@ -265,7 +283,8 @@ pub enum InputParam {
 /// # OpenAPI Specification
 /// Corresponds to the `Item` schema in the OpenAPI spec with a `type` discriminator.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Item {
     /// A message (type: "message").
@@ -372,7 +391,8 @@ pub enum Item {
 /// # OpenAPI Specification
 /// Corresponds to the `InputItem` schema: `anyOf[EasyInputMessage, Item, ItemReferenceParam]`
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum InputItem {
     /// A reference to an existing item by ID.
@@ -407,7 +427,8 @@ pub enum InputItem {
 /// Note: EasyInputMessage is NOT included here - it's a separate variant in `InputItem`,
 /// not part of the structured `Item` enum.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum MessageItem {
     /// An output message from the model (role: assistant, has required id & status).
@@ -425,7 +446,8 @@ pub enum MessageItem {
 
 /// A reference to an existing item by ID.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ItemReference {
     /// The type of item to reference. Can be "item_reference" or null.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -435,7 +457,8 @@ pub struct ItemReference {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ItemReferenceType {
     ItemReference,
@@ -443,7 +466,8 @@ pub enum ItemReferenceType {
 
 /// Output from a function call that you're providing back to the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionCallOutputItemParam {
     /// The unique ID of the function tool call generated by the model.
     pub call_id: String,
@@ -460,7 +484,8 @@ pub struct FunctionCallOutputItemParam {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum FunctionCallOutput {
     /// A JSON string of the output of the function tool call.
@@ -469,7 +494,8 @@ pub enum FunctionCallOutput {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ComputerCallOutputItemParam {
     /// The ID of the computer tool call that produced the output.
     pub call_id: String,
@@ -488,7 +514,8 @@ pub struct ComputerCallOutputItemParam {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ComputerScreenshotImageType {
     ComputerScreenshot,
@@ -496,7 +523,8 @@ pub enum ComputerScreenshotImageType {
 
 /// A computer screenshot image used with the computer use tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ComputerScreenshotImage {
     /// Specifies the event type. For a computer screenshot, this property is always
     /// set to `computer_screenshot`.
@@ -511,7 +539,8 @@ pub struct ComputerScreenshotImage {
 
 /// Output from a local shell tool call that you're providing back to the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct LocalShellToolCallOutput {
     /// The unique ID of the local shell tool call generated by the model.
     pub id: String,
@@ -526,7 +555,8 @@ pub struct LocalShellToolCallOutput {
 
 /// Output from a local shell command execution.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct LocalShellOutput {
     /// The stdout output from the command.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -543,7 +573,8 @@ pub struct LocalShellOutput {
 
 /// An MCP approval response that you're providing back to the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct MCPApprovalResponse {
     /// The ID of the approval request being answered.
     pub approval_request_id: String,
@@ -561,7 +592,8 @@ pub struct MCPApprovalResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum CustomToolCallOutputOutput {
     /// A string of the output of the custom tool call.
@@ -571,7 +603,8 @@ pub enum CustomToolCallOutputOutput {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CustomToolCallOutput {
     /// The call ID, used to map this custom tool call output to a custom tool call.
     pub call_id: String,
@@ -591,7 +624,8 @@ pub struct CustomToolCallOutput {
 /// string content and structured content. Role can include `assistant` for providing
 /// previous assistant responses.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "EasyInputMessageArgs",
     pattern = "mutable",
@@ -619,7 +653,8 @@ pub struct EasyInputMessage {
 /// This variant requires structured content (not a simple string) and does not support
 /// the `assistant` role (use OutputMessage for that). status is populated when items are returned via API.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "InputMessageArgs",
     pattern = "mutable",
@@ -644,7 +679,8 @@ pub struct InputMessage {
 /// The role for an input message - can only be `user`, `system`, or `developer`.
 /// This type ensures type safety by excluding the `assistant` role (use OutputMessage for that).
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum InputRole {
     #[default]
@@ -655,7 +691,8 @@ pub enum InputRole {
 
 /// Content for EasyInputMessage - can be a simple string or structured list.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum EasyInputContent {
     /// A text input to the model.
@@ -666,7 +703,8 @@ pub enum EasyInputContent {
 
 /// Parts of a message: text, image, file, or audio.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum InputContent {
     /// A text input to the model.
@@ -679,14 +717,16 @@ pub enum InputContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InputTextContent {
     /// The text input to the model.
     pub text: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "InputImageArgs",
     pattern = "mutable",
@@ -708,7 +748,8 @@ pub struct InputImageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "InputFileArgs",
     pattern = "mutable",
@@ -734,14 +775,16 @@ pub struct InputFileContent {
 /// The conversation that this response belonged to. Input items and output items from this
 /// response were automatically added to this conversation.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Conversation {
     /// The unique ID of the conversation that this response was associated with.
     pub id: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum ConversationParam {
     /// The unique ID of the conversation.
@@ -751,7 +794,8 @@ pub enum ConversationParam {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum IncludeEnum {
     #[serde(rename = "file_search_call.results")]
     FileSearchCallResults,
@@ -772,7 +816,8 @@ pub enum IncludeEnum {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseStreamOptions {
     /// When true, stream obfuscation will be enabled. Stream obfuscation adds
     /// random characters to an `obfuscation` field on streaming delta events to
@@ -787,14 +832,15 @@ pub struct ResponseStreamOptions {
 
 /// Builder for a Responses API request.
 #[derive(Clone, Serialize, Deserialize, Debug, Default, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "CreateResponseArgs",
     pattern = "mutable",
     setter(into, strip_option),
     default
 )]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(name = "build_unchecked", error = "OpenAIError"))]
 pub struct CreateResponse {
     /// Whether to run the model response in the background.
     /// [Learn more](https://platform.openai.com/docs/guides/background).
@@ -1006,18 +1052,50 @@ pub struct CreateResponse {
     pub truncation: Option<Truncation>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+impl CreateResponseArgs {
+    /// Appends `tool` to the [tools](CreateResponse::tools), e.g. a [FunctionTool] or a hosted
+    /// tool such as [WebSearchTool].
+    pub fn tool(&mut self, tool: impl Into<Tool>) -> &mut Self {
+        self.tools
+            .get_or_insert(None)
+            .get_or_insert_with(Vec::new)
+            .push(tool.into());
+        self
+    }
+
+    /// Builds the request after checking its parameters with [validate](Self::validate).
+    ///
+    /// Use [build_unchecked](Self::build_unchecked) for OpenAI compatible servers accepting values
+    /// that the OpenAI API does not.
+    pub fn build(&self) -> Result<CreateResponse, OpenAIError> {
+        self.validate()?;
+        self.build_unchecked()
+    }
+
+    /// Checks the documented ranges of the parameters set so far, called by [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        check_range("temperature", self.temperature.flatten(), 0.0, 2.0)?;
+        check_range("top_p", self.top_p.flatten(), 0.0, 1.0)?;
+        check_range("top_logprobs", self.top_logprobs.flatten(), 0, 20)?;
+        check_min("max_output_tokens", self.max_output_tokens.flatten(), 16)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum ResponsePromptVariables {
     String(String),
     Content(InputContent),
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     Custom(serde_json::Value),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Prompt {
     /// The unique identifier of the prompt template to use.
     pub id: String,
@@ -1034,7 +1112,8 @@ pub struct Prompt {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ServiceTier {
     #[default]
@@ -1047,7 +1126,8 @@ pub enum ServiceTier {
 
 /// Truncation strategies.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Truncation {
     Auto,
@@ -1055,14 +1135,16 @@ pub enum Truncation {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Billing {
     pub payer: String,
 }
 
 /// o-series reasoning settings.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "ReasoningArgs",
     pattern = "mutable",
@@ -1092,7 +1174,8 @@ pub struct Reasoning {
 
 /// o-series reasoning settings.
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Verbosity {
     Low,
@@ -1101,7 +1184,8 @@ pub enum Verbosity {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ReasoningSummary {
     Auto,
@@ -1111,7 +1195,8 @@ pub enum ReasoningSummary {
 
 /// The retention policy for the prompt cache.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum PromptCacheRetention {
     #[serde(rename = "in_memory")]
     InMemory,
@@ -1121,7 +1206,8 @@ pub enum PromptCacheRetention {
 
 /// Configuration for text response format.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseTextParam {
     /// An object specifying the format that the model must output.
     ///
@@ -1147,7 +1233,8 @@ pub struct ResponseTextParam {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum TextResponseFormatConfiguration {
     /// Default response format. Used to generate text responses.
@@ -1164,7 +1251,8 @@ pub enum TextResponseFormatConfiguration {
 
 /// Definitions for model-callable tools.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Tool {
     /// Defines a function in your own code the model can choose to call. Learn more about [function
@@ -1214,7 +1302,8 @@ pub enum Tool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CustomToolParam {
     /// The name of the custom tool, used to identify it in tool calls.
     pub name: String,
@@ -1228,7 +1317,8 @@ pub struct CustomToolParam {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum CustomToolParamFormat {
     /// Unconstrained free-form text.
@@ -1239,7 +1329,8 @@ pub enum CustomToolParamFormat {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "FileSearchToolArgs",
     pattern = "mutable",
@@ -1262,7 +1353,8 @@ pub struct FileSearchTool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "FunctionToolArgs",
     pattern = "mutable",
@@ -1274,7 +1366,7 @@ pub struct FunctionTool {
     pub name: String,
     /// A JSON schema object describing the parameters of the function.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub parameters: Option<serde_json::Value>,
     /// Whether to enforce strict parameter validation. Default `true`.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1289,7 +1381,8 @@ pub struct FunctionTool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WebSearchToolFilters {
     /// Allowed domains for the search. If not provided, all domains are allowed.
     /// Subdomains of the provided domains are allowed as well.
@@ -1300,7 +1393,8 @@ pub struct WebSearchToolFilters {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "WebSearchToolArgs",
     pattern = "mutable",
@@ -1324,7 +1418,8 @@ pub struct WebSearchTool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum WebSearchToolSearchContextSize {
     Low,
@@ -1334,7 +1429,8 @@ pub enum WebSearchToolSearchContextSize {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ComputerEnvironment {
     Windows,
@@ -1346,7 +1442,8 @@ pub enum ComputerEnvironment {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "ComputerUsePreviewToolArgs",
     pattern = "mutable",
@@ -1363,7 +1460,8 @@ pub struct ComputerUsePreviewTool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum RankVersionType {
     #[serde(rename = "auto")]
     Auto,
@@ -1372,7 +1470,8 @@ pub enum RankVersionType {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct HybridSearch {
     /// The weight of the embedding in the reciprocal ranking fusion.
     pub embedding_weight: f32,
@@ -1382,7 +1481,8 @@ pub struct HybridSearch {
 
 /// Options for search result ranking.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RankingOptions {
     /// Weights that control how reciprocal rank fusion balances semantic embedding matches versus
     /// sparse keyword matches when hybrid search is enabled.
@@ -1397,7 +1497,8 @@ pub struct RankingOptions {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum WebSearchApproximateLocationType {
     #[default]
@@ -1406,7 +1507,8 @@ pub enum WebSearchApproximateLocationType {
 
 /// Approximate user location for web search.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "WebSearchApproximateLocationArgs",
     pattern = "mutable",
@@ -1436,7 +1538,8 @@ pub struct WebSearchApproximateLocation {
 
 /// Container configuration for a code interpreter.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CodeInterpreterToolContainer {
     /// Configuration for a code interpreter container. Optionally specify the IDs of the
@@ -1450,7 +1553,8 @@ pub enum CodeInterpreterToolContainer {
 
 /// Auto configuration for code interpreter container.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CodeInterpreterContainerAuto {
     /// An optional list of uploaded files to make available to your code.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1461,7 +1565,8 @@ pub struct CodeInterpreterContainerAuto {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "CodeInterpreterToolArgs",
     pattern = "mutable",
@@ -1477,7 +1582,8 @@ pub struct CodeInterpreterTool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageGenToolInputImageMask {
     /// Base64-encoded mask image.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1488,7 +1594,8 @@ pub struct ImageGenToolInputImageMask {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum InputFidelity {
     #[default]
@@ -1497,7 +1604,8 @@ pub enum InputFidelity {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageGenToolModeration {
     #[default]
@@ -1507,7 +1615,8 @@ pub enum ImageGenToolModeration {
 
 /// Whether to generate a new image or edit an existing image.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageGenActionEnum {
     /// Generate a new image.
@@ -1521,7 +1630,8 @@ pub enum ImageGenActionEnum {
 
 /// Image generation tool definition.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default, Builder)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "ImageGenerationArgs",
     pattern = "mutable",
@@ -1573,7 +1683,8 @@ pub struct ImageGenTool {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageGenToolBackground {
     Transparent,
@@ -1583,7 +1694,8 @@ pub enum ImageGenToolBackground {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageGenToolOutputFormat {
     #[default]
@@ -1593,7 +1705,8 @@ pub enum ImageGenToolOutputFormat {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageGenToolQuality {
     Low,
@@ -1604,7 +1717,8 @@ pub enum ImageGenToolQuality {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ImageGenToolSize {
     #[default]
@@ -1618,7 +1732,8 @@ pub enum ImageGenToolSize {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ToolChoiceAllowedMode {
     Auto,
@@ -1626,7 +1741,8 @@ pub enum ToolChoiceAllowedMode {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ToolChoiceAllowed {
     /// Constrains the tools available to the model to a pre-defined set.
     ///
@@ -1645,14 +1761,15 @@ pub struct ToolChoiceAllowed {
     ///   { "type": "image_generation" }
     /// ]
     /// ```
-    #[schema(value_type = Vec<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<Object>))]
     pub tools: Vec<serde_json::Value>,
 }
 
 /// The type of hosted tool the model should to use. Learn more about
 /// [built-in tools](https://platform.openai.com/docs/guides/tools).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ToolChoiceTypes {
     FileSearch,
@@ -1667,14 +1784,16 @@ pub enum ToolChoiceTypes {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ToolChoiceFunction {
     /// The name of the function to call.
     pub name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ToolChoiceMCP {
     /// The name of the tool to call on the server.
     pub name: String,
@@ -1683,14 +1802,16 @@ pub struct ToolChoiceMCP {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ToolChoiceCustom {
     /// The name of the custom tool to call.
     pub name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ToolChoiceParam {
     /// Constrains the tools available to the model to a pre-defined set.
@@ -1729,7 +1850,8 @@ pub enum ToolChoiceParam {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ToolChoiceOptions {
     None,
@@ -1739,7 +1861,8 @@ pub enum ToolChoiceOptions {
 
 /// An error that occurred while generating the response.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ErrorObject {
     /// A machine-readable error code that was returned.
     pub code: String,
@@ -1749,14 +1872,16 @@ pub struct ErrorObject {
 
 /// Details about an incomplete response.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct IncompleteDetails {
     /// The reason why the response is incomplete.
     pub reason: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TopLogProb {
     pub bytes: Vec<u8>,
     pub logprob: f64,
@@ -1764,7 +1889,8 @@ pub struct TopLogProb {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct LogProb {
     pub bytes: Vec<u8>,
     pub logprob: f64,
@@ -1773,7 +1899,8 @@ pub struct LogProb {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseTopLobProb {
     /// The log probability of this token.
     pub logprob: f64,
@@ -1782,7 +1909,8 @@ pub struct ResponseTopLobProb {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseLogProb {
     /// The log probability of this token.
     pub logprob: f64,
@@ -1794,7 +1922,8 @@ pub struct ResponseLogProb {
 
 /// A simple text output from the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct OutputTextContent {
     /// The annotations of the text output.
     pub annotations: Vec<Annotation>,
@@ -1805,7 +1934,8 @@ pub struct OutputTextContent {
 
 /// An annotation that applies to a span of output text.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Annotation {
     /// A citation to a file.
@@ -1819,56 +1949,61 @@ pub enum Annotation {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FileCitationBody {
     /// The ID of the file.
-    file_id: String,
+    pub file_id: String,
     /// The filename of the file cited.
-    filename: String,
+    pub filename: String,
     /// The index of the file in the list of files.
-    index: u32,
+    pub index: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UrlCitationBody {
     /// The index of the last character of the URL citation in the message.
-    end_index: u32,
+    pub end_index: u32,
     /// The index of the first character of the URL citation in the message.
-    start_index: u32,
+    pub start_index: u32,
     /// The title of the web resource.
-    title: String,
+    pub title: String,
     /// The URL of the web resource.
-    url: String,
+    pub url: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ContainerFileCitationBody {
     /// The ID of the container file.
-    container_id: String,
+    pub container_id: String,
     /// The index of the last character of the container file citation in the message.
-    end_index: u32,
+    pub end_index: u32,
     /// The ID of the file.
-    file_id: String,
+    pub file_id: String,
     /// The filename of the container file cited.
-    filename: String,
+    pub filename: String,
     /// The index of the first character of the container file citation in the message.
-    start_index: u32,
+    pub start_index: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FilePath {
     /// The ID of the file.
-    file_id: String,
+    pub file_id: String,
     /// The index of the file in the list of files.
-    index: u32,
+    pub index: u32,
 }
 
 /// A refusal explanation from the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RefusalContent {
     /// The refusal explanation from the model.
     pub refusal: String,
@@ -1876,7 +2011,8 @@ pub struct RefusalContent {
 
 /// A message generated by the model.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct OutputMessage {
     /// The content of the output message.
     pub content: Vec<OutputMessageContent>,
@@ -1896,7 +2032,8 @@ pub struct OutputMessage {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum MessageType {
     #[default]
@@ -1906,7 +2043,8 @@ pub enum MessageType {
 /// The role for an output message - always `assistant`.
 /// This type ensures type safety by only allowing the assistant role.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum AssistantRole {
     #[default]
@@ -1914,7 +2052,8 @@ pub enum AssistantRole {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum OutputMessageContent {
     /// A text output from the model.
@@ -1924,7 +2063,8 @@ pub enum OutputMessageContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum OutputContent {
     /// A text output from the model.
@@ -1936,7 +2076,8 @@ pub enum OutputContent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ReasoningTextContent {
     /// The reasoning text from the model.
     pub text: String,
@@ -1944,7 +2085,8 @@ pub struct ReasoningTextContent {
 
 /// A reasoning item representing the model's chain of thought, including summary paragraphs.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ReasoningItem {
     /// Unique identifier of the reasoning content.
     pub id: String,
@@ -1964,7 +2106,8 @@ pub struct ReasoningItem {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SummaryPart {
     SummaryText(SummaryTextContent),
@@ -1972,7 +2115,8 @@ pub enum SummaryPart {
 
 /// File search tool call output.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FileSearchToolCall {
     /// The unique ID of the file search tool call.
     pub id: String,
@@ -1987,7 +2131,8 @@ pub struct FileSearchToolCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum FileSearchToolCallStatus {
     InProgress,
@@ -1999,13 +2144,14 @@ pub enum FileSearchToolCallStatus {
 
 /// A single result from a file search.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FileSearchToolCallResult {
     /// Set of 16 key-value pairs that can be attached to an object. This can be useful for storing
     /// additional information about the object in a structured format, and querying for objects
     /// API or the dashboard. Keys are strings with a maximum length of 64 characters
     /// . Values are strings with a maximum length of 512 characters, booleans, or numbers.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub attributes: HashMap<String, serde_json::Value>,
     /// The unique ID of the file.
     pub file_id: String,
@@ -2018,7 +2164,8 @@ pub struct FileSearchToolCallResult {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ComputerCallSafetyCheckParam {
     /// The ID of the pending safety check.
     pub id: String,
@@ -2031,7 +2178,8 @@ pub struct ComputerCallSafetyCheckParam {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum WebSearchToolCallStatus {
     InProgress,
@@ -2041,7 +2189,8 @@ pub enum WebSearchToolCallStatus {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WebSearchActionSearchSource {
     /// The type of source. Always `url`.
     pub r#type: String,
@@ -2050,7 +2199,8 @@ pub struct WebSearchActionSearchSource {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WebSearchActionSearch {
     /// The search query.
     pub query: String,
@@ -2059,14 +2209,16 @@ pub struct WebSearchActionSearch {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WebSearchActionOpenPage {
     /// The URL opened by the model.
     pub url: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WebSearchActionFind {
     /// The URL of the page searched for the pattern.
     pub url: String,
@@ -2075,7 +2227,8 @@ pub struct WebSearchActionFind {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum WebSearchToolCallAction {
     /// Action type "search" - Performs a web search query.
@@ -2090,7 +2243,8 @@ pub enum WebSearchToolCallAction {
 
 /// Web search tool call output.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct WebSearchToolCall {
     /// An object describing the specific action taken in this web search call. Includes
     /// details on how the model used the web (search, open_page, find, find_in_page).
@@ -2103,7 +2257,8 @@ pub struct WebSearchToolCall {
 
 /// Output from a computer tool call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ComputerToolCall {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub action: Option<ComputerAction>,
@@ -2124,7 +2279,8 @@ pub struct ComputerToolCall {
 
 /// An x/y coordinate pair.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CoordParam {
     /// The x-coordinate.
     pub x: i32,
@@ -2134,7 +2290,8 @@ pub struct CoordParam {
 
 /// Represents all user‐triggered actions.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ComputerAction {
     /// A click action.
@@ -2166,7 +2323,8 @@ pub enum ComputerAction {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum ClickButtonType {
     Left,
@@ -2178,7 +2336,8 @@ pub enum ClickButtonType {
 
 /// A click action.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ClickParam {
     /// Indicates which mouse button was pressed during the click. One of `left`,
     /// `right`, `wheel`, `back`, or `forward`.
@@ -2191,7 +2350,8 @@ pub struct ClickParam {
 
 /// A double click action.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DoubleClickAction {
     /// The x-coordinate where the double click occurred.
     pub x: i32,
@@ -2201,7 +2361,8 @@ pub struct DoubleClickAction {
 
 /// A drag action.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DragParam {
     /// An array of coordinates representing the path of the drag action.
     pub path: Vec<CoordParam>,
@@ -2209,7 +2370,8 @@ pub struct DragParam {
 
 /// A keypress action.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct KeyPressAction {
     /// The combination of keys the model is requesting to be pressed.
     /// This is an array of strings, each representing a key.
@@ -2218,7 +2380,8 @@ pub struct KeyPressAction {
 
 /// A mouse move action.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct MoveParam {
     /// The x-coordinate to move to.
     pub x: i32,
@@ -2228,7 +2391,8 @@ pub struct MoveParam {
 
 /// A scroll action.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ScrollParam {
     /// The horizontal scroll distance.
     pub scroll_x: i32,
@@ -2242,14 +2406,16 @@ pub struct ScrollParam {
 
 /// A typing (text entry) action.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TypeParam {
     /// The text to type.
     pub text: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionToolCall {
     /// A JSON string of the arguments to pass to the function.
     pub arguments: String,
@@ -2270,7 +2436,8 @@ pub struct FunctionToolCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ImageGenToolCallStatus {
     InProgress,
@@ -2280,7 +2447,8 @@ pub enum ImageGenToolCallStatus {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageGenToolCall {
     /// The unique ID of the image generation call.
     pub id: String,
@@ -2291,7 +2459,8 @@ pub struct ImageGenToolCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum CodeInterpreterToolCallStatus {
     InProgress,
@@ -2303,7 +2472,8 @@ pub enum CodeInterpreterToolCallStatus {
 
 /// Output of a code interpreter request.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CodeInterpreterToolCall {
     /// The code to run, or null if not available.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2323,7 +2493,8 @@ pub struct CodeInterpreterToolCall {
 
 /// Individual result from a code interpreter: either logs or files.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum CodeInterpreterToolCallOutput {
     /// Code interpreter output logs
@@ -2333,21 +2504,24 @@ pub enum CodeInterpreterToolCallOutput {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CodeInterpreterOutputLogs {
     /// The logs output from the code interpreter.
     pub logs: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CodeInterpreterOutputImage {
     /// The URL of the image output from the code interpreter.
     pub url: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CodeInterpreterFile {
     /// The ID of the file.
     file_id: String,
@@ -2356,7 +2530,8 @@ pub struct CodeInterpreterFile {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct LocalShellToolCall {
     /// Execute a shell command on the server.
     pub action: LocalShellExecAction,
@@ -2370,7 +2545,8 @@ pub struct LocalShellToolCall {
 
 /// Define the shape of a local shell action (exec).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct LocalShellExecAction {
     /// The command to run.
     pub command: Vec<String>,
@@ -2386,7 +2562,8 @@ pub struct LocalShellExecAction {
 
 /// Commands and limits describing how to run the shell tool call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellActionParam {
     /// Ordered shell commands for the execution environment to run.
     pub commands: Vec<String>,
@@ -2400,7 +2577,8 @@ pub struct FunctionShellActionParam {
 
 /// Status values reported for shell tool calls.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum FunctionShellCallItemStatus {
     InProgress,
@@ -2410,7 +2588,8 @@ pub enum FunctionShellCallItemStatus {
 
 /// The environment for a shell call item (request side).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FunctionShellCallItemEnvironment {
     /// Use a local computer environment.
@@ -2421,7 +2600,8 @@ pub enum FunctionShellCallItemEnvironment {
 
 /// A tool representing a request to execute one or more shell commands.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellCallItemParam {
     /// The unique ID of the shell tool call. Populated when this item is returned via API.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2440,7 +2620,8 @@ pub struct FunctionShellCallItemParam {
 
 /// Indicates that the shell commands finished and returned an exit code.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellCallOutputExitOutcomeParam {
     /// The exit code returned by the shell process.
     pub exit_code: i32,
@@ -2448,7 +2629,8 @@ pub struct FunctionShellCallOutputExitOutcomeParam {
 
 /// The exit or timeout outcome associated with this chunk.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FunctionShellCallOutputOutcomeParam {
     Timeout,
@@ -2457,7 +2639,8 @@ pub enum FunctionShellCallOutputOutcomeParam {
 
 /// Captured stdout and stderr for a portion of a shell tool call output.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellCallOutputContentParam {
     /// Captured stdout output for this chunk of the shell call.
     pub stdout: String,
@@ -2469,7 +2652,8 @@ pub struct FunctionShellCallOutputContentParam {
 
 /// The streamed output items emitted by a shell tool call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellCallOutputItemParam {
     /// The unique ID of the shell tool call output. Populated when this item is returned via API.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2485,7 +2669,8 @@ pub struct FunctionShellCallOutputItemParam {
 
 /// Status values reported for apply_patch tool calls.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ApplyPatchCallStatusParam {
     InProgress,
@@ -2494,7 +2679,8 @@ pub enum ApplyPatchCallStatusParam {
 
 /// Instruction for creating a new file via the apply_patch tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchCreateFileOperationParam {
     /// Path of the file to create relative to the workspace root.
     pub path: String,
@@ -2504,7 +2690,8 @@ pub struct ApplyPatchCreateFileOperationParam {
 
 /// Instruction for deleting an existing file via the apply_patch tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchDeleteFileOperationParam {
     /// Path of the file to delete relative to the workspace root.
     pub path: String,
@@ -2512,7 +2699,8 @@ pub struct ApplyPatchDeleteFileOperationParam {
 
 /// Instruction for updating an existing file via the apply_patch tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchUpdateFileOperationParam {
     /// Path of the file to update relative to the workspace root.
     pub path: String,
@@ -2522,7 +2710,8 @@ pub struct ApplyPatchUpdateFileOperationParam {
 
 /// One of the create_file, delete_file, or update_file operations supplied to the apply_patch tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ApplyPatchOperationParam {
     CreateFile(ApplyPatchCreateFileOperationParam),
@@ -2532,7 +2721,8 @@ pub enum ApplyPatchOperationParam {
 
 /// A tool call representing a request to create, delete, or update files using diff patches.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchToolCallItemParam {
     /// The unique ID of the apply patch tool call. Populated when this item is returned via API.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2547,7 +2737,8 @@ pub struct ApplyPatchToolCallItemParam {
 
 /// Outcome values reported for apply_patch tool call outputs.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ApplyPatchCallOutputStatusParam {
     Completed,
@@ -2556,7 +2747,8 @@ pub enum ApplyPatchCallOutputStatusParam {
 
 /// The streamed output emitted by an apply patch tool call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchToolCallOutputItemParam {
     /// The unique ID of the apply patch tool call output. Populated when this item is returned via API.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2573,7 +2765,8 @@ pub struct ApplyPatchToolCallOutputItemParam {
 /// Shell exec action
 /// Execute a shell command.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellAction {
     /// A list of commands to run.
     pub commands: Vec<String>,
@@ -2585,7 +2778,8 @@ pub struct FunctionShellAction {
 
 /// Status values reported for function shell tool calls.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum LocalShellCallStatus {
     InProgress,
@@ -2595,7 +2789,8 @@ pub enum LocalShellCallStatus {
 
 /// The environment for a shell call (response side).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FunctionShellCallEnvironment {
     /// A local computer environment.
@@ -2606,7 +2801,8 @@ pub enum FunctionShellCallEnvironment {
 
 /// A tool call that executes one or more shell commands in a managed environment.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellCall {
     /// The unique ID of the function shell tool call. Populated when this item is returned via API.
     pub id: String,
@@ -2625,7 +2821,8 @@ pub struct FunctionShellCall {
 
 /// The content of a shell tool call output that was emitted.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellCallOutputContent {
     /// The standard output that was captured.
     pub stdout: String,
@@ -2641,7 +2838,8 @@ pub struct FunctionShellCallOutputContent {
 
 /// Function shell call outcome
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FunctionShellCallOutputOutcome {
     Timeout,
@@ -2650,7 +2848,8 @@ pub enum FunctionShellCallOutputOutcome {
 
 /// Indicates that the shell commands finished and returned an exit code.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellCallOutputExitOutcome {
     /// Exit code from the shell process.
     pub exit_code: i32,
@@ -2658,7 +2857,8 @@ pub struct FunctionShellCallOutputExitOutcome {
 
 /// The output of a shell tool call that was emitted.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellCallOutput {
     /// The unique ID of the shell call output. Populated when this item is returned via API.
     pub id: String,
@@ -2676,7 +2876,8 @@ pub struct FunctionShellCallOutput {
 
 /// Status values reported for apply_patch tool calls.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ApplyPatchCallStatus {
     InProgress,
@@ -2685,7 +2886,8 @@ pub enum ApplyPatchCallStatus {
 
 /// Instruction describing how to create a file via the apply_patch tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchCreateFileOperation {
     /// Path of the file to create.
     pub path: String,
@@ -2695,7 +2897,8 @@ pub struct ApplyPatchCreateFileOperation {
 
 /// Instruction describing how to delete a file via the apply_patch tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchDeleteFileOperation {
     /// Path of the file to delete.
     pub path: String,
@@ -2703,7 +2906,8 @@ pub struct ApplyPatchDeleteFileOperation {
 
 /// Instruction describing how to update a file via the apply_patch tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchUpdateFileOperation {
     /// Path of the file to update.
     pub path: String,
@@ -2713,7 +2917,8 @@ pub struct ApplyPatchUpdateFileOperation {
 
 /// One of the create_file, delete_file, or update_file operations applied via apply_patch.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ApplyPatchOperation {
     CreateFile(ApplyPatchCreateFileOperation),
@@ -2723,7 +2928,8 @@ pub enum ApplyPatchOperation {
 
 /// A tool call that applies file diffs by creating, deleting, or updating files.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchToolCall {
     /// The unique ID of the apply patch tool call. Populated when this item is returned via API.
     pub id: String,
@@ -2740,7 +2946,8 @@ pub struct ApplyPatchToolCall {
 
 /// Outcome values reported for apply_patch tool call outputs.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum ApplyPatchCallOutputStatus {
     Completed,
@@ -2749,7 +2956,8 @@ pub enum ApplyPatchCallOutputStatus {
 
 /// The output emitted by an apply patch tool call.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ApplyPatchToolCallOutput {
     /// The unique ID of the apply patch tool call output. Populated when this item is returned via API.
     pub id: String,
@@ -2766,7 +2974,8 @@ pub struct ApplyPatchToolCallOutput {
 
 /// Output of an MCP server tool invocation.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct MCPToolCall {
     /// A JSON string of the arguments passed to the tool.
     pub arguments: String,
@@ -2790,7 +2999,8 @@ pub struct MCPToolCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum MCPToolCallStatus {
     InProgress,
@@ -2801,7 +3011,8 @@ pub enum MCPToolCallStatus {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct MCPListTools {
     /// The unique ID of the list.
     pub id: String,
@@ -2815,7 +3026,8 @@ pub struct MCPListTools {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct MCPApprovalRequest {
     /// JSON string of arguments for the tool.
     pub arguments: String,
@@ -2828,7 +3040,8 @@ pub struct MCPApprovalRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum Instructions {
     /// A text input to the model, equivalent to a text input with the `developer` role.
@@ -2839,7 +3052,8 @@ pub enum Instructions {
 
 /// The complete response returned by the Responses API.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Response {
     /// Whether to run the model response in the background.
     /// [Learn more](https://platform.openai.com/docs/guides/background).
@@ -3042,7 +3256,8 @@ pub struct Response {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum Status {
     Completed,
@@ -3055,7 +3270,8 @@ pub enum Status {
 
 /// Output item
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "snake_case")]
 pub enum OutputItem {
@@ -3113,7 +3329,8 @@ pub enum OutputItem {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[non_exhaustive]
 pub struct CustomToolCall {
     /// An identifier used to map this custom tool call to a tool call output.
@@ -3130,7 +3347,8 @@ pub struct CustomToolCall {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteResponse {
     pub object: String,
     pub deleted: bool,
@@ -3138,14 +3356,16 @@ pub struct DeleteResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct AnyItemReference {
     pub r#type: Option<String>,
     pub id: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ItemResourceItem {
     Message(MessageItem),
@@ -3172,7 +3392,8 @@ pub enum ItemResourceItem {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum ItemResource {
     ItemReference(AnyItemReference),
@@ -3181,7 +3402,8 @@ pub enum ItemResource {
 
 /// A list of Response items.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseItemList {
     /// The type of object returned, must be `list`.
     pub object: String,
@@ -3196,7 +3418,8 @@ pub struct ResponseItemList {
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug, Default, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(
     name = "TokenCountsBodyArgs",
     pattern = "mutable",
@@ -3275,7 +3498,8 @@ pub struct TokenCountsBody {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct TokenCountsResource {
     pub object: String,
     pub input_tokens: u32,
@@ -3283,7 +3507,8 @@ pub struct TokenCountsResource {
 
 /// A compaction item generated by the `/v1/responses/compact` API.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CompactionSummaryItemParam {
     /// The ID of the compaction item.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3294,7 +3519,8 @@ pub struct CompactionSummaryItemParam {
 
 /// A compaction item generated by the `/v1/responses/compact` API.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CompactionBody {
     /// The unique ID of the compaction item.
     pub id: String,
@@ -3307,7 +3533,8 @@ pub struct CompactionBody {
 
 /// Request to compact a conversation.
 #[derive(Clone, Serialize, Default, Debug, Deserialize, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CompactResponseRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -3344,7 +3571,8 @@ pub struct CompactResponseRequest {
 
 /// The compacted response object.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CompactResource {
     /// The unique identifier for the compacted response.
     pub id: String,
@@ -3365,7 +3593,8 @@ pub struct CompactResource {
 
 /// A domain-scoped secret injected for allowlisted domains.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ContainerNetworkPolicyDomainSecretParam {
     /// The domain associated with the secret.
     pub domain: String,
@@ -3377,7 +3606,8 @@ pub struct ContainerNetworkPolicyDomainSecretParam {
 
 /// Details for an allowlist network policy.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ContainerNetworkPolicyAllowlistDetails {
     /// A list of allowed domains.
     pub allowed_domains: Vec<String>,
@@ -3388,7 +3618,8 @@ pub struct ContainerNetworkPolicyAllowlistDetails {
 
 /// Network access policy for a container.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ContainerNetworkPolicy {
     /// Disable all outbound network access.
@@ -3399,7 +3630,8 @@ pub enum ContainerNetworkPolicy {
 
 /// A skill referenced by ID.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SkillReferenceParam {
     /// The ID of the skill to reference.
     pub skill_id: String,
@@ -3410,7 +3642,8 @@ pub struct SkillReferenceParam {
 
 /// An inline skill source (base64-encoded zip).
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InlineSkillSourceParam {
     /// The media type. Always `"application/zip"`.
     pub media_type: String,
@@ -3420,7 +3653,8 @@ pub struct InlineSkillSourceParam {
 
 /// An inline skill definition.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InlineSkillParam {
     /// The name of the skill.
     pub name: String,
@@ -3432,7 +3666,8 @@ pub struct InlineSkillParam {
 
 /// A skill parameter — either a reference or inline definition.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SkillParam {
     /// Reference a skill by ID.
@@ -3443,7 +3678,8 @@ pub enum SkillParam {
 
 /// Automatically creates a container for the request.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ContainerAutoParam {
     /// An optional list of uploaded file IDs to make available in the container.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3458,7 +3694,8 @@ pub struct ContainerAutoParam {
 
 /// A local skill available in a local environment.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct LocalSkillParam {
     /// The name of the skill.
     pub name: String,
@@ -3470,7 +3707,8 @@ pub struct LocalSkillParam {
 
 /// Uses a local computer environment.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct LocalEnvironmentParam {
     /// An optional list of local skills.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3479,7 +3717,8 @@ pub struct LocalEnvironmentParam {
 
 /// References a container created with the /v1/containers endpoint.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ContainerReferenceParam {
     /// The ID of the referenced container.
     pub container_id: String,
@@ -3487,7 +3726,8 @@ pub struct ContainerReferenceParam {
 
 /// A resource reference to a container by ID.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ContainerReferenceResource {
     /// The ID of the referenced container.
     pub container_id: String,
@@ -3495,7 +3735,8 @@ pub struct ContainerReferenceResource {
 
 /// The execution environment for a shell tool — container or local.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum FunctionShellEnvironment {
     /// Automatically creates a container for this request.
@@ -3508,7 +3749,8 @@ pub enum FunctionShellEnvironment {
 
 /// Parameters for the shell function tool.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionShellToolParam {
     /// The execution environment for the shell tool.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -3517,7 +3759,8 @@ pub struct FunctionShellToolParam {
 
 /// Context management configuration.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ContextManagementParam {
     /// The context management strategy type.
     #[serde(rename = "type")]
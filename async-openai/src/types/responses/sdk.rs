@@ -1,31 +1,69 @@
-use crate::types::responses::{OutputItem, OutputMessageContent, Response};
+use crate::types::responses::{
+    Annotation, OutputItem, OutputMessageContent, Response, SummaryPart, UrlCitationBody,
+};
 
 impl Response {
     /// SDK-only convenience property that contains the aggregated text output from all
-    /// `output_text` items in the `output` array, if any are present.
-    pub fn output_text(&self) -> Option<String> {
-        let output = self
+    /// `output_text` parts of the message items in the `output` array, skipping tool calls and
+    /// reasoning. Empty when the response has no text output.
+    pub fn output_text(&self) -> String {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                OutputItem::Message(msg) => Some(&msg.content),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|content| match content {
+                OutputMessageContent::OutputText(ot) => Some(ot.text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The reasoning summaries of all `reasoning` items in the `output` array, one paragraph
+    /// per summary part, if any are present. Summaries are only returned when requested
+    /// with `reasoning.summary`.
+    pub fn reasoning_summary(&self) -> Option<String> {
+        let summary = self
             .output
             .iter()
             .filter_map(|item| match item {
-                OutputItem::Message(msg) => Some(
-                    msg.content
-                        .iter()
-                        .filter_map(|content| match content {
-                            OutputMessageContent::OutputText(ot) => Some(ot.text.clone()),
-                            _ => None,
-                        })
-                        .collect::<Vec<String>>(),
-                ),
+                OutputItem::Reasoning(reasoning) => Some(&reasoning.summary),
                 _ => None,
             })
             .flatten()
-            .collect::<Vec<String>>()
-            .join("");
-        if output.is_empty() {
+            .map(|part| match part {
+                SummaryPart::SummaryText(summary) => summary.text.as_str(),
+            })
+            .collect::<Vec<&str>>()
+            .join("\n\n");
+        if summary.is_empty() {
             None
         } else {
-            Some(output)
+            Some(summary)
         }
     }
+
+    /// The `url_citation` annotations of all `output_text` items in the `output` array, in order.
+    /// These reference the web pages found by the `web_search` tool.
+    pub fn url_citations(&self) -> Vec<&UrlCitationBody> {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                OutputItem::Message(msg) => Some(&msg.content),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|content| match content {
+                OutputMessageContent::OutputText(ot) => Some(&ot.annotations),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|annotation| match annotation {
+                Annotation::UrlCitation(citation) => Some(citation),
+                _ => None,
+            })
+            .collect()
+    }
 }
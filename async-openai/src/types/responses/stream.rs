@@ -4,7 +4,8 @@ use crate::types::responses::{OutputContent, OutputItem, Response, ResponseLogPr
 
 /// Event types for streaming responses from the Responses API
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum ResponseStreamEvent {
     /// An event that is emitted when a response is created.
@@ -157,42 +158,48 @@ pub enum ResponseStreamEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseCreatedEvent {
     pub sequence_number: u64,
     pub response: Response,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseInProgressEvent {
     pub sequence_number: u64,
     pub response: Response,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseCompletedEvent {
     pub sequence_number: u64,
     pub response: Response,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseFailedEvent {
     pub sequence_number: u64,
     pub response: Response,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseIncompleteEvent {
     pub sequence_number: u64,
     pub response: Response,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseOutputItemAddedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -200,7 +207,8 @@ pub struct ResponseOutputItemAddedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseOutputItemDoneEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -208,7 +216,8 @@ pub struct ResponseOutputItemDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseContentPartAddedEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -218,7 +227,8 @@ pub struct ResponseContentPartAddedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseContentPartDoneEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -228,7 +238,8 @@ pub struct ResponseContentPartDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseTextDeltaEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -240,7 +251,8 @@ pub struct ResponseTextDeltaEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseTextDoneEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -251,7 +263,8 @@ pub struct ResponseTextDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseRefusalDeltaEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -261,7 +274,8 @@ pub struct ResponseRefusalDeltaEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseRefusalDoneEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -271,7 +285,8 @@ pub struct ResponseRefusalDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseFunctionCallArgumentsDeltaEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -280,7 +295,8 @@ pub struct ResponseFunctionCallArgumentsDeltaEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseFunctionCallArgumentsDoneEvent {
     /// <https://github.com/64bit/async-openai/issues/472>
     pub name: Option<String>,
@@ -291,7 +307,8 @@ pub struct ResponseFunctionCallArgumentsDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseFileSearchCallInProgressEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -299,7 +316,8 @@ pub struct ResponseFileSearchCallInProgressEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseFileSearchCallSearchingEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -307,7 +325,8 @@ pub struct ResponseFileSearchCallSearchingEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseFileSearchCallCompletedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -315,7 +334,8 @@ pub struct ResponseFileSearchCallCompletedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseWebSearchCallInProgressEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -323,7 +343,8 @@ pub struct ResponseWebSearchCallInProgressEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseWebSearchCallSearchingEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -331,7 +352,8 @@ pub struct ResponseWebSearchCallSearchingEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseWebSearchCallCompletedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -339,7 +361,8 @@ pub struct ResponseWebSearchCallCompletedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseReasoningSummaryPartAddedEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -349,7 +372,8 @@ pub struct ResponseReasoningSummaryPartAddedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseReasoningSummaryPartDoneEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -359,7 +383,8 @@ pub struct ResponseReasoningSummaryPartDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseReasoningSummaryTextDeltaEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -369,7 +394,8 @@ pub struct ResponseReasoningSummaryTextDeltaEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseReasoningSummaryTextDoneEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -379,7 +405,8 @@ pub struct ResponseReasoningSummaryTextDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseReasoningTextDeltaEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -389,7 +416,8 @@ pub struct ResponseReasoningTextDeltaEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseReasoningTextDoneEvent {
     pub sequence_number: u64,
     pub item_id: String,
@@ -399,7 +427,8 @@ pub struct ResponseReasoningTextDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseImageGenCallCompletedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -407,7 +436,8 @@ pub struct ResponseImageGenCallCompletedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseImageGenCallGeneratingEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -415,7 +445,8 @@ pub struct ResponseImageGenCallGeneratingEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseImageGenCallInProgressEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -423,7 +454,8 @@ pub struct ResponseImageGenCallInProgressEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseImageGenCallPartialImageEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -433,7 +465,8 @@ pub struct ResponseImageGenCallPartialImageEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseMCPCallArgumentsDeltaEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -442,7 +475,8 @@ pub struct ResponseMCPCallArgumentsDeltaEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseMCPCallArgumentsDoneEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -451,7 +485,8 @@ pub struct ResponseMCPCallArgumentsDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseMCPCallCompletedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -459,7 +494,8 @@ pub struct ResponseMCPCallCompletedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseMCPCallFailedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -467,7 +503,8 @@ pub struct ResponseMCPCallFailedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseMCPCallInProgressEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -475,7 +512,8 @@ pub struct ResponseMCPCallInProgressEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseMCPListToolsCompletedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -483,7 +521,8 @@ pub struct ResponseMCPListToolsCompletedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseMCPListToolsFailedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -491,7 +530,8 @@ pub struct ResponseMCPListToolsFailedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseMCPListToolsInProgressEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -499,7 +539,8 @@ pub struct ResponseMCPListToolsInProgressEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseCodeInterpreterCallInProgressEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -507,7 +548,8 @@ pub struct ResponseCodeInterpreterCallInProgressEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseCodeInterpreterCallInterpretingEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -515,7 +557,8 @@ pub struct ResponseCodeInterpreterCallInterpretingEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseCodeInterpreterCallCompletedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -523,7 +566,8 @@ pub struct ResponseCodeInterpreterCallCompletedEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseCodeInterpreterCallCodeDeltaEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -532,7 +576,8 @@ pub struct ResponseCodeInterpreterCallCodeDeltaEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseCodeInterpreterCallCodeDoneEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -541,26 +586,29 @@ pub struct ResponseCodeInterpreterCallCodeDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseOutputTextAnnotationAddedEvent {
     pub sequence_number: u64,
     pub output_index: u32,
     pub content_index: u32,
     pub annotation_index: u32,
     pub item_id: String,
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub annotation: serde_json::Value,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseQueuedEvent {
     pub sequence_number: u64,
     pub response: Response,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseCustomToolCallInputDeltaEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -569,7 +617,8 @@ pub struct ResponseCustomToolCallInputDeltaEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseCustomToolCallInputDoneEvent {
     pub sequence_number: u64,
     pub output_index: u32,
@@ -578,7 +627,8 @@ pub struct ResponseCustomToolCallInputDoneEvent {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseErrorEvent {
     pub sequence_number: u64,
     pub code: Option<String>,
@@ -2,8 +2,11 @@ use serde::{Deserialize, Serialize};
 
 /// Breakdown of tokens used in a completion.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CompletionTokensDetails {
+    /// When using Predicted Outputs, the number of tokens in the
+    /// prediction that appeared in the completion.
     pub accepted_prediction_tokens: Option<u32>,
     /// Audio input tokens generated by the model.
     pub audio_tokens: Option<u32>,
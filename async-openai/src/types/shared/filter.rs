@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 
 /// Filters for file search.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum Filter {
     /// A filter used to compare a specified attribute key to a given value using a defined
@@ -14,7 +15,8 @@ pub enum Filter {
 
 /// Single comparison filter.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ComparisonFilter {
     /// Specifies the comparison operator: `eq`, `ne`, `gt`, `gte`, `lt`, `lte`, `in`, `nin`.
     /// - `eq`: equals
@@ -29,12 +31,13 @@ pub struct ComparisonFilter {
     /// The key to compare against the value.
     pub key: String,
     /// The value to compare against the attribute key; supports string, number, or boolean types.
-    #[schema(value_type = Object)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
     pub value: serde_json::Value,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum ComparisonType {
     #[serde(rename = "eq")]
     Equals,
@@ -56,17 +59,19 @@ pub enum ComparisonType {
 
 /// Combine multiple filters using `and` or `or`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CompoundFilter {
     /// 'Type of operation: `and` or `or`.'
     pub r#type: CompoundType,
     /// Array of filters to combine. Items can be ComparisonFilter or CompoundFilter.
-    #[schema(value_type = Vec<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<Object>))]
     pub filters: Vec<Filter>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum CompoundType {
     And,
@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 
 /// The name and arguments of a function that should be called, as generated by the model.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct FunctionCall {
     /// The name of the function to call.
     pub name: String,
@@ -4,7 +4,8 @@ use serde::{Deserialize, Serialize};
 use crate::error::OpenAIError;
 
 #[derive(Clone, Serialize, Default, Debug, Deserialize, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "FunctionObjectArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -20,10 +21,39 @@ pub struct FunctionObject {
     ///
     /// Omitting `parameters` defines a function with an empty parameter list.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub parameters: Option<serde_json::Value>,
 
     /// Whether to enable strict schema adherence when generating the function call. If set to true, the model will follow the exact schema defined in the `parameters` field. Only a subset of JSON Schema is supported when `strict` is `true`. Learn more about Structured Outputs in the [function calling guide](https://platform.openai.com/docs/guides/function-calling).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strict: Option<bool>,
 }
+
+#[cfg(feature = "schema")]
+impl FunctionObject {
+    /// A strict function whose parameters are the JSON schema of `T`, see
+    /// [ResponseFormatJsonSchema::for_type](crate::types::chat::ResponseFormatJsonSchema::for_type).
+    ///
+    /// ```
+    /// # use async_openai::types::chat::FunctionObject;
+    /// #[derive(serde::Deserialize, schemars::JsonSchema)]
+    /// struct GetWeather {
+    ///     location: String,
+    /// }
+    ///
+    /// let function = FunctionObject::from_schema::<GetWeather>("get_weather", "Current weather of a location");
+    /// assert_eq!(function.strict, Some(true));
+    /// assert_eq!(function.parameters.unwrap()["required"], serde_json::json!(["location"]));
+    /// ```
+    pub fn from_schema<T: schemars::JsonSchema>(
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: Some(description.into()),
+            parameters: crate::types::shared::ResponseFormatJsonSchema::for_type::<T>().schema,
+            strict: Some(true),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use base64::{engine::general_purpose, Engine as _};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
@@ -5,7 +6,8 @@ use crate::error::OpenAIError;
 use crate::types::shared::ImageDetail;
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "ImageUrlArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -17,3 +19,59 @@ pub struct ImageUrl {
     /// Specifies the detail level of the image. Learn more in the [Vision guide](https://platform.openai.com/docs/guides/vision/low-or-high-fidelity-image-understanding).
     pub detail: Option<ImageDetail>,
 }
+
+impl ImageUrl {
+    /// Image from its encoded bytes, sent as a `data:` URL, e.g. `ImageUrl::from_bytes(png, "image/png")`.
+    pub fn from_bytes(bytes: impl AsRef<[u8]>, mime: &str) -> Self {
+        Self {
+            url: format!(
+                "data:{mime};base64,{}",
+                general_purpose::STANDARD.encode(bytes)
+            ),
+            detail: None,
+        }
+    }
+
+    /// Image read from a PNG, JPEG, GIF or WebP file, sent as a `data:` URL.
+    ///
+    /// The MIME type is detected from the content of the file, falling back to its extension.
+    #[cfg(all(feature = "_api", not(target_family = "wasm")))]
+    pub async fn from_path<P: AsRef<std::path::Path>>(path: P) -> Result<Self, OpenAIError> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path)
+            .await
+            .map_err(|e| OpenAIError::FileReadError(format!("{}: {e}", path.display())))?;
+
+        let mime = image_mime_type(&bytes)
+            .or_else(|| {
+                let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+                match extension.as_str() {
+                    "png" => Some("image/png"),
+                    "jpg" | "jpeg" => Some("image/jpeg"),
+                    "gif" => Some("image/gif"),
+                    "webp" => Some("image/webp"),
+                    _ => None,
+                }
+            })
+            .ok_or_else(|| {
+                OpenAIError::InvalidArgument(format!(
+                    "{} is not a PNG, JPEG, GIF or WebP image",
+                    path.display()
+                ))
+            })?;
+
+        Ok(Self::from_bytes(bytes, mime))
+    }
+}
+
+/// The MIME type of an image from its magic bytes, for the formats supported by vision models.
+#[cfg(all(feature = "_api", not(target_family = "wasm")))]
+fn image_mime_type(bytes: &[u8]) -> Option<&'static str> {
+    match bytes {
+        [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some("image/png"),
+        [0xFF, 0xD8, 0xFF, ..] => Some("image/jpeg"),
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some("image/gif"),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some("image/webp"),
+        _ => None,
+    }
+}
@@ -27,6 +27,8 @@ mod image_url;
 #[cfg(any(feature = "audio-types", feature = "realtime-types"))]
 mod log_prob_properties;
 #[cfg(any(feature = "chat-completion-types", feature = "response-types"))]
+mod partial_json;
+#[cfg(any(feature = "chat-completion-types", feature = "response-types"))]
 mod prompt_tokens_details;
 #[cfg(any(
     feature = "chat-completion-types",
@@ -72,6 +74,8 @@ pub use image_url::*;
 #[cfg(any(feature = "audio-types", feature = "realtime-types"))]
 pub use log_prob_properties::*;
 #[cfg(any(feature = "chat-completion-types", feature = "response-types"))]
+pub use partial_json::*;
+#[cfg(any(feature = "chat-completion-types", feature = "response-types"))]
 pub use prompt_tokens_details::*;
 #[cfg(any(
     feature = "chat-completion-types",
@@ -0,0 +1,186 @@
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+
+/// Parses the JSON content of a streamed response while it arrives, to render the fields of a
+/// structured output before the stream ends.
+///
+/// [push](Self::push) appends a content delta and returns the leniently parsed buffer so far:
+/// the open string value is closed, keys without a value and incomplete numbers or literals are
+/// dropped, and open objects and arrays are closed. The values before the last one are final,
+/// while the last one can still grow. [finish](Self::finish) parses the complete buffer as a `T`.
+///
+/// ```
+/// use async_openai::types::chat::PartialJsonAccumulator;
+/// use serde_json::json;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Answer {
+///     steps: Vec<String>,
+///     final_answer: String,
+/// }
+///
+/// let mut accumulator = PartialJsonAccumulator::<Answer>::new();
+/// assert_eq!(accumulator.push(r#"{"steps": ["add 1", "mul"#), Some(json!({"steps": ["add 1", "mul"]})));
+/// assert_eq!(accumulator.push(r#" 2"], "final_"#), Some(json!({"steps": ["add 1", "mul 2"]})));
+/// accumulator.push(r#"answer": "4"}"#);
+///
+/// let answer = accumulator.finish().unwrap();
+/// assert_eq!(answer.final_answer, "4");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PartialJsonAccumulator<T> {
+    buffer: String,
+    _output: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for PartialJsonAccumulator<T> {
+    fn default() -> Self {
+        Self {
+            buffer: String::new(),
+            _output: PhantomData,
+        }
+    }
+}
+
+impl<T: DeserializeOwned> PartialJsonAccumulator<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `delta` to the buffer and returns its [partial](Self::partial) value.
+    pub fn push(&mut self, delta: &str) -> Option<serde_json::Value> {
+        self.buffer.push_str(delta);
+        self.partial()
+    }
+
+    /// The buffer parsed leniently, `None` until it holds the start of a value.
+    pub fn partial(&self) -> Option<serde_json::Value> {
+        complete_json(&self.buffer)
+            .iter()
+            .find_map(|json| serde_json::from_str(json).ok())
+    }
+
+    /// The [partial](Self::partial) value of the buffer as a `P`, such as a variant of `T` whose
+    /// fields are all optional.
+    pub fn partial_as<P: DeserializeOwned>(&self) -> Option<P> {
+        self.partial()
+            .and_then(|value| serde_json::from_value(value).ok())
+    }
+
+    /// The content received so far.
+    pub fn buffer(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Parses the complete buffer as a `T`.
+    pub fn finish(self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.buffer)
+    }
+}
+
+/// An open container of the document, and for objects whether a key is expected next.
+#[derive(Debug, Clone, Copy)]
+enum Container {
+    Object { expect_key: bool },
+    Array,
+}
+
+impl Container {
+    fn closer(&self) -> char {
+        match self {
+            Container::Object { .. } => '}',
+            Container::Array => ']',
+        }
+    }
+}
+
+fn closers(stack: &[Container]) -> String {
+    stack.iter().rev().map(Container::closer).collect()
+}
+
+/// Candidate completions of the prefix `json` of a JSON document, most complete first: the
+/// prefix with its last value closed, then the prefix up to the last complete value.
+fn complete_json(json: &str) -> Vec<String> {
+    let mut stack: Vec<Container> = Vec::new();
+    // the end of the last complete value, with the closers of the containers open there
+    let mut checkpoint: Option<(usize, String)> = None;
+    // whether a string is being read, and whether it is an object key
+    let mut string: Option<bool> = None;
+    // the start of the escape sequence being read in a string
+    let mut escape: Option<usize> = None;
+    let mut in_scalar = false;
+
+    for (pos, c) in json.char_indices() {
+        if let Some(is_key) = string {
+            if let Some(start) = escape {
+                // `\uXXXX` escapes are 6 bytes long, the others 2
+                let unicode = json[start..].starts_with("\\u");
+                if !unicode || pos - start == 5 {
+                    escape = None;
+                }
+                continue;
+            }
+            match c {
+                '\\' => escape = Some(pos),
+                '"' => {
+                    string = None;
+                    if !is_key {
+                        checkpoint = Some((pos + 1, closers(&stack)));
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let delimiter = c.is_whitespace() || matches!(c, ',' | '}' | ']' | ':');
+        if in_scalar && delimiter {
+            in_scalar = false;
+            checkpoint = Some((pos, closers(&stack)));
+        }
+
+        match c {
+            '{' => {
+                stack.push(Container::Object { expect_key: true });
+                checkpoint = Some((pos + 1, closers(&stack)));
+            }
+            '[' => {
+                stack.push(Container::Array);
+                checkpoint = Some((pos + 1, closers(&stack)));
+            }
+            '}' | ']' => {
+                stack.pop();
+                checkpoint = Some((pos + 1, closers(&stack)));
+            }
+            ':' | ',' => {
+                if let Some(Container::Object { expect_key }) = stack.last_mut() {
+                    *expect_key = c == ',';
+                }
+            }
+            '"' => {
+                let is_key = match stack.last_mut() {
+                    Some(Container::Object { expect_key }) => std::mem::take(expect_key),
+                    _ => false,
+                };
+                string = Some(is_key);
+            }
+            c if c.is_whitespace() => {}
+            _ => in_scalar = true,
+        }
+    }
+
+    let mut candidates = Vec::new();
+    match string {
+        Some(false) => {
+            let end = escape.unwrap_or(json.len());
+            candidates.push(format!("{}\"{}", &json[..end], closers(&stack)));
+        }
+        None if in_scalar => candidates.push(format!("{json}{}", closers(&stack))),
+        _ => {}
+    }
+    if let Some((end, closers)) = checkpoint {
+        candidates.push(format!("{}{closers}", &json[..end]));
+    }
+    candidates
+}
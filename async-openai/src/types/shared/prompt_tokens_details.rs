@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 
 /// Breakdown of tokens used in a completion.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct PromptTokensDetails {
     /// Audio input tokens present in the prompt.
     pub audio_tokens: Option<u32>,
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ResponseFormat {
     /// The type of response format being defined: `text`
@@ -15,7 +16,8 @@ pub enum ResponseFormat {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ResponseFormatJsonSchema {
     /// A description of what the response format is for, used by the model to determine how to respond in the format.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -25,7 +27,7 @@ pub struct ResponseFormatJsonSchema {
     /// The schema for the response format, described as a JSON Schema object.
     /// Learn how to build JSON schemas [here](https://json-schema.org/).
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[schema(value_type = Option<Object>)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<Object>))]
     pub schema: Option<serde_json::Value>,
     /// Whether to enable strict schema adherence when generating the output.
     /// If set to true, the model will always follow the exact schema defined
@@ -35,3 +37,85 @@ pub struct ResponseFormatJsonSchema {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub strict: Option<bool>,
 }
+
+#[cfg(feature = "schema")]
+impl ResponseFormat {
+    /// Strict `json_schema` response format with the schema of `T`, see [ResponseFormatJsonSchema::for_type].
+    pub fn json_schema_for<T: schemars::JsonSchema>() -> Self {
+        ResponseFormat::JsonSchema {
+            json_schema: ResponseFormatJsonSchema::for_type::<T>(),
+        }
+    }
+}
+
+#[cfg(feature = "schema")]
+impl ResponseFormatJsonSchema {
+    /// Derives a strict JSON schema from `T`, named after `T`.
+    ///
+    /// Strict mode requires every object to list all of its properties as `required` and to
+    /// disallow `additionalProperties`, so the derived schema is adjusted accordingly.
+    /// Optional fields remain nullable.
+    pub fn for_type<T: schemars::JsonSchema>() -> Self {
+        let mut schema = schemars::schema_for!(T).to_value();
+        make_strict(&mut schema);
+
+        let description = schema
+            .get("description")
+            .and_then(|description| description.as_str())
+            .map(str::to_string);
+
+        // The name must be a-z, A-Z, 0-9, or contain underscores and dashes, with a maximum length of 64.
+        let name: String = T::schema_name()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .take(64)
+            .collect();
+
+        Self {
+            description,
+            name,
+            schema: Some(schema),
+            strict: Some(true),
+        }
+    }
+}
+
+/// Requires every property and forbids additional ones, in `schema` and the schemas nested in
+/// it. Only schema positions are visited, so that properties named like keywords, such as
+/// `properties`, are not taken for schemas.
+#[cfg(feature = "schema")]
+fn make_strict(schema: &mut serde_json::Value) {
+    let serde_json::Value::Object(object) = schema else {
+        return;
+    };
+
+    if let Some(serde_json::Value::Object(properties)) = object.get_mut("properties") {
+        properties.values_mut().for_each(make_strict);
+        let required = properties
+            .keys()
+            .cloned()
+            .map(serde_json::Value::String)
+            .collect();
+        object.insert("required".into(), serde_json::Value::Array(required));
+        object.insert("additionalProperties".into(), false.into());
+    }
+
+    for key in ["items", "anyOf", "oneOf", "allOf"] {
+        match object.get_mut(key) {
+            Some(serde_json::Value::Array(schemas)) => schemas.iter_mut().for_each(make_strict),
+            Some(schema) => make_strict(schema),
+            None => {}
+        }
+    }
+    for key in ["$defs", "definitions"] {
+        if let Some(serde_json::Value::Object(definitions)) = object.get_mut(key) {
+            definitions.values_mut().for_each(make_strict);
+        }
+    }
+}
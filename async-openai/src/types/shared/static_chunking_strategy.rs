@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{error::OpenAIError, types::validation::check_range};
+
 /// Static Chunking Strategy
 #[derive(Clone, Serialize, Debug, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct StaticChunkingStrategy {
     /// The maximum number of tokens in each chunk. The default value is `800`. The minimum value is `100` and the maximum value is `4096`.
     pub max_chunk_size_tokens: u16,
@@ -11,3 +14,30 @@ pub struct StaticChunkingStrategy {
     /// Note that the overlap must not exceed half of `max_chunk_size_tokens`.
     pub chunk_overlap_tokens: u16,
 }
+
+impl StaticChunkingStrategy {
+    pub fn new(max_chunk_size_tokens: u16, chunk_overlap_tokens: u16) -> Self {
+        Self {
+            max_chunk_size_tokens,
+            chunk_overlap_tokens,
+        }
+    }
+
+    /// Checks that the chunk size is from 100 to 4096 tokens, and that the overlap is at most half
+    /// of it, so always less than the chunk size.
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        check_range(
+            "max_chunk_size_tokens",
+            Some(self.max_chunk_size_tokens),
+            100,
+            4096,
+        )?;
+        if self.chunk_overlap_tokens > self.max_chunk_size_tokens / 2 {
+            return Err(OpenAIError::InvalidArgument(format!(
+                "chunk_overlap_tokens must be at most half of max_chunk_size_tokens, got {} for {}",
+                self.chunk_overlap_tokens, self.max_chunk_size_tokens
+            )));
+        }
+        Ok(())
+    }
+}
@@ -4,7 +4,8 @@ use crate::types::InputSource;
 
 /// Reference a skill by ID when providing it to a tool or container.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SkillReferenceParam {
     /// The ID of the skill to reference.
     pub skill_id: String,
@@ -15,7 +16,8 @@ pub struct SkillReferenceParam {
 
 /// The source for an inline skill (base64-encoded zip archive).
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InlineSkillSourceParam {
     /// The media type. Always `"application/zip"`.
     pub media_type: String,
@@ -25,7 +27,8 @@ pub struct InlineSkillSourceParam {
 
 /// An inline skill definition provided directly in a request.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct InlineSkillParam {
     /// The name of the skill.
     pub name: String,
@@ -37,7 +40,8 @@ pub struct InlineSkillParam {
 
 /// Represents a skill object.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SkillResource {
     /// Unique identifier for the skill.
     pub id: String,
@@ -57,7 +61,8 @@ pub struct SkillResource {
 
 /// List of skills.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SkillListResource {
     /// The object type, which is always `list`.
     pub object: String,
@@ -80,7 +85,8 @@ pub struct CreateSkillRequest {
 
 /// Request to update the default version pointer for a skill.
 #[derive(Debug, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SetDefaultSkillVersionRequest {
     /// The skill version number to set as default.
     pub default_version: String,
@@ -88,7 +94,8 @@ pub struct SetDefaultSkillVersionRequest {
 
 /// Confirmation of skill deletion.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeletedSkillResource {
     /// The object type, which is always `skill.deleted`.
     pub object: String,
@@ -100,7 +107,8 @@ pub struct DeletedSkillResource {
 
 /// Represents a skill version object.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SkillVersionResource {
     /// The object type, which is always `skill.version`.
     pub object: String,
@@ -120,7 +128,8 @@ pub struct SkillVersionResource {
 
 /// List of skill versions.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct SkillVersionListResource {
     /// The object type, which is always `list`.
     pub object: String,
@@ -145,7 +154,8 @@ pub struct CreateSkillVersionRequest {
 
 /// Confirmation of skill version deletion.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeletedSkillVersionResource {
     /// The object type, which is always `skill.version.deleted`.
     pub object: String,
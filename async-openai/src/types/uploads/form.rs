@@ -1,12 +1,43 @@
 use crate::{
-    error::OpenAIError, traits::AsyncTryFrom, types::uploads::AddUploadPartRequest,
+    error::OpenAIError,
+    traits::AsyncTryFrom,
+    types::{
+        uploads::{AddUploadPartRequest, MAX_UPLOAD_PART_BYTES},
+        InputSource,
+    },
     util::create_file_part,
 };
 
+/// Size of the part in bytes, `None` for streams of unknown length.
+async fn part_size(source: &InputSource) -> Result<Option<u64>, OpenAIError> {
+    let size = match source {
+        #[cfg(not(target_family = "wasm"))]
+        InputSource::Path { path } => Some(
+            tokio::fs::metadata(path)
+                .await
+                .map_err(|e| OpenAIError::FileReadError(e.to_string()))?
+                .len(),
+        ),
+        InputSource::Bytes { bytes, .. } => Some(bytes.len() as u64),
+        InputSource::VecU8 { vec, .. } => Some(vec.len() as u64),
+        #[cfg(not(target_family = "wasm"))]
+        InputSource::Stream { length, .. } => *length,
+    };
+    Ok(size)
+}
+
 impl AsyncTryFrom<AddUploadPartRequest> for reqwest::multipart::Form {
     type Error = OpenAIError;
 
     async fn try_from(request: AddUploadPartRequest) -> Result<Self, Self::Error> {
+        if let Some(size) = part_size(&request.data).await? {
+            if size > MAX_UPLOAD_PART_BYTES {
+                return Err(OpenAIError::InvalidArgument(format!(
+                    "upload part is {size} bytes, parts can be at most {MAX_UPLOAD_PART_BYTES} bytes"
+                )));
+            }
+        }
+
         let file_part = create_file_part(request.data).await?;
         let form = reqwest::multipart::Form::new().part("data", file_part);
         Ok(form)
@@ -6,7 +6,8 @@ use crate::types::{files::OpenAIFile, InputSource};
 
 /// Request to create an upload object that can accept byte chunks in the form of Parts.
 #[derive(Clone, Serialize, Default, Debug, Deserialize, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateUploadRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -37,7 +38,8 @@ pub struct CreateUploadRequest {
 
 /// The intended purpose of the uploaded file.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum UploadPurpose {
     /// For use with Assistants and Message files
     #[serde(rename = "assistants")]
@@ -56,7 +58,8 @@ pub enum UploadPurpose {
 
 /// The Upload object can accept byte chunks in the form of Parts.
 #[derive(Debug, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct Upload {
     /// The Upload unique identifier, which can be referenced in API endpoints
     pub id: String,
@@ -89,7 +92,8 @@ pub struct Upload {
 
 /// The status of an upload
 #[derive(Debug, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum UploadStatus {
     /// Upload is pending
@@ -104,7 +108,8 @@ pub enum UploadStatus {
 
 /// The upload Part represents a chunk of bytes we can add to an Upload object.
 #[derive(Debug, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UploadPart {
     /// The upload Part unique identifier, which can be referenced in API endpoints
     pub id: String,
@@ -119,16 +124,23 @@ pub struct UploadPart {
     pub object: String,
 }
 
+/// The maximum size of a single Part of an Upload, 64 MB.
+pub const MAX_UPLOAD_PART_BYTES: u64 = 64 * 1024 * 1024;
+
 /// Request parameters for adding a part to an Upload
 #[derive(Debug, Clone)]
 pub struct AddUploadPartRequest {
-    /// The chunk of bytes for this Part
+    /// The chunk of bytes for this Part, at most [MAX_UPLOAD_PART_BYTES].
+    ///
+    /// Larger parts are rejected with [OpenAIError::InvalidArgument] before any request is sent.
+    /// The size of an [InputSource::Stream] can only be checked when its length is given.
     pub data: InputSource,
 }
 
 /// Request parameters for completing an Upload
 #[derive(Debug, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct CompleteUploadRequest {
     /// The ordered list of Part IDs
     pub part_ids: Vec<String>,
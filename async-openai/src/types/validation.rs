@@ -0,0 +1,36 @@
+//! Checks for the documented ranges of request parameters, used by the `validate` methods of request builders.
+use std::fmt::Display;
+
+use crate::error::OpenAIError;
+
+pub(crate) fn check_range<T: PartialOrd + Display + Copy>(
+    name: &str,
+    value: Option<T>,
+    min: T,
+    max: T,
+) -> Result<(), OpenAIError> {
+    match value {
+        Some(value) if !(min <= value && value <= max) => Err(OpenAIError::InvalidArgument(
+            format!("{name} must be between {min} and {max}, got {value}"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(any(
+    feature = "chat-completion-types",
+    feature = "embedding-types",
+    feature = "response-types"
+))]
+pub(crate) fn check_min<T: PartialOrd + Display + Copy>(
+    name: &str,
+    value: Option<T>,
+    min: T,
+) -> Result<(), OpenAIError> {
+    match value {
+        Some(value) if value < min => Err(OpenAIError::InvalidArgument(format!(
+            "{name} must be at least {min}, got {value}"
+        ))),
+        _ => Ok(()),
+    }
+}
@@ -11,12 +11,13 @@ use crate::{
 use crate::types::vectorstores::StaticChunkingStrategy;
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateVectorStoreRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(error = "OpenAIError", validate = "Self::validate"))]
 pub struct CreateVectorStoreRequest {
     /// A list of [File](https://platform.openai.com/docs/api-reference/files) IDs that the vector store should use. Useful for tools like `file_search` that can access files.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -42,8 +43,32 @@ pub struct CreateVectorStoreRequest {
     pub metadata: Option<Metadata>,
 }
 
+impl CreateVectorStoreRequestArgs {
+    /// Checks the `chunking_strategy` set so far, called by [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        if let Some(Some(chunking_strategy)) = &self.chunking_strategy {
+            chunking_strategy.validate()?;
+        }
+        Ok(())
+    }
+}
+
+/// How files added to a vector store are split into chunks, see [ChunkingStrategyRequestParam].
+pub type ChunkingStrategy = ChunkingStrategyRequestParam;
+
+/// How the files are split into chunks to be embedded, retrieved by `file_search`.
+///
+/// Smaller chunks make search results more precise, at the cost of less context per result.
+///
+/// ```
+/// use async_openai::types::vectorstores::ChunkingStrategy;
+///
+/// assert!(ChunkingStrategy::fixed(400, 100).validate().is_ok());
+/// assert!(ChunkingStrategy::fixed(400, 400).validate().is_err());
+/// ```
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 pub enum ChunkingStrategyRequestParam {
     /// The default strategy. This strategy currently uses a `max_chunk_size_tokens` of `800` and `chunk_overlap_tokens` of `400`.
@@ -57,9 +82,28 @@ pub enum ChunkingStrategyRequestParam {
     },
 }
 
+impl ChunkingStrategyRequestParam {
+    /// The `static` strategy, with chunks of at most `max_chunk_size_tokens` tokens overlapping by
+    /// `chunk_overlap_tokens`.
+    pub fn fixed(max_chunk_size_tokens: u16, chunk_overlap_tokens: u16) -> Self {
+        Self::Static {
+            config: StaticChunkingStrategy::new(max_chunk_size_tokens, chunk_overlap_tokens),
+        }
+    }
+
+    /// Checks the token counts of the `static` strategy, see [StaticChunkingStrategy::validate].
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        match self {
+            Self::Auto => Ok(()),
+            Self::Static { config } => config.validate(),
+        }
+    }
+}
+
 /// Vector store expiration policy
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreExpirationAfter {
     /// Anchor timestamp after which the expiration policy applies. Supported anchors: `last_active_at`.
     pub anchor: String,
@@ -69,7 +113,8 @@ pub struct VectorStoreExpirationAfter {
 
 /// A vector store is a collection of processed files can be used by the `file_search` tool.
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreObject {
     /// The identifier, which can be referenced in API endpoints.
     pub id: String,
@@ -95,7 +140,8 @@ pub struct VectorStoreObject {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum VectorStoreStatus {
     Expired,
@@ -104,7 +150,8 @@ pub enum VectorStoreStatus {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreFileCounts {
     /// The number of files that are currently being processed.
     pub in_progress: u32,
@@ -119,7 +166,8 @@ pub struct VectorStoreFileCounts {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListVectorStoresResponse {
     pub object: String,
     pub data: Vec<VectorStoreObject>,
@@ -129,7 +177,8 @@ pub struct ListVectorStoresResponse {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteVectorStoreResponse {
     pub id: String,
     pub object: String,
@@ -137,7 +186,8 @@ pub struct DeleteVectorStoreResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "UpdateVectorStoreRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -153,7 +203,8 @@ pub struct UpdateVectorStoreRequest {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ListVectorStoreFilesResponse {
     pub object: String,
     pub data: Vec<VectorStoreFileObject>,
@@ -163,7 +214,8 @@ pub struct ListVectorStoreFilesResponse {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreFileObject {
     /// The identifier, which can be referenced in API endpoints.
     pub id: String,
@@ -186,7 +238,8 @@ pub struct VectorStoreFileObject {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum VectorStoreFileStatus {
     InProgress,
@@ -196,7 +249,8 @@ pub enum VectorStoreFileStatus {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreFileError {
     pub code: VectorStoreFileErrorCode,
     /// A human-readable description of the error.
@@ -204,7 +258,8 @@ pub struct VectorStoreFileError {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum VectorStoreFileErrorCode {
     ServerError,
@@ -213,7 +268,8 @@ pub enum VectorStoreFileErrorCode {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(tag = "type")]
 #[serde(rename_all = "lowercase")]
 pub enum ChunkingStrategyResponse {
@@ -225,7 +281,8 @@ pub enum ChunkingStrategyResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(transparent)]
 pub struct VectorStoreFileAttributes(pub HashMap<String, AttributeValue>);
 
@@ -236,12 +293,13 @@ impl From<HashMap<String, AttributeValue>> for VectorStoreFileAttributes {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateVectorStoreFileRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(error = "OpenAIError", validate = "Self::validate"))]
 pub struct CreateVectorStoreFileRequest {
     /// A [File](https://platform.openai.com/docs/api-reference/files) ID that the vector store should use. Useful for tools like `file_search` that can access files.
     pub file_id: String,
@@ -251,8 +309,19 @@ pub struct CreateVectorStoreFileRequest {
     pub attributes: Option<VectorStoreFileAttributes>,
 }
 
+impl CreateVectorStoreFileRequestArgs {
+    /// Checks the `chunking_strategy` set so far, called by [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        if let Some(Some(chunking_strategy)) = &self.chunking_strategy {
+            chunking_strategy.validate()?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeleteVectorStoreFileResponse {
     pub id: String,
     pub object: String,
@@ -260,12 +329,13 @@ pub struct DeleteVectorStoreFileResponse {
 }
 
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "CreateVectorStoreFileBatchRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
 #[builder(derive(Debug))]
-#[builder(build_fn(error = "OpenAIError"))]
+#[builder(build_fn(error = "OpenAIError", validate = "Self::validate"))]
 pub struct CreateVectorStoreFileBatchRequest {
     /// A list of [File](https://platform.openai.com/docs/api-reference/files) IDs that the vector store
     /// should use. Useful for tools like `file_search` that can access files. If `attributes` or
@@ -286,8 +356,25 @@ pub struct CreateVectorStoreFileBatchRequest {
     pub attributes: Option<VectorStoreFileAttributes>,
 }
 
+impl CreateVectorStoreFileBatchRequestArgs {
+    /// Checks the `chunking_strategy` set so far, and those of the `files`, called by
+    /// [build](Self::build).
+    pub fn validate(&self) -> Result<(), OpenAIError> {
+        if let Some(Some(chunking_strategy)) = &self.chunking_strategy {
+            chunking_strategy.validate()?;
+        }
+        for file in self.files.iter().flatten().flatten() {
+            if let Some(chunking_strategy) = &file.chunking_strategy {
+                chunking_strategy.validate()?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum VectorStoreFileBatchStatus {
     InProgress,
@@ -296,8 +383,16 @@ pub enum VectorStoreFileBatchStatus {
     Failed,
 }
 
+impl VectorStoreFileBatchStatus {
+    /// Returns `true` when the batch is `completed`, `cancelled` or `failed` and will not change anymore.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, VectorStoreFileBatchStatus::InProgress)
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreFileBatchCounts {
     /// The number of files that are currently being processed.
     pub in_progress: u32,
@@ -313,7 +408,8 @@ pub struct VectorStoreFileBatchCounts {
 
 /// A batch of files attached to a vector store.
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreFileBatchObject {
     /// The identifier, which can be referenced in API endpoints.
     pub id: String,
@@ -325,12 +421,16 @@ pub struct VectorStoreFileBatchObject {
     pub vector_store_id: String,
     /// The status of the vector store files batch, which can be either `in_progress`, `completed`, `cancelled` or `failed`.
     pub status: VectorStoreFileBatchStatus,
+    /// The number of files of the batch per status. A `completed` batch can still have `failed`
+    /// files, list them with [list_files](crate::VectorStoreFileBatches::list_files) and the
+    /// `filter=failed` query parameter.
     pub file_counts: VectorStoreFileBatchCounts,
 }
 
 /// Represents the parsed content of a vector store file.
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreFileContentResponse {
     /// The object type, which is always `vector_store.file_content.page`
     pub object: String,
@@ -347,7 +447,8 @@ pub struct VectorStoreFileContentResponse {
 
 /// Represents the parsed content of a vector store file.
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreFileContentObject {
     /// The content type (currently only `"text"`)
     pub r#type: String,
@@ -357,7 +458,8 @@ pub struct VectorStoreFileContentObject {
 }
 
 #[derive(Debug, Serialize, Default, Clone, Builder, PartialEq, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "VectorStoreSearchRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -385,7 +487,8 @@ pub struct VectorStoreSearchRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum VectorStoreSearchQuery {
     /// A single query to search for.
@@ -420,7 +523,8 @@ impl From<Vec<String>> for VectorStoreSearchQuery {
 
 /// The value to compare against the attribute key; supports string, number, or boolean types.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
 pub enum AttributeValue {
     String(String),
@@ -454,7 +558,8 @@ impl From<&str> for AttributeValue {
 
 /// Ranking options for search.
 #[derive(Debug, Serialize, Default, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct RankingOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ranker: Option<Ranker>,
@@ -464,7 +569,8 @@ pub struct RankingOptions {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum Ranker {
     /// Enable re-ranking; set to `none` to disable, which can help reduce latency.
     #[serde(rename = "none")]
@@ -476,7 +582,8 @@ pub enum Ranker {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreSearchResultsPage {
     /// The object type, which is always `vector_store.search_results.page`.
     pub object: String,
@@ -495,7 +602,8 @@ pub struct VectorStoreSearchResultsPage {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreSearchResultItem {
     /// The ID of the vector store file.
     pub file_id: String,
@@ -514,7 +622,8 @@ pub struct VectorStoreSearchResultItem {
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VectorStoreSearchResultContentObject {
     /// The type of content
     pub r#type: String,
@@ -524,7 +633,8 @@ pub struct VectorStoreSearchResultContentObject {
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct UpdateVectorStoreFileAttributesRequest {
     pub attributes: VectorStoreFileAttributes,
 }
@@ -6,7 +6,8 @@ use crate::types::videos::ImageInput;
 use crate::types::InputSource;
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum VideoSize {
     #[default]
     #[serde(rename = "720x1280")]
@@ -20,7 +21,8 @@ pub enum VideoSize {
 }
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub enum VideoSeconds {
     #[default]
     #[serde(rename = "4")]
@@ -39,7 +41,8 @@ pub enum VideoSeconds {
 
 /// Reference to a completed video by its ID.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VideoReferenceInputParam {
     /// The identifier of the completed video.
     pub id: String,
@@ -47,7 +50,8 @@ pub struct VideoReferenceInputParam {
 
 /// Image reference parameter for video generation.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct ImageRefParam {
     /// A fully qualified URL or base64-encoded data URL.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -97,7 +101,6 @@ pub struct CreateVideoCharacterRequest {
 
 /// Parameters for editing an existing generated video.
 #[derive(Clone, Default, Debug, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
 #[builder(name = "CreateVideoEditRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -127,7 +130,6 @@ impl Default for VideoEditInput {
 
 /// Parameters for extending a completed video.
 #[derive(Clone, Default, Debug, Builder, PartialEq)]
-#[derive(utoipa::ToSchema)]
 #[builder(name = "CreateVideoExtendRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -143,7 +145,8 @@ pub struct CreateVideoExtendRequest {
 }
 
 #[derive(Clone, Default, Debug, Builder, PartialEq, Serialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[builder(name = "RemixVideoRequestArgs")]
 #[builder(pattern = "mutable")]
 #[builder(setter(into, strip_option), default)]
@@ -154,7 +157,8 @@ pub struct RemixVideoRequest {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VideoResourceError {
     pub code: String,
     pub message: String,
@@ -162,7 +166,8 @@ pub struct VideoResourceError {
 
 /// Structured information describing a generated video job.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VideoResource {
     /// Unix timestamp (seconds) for when the job completed, if finished.
     pub completed_at: Option<u64>,
@@ -203,7 +208,8 @@ pub struct VideoResource {
 
 /// Character resource returned by character creation and retrieval.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VideoCharacterResource {
     /// Identifier for the character.
     pub id: Option<String>,
@@ -214,7 +220,8 @@ pub struct VideoCharacterResource {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum VideoStatus {
     Queued,
@@ -224,7 +231,8 @@ pub enum VideoStatus {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct DeletedVideoResource {
     pub id: String,
     pub object: String,
@@ -232,7 +240,8 @@ pub struct DeletedVideoResource {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 pub struct VideoListResource {
     pub data: Vec<VideoResource>,
     pub object: String,
@@ -242,7 +251,8 @@ pub struct VideoListResource {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
-#[derive(utoipa::ToSchema)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum VideoVariant {
     #[default]
@@ -8,6 +8,10 @@ use crate::{
 };
 
 /// Allows you to upload large files in multiple parts.
+///
+/// [create](Self::create) an Upload with the total size of the file, [add_part](Self::add_part)
+/// chunks of it by byte range (in any order, possibly in parallel), then [complete](Self::complete)
+/// it with the ordered Part IDs to get the resulting File.
 pub struct Uploads<'c, C: Config> {
     client: &'c Client<C>,
     pub(crate) request_options: RequestOptions,
@@ -45,7 +49,9 @@ impl<'c, C: Config> Uploads<'c, C> {
     /// [Upload](https://platform.openai.com/docs/api-reference/uploads/object) object.
     /// A Part represents a chunk of bytes from the file you are trying to upload.
     ///
-    /// Each Part can be at most 64 MB, and you can add Parts until you hit the Upload maximum of 8 GB.
+    /// Each Part can be at most 64 MB ([MAX_UPLOAD_PART_BYTES](crate::types::uploads::MAX_UPLOAD_PART_BYTES)), and you can add Parts until you hit the
+    /// Upload maximum of 8 GB. Larger Parts are rejected with [OpenAIError::InvalidArgument] without
+    /// sending the request.
     ///
     /// It is possible to add multiple Parts in parallel. You can decide the intended order of the Parts
     /// when you [complete the Upload](https://platform.openai.com/docs/api-reference/uploads/complete).
@@ -1,5 +1,7 @@
 use crate::error::OpenAIError;
 use crate::types::InputSource;
+use reqwest::multipart::Part;
+#[cfg(not(target_family = "wasm"))]
 use reqwest::Body;
 
 #[cfg(not(target_family = "wasm"))]
@@ -26,7 +28,7 @@ pub(crate) async fn file_stream_body(source: InputSource) -> Result<Body, OpenAI
 pub(crate) async fn create_file_part(
     source: InputSource,
 ) -> Result<reqwest::multipart::Part, OpenAIError> {
-    let (stream, file_name) = match source {
+    let (file_part, file_name) = match source {
         #[cfg(not(target_family = "wasm"))]
         InputSource::Path { path } => {
             let file_name = path
@@ -42,17 +44,66 @@ pub(crate) async fn create_file_part(
                 .to_string();
 
             (
-                file_stream_body(InputSource::Path { path }).await?,
+                Part::stream(file_stream_body(InputSource::Path { path }).await?),
                 file_name,
             )
         }
-        InputSource::Bytes { filename, bytes } => (Body::from(bytes), filename),
-        InputSource::VecU8 { filename, vec } => (Body::from(vec), filename),
+        InputSource::Bytes { filename, bytes } => (Part::stream(bytes), filename),
+        InputSource::VecU8 { filename, vec } => (Part::stream(vec), filename),
+        #[cfg(not(target_family = "wasm"))]
+        InputSource::Stream {
+            filename,
+            stream,
+            length,
+        } => {
+            let stream = stream.take().ok_or_else(|| {
+                OpenAIError::FileReadError(
+                    "stream was already consumed, streamed uploads cannot be retried".into(),
+                )
+            })?;
+            crate::client::mark_streamed_upload();
+            let body = Body::wrap_stream(stream);
+            let part = match length {
+                Some(length) => Part::stream_with_length(body, length),
+                None => Part::stream(body),
+            };
+            (part, filename)
+        }
     };
 
-    let file_part = reqwest::multipart::Part::stream(stream).file_name(file_name);
+    let file_part = match mime_type(&file_name) {
+        Some(mime) => file_part.mime_str(mime)?,
+        None => file_part,
+    };
 
-    Ok(file_part)
+    Ok(file_part.file_name(file_name))
+}
+
+/// Content type of an upload, guessed from the extension of its file name.
+fn mime_type(file_name: &str) -> Option<&'static str> {
+    let (_, extension) = file_name.rsplit_once('.')?;
+    let mime = match extension.to_ascii_lowercase().as_str() {
+        "jsonl" => "application/jsonl",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        "txt" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "html" => "text/html",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "mp3" | "mpga" | "mpeg" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "wav" => "audio/wav",
+        "ogg" => "audio/ogg",
+        "flac" => "audio/flac",
+        "webm" => "audio/webm",
+        "mp4" => "video/mp4",
+        _ => return None,
+    };
+    Some(mime)
 }
 
 #[cfg(all(any(feature = "image", feature = "audio"), not(target_family = "wasm")))]
@@ -6,6 +6,8 @@ use crate::{
     },
     Client, RequestOptions,
 };
+#[cfg(not(target_family = "wasm"))]
+use crate::{poll::poll_until, PollOptions};
 
 /// Vector store file batches represent operations to add multiple files to a vector store.
 ///
@@ -88,4 +90,38 @@ impl<'c, C: Config> VectorStoreFileBatches<'c, C> {
             )
             .await
     }
+
+    /// Polls the batch until its status is terminal, see
+    /// [VectorStoreFileBatchStatus::is_terminal](crate::types::vectorstores::VectorStoreFileBatchStatus::is_terminal),
+    /// and returns it.
+    ///
+    /// `poll` is either the interval between polls as a [Duration](std::time::Duration), or
+    /// [PollOptions] with a timeout and a progress callback, e.g. to report the `file_counts`.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn wait_for_completion(
+        &self,
+        batch_id: &str,
+        poll: impl Into<PollOptions<VectorStoreFileBatchObject>>,
+    ) -> Result<VectorStoreFileBatchObject, OpenAIError> {
+        poll_until(
+            poll.into(),
+            || self.retrieve(batch_id),
+            |batch| batch.status.is_terminal(),
+        )
+        .await
+    }
+
+    /// Creates a batch and polls it until its status is terminal, see [wait_for_completion](Self::wait_for_completion).
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn create_and_poll(
+        &self,
+        request: CreateVectorStoreFileBatchRequest,
+        poll: impl Into<PollOptions<VectorStoreFileBatchObject>>,
+    ) -> Result<VectorStoreFileBatchObject, OpenAIError> {
+        let batch = self.create(request).await?;
+        if batch.status.is_terminal() {
+            return Ok(batch);
+        }
+        self.wait_for_completion(&batch.id, poll).await
+    }
 }
@@ -1,6 +1,9 @@
+use futures::Stream;
+
 use crate::{
     config::Config,
     error::OpenAIError,
+    pagination::{impl_list_page, paginate},
     types::vectorstores::{
         CreateVectorStoreFileRequest, DeleteVectorStoreFileResponse, ListVectorStoreFilesResponse,
         UpdateVectorStoreFileAttributesRequest, VectorStoreFileContentResponse,
@@ -9,6 +12,8 @@ use crate::{
     Client, RequestOptions,
 };
 
+impl_list_page!(ListVectorStoreFilesResponse => VectorStoreFileObject);
+
 /// Vector store files represent files inside a vector store.
 ///
 /// Related guide: [File Search](https://platform.openai.com/docs/assistants/tools/file-search)
@@ -78,6 +83,17 @@ impl<'c, C: Config> VectorStoreFiles<'c, C> {
             .await
     }
 
+    /// All the files of the vector store, following the `after` cursor of [list](Self::list) page by page.
+    pub fn list_stream(
+        &self,
+    ) -> impl Stream<Item = Result<VectorStoreFileObject, OpenAIError>> + 'c {
+        paginate::<_, ListVectorStoreFilesResponse>(
+            self.client,
+            format!("/vector_stores/{}/files", self.vector_store_id),
+            self.request_options.clone(),
+        )
+    }
+
     /// Update attributes on a vector store file.
     #[crate::byot(T0 = std::fmt::Display, T1 = serde::Serialize, R = serde::de::DeserializeOwned)]
     pub async fn update(
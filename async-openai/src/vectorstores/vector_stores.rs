@@ -1,13 +1,24 @@
+use futures::Stream;
+
 use crate::{
     config::Config,
     error::OpenAIError,
+    pagination::{impl_list_page, paginate},
     types::vectorstores::{
-        CreateVectorStoreRequest, DeleteVectorStoreResponse, ListVectorStoresResponse,
-        UpdateVectorStoreRequest, VectorStoreObject, VectorStoreSearchRequest,
+        ChunkingStrategy, CreateVectorStoreFileRequest, CreateVectorStoreRequest,
+        DeleteVectorStoreResponse, ListVectorStoresResponse, UpdateVectorStoreRequest,
+        VectorStoreFileObject, VectorStoreObject, VectorStoreSearchRequest,
         VectorStoreSearchResultsPage,
     },
     Client, RequestOptions, VectorStoreFileBatches, VectorStoreFiles,
 };
+#[cfg(not(target_family = "wasm"))]
+use crate::{
+    types::vectorstores::{CreateVectorStoreFileBatchRequest, VectorStoreFileBatchObject},
+    PollOptions,
+};
+
+impl_list_page!(ListVectorStoresResponse => VectorStoreObject);
 
 pub struct VectorStores<'c, C: Config> {
     client: &'c Client<C>,
@@ -32,6 +43,60 @@ impl<'c, C: Config> VectorStores<'c, C> {
         VectorStoreFileBatches::new(self.client, vector_store_id)
     }
 
+    /// Attaches the uploaded file `file_id` to the vector store, chunked with `chunking_strategy`
+    /// or the `auto` strategy when `None`, see [VectorStoreFiles::create].
+    ///
+    /// Returns [OpenAIError::InvalidArgument] without sending the request when the strategy is
+    /// invalid, see [ChunkingStrategy::validate].
+    pub async fn create_file(
+        &self,
+        vector_store_id: &str,
+        file_id: impl Into<String>,
+        chunking_strategy: Option<ChunkingStrategy>,
+    ) -> Result<VectorStoreFileObject, OpenAIError> {
+        if let Some(chunking_strategy) = &chunking_strategy {
+            chunking_strategy.validate()?;
+        }
+        let request = CreateVectorStoreFileRequest {
+            file_id: file_id.into(),
+            chunking_strategy,
+            attributes: None,
+        };
+        let mut files = self.files(vector_store_id);
+        files.request_options = self.request_options.clone();
+        files.create(request).await
+    }
+
+    /// Attaches the uploaded files `file_ids` to the vector store in a batch, all chunked with
+    /// `chunking_strategy` or the `auto` strategy when `None`, and polls the batch until its
+    /// status is terminal, see [VectorStoreFileBatches::create_and_poll].
+    ///
+    /// Check the `file_counts` of the returned batch for files that failed to process.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn create_file_batch_and_poll<I, S>(
+        &self,
+        vector_store_id: &str,
+        file_ids: I,
+        chunking_strategy: Option<ChunkingStrategy>,
+        poll: impl Into<PollOptions<VectorStoreFileBatchObject>>,
+    ) -> Result<VectorStoreFileBatchObject, OpenAIError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        if let Some(chunking_strategy) = &chunking_strategy {
+            chunking_strategy.validate()?;
+        }
+        let request = CreateVectorStoreFileBatchRequest {
+            file_ids: Some(file_ids.into_iter().map(Into::into).collect()),
+            chunking_strategy,
+            ..Default::default()
+        };
+        let mut batches = self.file_batches(vector_store_id);
+        batches.request_options = self.request_options.clone();
+        batches.create_and_poll(request, poll).await
+    }
+
     /// Create a vector store.
     #[crate::byot(T0 = serde::Serialize, R = serde::de::DeserializeOwned)]
     pub async fn create(
@@ -62,6 +127,15 @@ impl<'c, C: Config> VectorStores<'c, C> {
             .await
     }
 
+    /// All the vector stores, following the `after` cursor of [list](Self::list) page by page.
+    pub fn list_stream(&self) -> impl Stream<Item = Result<VectorStoreObject, OpenAIError>> + 'c {
+        paginate::<_, ListVectorStoresResponse>(
+            self.client,
+            "/vector_stores".to_string(),
+            self.request_options.clone(),
+        )
+    }
+
     /// Delete a vector store.
     #[crate::byot(T0 = std::fmt::Display, R = serde::de::DeserializeOwned)]
     pub async fn delete(
@@ -0,0 +1,132 @@
+//! Streaming Assistants runs.
+#![allow(deprecated)]
+use async_openai::types::assistants::{AssistantStreamEvent, CreateRunRequest};
+use futures::StreamExt;
+use serde_json::json;
+
+mod common;
+use common::{mock_server, MockResponse};
+
+/// An SSE response with `events` as `(event, data)` pairs.
+fn sse(events: Vec<(&'static str, String)>) -> MockResponse {
+    let body: String = events
+        .iter()
+        .map(|(event, data)| format!("event: {event}\ndata: {data}\n\n"))
+        .collect();
+    MockResponse::new(200, body).header("content-type", "text/event-stream")
+}
+
+fn run() -> String {
+    json!({
+        "id": "run_1",
+        "object": "thread.run",
+        "created_at": 1699063290,
+        "thread_id": "thread_1",
+        "assistant_id": "asst_1",
+        "status": "queued",
+        "required_action": null,
+        "last_error": null,
+        "expires_at": null,
+        "started_at": null,
+        "cancelled_at": null,
+        "failed_at": null,
+        "completed_at": null,
+        "incomplete_details": null,
+        "model": "gpt-4o",
+        "instructions": "",
+        "tools": [],
+        "metadata": {},
+        "usage": null,
+        "truncation_strategy": null,
+        "tool_choice": null,
+        "parallel_tool_calls": true,
+        "response_format": null,
+    })
+    .to_string()
+}
+
+fn message_delta(text: &str) -> String {
+    json!({
+        "id": "msg_1",
+        "object": "thread.message.delta",
+        "delta": {
+            "content": [{ "index": 0, "type": "text", "text": { "value": text, "annotations": [] } }]
+        }
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn create_stream_parses_run_events() {
+    let (api_base, _) = mock_server(vec![sse(vec![
+        ("thread.run.created", run()),
+        ("thread.message.delta", message_delta("Hello")),
+        ("thread.message.delta", message_delta(" world")),
+        (
+            "error",
+            json!({ "message": "Server error", "type": "server_error", "param": null, "code": null })
+                .to_string(),
+        ),
+        ("done", "[DONE]".into()),
+    ])])
+    .await;
+    let client = common::client(api_base);
+
+    let mut stream = client
+        .threads()
+        .runs("thread_1")
+        .create_stream(CreateRunRequest {
+            assistant_id: "asst_1".into(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    let mut events = vec![];
+    while let Some(event) = stream.next().await {
+        events.push(event.unwrap());
+    }
+    assert_eq!(events.len(), 5);
+
+    match &events[0] {
+        AssistantStreamEvent::ThreadRunCreated(run) => assert_eq!(run.id, "run_1"),
+        other => panic!("unexpected event: {other:?}"),
+    }
+
+    let text: String = events
+        .iter()
+        .filter_map(|event| match event {
+            AssistantStreamEvent::ThreadMessageDelta(delta) => Some(delta.text()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(text, "Hello world");
+
+    match &events[3] {
+        AssistantStreamEvent::ErrorEvent(error) => {
+            assert_eq!(error.message, "Server error");
+            assert_eq!(error.r#type.as_deref(), Some("server_error"));
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+    assert!(matches!(&events[4], AssistantStreamEvent::Done(data) if data == "[DONE]"));
+}
+
+#[test]
+fn error_event_accepts_wrapped_errors() {
+    let event = eventsource_stream::Event {
+        event: "error".into(),
+        data: json!({ "error": { "message": "Server error", "type": "server_error", "param": null, "code": "server_error" } })
+            .to_string(),
+        id: String::new(),
+        retry: None,
+    };
+
+    match AssistantStreamEvent::try_from(event).unwrap() {
+        AssistantStreamEvent::ErrorEvent(error) => {
+            assert_eq!(error.message, "Server error");
+            assert_eq!(error.code.as_deref(), Some("server_error"));
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
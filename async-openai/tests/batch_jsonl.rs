@@ -0,0 +1,152 @@
+//! Writing batch input files and reading batch output files.
+use async_openai::types::{
+    batches::{BatchEndpoint, BatchOutputError, BatchRequestInput, BatchRequestOutput},
+    chat::{
+        ChatCompletionRequestUserMessage, CreateChatCompletionRequest,
+        CreateChatCompletionRequestArgs, CreateChatCompletionResponse,
+    },
+};
+use serde_json::json;
+
+fn request(content: &str) -> CreateChatCompletionRequest {
+    CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from(content).into()])
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn batch_input_writes_one_request_per_line() {
+    let inputs = [
+        BatchRequestInput::new("request-1", BatchEndpoint::V1ChatCompletions, request("Hi")),
+        BatchRequestInput::new(
+            "request-2",
+            BatchEndpoint::V1ChatCompletions,
+            request("Bye"),
+        ),
+    ];
+
+    let jsonl = BatchRequestInput::to_jsonl(&inputs).unwrap();
+    let lines: Vec<serde_json::Value> = jsonl
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert!(jsonl.ends_with('\n'));
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["custom_id"], "request-1");
+    assert_eq!(lines[0]["method"], "POST");
+    assert_eq!(lines[0]["url"], "/v1/chat/completions");
+    assert_eq!(lines[0]["body"]["model"], "gpt-4o-mini");
+    assert_eq!(lines[1]["body"]["messages"][0]["content"], "Bye");
+
+    // untyped bodies read the file back
+    let read: BatchRequestInput = serde_json::from_str(jsonl.lines().next().unwrap()).unwrap();
+    assert_eq!(read.body.unwrap()["messages"][0]["content"], "Hi");
+}
+
+#[test]
+fn batch_output_parses_results_by_custom_id() {
+    let lines = [
+        json!({
+            "id": "batch_req_1",
+            "custom_id": "request-1",
+            "response": {
+                "status_code": 200,
+                "request_id": "req_1",
+                "body": {
+                    "id": "chatcmpl-123",
+                    "object": "chat.completion",
+                    "created": 1711652795,
+                    "model": "gpt-4o-mini",
+                    "choices": [{
+                        "index": 0,
+                        "message": { "role": "assistant", "content": "Hello." },
+                        "finish_reason": "stop"
+                    }]
+                }
+            },
+            "error": null
+        }),
+        json!({
+            "id": "batch_req_2",
+            "custom_id": "request-2",
+            "response": {
+                "status_code": 400,
+                "request_id": "req_2",
+                "body": {
+                    "error": {
+                        "message": "Invalid model",
+                        "type": "invalid_request_error",
+                        "param": "model",
+                        "code": "model_not_found"
+                    }
+                }
+            },
+            "error": null
+        }),
+        json!({
+            "id": "batch_req_3",
+            "custom_id": "request-3",
+            "response": null,
+            "error": { "code": "batch_expired", "message": "This request could not be executed before the completion window expired." }
+        }),
+    ];
+    let jsonl = lines
+        .iter()
+        .map(|line| line.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n\n";
+
+    let results =
+        BatchRequestOutput::results_from_jsonl::<CreateChatCompletionResponse>(jsonl).unwrap();
+    assert_eq!(results.len(), 3);
+
+    let (custom_id, response) = &results[0];
+    assert_eq!(custom_id, "request-1");
+    let response = response.as_ref().unwrap();
+    assert_eq!(
+        response.choices[0].message.content.as_deref(),
+        Some("Hello.")
+    );
+
+    let (custom_id, error) = &results[1];
+    assert_eq!(custom_id, "request-2");
+    match error {
+        Err(BatchOutputError::Api(error)) => {
+            assert_eq!(error.status, Some(400));
+            assert_eq!(error.code.as_deref(), Some("model_not_found"));
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    let (custom_id, error) = &results[2];
+    assert_eq!(custom_id, "request-3");
+    match error {
+        Err(BatchOutputError::Request(error)) => assert_eq!(error.code, "batch_expired"),
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn batch_output_reports_invalid_lines_and_bodies() {
+    let line = json!({
+        "id": "batch_req_1",
+        "custom_id": "request-1",
+        "response": { "status_code": 200, "request_id": "req_1", "body": { "unexpected": true } },
+        "error": null
+    });
+
+    let error = BatchRequestOutput::from_jsonl(format!("{line}\nnot json\n")).unwrap_err();
+    assert!(error.to_string().contains("line 2"), "{error}");
+
+    let results =
+        BatchRequestOutput::results_from_jsonl::<CreateChatCompletionResponse>(line.to_string())
+            .unwrap();
+    assert!(matches!(
+        results[0].1,
+        Err(BatchOutputError::Deserialize(_))
+    ));
+}
@@ -0,0 +1,116 @@
+//! Polling batches until they finish.
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_openai::{
+    error::OpenAIError,
+    transport::{MockResponse, MockTransport},
+    types::batches::{Batch, BatchStatus},
+    Client, PollOptions,
+};
+
+fn batch(status: &str, completed: u32) -> MockResponse {
+    MockResponse::json(
+        200,
+        serde_json::json!({
+            "id": "batch_123",
+            "object": "batch",
+            "endpoint": "/v1/chat/completions",
+            "input_file_id": "file-123",
+            "completion_window": "24h",
+            "status": status,
+            "created_at": 1711471533,
+            "request_counts": { "total": 2, "completed": completed, "failed": 0 },
+        }),
+    )
+}
+
+#[tokio::test]
+async fn wait_for_completion_polls_until_terminal_status() {
+    let transport = MockTransport::new()
+        .on("/batches/batch_123", batch("validating", 0))
+        .on("/batches/batch_123", batch("in_progress", 1))
+        .on("/batches/batch_123", batch("completed", 2));
+    let client = Client::new().with_transport(transport.clone());
+
+    let progress = Arc::new(Mutex::new(vec![]));
+    let recorded = progress.clone();
+    let options = PollOptions::new(Duration::from_millis(1)).on_progress(move |batch: &Batch| {
+        recorded
+            .lock()
+            .unwrap()
+            .push(batch.request_counts.as_ref().unwrap().completed)
+    });
+
+    let batch = client
+        .batches()
+        .wait_for_completion("batch_123", options)
+        .await
+        .unwrap();
+
+    assert_eq!(batch.status, BatchStatus::Completed);
+    assert_eq!(*progress.lock().unwrap(), [0, 1, 2]);
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn wait_for_completion_returns_failed_batches() {
+    let transport = MockTransport::new().on("/batches/batch_123", batch("failed", 0));
+    let client = Client::new().with_transport(transport.clone());
+
+    let batch = client
+        .batches()
+        .wait_for_completion("batch_123", Duration::from_millis(1))
+        .await
+        .unwrap();
+
+    assert_eq!(batch.status, BatchStatus::Failed);
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn wait_for_completion_times_out() {
+    let transport = MockTransport::new().on("/batches/batch_123", batch("in_progress", 1));
+    let client = Client::new().with_transport(transport.clone());
+
+    let options = PollOptions::new(Duration::from_millis(5)).timeout(Duration::from_millis(50));
+    let error = client
+        .batches()
+        .wait_for_completion("batch_123", options)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, OpenAIError::Timeout(timeout) if timeout == Duration::from_millis(50)));
+    assert!(transport.requests().len() > 1);
+}
+
+#[tokio::test]
+async fn wait_for_completion_surfaces_api_errors() {
+    let transport = MockTransport::new().on(
+        "/batches/batch_123",
+        MockResponse::error(404, "not_found", "No batch found with id 'batch_123'."),
+    );
+    let client = Client::new().with_transport(transport);
+
+    let error = client
+        .batches()
+        .wait_for_completion("batch_123", Duration::from_millis(1))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, OpenAIError::ApiError(error) if error.status == Some(404)));
+}
+
+#[test]
+fn terminal_batch_statuses() {
+    assert!(BatchStatus::Completed.is_terminal());
+    assert!(BatchStatus::Failed.is_terminal());
+    assert!(BatchStatus::Expired.is_terminal());
+    assert!(BatchStatus::Cancelled.is_terminal());
+    assert!(!BatchStatus::Validating.is_terminal());
+    assert!(!BatchStatus::InProgress.is_terminal());
+    assert!(!BatchStatus::Finalizing.is_terminal());
+    assert!(!BatchStatus::Cancelling.is_terminal());
+}
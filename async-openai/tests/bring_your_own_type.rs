@@ -1,4 +1,4 @@
-#![allow(dead_code)]
+#![allow(dead_code, deprecated)]
 //! The purpose of this test to make sure that all _byot methods compiles with custom types.
 use std::pin::Pin;
 
@@ -0,0 +1,71 @@
+//! The `*Args` builders are `Clone` and `Default`, so that a base request can be built once
+//! and tweaked for every call.
+use async_openai::types::{
+    chat::{
+        ChatCompletionRequestMessage, ChatCompletionRequestSystemMessage,
+        ChatCompletionRequestUserMessage, CreateChatCompletionRequestArgs,
+    },
+    embeddings::{CreateEmbeddingRequestArgs, EmbeddingInput},
+    images::{CreateImageRequestArgs, ImageModel, ImageSize},
+};
+
+#[test]
+fn chat_builder_clone() {
+    let system: ChatCompletionRequestMessage =
+        ChatCompletionRequestSystemMessage::from("Answer in one word.").into();
+    let mut base = CreateChatCompletionRequestArgs::default();
+    base.model("gpt-4o-mini").temperature(0.0);
+
+    let mut first = base.clone();
+    first.messages([
+        system.clone(),
+        ChatCompletionRequestUserMessage::from("Capital of France?").into(),
+    ]);
+    let mut second = base.clone();
+    second.messages([
+        system.clone(),
+        ChatCompletionRequestUserMessage::from("Capital of Italy?").into(),
+    ]);
+
+    let first = first.build().unwrap();
+    let second = second.build().unwrap();
+    assert_eq!(first.model, second.model);
+    assert_eq!(first.temperature, Some(0.0));
+    assert_eq!(first.messages[0], second.messages[0]);
+    assert_ne!(first.messages[1], second.messages[1]);
+    // the base is left untouched by its copies
+    assert!(base.build().unwrap().messages.is_empty());
+}
+
+#[test]
+fn embeddings_builder_clone() {
+    let mut base = CreateEmbeddingRequestArgs::default();
+    base.model("text-embedding-3-small").dimensions(256u32);
+
+    let mut request = base.clone();
+    request.input("hello");
+    let request = request.build().unwrap();
+
+    assert_eq!(request.model, "text-embedding-3-small");
+    assert_eq!(request.dimensions, Some(256));
+    assert_eq!(request.input, EmbeddingInput::String("hello".into()));
+}
+
+#[test]
+fn images_builder_clone() {
+    let mut base = CreateImageRequestArgs::default();
+    base.model(ImageModel::DallE3).size(ImageSize::S1024x1024);
+
+    let mut cat = base.clone();
+    cat.prompt("a cat");
+    let mut dog = base.clone();
+    dog.prompt("a dog");
+
+    let (cat, dog) = (cat.build().unwrap(), dog.build().unwrap());
+    assert_eq!(cat.model, dog.model);
+    assert_eq!(cat.size, Some(ImageSize::S1024x1024));
+    assert_eq!(
+        (cat.prompt.as_str(), dog.prompt.as_str()),
+        ("a cat", "a dog")
+    );
+}
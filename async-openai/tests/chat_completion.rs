@@ -45,3 +45,58 @@ fn create_prompt_input() {
     let _ = prompt_input(&prompt);
     let _ = prompt_input(prompt);
 }
+
+#[test]
+fn push_messages() {
+    use async_openai::types::chat::{
+        ChatCompletionRequestUserMessageArgs, ChatCompletionResponseMessage,
+        CreateChatCompletionRequestArgs,
+    };
+    use serde_json::json;
+
+    let mut request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .build()
+        .unwrap();
+    let response: ChatCompletionResponseMessage = serde_json::from_value(json!({
+        "role": "assistant",
+        "content": null,
+        "tool_calls": [{
+            "id": "call_1",
+            "type": "function",
+            "function": {"name": "get_weather", "arguments": "{}"}
+        }]
+    }))
+    .unwrap();
+
+    request
+        .push_system("You are a weather bot.")
+        .push_user("Weather in Paris?")
+        .push_assistant(response)
+        .push_tool_result("call_1", "sunny")
+        .push_assistant("It is sunny.")
+        .push_user(
+            ChatCompletionRequestUserMessageArgs::default()
+                .content("Thanks!")
+                .name("alice")
+                .build()
+                .unwrap(),
+        );
+
+    let messages = serde_json::to_value(&request.messages).unwrap();
+    assert_eq!(
+        messages,
+        json!([
+            {"role": "system", "content": "You are a weather bot."},
+            {"role": "user", "content": "Weather in Paris?"},
+            {"role": "assistant", "tool_calls": [{
+                "id": "call_1",
+                "type": "function",
+                "function": {"name": "get_weather", "arguments": "{}"}
+            }]},
+            {"role": "tool", "content": "sunny", "tool_call_id": "call_1"},
+            {"role": "assistant", "content": "It is sunny."},
+            {"role": "user", "content": "Thanks!", "name": "alice"},
+        ])
+    );
+}
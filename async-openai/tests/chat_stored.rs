@@ -0,0 +1,146 @@
+//! Stored chat completions: `store` and `metadata` on the request, and the endpoints to retrieve,
+//! list, update and delete them.
+use std::collections::HashMap;
+
+use async_openai::{
+    traits::RequestOptionsBuilder,
+    transport::{MockResponse, MockTransport},
+    types::{
+        chat::{
+            ChatCompletionRequestUserMessage, CreateChatCompletionRequestArgs,
+            UpdateChatCompletionRequestArgs,
+        },
+        Metadata,
+    },
+    Client,
+};
+use serde_json::json;
+
+fn completion(id: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "object": "chat.completion",
+        "created": 1677652288,
+        "model": "gpt-4o-mini",
+        "metadata": {"pipeline": "eval"},
+        "choices": [{
+            "index": 0,
+            "message": {"role": "assistant", "content": "Hi"},
+            "finish_reason": "stop"
+        }]
+    })
+}
+
+#[tokio::test]
+async fn stores_completions_with_metadata() {
+    let transport = MockTransport::new().on(
+        "/chat/completions",
+        MockResponse::json(200, completion("chatcmpl-1")),
+    );
+    let client = Client::new().with_transport(transport.clone());
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello").into()])
+        .store(true)
+        .metadata([("pipeline", "eval")].into_iter().collect::<Metadata>())
+        .build()
+        .unwrap();
+    client.chat().create(request).await.unwrap();
+
+    let body = transport.requests()[0].json().unwrap();
+    assert_eq!(body["store"], true);
+    assert_eq!(body["metadata"], json!({"pipeline": "eval"}));
+}
+
+#[tokio::test]
+async fn retrieves_lists_updates_and_deletes_stored_completions() {
+    let transport = MockTransport::new()
+        .on(
+            "/chat/completions",
+            MockResponse::json(
+                200,
+                json!({
+                    "object": "list",
+                    "data": [completion("chatcmpl-1")],
+                    "first_id": "chatcmpl-1",
+                    "last_id": "chatcmpl-1",
+                    "has_more": false
+                }),
+            ),
+        )
+        .on(
+            "/chat/completions/chatcmpl-1",
+            MockResponse::json(200, completion("chatcmpl-1")),
+        )
+        .on(
+            "/chat/completions/chatcmpl-1/messages",
+            MockResponse::json(
+                200,
+                json!({
+                    "object": "list",
+                    "data": [{"id": "chatcmpl-1-0", "role": "user", "content": "Hello"}],
+                    "first_id": "chatcmpl-1-0",
+                    "last_id": "chatcmpl-1-0",
+                    "has_more": false
+                }),
+            ),
+        );
+    let client = Client::new().with_transport(transport.clone());
+
+    let list = client
+        .chat()
+        .query(&[("metadata[pipeline]", "eval")])
+        .unwrap()
+        .list()
+        .await
+        .unwrap();
+    assert_eq!(list.data[0].id, "chatcmpl-1");
+
+    let completion = client.chat().retrieve("chatcmpl-1").await.unwrap();
+    assert_eq!(
+        completion.metadata.as_ref().and_then(|m| m.get("pipeline")),
+        Some("eval")
+    );
+
+    let messages = client.chat().messages("chatcmpl-1").await.unwrap();
+    assert_eq!(messages.data.len(), 1);
+
+    let metadata: Metadata = HashMap::from([("pipeline".to_string(), "prod".to_string())]).into();
+    client
+        .chat()
+        .update(
+            "chatcmpl-1",
+            UpdateChatCompletionRequestArgs::default()
+                .metadata(metadata)
+                .build()
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].url.query(), Some("metadata%5Bpipeline%5D=eval"));
+    assert_eq!(requests[3].method, "POST");
+    assert_eq!(
+        requests[3].json().unwrap(),
+        json!({"metadata": {"pipeline": "prod"}})
+    );
+}
+
+#[tokio::test]
+async fn deletes_stored_completions() {
+    let transport = MockTransport::new().on(
+        "/chat/completions/chatcmpl-1",
+        MockResponse::json(
+            200,
+            json!({"object": "chat.completion.deleted", "id": "chatcmpl-1", "deleted": true}),
+        ),
+    );
+    let client = Client::new().with_transport(transport.clone());
+
+    let deleted = client.chat().delete("chatcmpl-1").await.unwrap();
+
+    assert!(deleted.deleted);
+    assert_eq!(transport.requests()[0].method, "DELETE");
+}
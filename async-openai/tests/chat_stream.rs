@@ -0,0 +1,553 @@
+use async_openai::types::chat::{
+    ChatCompletionMessageToolCalls, ChatCompletionStreamAccumulator,
+    ChatCompletionStreamResponseDelta, CompletionRecord, CreateChatCompletionResponse,
+    CreateChatCompletionStreamResponse, FinishReason, PartialJsonAccumulator, Role, ServiceTier,
+    TokenPrices, ToolCallStreamAccumulator, UsageTracker,
+};
+use serde_json::json;
+
+fn delta(tool_calls: serde_json::Value) -> ChatCompletionStreamResponseDelta {
+    serde_json::from_value(json!({ "tool_calls": tool_calls })).unwrap()
+}
+
+#[test]
+fn tool_call_accumulator_assembles_fragments() {
+    let deltas = [
+        delta(json!([{
+            "index": 0,
+            "id": "call_1",
+            "type": "function",
+            "function": { "name": "get_weather", "arguments": "" }
+        }])),
+        delta(json!([{ "index": 0, "function": { "arguments": "{\"loc" } }])),
+        delta(json!([{ "index": 0, "function": { "arguments": "ation\":\"Paris\"}" } }])),
+        delta(json!([{
+            "index": 1,
+            "id": "call_2",
+            "type": "function",
+            "function": { "name": "get_time", "arguments": "{}" }
+        }])),
+    ];
+
+    let mut accumulator = ToolCallStreamAccumulator::new();
+    assert!(accumulator.push(&deltas[0]).is_empty());
+    assert!(accumulator.push(&deltas[1]).is_empty());
+    assert!(accumulator.push(&deltas[2]).is_empty());
+
+    let completed = accumulator.push(&deltas[3]);
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].id, "call_1");
+    assert_eq!(completed[0].function.name, "get_weather");
+    assert_eq!(completed[0].function.arguments, "{\"location\":\"Paris\"}");
+
+    let remaining = accumulator.finish();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, "call_2");
+    assert_eq!(remaining[0].function.name, "get_time");
+    assert_eq!(remaining[0].function.arguments, "{}");
+    assert!(accumulator.is_empty());
+}
+
+#[test]
+fn tool_call_accumulator_handles_multiple_calls_in_one_delta() {
+    let mut accumulator = ToolCallStreamAccumulator::new();
+    let completed = accumulator.push(&delta(json!([
+        { "index": 0, "id": "call_a", "function": { "name": "a", "arguments": "{\"x\":1}" } },
+        { "index": 1, "id": "call_b", "function": { "name": "b", "arguments": "{\"y\":" } }
+    ])));
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].id, "call_a");
+    assert_eq!(completed[0].function.arguments, "{\"x\":1}");
+
+    assert!(accumulator
+        .push(&delta(
+            json!([{ "index": 1, "function": { "arguments": "2}" } }])
+        ))
+        .is_empty());
+
+    let remaining = accumulator.finish();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, "call_b");
+    assert_eq!(remaining[0].function.arguments, "{\"y\":2}");
+    assert!(accumulator.finish().is_empty());
+}
+
+#[test]
+fn tool_call_accumulator_handles_interleaved_calls() {
+    let mut accumulator = ToolCallStreamAccumulator::new();
+    let deltas = [
+        delta(
+            json!([{ "index": 0, "id": "call_a", "function": { "name": "a", "arguments": "{\"x\":" } }]),
+        ),
+        delta(
+            json!([{ "index": 1, "id": "call_b", "function": { "name": "b", "arguments": "{\"y\":" } }]),
+        ),
+        delta(json!([{ "index": 0, "function": { "arguments": "1}" } }])),
+        delta(json!([{ "index": 1, "function": { "arguments": "2}" } }])),
+    ];
+    for delta in &deltas[..3] {
+        assert!(accumulator.push(delta).is_empty());
+    }
+
+    // call_a is complete once a chunk for another index arrives
+    let completed = accumulator.push(&deltas[3]);
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].id, "call_a");
+    assert_eq!(completed[0].function.arguments, "{\"x\":1}");
+
+    let remaining = accumulator.finish();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, "call_b");
+    assert_eq!(remaining[0].function.name, "b");
+    assert_eq!(remaining[0].function.arguments, "{\"y\":2}");
+}
+
+#[test]
+fn tool_call_accumulator_keeps_repeated_id_and_name() {
+    // as sent by compatible servers repeating the id and name in every chunk
+    let mut accumulator = ToolCallStreamAccumulator::new();
+    for arguments in ["{\"city\":", "\"Paris\"}"] {
+        let delta = delta(json!([{
+            "index": 0,
+            "id": "call_1",
+            "type": "function",
+            "function": { "name": "get_weather", "arguments": arguments }
+        }]));
+        assert!(accumulator.push(&delta).is_empty());
+    }
+
+    let completed = accumulator.finish();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].id, "call_1");
+    assert_eq!(completed[0].function.name, "get_weather");
+    assert_eq!(completed[0].function.arguments, "{\"city\":\"Paris\"}");
+}
+
+fn chunk(
+    choices: serde_json::Value,
+    usage: serde_json::Value,
+) -> CreateChatCompletionStreamResponse {
+    serde_json::from_value(json!({
+        "id": "chatcmpl-123",
+        "object": "chat.completion.chunk",
+        "created": 1694268190,
+        "model": "gpt-4o-mini",
+        "system_fingerprint": "fp_44709d6fcb",
+        "choices": choices,
+        "usage": usage,
+    }))
+    .unwrap()
+}
+
+#[test]
+#[allow(deprecated)]
+fn stream_accumulator_rebuilds_response() {
+    let chunks = [
+        chunk(
+            json!([
+                { "index": 0, "delta": { "role": "assistant", "content": "" }, "finish_reason": null },
+                { "index": 1, "delta": { "role": "assistant", "content": "" }, "finish_reason": null }
+            ]),
+            json!(null),
+        ),
+        chunk(
+            json!([
+                { "index": 0, "delta": { "content": "Hello" }, "finish_reason": null },
+                { "index": 1, "delta": { "tool_calls": [{ "index": 0, "id": "call_1", "type": "function", "function": { "name": "greet", "arguments": "{\"na" } }] }, "finish_reason": null }
+            ]),
+            json!(null),
+        ),
+        chunk(
+            json!([
+                { "index": 0, "delta": { "content": " world" }, "finish_reason": null },
+                { "index": 1, "delta": { "tool_calls": [{ "index": 0, "function": { "arguments": "me\":\"Bob\"}" } }] }, "finish_reason": null }
+            ]),
+            json!(null),
+        ),
+        chunk(
+            json!([
+                { "index": 0, "delta": {}, "finish_reason": "stop" },
+                { "index": 1, "delta": {}, "finish_reason": "tool_calls" }
+            ]),
+            json!(null),
+        ),
+        chunk(
+            json!([]),
+            json!({ "prompt_tokens": 9, "completion_tokens": 12, "total_tokens": 21 }),
+        ),
+    ];
+
+    let mut accumulator = ChatCompletionStreamAccumulator::new();
+    for chunk in &chunks {
+        accumulator.push(chunk);
+    }
+    let response = accumulator.finish();
+
+    assert_eq!(response.id, "chatcmpl-123");
+    assert_eq!(response.object, "chat.completion");
+    assert_eq!(response.model, "gpt-4o-mini");
+    assert_eq!(response.created, 1694268190);
+    assert_eq!(
+        response.system_fingerprint.as_deref(),
+        Some("fp_44709d6fcb")
+    );
+    assert_eq!(response.usage.as_ref().unwrap().total_tokens, 21);
+    assert_eq!(response.choices.len(), 2);
+
+    let first = &response.choices[0];
+    assert_eq!(first.index, 0);
+    assert_eq!(first.message.role, Role::Assistant);
+    assert_eq!(first.message.content.as_deref(), Some("Hello world"));
+    assert_eq!(first.message.tool_calls, None);
+    assert_eq!(first.finish_reason, Some(FinishReason::Stop));
+
+    let second = &response.choices[1];
+    assert_eq!(second.index, 1);
+    assert_eq!(second.finish_reason, Some(FinishReason::ToolCalls));
+    let tool_calls = second.message.tool_calls.as_ref().unwrap();
+    assert_eq!(tool_calls.len(), 1);
+    match &tool_calls[0] {
+        ChatCompletionMessageToolCalls::Function(tool_call) => {
+            assert_eq!(tool_call.id, "call_1");
+            assert_eq!(tool_call.function.name, "greet");
+            assert_eq!(tool_call.function.arguments, "{\"name\":\"Bob\"}");
+        }
+        other => panic!("unexpected tool call: {other:?}"),
+    }
+}
+
+#[test]
+fn stream_accumulator_demultiplexes_choices() {
+    // n = 2, with the chunks of both choices interleaved and out of order
+    let chunks = [
+        json!([{ "index": 1, "delta": { "role": "assistant", "content": "" }, "finish_reason": null }]),
+        json!([{ "index": 0, "delta": { "role": "assistant", "content": "" }, "finish_reason": null }]),
+        json!([{ "index": 0, "delta": { "content": "Roses are" }, "finish_reason": null }]),
+        json!([{ "index": 1, "delta": { "content": "The sky" }, "finish_reason": null }]),
+        json!([{ "index": 1, "delta": { "content": " is blue" }, "finish_reason": null }]),
+        json!([{ "index": 0, "delta": { "content": " red" }, "finish_reason": null }]),
+        json!([{ "index": 1, "delta": {}, "finish_reason": "stop" }]),
+        json!([{ "index": 0, "delta": {}, "finish_reason": "length" }]),
+    ];
+
+    let mut accumulator = ChatCompletionStreamAccumulator::new();
+    for (i, choices) in chunks.into_iter().enumerate() {
+        accumulator.push(&chunk(choices, json!(null)));
+        if i == 3 {
+            assert_eq!(accumulator.contents(), ["Roses are", "The sky"]);
+        }
+    }
+    assert_eq!(accumulator.content(0), Some("Roses are red"));
+    assert_eq!(accumulator.content(1), Some("The sky is blue"));
+    assert_eq!(accumulator.content(2), None);
+
+    let response = accumulator.finish();
+    let choices: Vec<_> = response
+        .choices
+        .iter()
+        .map(|choice| {
+            (
+                choice.index,
+                choice.message.content.as_deref(),
+                choice.finish_reason.clone(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        choices,
+        [
+            (0, Some("Roses are red"), Some(FinishReason::Length)),
+            (1, Some("The sky is blue"), Some(FinishReason::Stop)),
+        ]
+    );
+}
+
+#[test]
+fn stream_accumulator_orders_interleaved_tool_calls_by_index() {
+    // call_b completes first, when a chunk for index 0 arrives
+    let chunks = [
+        json!([{ "index": 0, "id": "call_a", "function": { "name": "a", "arguments": "{\"x\":" } }]),
+        json!([{ "index": 1, "id": "call_b", "function": { "name": "b", "arguments": "{\"y\":2}" } }]),
+        json!([{ "index": 0, "function": { "arguments": "1}" } }]),
+    ];
+
+    let mut accumulator = ChatCompletionStreamAccumulator::new();
+    for tool_calls in chunks {
+        accumulator.push(&chunk(
+            json!([{ "index": 0, "delta": { "tool_calls": tool_calls }, "finish_reason": null }]),
+            json!(null),
+        ));
+    }
+    let response = accumulator.finish();
+
+    let tool_calls: Vec<_> = response.choices[0]
+        .message
+        .tool_calls
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|tool_call| match tool_call {
+            ChatCompletionMessageToolCalls::Function(tool_call) => {
+                (tool_call.id.as_str(), tool_call.function.arguments.as_str())
+            }
+            other => panic!("unexpected tool call: {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        tool_calls,
+        [("call_a", "{\"x\":1}"), ("call_b", "{\"y\":2}")]
+    );
+}
+
+#[test]
+fn stream_accumulator_concatenates_refusal_separately() {
+    let chunks = [
+        chunk(
+            json!([{ "index": 0, "delta": { "role": "assistant", "content": "", "refusal": null }, "finish_reason": null }]),
+            json!(null),
+        ),
+        chunk(
+            json!([{ "index": 0, "delta": { "refusal": "I can't " }, "finish_reason": null }]),
+            json!(null),
+        ),
+        chunk(
+            json!([{ "index": 0, "delta": { "refusal": "help with that." }, "finish_reason": "stop" }]),
+            json!(null),
+        ),
+    ];
+
+    let mut accumulator = ChatCompletionStreamAccumulator::new();
+    for chunk in &chunks {
+        accumulator.push(chunk);
+    }
+    let response = accumulator.finish();
+
+    let message = &response.choices[0].message;
+    assert!(message.is_refusal());
+    assert_eq!(message.refusal(), Some("I can't help with that."));
+    assert_eq!(message.content.as_deref(), Some(""));
+}
+
+#[test]
+fn usage_tracker_totals_streams_and_responses() {
+    let mut tracker = UsageTracker::new();
+    for chunk in [
+        chunk(
+            json!([{ "index": 0, "delta": { "content": "Hi" }, "finish_reason": "stop" }]),
+            json!(null),
+        ),
+        chunk(
+            json!([]),
+            json!({
+                "prompt_tokens": 1200,
+                "completion_tokens": 300,
+                "total_tokens": 1500,
+                "prompt_tokens_details": { "cached_tokens": 1024 },
+                "completion_tokens_details": { "reasoning_tokens": 128 }
+            }),
+        ),
+    ] {
+        tracker.add_chunk(&chunk);
+    }
+
+    let response: CreateChatCompletionResponse = serde_json::from_value(json!({
+        "id": "chatcmpl-456",
+        "object": "chat.completion",
+        "created": 1694268190,
+        "model": "gpt-4o-mini",
+        "choices": [],
+        "usage": { "prompt_tokens": 800, "completion_tokens": 200, "total_tokens": 1000 }
+    }))
+    .unwrap();
+    tracker.add_response(&response);
+
+    assert_eq!(tracker.requests, 2);
+    assert_eq!(tracker.prompt_tokens, 2000);
+    assert_eq!(tracker.completion_tokens, 500);
+    assert_eq!(tracker.total_tokens, 2500);
+    assert_eq!(tracker.cached_tokens, 1024);
+    assert_eq!(tracker.reasoning_tokens, 128);
+    assert_eq!(tracker.uncached_prompt_tokens(), 976);
+
+    // 976 * 1.0 + 1024 * 0.5 + 500 * 2.0 per 1K tokens
+    let prices = TokenPrices::new(1.0, 2.0).with_cached_prompt(0.5);
+    assert!((tracker.cost_estimate(&prices) - 2.488).abs() < 1e-9);
+}
+
+#[test]
+fn stream_accumulator_records_the_completion() {
+    let mut first = chunk(
+        json!([{ "index": 0, "delta": { "role": "assistant", "content": "Hi" }, "finish_reason": "stop" }]),
+        json!(null),
+    );
+    first.service_tier = Some(ServiceTier::Priority);
+    let last = chunk(
+        json!([]),
+        json!({
+            "prompt_tokens": 1200,
+            "completion_tokens": 300,
+            "total_tokens": 1500,
+            "prompt_tokens_details": { "cached_tokens": 1024 }
+        }),
+    );
+
+    let mut accumulator = ChatCompletionStreamAccumulator::new();
+    accumulator.push(&first);
+    accumulator.push(&last);
+    let record = accumulator.record(std::time::Duration::from_millis(1250));
+
+    assert_eq!(
+        serde_json::to_value(&record).unwrap(),
+        json!({
+            "id": "chatcmpl-123",
+            "model": "gpt-4o-mini",
+            "service_tier": "priority",
+            "system_fingerprint": "fp_44709d6fcb",
+            "usage": {
+                "prompt_tokens": 1200,
+                "completion_tokens": 300,
+                "total_tokens": 1500,
+                "prompt_tokens_details": { "audio_tokens": null, "cached_tokens": 1024 }
+            },
+            "latency_ms": 1250
+        })
+    );
+    assert_eq!(
+        CompletionRecord::from_response(
+            &accumulator.finish(),
+            std::time::Duration::from_millis(1250)
+        ),
+        record
+    );
+}
+
+#[test]
+fn accumulator_keeps_azure_content_filter_results() {
+    let chunks: Vec<CreateChatCompletionStreamResponse> = [
+        json!({
+            "id": "", "object": "", "created": 0, "model": "", "choices": [],
+            "prompt_filter_results": [{
+                "prompt_index": 0,
+                "content_filter_results": {"hate": {"filtered": false, "severity": "safe"}}
+            }]
+        }),
+        json!({
+            "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "delta": {"role": "assistant", "content": "Hi"},
+                "content_filter_results": {"hate": {"filtered": false, "severity": "safe"}}
+            }]
+        }),
+        json!({
+            "id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "delta": {},
+                "finish_reason": "content_filter",
+                "content_filter_results": {"hate": {"filtered": true, "severity": "high"}}
+            }]
+        }),
+    ]
+    .into_iter()
+    .map(|chunk| serde_json::from_value(chunk).unwrap())
+    .collect();
+
+    let mut accumulator = ChatCompletionStreamAccumulator::new();
+    for chunk in &chunks {
+        accumulator.push(chunk);
+    }
+    let response = accumulator.finish();
+
+    assert_eq!(response.prompt_filter_results.unwrap().len(), 1);
+    assert!(response.choices[0]
+        .content_filter_results
+        .as_ref()
+        .unwrap()
+        .is_filtered());
+}
+
+#[derive(Debug, serde::Deserialize, PartialEq)]
+struct Recipe {
+    title: String,
+    servings: u32,
+    vegan: bool,
+    ingredients: Vec<String>,
+}
+
+#[test]
+fn partial_json_accumulator_parses_prefixes() {
+    let content = r#"{"title": "Pancakes \"fluffy\" \u00e9", "servings": 4, "vegan": false, "ingredients": ["flour", "milk"]}"#;
+    let mut accumulator = PartialJsonAccumulator::<Recipe>::new();
+    assert_eq!(accumulator.partial(), None);
+
+    // every prefix parses, and the fields seen so far only ever grow
+    let mut previous = json!({});
+    for c in content.chars() {
+        let partial = accumulator.push(&c.to_string()).unwrap();
+        for (key, value) in previous.as_object().unwrap() {
+            let current = &partial[key];
+            match (value, current) {
+                (serde_json::Value::String(before), serde_json::Value::String(after)) => {
+                    assert!(after.starts_with(before.as_str()), "{before} -> {after}")
+                }
+                (serde_json::Value::Array(before), serde_json::Value::Array(after)) => {
+                    assert!(after.len() >= before.len())
+                }
+                _ => assert_eq!(value, current),
+            }
+        }
+        previous = partial;
+    }
+
+    assert_eq!(accumulator.buffer(), content);
+    let recipe = accumulator.finish().unwrap();
+    assert_eq!(recipe.title, "Pancakes \"fluffy\" \u{e9}");
+    assert_eq!(recipe.ingredients, ["flour", "milk"]);
+}
+
+#[test]
+fn partial_json_accumulator_closes_and_drops_incomplete_values() {
+    let partial = |json: &str| {
+        let mut accumulator = PartialJsonAccumulator::<serde_json::Value>::new();
+        accumulator.push(json)
+    };
+
+    assert_eq!(partial(r#"{"title": "Pan"#), Some(json!({"title": "Pan"})));
+    assert_eq!(partial(r#"{"title": "Pan\"#), Some(json!({"title": "Pan"})));
+    assert_eq!(partial(r#"{"title": "P\u00"#), Some(json!({"title": "P"})));
+    assert_eq!(
+        partial(r#"{"title": "Pan", "serv"#),
+        Some(json!({"title": "Pan"}))
+    );
+    assert_eq!(
+        partial(r#"{"title": "Pan", "servings":"#),
+        Some(json!({"title": "Pan"}))
+    );
+    assert_eq!(partial(r#"{"servings": 12"#), Some(json!({"servings": 12})));
+    assert_eq!(
+        partial(r#"{"servings": 1, "vegan": tr"#),
+        Some(json!({"servings": 1}))
+    );
+    assert_eq!(
+        partial(r#"{"steps": [{"a": [1, 2"#),
+        Some(json!({"steps": [{"a": [1, 2]}]}))
+    );
+    assert_eq!(partial("  "), None);
+
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    struct PartialRecipe {
+        title: Option<String>,
+        servings: Option<u32>,
+    }
+    let mut accumulator = PartialJsonAccumulator::<Recipe>::new();
+    accumulator.push(r#"{"title": "Pancakes", "serv"#);
+    assert_eq!(
+        accumulator.partial_as::<PartialRecipe>(),
+        Some(PartialRecipe {
+            title: Some("Pancakes".into()),
+            servings: None
+        })
+    );
+    assert!(accumulator.finish().is_err());
+}
@@ -0,0 +1,248 @@
+//! Minimal HTTP/1.1 server serving canned responses, for tests that need a real socket.
+#![allow(dead_code)]
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use async_openai::{config::OpenAIConfig, transport::MockTransport, Client};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(&'static str, &'static str)>,
+    pub body: Vec<u8>,
+    pub delay: Option<Duration>,
+}
+
+impl MockResponse {
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self::bytes(status, body.into())
+    }
+
+    /// A response with a body that is not UTF-8, such as audio.
+    pub fn bytes(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: body.into(),
+            delay: None,
+        }
+    }
+
+    pub fn header(mut self, name: &'static str, value: &'static str) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    /// Wait for `delay` after reading the request before responding.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+/// Bodies are recorded up to this many bytes, larger uploads only count towards `body_len`.
+pub const RECORDED_BODY_LIMIT: usize = 1024 * 1024;
+
+/// A request received by [mock_server_recording], with its body de-chunked.
+#[derive(Clone, Debug, Default)]
+pub struct RecordedRequest {
+    pub head: String,
+    /// The first [RECORDED_BODY_LIMIT] bytes of the body.
+    pub body: Vec<u8>,
+    pub body_len: usize,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.head.lines().skip(1).find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim().eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+
+    fn record(&mut self, bytes: &[u8]) {
+        let keep = bytes
+            .len()
+            .min(RECORDED_BODY_LIMIT.saturating_sub(self.body.len()));
+        self.body.extend_from_slice(&bytes[..keep]);
+        self.body_len += bytes.len();
+    }
+}
+
+/// Serves `responses` in order (the last one repeats), returns base url and request counter.
+pub async fn mock_server(responses: Vec<MockResponse>) -> (String, Arc<AtomicUsize>) {
+    let (api_base, requests, _) = serve(responses, false).await;
+    (api_base, requests)
+}
+
+/// Like [mock_server], but reads and records every request including its body.
+pub async fn mock_server_recording(
+    responses: Vec<MockResponse>,
+) -> (String, Arc<Mutex<Vec<RecordedRequest>>>) {
+    let (api_base, _, recorded) = serve(responses, true).await;
+    (api_base, recorded)
+}
+
+/// Client sending its requests to `api_base`, e.g. of [mock_server].
+pub fn client(api_base: String) -> Client<OpenAIConfig> {
+    Client::with_config(
+        OpenAIConfig::new()
+            .with_api_base(api_base)
+            .with_api_key("test"),
+    )
+}
+
+/// A chat completion request saying "Hello" to `gpt-4o-mini`.
+#[cfg(feature = "chat-completion-types")]
+pub fn chat_request() -> async_openai::types::chat::CreateChatCompletionRequest {
+    use async_openai::types::chat::{
+        ChatCompletionRequestUserMessage, CreateChatCompletionRequestArgs,
+    };
+
+    CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello").into()])
+        .build()
+        .unwrap()
+}
+
+/// Client sending its requests to `transport` instead of the network.
+pub fn transport_client(transport: &MockTransport) -> Client<OpenAIConfig> {
+    Client::with_config(OpenAIConfig::new().with_api_key("test")).with_transport(transport.clone())
+}
+
+async fn serve(
+    responses: Vec<MockResponse>,
+    record: bool,
+) -> (String, Arc<AtomicUsize>, Arc<Mutex<Vec<RecordedRequest>>>) {
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+    let recorder = recorded.clone();
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let n = counter.fetch_add(1, Ordering::SeqCst);
+            let response = responses[n.min(responses.len() - 1)].clone();
+            let recorder = recorder.clone();
+
+            tokio::spawn(async move {
+                let mut buf = Vec::new();
+                let mut chunk = vec![0u8; 64 * 1024];
+                let head_end = loop {
+                    if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                        break pos + 4;
+                    }
+                    let len = socket.read(&mut chunk).await.unwrap_or(0);
+                    if len == 0 {
+                        break buf.len();
+                    }
+                    buf.extend_from_slice(&chunk[..len]);
+                };
+
+                if record {
+                    let mut request = RecordedRequest {
+                        head: String::from_utf8_lossy(&buf[..head_end]).into_owned(),
+                        ..Default::default()
+                    };
+                    let mut rest = buf.split_off(head_end);
+                    if request
+                        .header("transfer-encoding")
+                        .is_some_and(|v| v.eq_ignore_ascii_case("chunked"))
+                    {
+                        read_chunked(&mut socket, &mut rest, &mut request).await;
+                    } else {
+                        let length: usize = request
+                            .header("content-length")
+                            .and_then(|v| v.parse().ok())
+                            .unwrap_or(0);
+                        request.record(&rest);
+                        while request.body_len < length {
+                            let len = socket.read(&mut chunk).await.unwrap_or(0);
+                            if len == 0 {
+                                break;
+                            }
+                            request.record(&chunk[..len]);
+                        }
+                    }
+                    recorder.lock().unwrap().push(request);
+                }
+
+                if let Some(delay) = response.delay {
+                    tokio::time::sleep(delay).await;
+                }
+
+                let mut raw = format!("HTTP/1.1 {} Mock\r\n", response.status);
+                for (name, value) in response.headers.iter() {
+                    raw.push_str(&format!("{name}: {value}\r\n"));
+                }
+                // JSON unless the response sets its own content type
+                if !response
+                    .headers
+                    .iter()
+                    .any(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                {
+                    raw.push_str("content-type: application/json\r\n");
+                }
+                raw.push_str(&format!(
+                    "content-length: {}\r\nconnection: close\r\n\r\n",
+                    response.body.len(),
+                ));
+                let mut raw = raw.into_bytes();
+                raw.extend_from_slice(&response.body);
+                // the client may have given up waiting already
+                let _ = socket.write_all(&raw).await;
+                let _ = socket.shutdown().await;
+            });
+        }
+    });
+
+    (format!("http://{addr}/v1"), requests, recorded)
+}
+
+/// Decodes a `transfer-encoding: chunked` body into `request`, `pending` holds bytes already read.
+async fn read_chunked(
+    socket: &mut tokio::net::TcpStream,
+    pending: &mut Vec<u8>,
+    request: &mut RecordedRequest,
+) {
+    let mut chunk = vec![0u8; 64 * 1024];
+    loop {
+        let line_end = loop {
+            if let Some(pos) = pending.windows(2).position(|w| w == b"\r\n") {
+                break pos;
+            }
+            let len = socket.read(&mut chunk).await.unwrap_or(0);
+            if len == 0 {
+                return;
+            }
+            pending.extend_from_slice(&chunk[..len]);
+        };
+        let size_line = String::from_utf8_lossy(&pending[..line_end]).into_owned();
+        let size = usize::from_str_radix(size_line.split(';').next().unwrap().trim(), 16).unwrap();
+        while pending.len() < line_end + 2 + size + 2 {
+            let len = socket.read(&mut chunk).await.unwrap_or(0);
+            if len == 0 {
+                return;
+            }
+            pending.extend_from_slice(&chunk[..len]);
+        }
+        request.record(&pending[line_end + 2..line_end + 2 + size]);
+        pending.drain(..line_end + 2 + size + 2);
+        if size == 0 {
+            return;
+        }
+    }
+}
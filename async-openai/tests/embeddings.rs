@@ -1,5 +1,11 @@
 //! This test is primarily to make sure that macros_rules for From traits are correct.
-use async_openai::types::embeddings::EmbeddingInput;
+use async_openai::{
+    error::OpenAIError,
+    types::embeddings::{
+        Base64Embedding, Base64EmbeddingVector, CreateEmbeddingRequest, CreateEmbeddingRequestArgs,
+        CreateEmbeddingResponse, CreateEncodedEmbeddingResponse, EmbeddingInput, EmbeddingVector,
+    },
+};
 
 fn embedding_input<T>(input: T) -> EmbeddingInput
 where
@@ -44,3 +50,148 @@ fn create_embedding_input() {
     let _ = embedding_input(&input);
     let _ = embedding_input(input);
 }
+
+#[test]
+fn base64_embedding_round_trip() {
+    let floats = vec![1.0f32, -0.5, 0.0, 3.25e-3, f32::MIN_POSITIVE];
+    let encoded = Base64EmbeddingVector::encode(&floats);
+    assert_eq!(Base64EmbeddingVector::encode(&[1.0]).0, "AACAPw==");
+
+    let json = serde_json::to_value(EmbeddingVector::from(encoded.clone())).unwrap();
+    assert_eq!(json, serde_json::Value::String(encoded.0.clone()));
+
+    let vector: EmbeddingVector = serde_json::from_value(json).unwrap();
+    assert_eq!(vector, EmbeddingVector::Base64(encoded.clone()));
+    assert_eq!(vector.to_f32().unwrap(), floats);
+    assert_eq!(Vec::<f32>::from(encoded), floats);
+}
+
+#[test]
+fn embedding_vector_deserializes_floats() {
+    let vector: EmbeddingVector = serde_json::from_str("[0.25, -1.5]").unwrap();
+    assert_eq!(vector, EmbeddingVector::Float(vec![0.25, -1.5]));
+    assert_eq!(vector.to_f32().unwrap(), [0.25, -1.5]);
+}
+
+#[test]
+fn base64_embedding_from_response() {
+    let embedding: Base64Embedding = serde_json::from_str(
+        r#"{ "index": 0, "object": "embedding", "embedding": "AACAPwAAAMA=" }"#,
+    )
+    .unwrap();
+    assert_eq!(embedding.embedding.to_f32().unwrap(), [1.0, -2.0]);
+}
+
+#[test]
+fn encoded_embedding_response_in_either_format() {
+    for embedding in [
+        serde_json::json!([1.0, -2.0]),
+        serde_json::json!("AACAPwAAAMA="),
+    ] {
+        let response: CreateEncodedEmbeddingResponse = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "model": "text-embedding-3-small",
+            "data": [{ "index": 0, "object": "embedding", "embedding": embedding }],
+            "usage": { "prompt_tokens": 1, "total_tokens": 1 }
+        }))
+        .unwrap();
+        assert_eq!(response.data[0].embedding.to_f32().unwrap(), [1.0, -2.0]);
+    }
+}
+
+#[test]
+fn invalid_base64_embedding_is_an_error() {
+    assert!(Base64EmbeddingVector("not base64!".into())
+        .to_f32()
+        .is_err());
+    // 3 bytes is not a whole f32
+    assert!(Base64EmbeddingVector("AAAA".into()).to_f32().is_err());
+}
+
+#[test]
+fn embedding_math() {
+    use async_openai::types::embeddings::math::{cosine_similarity, dot, l2_norm, top_k};
+
+    assert_eq!(dot(&[1.0, 2.0, 3.0], &[4.0, -5.0, 6.0]), 12.0);
+    assert_eq!(l2_norm(&[3.0, 4.0]), 5.0);
+    assert!((cosine_similarity(&[1.0, 1.0], &[2.0, 2.0]) - 1.0).abs() < 1e-6);
+    assert!((cosine_similarity(&[1.0, 0.0], &[-3.0, 0.0]) + 1.0).abs() < 1e-6);
+    assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+
+    let corpus: Vec<Vec<f32>> = vec![
+        vec![0.0, 1.0],
+        vec![1.0, 0.1],
+        vec![-1.0, 0.0],
+        vec![0.7, 0.7],
+    ];
+    let nearest = top_k(&[1.0, 0.0], &corpus, 3);
+    let indices: Vec<usize> = nearest.iter().map(|(index, _)| *index).collect();
+    assert_eq!(indices, [1, 3, 0]);
+    assert!(nearest.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    assert_eq!(top_k(&[1.0, 0.0], &corpus, 10).len(), 4);
+}
+
+#[test]
+#[should_panic(expected = "same length")]
+fn embedding_math_length_mismatch() {
+    async_openai::types::embeddings::math::dot(&[1.0], &[1.0, 2.0]);
+}
+
+#[test]
+#[should_panic(expected = "same length")]
+fn cosine_similarity_length_mismatch_with_a_zero_embedding() {
+    async_openai::types::embeddings::math::cosine_similarity(&[0.0], &[1.0, 2.0]);
+}
+
+#[test]
+fn embedding_dimensions_round_trip() {
+    let request = CreateEmbeddingRequestArgs::default()
+        .model("text-embedding-3-small")
+        .input("The food was delicious")
+        .dimensions(256u32)
+        .build()
+        .unwrap();
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(json["dimensions"], 256);
+    assert_eq!(
+        serde_json::from_value::<CreateEmbeddingRequest>(json).unwrap(),
+        request
+    );
+
+    let response: CreateEmbeddingResponse = serde_json::from_value(serde_json::json!({
+        "object": "list",
+        "model": "text-embedding-3-small",
+        "data": [{ "index": 0, "object": "embedding", "embedding": vec![0.0625; 256] }],
+        "usage": { "prompt_tokens": 4, "total_tokens": 4 }
+    }))
+    .unwrap();
+    assert_eq!(response.data[0].embedding.len(), 256);
+}
+
+#[test]
+fn embedding_dimensions_are_validated() {
+    let invalid = |model: &str, dimensions: u32| {
+        let mut args = CreateEmbeddingRequestArgs::default();
+        args.model(model).input("text").dimensions(dimensions);
+        let error = args.validate().unwrap_err();
+        assert!(matches!(error, OpenAIError::InvalidArgument(msg) if msg.contains("dimensions")));
+        // left to compatible servers by build
+        assert!(args.build().is_ok());
+    };
+    invalid("text-embedding-ada-002", 256);
+    invalid("text-embedding-3-small", 0);
+    invalid("text-embedding-3-small", 2048);
+    invalid("text-embedding-3-large", 3073);
+
+    for (model, dimensions) in [
+        ("text-embedding-3-small", 1536u32),
+        ("text-embedding-3-large", 3072),
+        ("my-local-model", 4096),
+    ] {
+        assert!(CreateEmbeddingRequestArgs::default()
+            .model(model)
+            .dimensions(dimensions)
+            .validate()
+            .is_ok());
+    }
+}
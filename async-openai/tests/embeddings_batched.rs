@@ -0,0 +1,247 @@
+//! `Embeddings::create_batched` and `Embeddings::create_deduped` through `MockTransport`.
+use async_openai::{
+    error::OpenAIError,
+    transport::{MockResponse, MockTransport},
+    types::embeddings::{CreateEmbeddingRequest, CreateEmbeddingRequestArgs},
+};
+use serde_json::json;
+
+mod common;
+
+/// A response with one embedding `[value, i]` per input of the batch, listed in reverse order.
+fn embeddings(value: f32, count: u32) -> MockResponse {
+    let data: Vec<_> = (0..count)
+        .rev()
+        .map(|i| json!({ "index": i, "object": "embedding", "embedding": [value, i as f32] }))
+        .collect();
+    MockResponse::json(
+        200,
+        json!({
+            "object": "list",
+            "model": "text-embedding-3-small",
+            "data": data,
+            "usage": { "prompt_tokens": count, "total_tokens": count }
+        }),
+    )
+}
+
+fn request() -> CreateEmbeddingRequest {
+    CreateEmbeddingRequestArgs::default()
+        .model("text-embedding-3-small")
+        .dimensions(2u32)
+        .build()
+        .unwrap()
+}
+
+fn inputs(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("input {i}")).collect()
+}
+
+#[tokio::test]
+async fn splits_inputs_and_preserves_order() {
+    let transport = MockTransport::new()
+        .on("/embeddings", embeddings(0.0, 2))
+        .on("/embeddings", embeddings(1.0, 2))
+        .on("/embeddings", embeddings(2.0, 1));
+    let client = common::transport_client(&transport);
+
+    let embeddings = client
+        .embeddings()
+        .create_batched(request(), inputs(5), 2, 1)
+        .await
+        .unwrap();
+
+    let indices: Vec<_> = embeddings.iter().map(|e| e.index).collect();
+    assert_eq!(indices, [0, 1, 2, 3, 4]);
+    let vectors: Vec<_> = embeddings.iter().map(|e| e.embedding.clone()).collect();
+    assert_eq!(
+        vectors,
+        [
+            vec![0.0, 0.0],
+            vec![0.0, 1.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![2.0, 0.0]
+        ]
+    );
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    let bodies: Vec<_> = requests.iter().map(|r| r.json().unwrap()).collect();
+    assert_eq!(bodies[0]["input"], json!(["input 0", "input 1"]));
+    assert_eq!(bodies[1]["input"], json!(["input 2", "input 3"]));
+    assert_eq!(bodies[2]["input"], json!(["input 4"]));
+    for body in &bodies {
+        assert_eq!(body["model"], "text-embedding-3-small");
+        assert_eq!(body["dimensions"], 2);
+    }
+}
+
+#[tokio::test]
+async fn sends_batches_concurrently() {
+    let transport = MockTransport::new().on("/embeddings", embeddings(0.0, 3));
+    let client = common::transport_client(&transport);
+
+    let embeddings = client
+        .embeddings()
+        .create_batched(request(), inputs(9), 3, 3)
+        .await
+        .unwrap();
+
+    let indices: Vec<_> = embeddings.iter().map(|e| e.index).collect();
+    assert_eq!(indices, (0..9).collect::<Vec<_>>());
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn returns_first_error() {
+    let transport = MockTransport::new()
+        .on("/embeddings", embeddings(0.0, 2))
+        .on(
+            "/embeddings",
+            MockResponse::error(400, "invalid_request_error", "bad input"),
+        );
+    let client = common::transport_client(&transport);
+
+    let error = client
+        .embeddings()
+        .create_batched(request(), inputs(6), 2, 1)
+        .await
+        .unwrap_err();
+
+    match error {
+        OpenAIError::ApiError(error) => assert_eq!(error.message, "bad input"),
+        other => panic!("unexpected error: {other:?}"),
+    }
+    // the remaining batches are not sent after the error
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn short_response_is_an_error() {
+    // a compatible server embedding only 1 of the 2 inputs of the first batch
+    let transport = MockTransport::new()
+        .on("/embeddings", embeddings(0.0, 1))
+        .on("/embeddings", embeddings(1.0, 2));
+    let client = common::transport_client(&transport);
+
+    let error = client
+        .embeddings()
+        .create_batched(request(), inputs(4), 2, 1)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&error, OpenAIError::JSONDeserialize(e, _) if e.to_string().contains("expected 2 embeddings for inputs 0..2, got 1")),
+        "{error:?}"
+    );
+
+    let transport = MockTransport::new().on("/embeddings", embeddings(0.0, 1));
+    let client = common::transport_client(&transport);
+    let inputs = ["a", "b", "a"].map(String::from).to_vec();
+    let error = client
+        .embeddings()
+        .create_deduped(request(), inputs, 2, 1)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, OpenAIError::JSONDeserialize(..)));
+}
+
+#[tokio::test]
+async fn empty_inputs_send_no_request() {
+    let transport = MockTransport::new();
+    let client = common::transport_client(&transport);
+
+    let embeddings = client
+        .embeddings()
+        .create_batched(request(), vec![], 2, 1)
+        .await
+        .unwrap();
+
+    assert!(embeddings.is_empty());
+    assert!(transport.requests().is_empty());
+}
+
+#[tokio::test]
+async fn rejects_zero_batch_size() {
+    let client = common::transport_client(&MockTransport::new());
+
+    let error = client
+        .embeddings()
+        .create_batched(request(), inputs(2), 0, 1)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, OpenAIError::InvalidArgument(_)));
+}
+
+#[tokio::test]
+async fn dedupes_inputs_and_preserves_order() {
+    let transport = MockTransport::new().on("/embeddings", embeddings(0.0, 3));
+    let client = common::transport_client(&transport);
+
+    let inputs = ["b", "a", "b", "c", "a"].map(String::from).to_vec();
+    let embeddings = client
+        .embeddings()
+        .create_deduped(request(), inputs, 100, 1)
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].json().unwrap()["input"], json!(["b", "a", "c"]));
+
+    let indices: Vec<_> = embeddings.iter().map(|e| e.index).collect();
+    assert_eq!(indices, [0, 1, 2, 3, 4]);
+    let vectors: Vec<_> = embeddings.iter().map(|e| e.embedding[1]).collect();
+    assert_eq!(vectors, [0.0, 1.0, 0.0, 2.0, 1.0]);
+}
+
+#[tokio::test]
+async fn dedupes_across_batches() {
+    let transport = MockTransport::new()
+        .on("/embeddings", embeddings(0.0, 2))
+        .on("/embeddings", embeddings(1.0, 1));
+    let client = common::transport_client(&transport);
+
+    let inputs = ["a", "b", "a", "c", "b"].map(String::from).to_vec();
+    let embeddings = client
+        .embeddings()
+        .create_deduped(request(), inputs, 2, 1)
+        .await
+        .unwrap();
+
+    let bodies: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|r| r.json().unwrap()["input"].clone())
+        .collect();
+    assert_eq!(bodies, [json!(["a", "b"]), json!(["c"])]);
+
+    let vectors: Vec<_> = embeddings.iter().map(|e| e.embedding.clone()).collect();
+    assert_eq!(
+        vectors,
+        [
+            vec![0.0, 0.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![0.0, 1.0]
+        ]
+    );
+}
+
+#[tokio::test]
+async fn dedupe_rejects_empty_strings() {
+    let transport = MockTransport::new();
+    let client = common::transport_client(&transport);
+
+    let inputs = ["a", ""].map(String::from).to_vec();
+    let error = client
+        .embeddings()
+        .create_deduped(request(), inputs, 2, 1)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, OpenAIError::InvalidArgument(msg) if msg.contains("input 1")));
+    assert!(transport.requests().is_empty());
+}
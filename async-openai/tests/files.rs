@@ -0,0 +1,98 @@
+//! Streamed multipart uploads to the Files API.
+use std::io::Write;
+
+use async_openai::types::files::{CreateFileRequestArgs, FileInput, FilePurpose};
+
+mod common;
+use common::{mock_server_recording, MockResponse};
+
+const OPENAI_FILE: &str = r#"{
+  "id": "file-abc123",
+  "object": "file",
+  "bytes": 52428800,
+  "created_at": 1677610602,
+  "filename": "train.jsonl",
+  "purpose": "fine-tune"
+}"#;
+
+#[tokio::test]
+async fn upload_streams_large_file_from_disk() {
+    const SIZE: u64 = 50 * 1024 * 1024;
+    let line = b"{\"messages\":[{\"role\":\"user\",\"content\":\"hi\"}]}\n";
+
+    let path =
+        std::env::temp_dir().join(format!("async-openai-upload-{}.jsonl", std::process::id()));
+    let mut writer = std::io::BufWriter::new(std::fs::File::create(&path).unwrap());
+    let mut written = 0;
+    while written < SIZE {
+        let len = line.len().min((SIZE - written) as usize);
+        writer.write_all(&line[..len]).unwrap();
+        written += len as u64;
+    }
+    writer.flush().unwrap();
+    drop(writer);
+
+    let (api_base, recorded) =
+        mock_server_recording(vec![MockResponse::new(200, OPENAI_FILE)]).await;
+    let client = common::client(api_base);
+
+    let file = tokio::fs::File::open(&path).await.unwrap();
+    let length = file.metadata().await.unwrap().len();
+    let request = CreateFileRequestArgs::default()
+        .file(FileInput::from_reader(
+            "train.jsonl".into(),
+            file,
+            Some(length),
+        ))
+        .purpose(FilePurpose::FineTune)
+        .build()
+        .unwrap();
+
+    let response = client.files().create(request).await;
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(response.unwrap().id, "file-abc123");
+
+    let recorded = recorded.lock().unwrap();
+    let request = &recorded[0];
+    // the body size is known upfront, so the upload is not chunked
+    let content_length: usize = request.header("content-length").unwrap().parse().unwrap();
+    assert_eq!(request.body_len, content_length);
+    assert!(request.body_len > SIZE as usize);
+
+    let body = String::from_utf8_lossy(&request.body);
+    assert!(body.contains(r#"name="file"; filename="train.jsonl""#));
+    assert!(body.contains("Content-Type: application/jsonl"));
+}
+
+#[tokio::test]
+async fn upload_sets_content_type_from_filename() {
+    let (api_base, recorded) =
+        mock_server_recording(vec![MockResponse::new(200, OPENAI_FILE)]).await;
+    let client = common::client(api_base);
+
+    let request = CreateFileRequestArgs::default()
+        .file(FileInput::from_vec_u8(
+            "notes.PDF".into(),
+            b"%PDF-1.4".to_vec(),
+        ))
+        .purpose(FilePurpose::Assistants)
+        .build()
+        .unwrap();
+    client.files().create(request).await.unwrap();
+
+    let request = CreateFileRequestArgs::default()
+        .file(FileInput::from_vec_u8("data.unknown".into(), b"x".to_vec()))
+        .purpose(FilePurpose::UserData)
+        .build()
+        .unwrap();
+    client.files().create(request).await.unwrap();
+
+    let recorded = recorded.lock().unwrap();
+    let pdf = String::from_utf8_lossy(&recorded[0].body);
+    assert!(pdf.contains(r#"filename="notes.PDF""#));
+    assert!(pdf.contains("Content-Type: application/pdf"));
+
+    let unknown = String::from_utf8_lossy(&recorded[1].body);
+    assert!(unknown.contains(r#"filename="data.unknown""#));
+    assert!(!unknown.contains("Content-Type"));
+}
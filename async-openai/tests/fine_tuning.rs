@@ -0,0 +1,345 @@
+//! Events, checkpoints and checkpoint permissions of fine-tuning jobs.
+use std::time::Duration;
+
+use async_openai::{
+    traits::RequestOptionsBuilder,
+    transport::{MockResponse, MockTransport},
+    types::finetuning::{
+        CreateFineTuningCheckpointPermissionRequest, FineTuningJobEventMetrics,
+        FineTuningJobEventType, ListFineTuningCheckpointPermissionsQueryArgs,
+    },
+    Client,
+};
+use futures::TryStreamExt;
+use serde_json::{json, Value};
+
+fn job(status: &str) -> MockResponse {
+    MockResponse::json(
+        200,
+        json!({
+            "id": "ftjob-abc",
+            "object": "fine_tuning.job",
+            "created_at": 1721764800,
+            "error": null,
+            "fine_tuned_model": null,
+            "finished_at": null,
+            "hyperparameters": { "batch_size": "auto", "learning_rate_multiplier": "auto", "n_epochs": "auto" },
+            "model": "gpt-4o-mini-2024-07-18",
+            "organization_id": "org-123",
+            "result_files": [],
+            "status": status,
+            "trained_tokens": null,
+            "training_file": "file-abc",
+            "validation_file": null,
+            "seed": 42
+        }),
+    )
+}
+
+fn event(id: &str, message: &str) -> Value {
+    json!({
+        "id": id,
+        "object": "fine_tuning.job.event",
+        "created_at": 1721764800,
+        "level": "info",
+        "message": message,
+        "type": "message"
+    })
+}
+
+fn metrics(id: &str, step: u64, train_loss: f64) -> Value {
+    json!({
+        "id": id,
+        "object": "fine_tuning.job.event",
+        "created_at": 1721764900,
+        "level": "info",
+        "message": format!("Step {step}/100: training loss={train_loss}"),
+        "type": "metrics",
+        "data": { "step": step, "total_steps": 100, "train_loss": train_loss, "train_mean_token_accuracy": 0.75 }
+    })
+}
+
+/// Events newest first, as listed by the API.
+fn events(events: Vec<Value>, has_more: bool) -> MockResponse {
+    MockResponse::json(
+        200,
+        json!({ "object": "list", "data": events, "has_more": has_more }),
+    )
+}
+
+#[tokio::test]
+async fn job_events_stream_yields_new_events_until_the_job_finishes() {
+    let transport = MockTransport::new()
+        .on("/fine_tuning/jobs/ftjob-abc", job("running"))
+        .on("/fine_tuning/jobs/ftjob-abc", job("running"))
+        .on("/fine_tuning/jobs/ftjob-abc", job("succeeded"))
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/events",
+            events(
+                vec![
+                    event("ev-2", "Fine-tuning job started"),
+                    event("ev-1", "Validating training file"),
+                ],
+                false,
+            ),
+        )
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/events",
+            events(
+                vec![
+                    metrics("ev-3", 10, 1.25),
+                    event("ev-2", "Fine-tuning job started"),
+                    event("ev-1", "Validating training file"),
+                ],
+                false,
+            ),
+        )
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/events",
+            events(
+                vec![
+                    event("ev-4", "The job has successfully completed"),
+                    metrics("ev-3", 10, 1.25),
+                    event("ev-2", "Fine-tuning job started"),
+                ],
+                false,
+            ),
+        );
+    let client = Client::new().with_transport(transport.clone());
+
+    let events: Vec<_> = client
+        .fine_tuning()
+        .job_events_stream("ftjob-abc", Duration::from_millis(1))
+        .try_collect()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = events.iter().map(|event| event.id.as_str()).collect();
+    assert_eq!(ids, ["ev-1", "ev-2", "ev-3", "ev-4"]);
+
+    assert_eq!(events[2].r#type, Some(FineTuningJobEventType::Metrics));
+    assert_eq!(
+        events[2].metrics(),
+        Some(FineTuningJobEventMetrics {
+            step: Some(10),
+            total_steps: Some(100),
+            train_loss: Some(1.25),
+            train_mean_token_accuracy: Some(0.75),
+            ..Default::default()
+        })
+    );
+    assert_eq!(events[3].metrics(), None);
+
+    // three polls of the job and its events, then none after the job succeeded
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 6);
+    assert_eq!(requests[1].url.query(), Some("limit=100"));
+}
+
+#[tokio::test]
+async fn job_events_stream_pages_back_through_more_than_a_page_of_events() {
+    // 150 events, newest first, listed 100 per page
+    let all: Vec<_> = (1..=150)
+        .rev()
+        .map(|i| event(&format!("ev-{i}"), "Step"))
+        .collect();
+    let transport = MockTransport::new()
+        .on("/fine_tuning/jobs/ftjob-abc", job("succeeded"))
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/events",
+            events(all[..100].to_vec(), true),
+        )
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/events",
+            events(all[100..].to_vec(), false),
+        );
+    let client = Client::new().with_transport(transport.clone());
+
+    let events: Vec<_> = client
+        .fine_tuning()
+        .job_events_stream("ftjob-abc", Duration::from_millis(1))
+        .try_collect()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = events.iter().map(|event| event.id.clone()).collect();
+    let expected: Vec<_> = (1..=150).map(|i| format!("ev-{i}")).collect();
+    assert_eq!(ids, expected);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[2].url.query(), Some("limit=100&after=ev-51"));
+}
+
+#[tokio::test]
+async fn job_events_stream_pages_back_through_pages_smaller_than_requested() {
+    // a server listing 2 events per page whatever the limit
+    let transport = MockTransport::new()
+        .on("/fine_tuning/jobs/ftjob-abc", job("succeeded"))
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/events",
+            events(vec![event("ev-5", "Step"), event("ev-4", "Step")], true),
+        )
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/events",
+            events(vec![event("ev-3", "Step"), event("ev-2", "Step")], true),
+        )
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/events",
+            events(vec![event("ev-1", "Step")], false),
+        );
+    let client = Client::new().with_transport(transport.clone());
+
+    let events: Vec<_> = client
+        .fine_tuning()
+        .job_events_stream("ftjob-abc", Duration::from_millis(1))
+        .try_collect()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = events.iter().map(|event| event.id.as_str()).collect();
+    assert_eq!(ids, ["ev-1", "ev-2", "ev-3", "ev-4", "ev-5"]);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 4);
+    assert_eq!(requests[3].url.query(), Some("limit=100&after=ev-2"));
+}
+
+fn checkpoint(id: &str, step: u32, metrics: Value) -> Value {
+    json!({
+        "object": "fine_tuning.job.checkpoint",
+        "id": id,
+        "created_at": 1721764867,
+        "fine_tuned_model_checkpoint": format!("ft:gpt-4o-mini-2024-07-18:org::abc:ckpt-step-{step}"),
+        "fine_tuning_job_id": "ftjob-abc",
+        "metrics": metrics,
+        "step_number": step
+    })
+}
+
+#[tokio::test]
+async fn list_checkpoints_stream_follows_pages() {
+    let transport = MockTransport::new()
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/checkpoints",
+            MockResponse::json(
+                200,
+                json!({
+                    "object": "list",
+                    "data": [checkpoint("ckpt-2", 2000, json!({ "step": 2000, "train_loss": 0.5, "train_mean_token_accuracy": 0.9 }))],
+                    "first_id": "ckpt-2",
+                    "last_id": "ckpt-2",
+                    "has_more": true
+                }),
+            ),
+        )
+        .on(
+            "/fine_tuning/jobs/ftjob-abc/checkpoints",
+            MockResponse::json(
+                200,
+                json!({
+                    "object": "list",
+                    // metrics of the last checkpoint are only computed on the full validation file
+                    "data": [checkpoint("ckpt-1", 1000, json!({ "full_valid_loss": 0.134, "full_valid_mean_token_accuracy": 0.874 }))],
+                    "first_id": "ckpt-1",
+                    "last_id": "ckpt-1",
+                    "has_more": false
+                }),
+            ),
+        );
+    let client = Client::new().with_transport(transport.clone());
+
+    let checkpoints: Vec<_> = client
+        .fine_tuning()
+        .list_checkpoints_stream("ftjob-abc")
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(checkpoints.len(), 2);
+    assert_eq!(checkpoints[0].step_number, 2000);
+    assert_eq!(checkpoints[0].metrics.train_loss, Some(0.5));
+    assert_eq!(checkpoints[0].metrics.valid_loss, None);
+    assert_eq!(checkpoints[1].metrics.full_valid_loss, Some(0.134));
+    assert_eq!(
+        checkpoints[1].fine_tuned_model_checkpoint,
+        "ft:gpt-4o-mini-2024-07-18:org::abc:ckpt-step-1000"
+    );
+    assert_eq!(transport.requests()[1].url.query(), Some("after=ckpt-2"));
+}
+
+#[tokio::test]
+async fn checkpoint_permissions() {
+    let checkpoint = "ft:gpt-4o-mini-2024-07-18:org::abc:ckpt-step-1000";
+    let permissions = MockResponse::json(
+        200,
+        json!({
+            "object": "list",
+            "data": [{
+                "object": "checkpoint.permission",
+                "id": "cp_123",
+                "created_at": 1721764867,
+                "project_id": "proj_abc"
+            }],
+            "first_id": "cp_123",
+            "last_id": "cp_123",
+            "has_more": false
+        }),
+    );
+    let transport = MockTransport::new().on("/permissions", permissions).on(
+        "/permissions/cp_123",
+        MockResponse::json(
+            200,
+            json!({ "object": "checkpoint.permission", "id": "cp_123", "deleted": true }),
+        ),
+    );
+    let client = Client::new().with_transport(transport.clone());
+
+    let created = client
+        .fine_tuning()
+        .create_checkpoint_permissions(
+            checkpoint,
+            CreateFineTuningCheckpointPermissionRequest {
+                project_ids: vec!["proj_abc".into()],
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(created.data[0].project_id, "proj_abc");
+
+    let query = ListFineTuningCheckpointPermissionsQueryArgs::default()
+        .project_id("proj_abc")
+        .limit(10u32)
+        .build()
+        .unwrap();
+    let listed = client
+        .fine_tuning()
+        .query(&query)
+        .unwrap()
+        .list_checkpoint_permissions(checkpoint)
+        .await
+        .unwrap();
+    assert_eq!(listed.data[0].id, "cp_123");
+
+    let deleted = client
+        .fine_tuning()
+        .delete_checkpoint_permissions(checkpoint, "cp_123")
+        .await
+        .unwrap();
+    assert!(deleted.deleted);
+
+    let requests = transport.requests();
+    assert_eq!(
+        requests[0].json().unwrap(),
+        json!({ "project_ids": ["proj_abc"] })
+    );
+    assert_eq!(
+        requests[1].url.query(),
+        Some("project_id=proj_abc&limit=10")
+    );
+    assert_eq!(requests[2].method, "DELETE");
+    assert!(requests[2]
+        .url
+        .path()
+        .ends_with("/fine_tuning/checkpoints/ft:gpt-4o-mini-2024-07-18:org::abc:ckpt-step-1000/permissions/cp_123"));
+}
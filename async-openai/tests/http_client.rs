@@ -0,0 +1,135 @@
+//! Requests sent through a custom `reqwest::Client`, or one configured by `ClientBuilder`, keep
+//! the headers of the config.
+use std::time::Duration;
+
+use async_openai::{
+    config::OpenAIConfig,
+    error::OpenAIError,
+    types::chat::{ChatCompletionRequestUserMessage, CreateChatCompletionRequestArgs},
+    Client, ClientBuilder,
+};
+use reqwest::header::{HeaderMap, HeaderValue};
+
+mod common;
+use common::{mock_server_recording, MockResponse};
+
+const CHAT_COMPLETION: &str = r#"{
+  "id": "chatcmpl-123",
+  "object": "chat.completion",
+  "created": 1677652288,
+  "model": "gpt-4o-mini",
+  "choices": [{
+    "index": 0,
+    "message": {"role": "assistant", "content": "Hello there!"},
+    "finish_reason": "stop"
+  }]
+}"#;
+
+fn http_client() -> reqwest::Client {
+    let mut headers = HeaderMap::new();
+    headers.insert("x-client-default", HeaderValue::from_static("1"));
+    headers.insert("openai-project", HeaderValue::from_static("proj_client"));
+    reqwest::Client::builder()
+        .pool_max_idle_per_host(4)
+        .pool_idle_timeout(Duration::from_secs(30))
+        .tcp_keepalive(Duration::from_secs(60))
+        .user_agent("custom-agent")
+        .default_headers(headers)
+        .build()
+        .unwrap()
+}
+
+async fn chat<C: async_openai::config::Config>(client: &Client<C>) {
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello!").into()])
+        .build()
+        .unwrap();
+    let response = client.chat().create(request).await.unwrap();
+    assert_eq!(response.id, "chatcmpl-123");
+}
+
+#[tokio::test]
+async fn custom_http_client_gets_config_headers() {
+    let (api_base, recorded) =
+        mock_server_recording(vec![MockResponse::new(200, CHAT_COMPLETION)]).await;
+    let config = OpenAIConfig::new()
+        .with_api_base(api_base)
+        .with_api_key("sk-test")
+        .with_project_id("proj_config");
+
+    let client = Client::with_config(config).with_http_client(http_client());
+    chat(&client).await;
+    chat(&client).await;
+
+    let recorded = recorded.lock().unwrap();
+    assert_eq!(recorded.len(), 2);
+    for request in recorded.iter() {
+        assert_eq!(request.header("authorization"), Some("Bearer sk-test"));
+        assert_eq!(request.header("openai-project"), Some("proj_config"));
+        assert_eq!(request.header("x-client-default"), Some("1"));
+        assert_eq!(request.header("user-agent"), Some("custom-agent"));
+    }
+}
+
+#[tokio::test]
+async fn build_with_custom_http_client() {
+    let (api_base, recorded) =
+        mock_server_recording(vec![MockResponse::new(200, CHAT_COMPLETION)]).await;
+    let config = OpenAIConfig::new()
+        .with_api_base(api_base)
+        .with_api_key("sk-test");
+
+    let client = Client::build(http_client(), config, Default::default());
+    chat(&client).await;
+
+    let recorded = recorded.lock().unwrap();
+    assert_eq!(recorded[0].header("authorization"), Some("Bearer sk-test"));
+    assert_eq!(recorded[0].header("openai-project"), Some("proj_client"));
+}
+
+#[tokio::test]
+async fn requests_go_through_the_proxy_with_basic_auth() {
+    let (proxy, recorded) =
+        mock_server_recording(vec![MockResponse::new(200, CHAT_COMPLETION)]).await;
+    let config = OpenAIConfig::new()
+        .with_api_base("http://api.example.invalid/v1")
+        .with_api_key("sk-test");
+
+    let client = ClientBuilder::new(config)
+        .proxy(proxy.trim_end_matches("/v1"))
+        .proxy_basic_auth("user", "secret")
+        .build()
+        .unwrap();
+    chat(&client).await;
+
+    let recorded = recorded.lock().unwrap();
+    let request = &recorded[0];
+    assert!(request
+        .head
+        .starts_with("POST http://api.example.invalid/v1/chat/completions HTTP/1.1"));
+    // base64 of `user:secret`
+    assert_eq!(
+        request.header("proxy-authorization"),
+        Some("Basic dXNlcjpzZWNyZXQ=")
+    );
+    assert_eq!(request.header("authorization"), Some("Bearer sk-test"));
+}
+
+#[test]
+fn invalid_builder_settings_fail_to_build() {
+    assert!(matches!(
+        Client::builder().proxy("not a url").build(),
+        Err(OpenAIError::Reqwest(_))
+    ));
+    assert!(matches!(
+        Client::builder().proxy_basic_auth("user", "secret").build(),
+        Err(OpenAIError::InvalidArgument(_))
+    ));
+    assert!(Client::builder()
+        .add_root_certificate_pem(
+            "-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----\n"
+        )
+        .build()
+        .is_err());
+}
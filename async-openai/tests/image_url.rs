@@ -0,0 +1,78 @@
+//! Image inputs from local files and bytes.
+use async_openai::{
+    error::OpenAIError,
+    types::chat::{ChatCompletionRequestUserMessageContentPart, ImageUrl, UserContent},
+};
+
+const PNG: &[u8] = &[
+    0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0x0D,
+];
+
+fn write_temp(name: &str, bytes: &[u8]) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("async-openai-image-url-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, bytes).unwrap();
+    path
+}
+
+#[test]
+fn from_bytes_builds_data_url() {
+    let image = ImageUrl::from_bytes(b"hello", "image/webp");
+    assert_eq!(image.url, "data:image/webp;base64,aGVsbG8=");
+    assert_eq!(image.detail, None);
+}
+
+#[tokio::test]
+async fn from_path_detects_mime_type_from_content() {
+    // the content wins over a misleading extension
+    let path = write_temp("image.jpg", PNG);
+    let image = ImageUrl::from_path(&path).await.unwrap();
+    assert!(image.url.starts_with("data:image/png;base64,iVBORw0KGgo"));
+
+    let gif = write_temp("image.bin", b"GIF89a\x01\x00\x01\x00");
+    let image = ImageUrl::from_path(&gif).await.unwrap();
+    assert!(image.url.starts_with("data:image/gif;base64,"));
+
+    let webp = write_temp("image", b"RIFF\x24\x00\x00\x00WEBPVP8 ");
+    let image = ImageUrl::from_path(&webp).await.unwrap();
+    assert!(image.url.starts_with("data:image/webp;base64,"));
+}
+
+#[tokio::test]
+async fn from_path_falls_back_to_extension() {
+    let path = write_temp("photo.JPEG", b"truncated");
+    let image = ImageUrl::from_path(&path).await.unwrap();
+    assert!(image.url.starts_with("data:image/jpeg;base64,"));
+}
+
+#[tokio::test]
+async fn from_path_rejects_unsupported_files() {
+    let path = write_temp("notes.txt", b"not an image");
+    assert!(matches!(
+        ImageUrl::from_path(&path).await,
+        Err(OpenAIError::InvalidArgument(_))
+    ));
+
+    assert!(matches!(
+        ImageUrl::from_path(path.with_file_name("missing.png")).await,
+        Err(OpenAIError::FileReadError(_))
+    ));
+}
+
+#[tokio::test]
+async fn user_content_image_path() {
+    let path = write_temp("content.png", PNG);
+    let parts = UserContent::new()
+        .text("What is this?")
+        .image_path(&path)
+        .await
+        .unwrap()
+        .into_parts();
+
+    assert_eq!(parts.len(), 2);
+    let ChatCompletionRequestUserMessageContentPart::ImageUrl(image) = &parts[1] else {
+        panic!("expected an image part, got {:?}", parts[1]);
+    };
+    assert!(image.image_url.url.starts_with("data:image/png;base64,"));
+}
@@ -0,0 +1,155 @@
+//! Reading and saving generated images.
+use std::path::PathBuf;
+
+use async_openai::types::images::{
+    CreateImageEditRequestArgs, Image, ImageInput, ImageModel, ImagesResponse,
+};
+use base64::{engine::general_purpose, Engine as _};
+
+mod common;
+use common::{mock_server, MockResponse};
+
+const JPEG: &[u8] = &[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10, b'J', b'F', b'I', b'F'];
+const PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+fn temp_dir(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("async-openai-images-{name}-{}", std::process::id()))
+}
+
+fn response(images: serde_json::Value) -> ImagesResponse {
+    serde_json::from_value(serde_json::json!({ "created": 1713833628, "data": images })).unwrap()
+}
+
+#[test]
+fn image_accessors() {
+    let response = response(serde_json::json!([
+        { "url": "https://example.com/img.png", "revised_prompt": "A cute otter" },
+        { "b64_json": "aGVsbG8=" }
+    ]));
+
+    let url: &Image = &response.data[0];
+    assert_eq!(url.url(), Some("https://example.com/img.png"));
+    assert_eq!(url.b64_json(), None);
+    assert_eq!(url.revised_prompt(), Some("A cute otter"));
+
+    let b64: &Image = &response.data[1];
+    assert_eq!(b64.url(), None);
+    assert_eq!(b64.b64_json(), Some("aGVsbG8="));
+    assert_eq!(b64.revised_prompt(), None);
+}
+
+#[tokio::test]
+async fn b64_images_are_decoded_and_saved_with_their_format() {
+    let response = response(serde_json::json!([
+        { "b64_json": general_purpose::STANDARD.encode(JPEG) },
+        { "b64_json": general_purpose::STANDARD.encode(PNG) }
+    ]));
+
+    assert_eq!(response.data[0].bytes().await.unwrap(), JPEG);
+
+    let dir = temp_dir("b64");
+    let mut paths = response.save(&dir).await.unwrap();
+    paths.sort_by_key(|path| path.extension().map(|e| e.to_owned()));
+
+    assert_eq!(paths.len(), 2);
+    assert_eq!(paths[0].extension().unwrap(), "jpeg");
+    assert_eq!(std::fs::read(&paths[0]).unwrap(), JPEG);
+    assert_eq!(paths[1].extension().unwrap(), "png");
+    assert_eq!(std::fs::read(&paths[1]).unwrap(), PNG);
+}
+
+#[tokio::test]
+async fn a_single_image_is_saved_in_a_new_dir() {
+    let response = response(serde_json::json!([
+        { "b64_json": general_purpose::STANDARD.encode(PNG) }
+    ]));
+
+    let dir = temp_dir("single").join("nested");
+    let path = response.data[0].save(&dir).await.unwrap();
+
+    assert_eq!(path.parent(), Some(dir.as_path()));
+    assert_eq!(std::fs::read(&path).unwrap(), PNG);
+}
+
+#[tokio::test]
+async fn url_images_are_downloaded() {
+    let (api_base, requests) = mock_server(vec![
+        MockResponse::bytes(200, PNG).header("content-type", "image/png")
+    ])
+    .await;
+    let url = format!("{api_base}/images/img-123.png");
+
+    let image = response(serde_json::json!([{ "url": url }]));
+    assert_eq!(image.data[0].bytes().await.unwrap(), PNG);
+
+    let dir = temp_dir("url");
+    let path = image.data[0].save(&dir).await.unwrap();
+    assert_eq!(path, dir.join("v1").join("images").join("img-123.png"));
+    assert_eq!(std::fs::read(&path).unwrap(), PNG);
+    assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+/// A PNG signature and IHDR chunk of a `width`x`height` image.
+fn png(width: u32, height: u32) -> ImageInput {
+    let mut png = PNG.to_vec();
+    png.extend_from_slice(&13u32.to_be_bytes());
+    png.extend_from_slice(b"IHDR");
+    png.extend_from_slice(&width.to_be_bytes());
+    png.extend_from_slice(&height.to_be_bytes());
+    ImageInput::from_vec_u8(format!("{width}x{height}.png"), png)
+}
+
+#[test]
+fn dall_e_2_edits_a_single_image_with_a_matching_mask() {
+    let error = CreateImageEditRequestArgs::default()
+        .model(ImageModel::DallE2)
+        .image(vec![png(256, 256), png(256, 256)])
+        .prompt("a hat")
+        .build()
+        .unwrap_err();
+    assert!(error.to_string().contains("single image"), "{error}");
+
+    let error = CreateImageEditRequestArgs::default()
+        .model(ImageModel::DallE2)
+        .image(png(256, 256))
+        .mask(png(512, 512))
+        .prompt("a hat")
+        .build()
+        .unwrap_err();
+    assert!(error.to_string().contains("512x512"), "{error}");
+
+    CreateImageEditRequestArgs::default()
+        .model(ImageModel::DallE2)
+        .image(png(256, 256))
+        .mask(png(256, 256))
+        .prompt("a hat")
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn gpt_image_edits_several_images() {
+    CreateImageEditRequestArgs::default()
+        .model(ImageModel::GptImage1)
+        .image(vec![png(256, 256), png(1024, 1024)])
+        .mask(png(256, 256))
+        .prompt("a gift basket of these items")
+        .build()
+        .unwrap();
+
+    let error = CreateImageEditRequestArgs::default()
+        .model(ImageModel::GptImage1)
+        .image((0..17).map(|_| png(256, 256)).collect::<Vec<_>>())
+        .prompt("a collage")
+        .build()
+        .unwrap_err();
+    assert!(error.to_string().contains("16"), "{error}");
+
+    let error = CreateImageEditRequestArgs::default()
+        .image(png(256, 256))
+        .prompt("a hat")
+        .partial_images(4)
+        .build()
+        .unwrap_err();
+    assert!(error.to_string().contains("partial_images"), "{error}");
+}
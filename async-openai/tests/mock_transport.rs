@@ -0,0 +1,310 @@
+//! Canned responses through `MockTransport`, without a network connection.
+use async_openai::{
+    config::{Config, OpenAIConfig},
+    error::{ApiError, ApiErrorCode, OpenAIError},
+    transport::{MockResponse, MockTransport},
+    types::chat::ChatCompletionRequestUserMessage,
+    Client,
+};
+use serde_json::json;
+
+mod common;
+
+fn chat_completion(content: &str) -> MockResponse {
+    MockResponse::json(
+        200,
+        json!({
+            "id": "chatcmpl-123",
+            "object": "chat.completion",
+            "created": 1677652288,
+            "model": "gpt-4o-mini",
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": content },
+                "finish_reason": "stop"
+            }]
+        }),
+    )
+}
+
+#[tokio::test]
+async fn serves_canned_responses_and_records_requests() {
+    let transport = MockTransport::new()
+        .on("/chat/completions", chat_completion("first"))
+        .on("/chat/completions", chat_completion("second"));
+    let client = common::transport_client(&transport);
+
+    let first = client.chat().create(common::chat_request()).await.unwrap();
+    let second = client.chat().create(common::chat_request()).await.unwrap();
+    let third = client.chat().create(common::chat_request()).await.unwrap();
+
+    assert_eq!(first.choices[0].message.content.as_deref(), Some("first"));
+    assert_eq!(second.choices[0].message.content.as_deref(), Some("second"));
+    // the last response repeats
+    assert_eq!(third.choices[0].message.content.as_deref(), Some("second"));
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[0].method, reqwest::Method::POST);
+    assert_eq!(
+        requests[0].url.as_str(),
+        "https://api.openai.com/v1/chat/completions"
+    );
+    assert_eq!(
+        requests[0].headers.get("authorization").unwrap(),
+        "Bearer test"
+    );
+    let body = requests[0].json().unwrap();
+    assert_eq!(body["model"], "gpt-4o-mini");
+    assert_eq!(body["messages"][0]["content"], "Hello");
+}
+
+#[tokio::test]
+async fn retries_injected_rate_limits() {
+    let transport = MockTransport::new()
+        .on(
+            "/chat/completions",
+            MockResponse::error(429, "rate_limit_exceeded", "Rate limit reached")
+                .header("retry-after-ms", "1"),
+        )
+        .on("/chat/completions", chat_completion("ok"));
+    let response = common::transport_client(&transport)
+        .chat()
+        .create(common::chat_request())
+        .await
+        .unwrap();
+    assert_eq!(response.choices[0].message.content.as_deref(), Some("ok"));
+    assert_eq!(transport.requests().len(), 2);
+
+    let transport = MockTransport::new().on(
+        "/chat/completions",
+        MockResponse::error(429, "rate_limit_exceeded", "Rate limit reached"),
+    );
+    let err = common::transport_client(&transport)
+        .with_max_retries(0)
+        .chat()
+        .create(common::chat_request())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, OpenAIError::ApiError(e) if e.message == "Rate limit reached"));
+}
+
+#[tokio::test]
+async fn malformed_json_fails_to_deserialize() {
+    let transport = MockTransport::new().on(
+        "/chat/completions",
+        MockResponse::new(200, r#"{"id": "chatcmpl-123", "choices": ["#),
+    );
+
+    let err = common::transport_client(&transport)
+        .chat()
+        .create(common::chat_request())
+        .await
+        .unwrap_err();
+    match err {
+        OpenAIError::JSONDeserialize(_, content) => {
+            assert_eq!(content, r#"{"id": "chatcmpl-123", "choices": ["#)
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn deserialization_error_truncates_long_body() {
+    let body = format!(r#"{{"id": "{}""#, "é".repeat(10_000));
+    let transport = MockTransport::new().on("/chat/completions", MockResponse::new(200, body));
+
+    let err = common::transport_client(&transport)
+        .chat()
+        .create(common::chat_request())
+        .await
+        .unwrap_err();
+    match err {
+        OpenAIError::JSONDeserialize(_, content) => {
+            assert!(content.starts_with(r#"{"id": "éé"#));
+            assert!(content.ends_with("... (11817 more bytes)"), "{content}");
+            assert!(content.len() < 8 * 1024 + 32);
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn longest_matching_path_wins() {
+    let transport = MockTransport::new()
+        .on("/completions", MockResponse::new(200, "{}"))
+        .on("/chat/completions", chat_completion("chat"));
+
+    let response = common::transport_client(&transport)
+        .chat()
+        .create(common::chat_request())
+        .await
+        .unwrap();
+    assert_eq!(response.choices[0].message.content.as_deref(), Some("chat"));
+}
+
+#[tokio::test]
+async fn unregistered_path_is_an_error() {
+    let transport = MockTransport::new().on("/embeddings", MockResponse::new(200, "{}"));
+
+    let err = common::transport_client(&transport)
+        .chat()
+        .create(common::chat_request())
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, OpenAIError::InvalidArgument(msg) if msg.contains("POST /v1/chat/completions"))
+    );
+}
+
+#[tokio::test]
+async fn api_error_carries_status_code() {
+    let transport = MockTransport::new().on(
+        "/chat/completions",
+        MockResponse::error(400, "invalid_request_error", "Bad request"),
+    );
+
+    let err = common::transport_client(&transport)
+        .chat()
+        .create(common::chat_request())
+        .await
+        .unwrap_err();
+    let OpenAIError::ApiError(api_error) = err else {
+        panic!("expected an api error, got {err:?}");
+    };
+    assert_eq!(api_error.status, Some(400));
+    assert_eq!(
+        api_error.to_string(),
+        "invalid_request_error: Bad request (code: invalid_request_error) (status: 400)"
+    );
+}
+
+#[tokio::test]
+async fn api_error_exposes_param_and_code() {
+    let transport = MockTransport::new().on(
+        "/chat/completions",
+        MockResponse::json(
+            400,
+            json!({
+                "error": {
+                    "message": "This model's maximum context length is 128000 tokens.",
+                    "type": "invalid_request_error",
+                    "param": "messages",
+                    "code": "context_length_exceeded"
+                }
+            }),
+        ),
+    );
+
+    let err = common::transport_client(&transport)
+        .chat()
+        .create(common::chat_request())
+        .await
+        .unwrap_err();
+    let OpenAIError::ApiError(api_error) = err else {
+        panic!("expected an api error, got {err:?}");
+    };
+    assert_eq!(api_error.param.as_deref(), Some("messages"));
+    assert_eq!(
+        api_error.error_code(),
+        Some(ApiErrorCode::ContextLengthExceeded)
+    );
+}
+
+#[test]
+fn api_error_code_values() {
+    let error: ApiError = serde_json::from_value(json!({
+        "message": "Unknown",
+        "type": null,
+        "param": null,
+        "code": "some_new_code"
+    }))
+    .unwrap();
+    assert_eq!(
+        error.error_code(),
+        Some(ApiErrorCode::Other("some_new_code".into()))
+    );
+
+    // some compatible servers send numeric codes
+    let error: ApiError =
+        serde_json::from_value(json!({ "message": "Not found", "code": 404 })).unwrap();
+    assert_eq!(error.code.as_deref(), Some("404"));
+    assert_eq!(error.r#type, None);
+
+    for code in [
+        ApiErrorCode::ContextLengthExceeded,
+        ApiErrorCode::RateLimitExceeded,
+        ApiErrorCode::InvalidApiKey,
+        ApiErrorCode::InsufficientQuota,
+    ] {
+        assert_eq!(ApiErrorCode::from(code.to_string().as_str()), code);
+    }
+}
+
+#[tokio::test]
+async fn clones_share_the_client_across_tasks() {
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+    assert_send_sync::<Client<OpenAIConfig>>();
+    assert_send_sync::<Client<Box<dyn Config>>>();
+
+    let transport = MockTransport::new().on("/chat/completions", chat_completion("Hi"));
+    let config = Box::new(OpenAIConfig::new().with_api_key("test")) as Box<dyn Config>;
+    let client = Client::with_config(config).with_transport(transport.clone());
+
+    let tasks: Vec<_> = (0..8)
+        .map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move { client.chat().create(common::chat_request()).await })
+        })
+        .collect();
+    for task in tasks {
+        let response = task.await.unwrap().unwrap();
+        assert_eq!(response.choices[0].message.content.as_deref(), Some("Hi"));
+    }
+
+    assert_eq!(transport.requests().len(), 8);
+}
+
+#[tokio::test]
+async fn quick_uses_the_default_chat_model() {
+    let transport = MockTransport::new().on("/chat/completions", chat_completion("Hi"));
+    let client = common::transport_client(&transport).with_default_chat_model("gpt-4o-mini");
+
+    let response = client
+        .chat()
+        .quick([ChatCompletionRequestUserMessage::from("Hello")])
+        .await
+        .unwrap();
+    assert_eq!(response.choices[0].message.content.as_deref(), Some("Hi"));
+    client
+        .chat()
+        .model("gpt-4o")
+        .quick([ChatCompletionRequestUserMessage::from("Hello")])
+        .await
+        .unwrap();
+
+    let bodies: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|r| r.json().unwrap())
+        .collect();
+    assert_eq!(bodies[0]["model"], "gpt-4o-mini");
+    assert_eq!(
+        bodies[0]["messages"],
+        json!([{ "role": "user", "content": "Hello" }])
+    );
+    assert_eq!(bodies[1]["model"], "gpt-4o");
+}
+
+#[tokio::test]
+async fn quick_without_a_model_is_an_error() {
+    let transport = MockTransport::new();
+    let error = common::transport_client(&transport)
+        .chat()
+        .quick([ChatCompletionRequestUserMessage::from("Hello")])
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, OpenAIError::InvalidArgument(_)));
+    assert!(transport.requests().is_empty());
+}
@@ -0,0 +1,227 @@
+//! Models endpoints, their cache and the capabilities of known model families.
+use std::time::Duration;
+
+use async_openai::{
+    error::OpenAIError,
+    traits::RequestOptionsBuilder,
+    transport::{MockResponse, MockTransport},
+    types::{ModelCapabilities, ModelId},
+    Client,
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn retrieve_and_delete() {
+    let transport = MockTransport::new()
+        .on(
+            "/models/gpt-4o-mini",
+            MockResponse::json(
+                200,
+                json!({ "id": "gpt-4o-mini", "object": "model", "created": 1721172741, "owned_by": "system" }),
+            ),
+        )
+        .on(
+            "/models/ft:gpt-4o-mini:acme:suffix:abc123",
+            MockResponse::json(
+                200,
+                json!({ "id": "ft:gpt-4o-mini:acme:suffix:abc123", "object": "model", "deleted": true }),
+            ),
+        );
+    let client = Client::new().with_transport(transport.clone());
+
+    let model = client.models().retrieve("gpt-4o-mini").await.unwrap();
+    assert_eq!(model.owned_by, "system");
+    assert_eq!(model.capabilities().unwrap().max_output_tokens, 16_384);
+
+    let deleted = client
+        .models()
+        .delete("ft:gpt-4o-mini:acme:suffix:abc123")
+        .await
+        .unwrap();
+    assert!(deleted.deleted);
+    assert_eq!(deleted.id, "ft:gpt-4o-mini:acme:suffix:abc123");
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[1].method, "DELETE");
+}
+
+#[tokio::test]
+async fn retrieve_unknown_model_is_not_found() {
+    let transport = MockTransport::new().on(
+        "/models/gpt-unknown",
+        MockResponse::error(
+            404,
+            "model_not_found",
+            "The model 'gpt-unknown' does not exist",
+        ),
+    );
+    let client = Client::new().with_transport(transport);
+
+    let OpenAIError::ApiError(error) = client.models().retrieve("gpt-unknown").await.unwrap_err()
+    else {
+        panic!("expected an API error");
+    };
+    assert_eq!(error.status, Some(404));
+    assert_eq!(error.code.as_deref(), Some("model_not_found"));
+}
+
+fn models_list() -> MockResponse {
+    MockResponse::json(
+        200,
+        json!({
+            "object": "list",
+            "data": [
+                { "id": "gpt-4o-mini", "object": "model", "created": 1721172741, "owned_by": "system" }
+            ]
+        }),
+    )
+}
+
+#[tokio::test]
+async fn model_cache_serves_list_and_retrieve() {
+    let transport = MockTransport::new().on("/models", models_list()).on(
+        "/models/gpt-4o-mini",
+        MockResponse::json(
+            200,
+            json!({ "id": "gpt-4o-mini", "object": "model", "created": 1721172741, "owned_by": "system" }),
+        ),
+    );
+    let client = Client::new()
+        .with_transport(transport.clone())
+        .with_model_cache(Duration::from_secs(60));
+
+    for _ in 0..3 {
+        let models = client.models().list().await.unwrap();
+        assert_eq!(models.data[0].id, "gpt-4o-mini");
+        client
+            .clone()
+            .models()
+            .retrieve("gpt-4o-mini")
+            .await
+            .unwrap();
+    }
+    assert_eq!(transport.requests().len(), 2);
+
+    let models = client.models().refresh().await.unwrap();
+    assert_eq!(models.data.len(), 1);
+    client.models().retrieve("gpt-4o-mini").await.unwrap();
+    assert_eq!(transport.requests().len(), 4);
+}
+
+#[tokio::test]
+async fn model_cache_expires_and_skips_errors() {
+    let transport = MockTransport::new()
+        .on(
+            "/models",
+            MockResponse::error(500, "server_error", "The server had an error"),
+        )
+        .on("/models", models_list());
+    let client = Client::new()
+        .with_transport(transport.clone())
+        .with_max_retries(0)
+        .with_model_cache(Duration::from_millis(50));
+
+    assert!(client.models().list().await.is_err());
+    client.models().list().await.unwrap();
+    client.models().list().await.unwrap();
+    assert_eq!(transport.requests().len(), 2);
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    client.models().list().await.unwrap();
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn models_are_not_cached_by_default() {
+    let transport = MockTransport::new().on("/models", models_list());
+    let client = Client::new().with_transport(transport.clone());
+
+    client.models().list().await.unwrap();
+    client.models().list().await.unwrap();
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[test]
+fn capabilities_of_families_and_snapshots() {
+    let gpt_4o = ModelCapabilities::for_model("gpt-4o").unwrap();
+    assert_eq!(gpt_4o.context_window, 128_000);
+    assert!(gpt_4o.image_input && !gpt_4o.audio_output && !gpt_4o.reasoning);
+    assert_eq!(
+        ModelCapabilities::for_model("gpt-4o-2024-08-06"),
+        Some(gpt_4o)
+    );
+
+    // longer family names are not mistaken for snapshots
+    let audio = ModelId::GPT_4O_AUDIO_PREVIEW.capabilities().unwrap();
+    assert!(audio.audio_input && audio.audio_output && !audio.image_input);
+    assert_eq!(
+        ModelCapabilities::for_model("gpt-4o-mini-audio-preview-2024-12-17"),
+        Some(audio)
+    );
+    assert_eq!(ModelCapabilities::for_model("gpt-4o-mini-tts"), None);
+    assert_eq!(
+        ModelCapabilities::for_model("gpt-4-0613")
+            .unwrap()
+            .context_window,
+        8_192
+    );
+    assert_eq!(
+        ModelCapabilities::for_model("gpt-4-turbo-preview")
+            .unwrap()
+            .context_window,
+        128_000
+    );
+
+    assert!(ModelId::O4_MINI.capabilities().unwrap().reasoning);
+    assert!(!ModelCapabilities::for_model("o1-mini").unwrap().image_input);
+    assert_eq!(
+        ModelCapabilities::for_model("ft:gpt-4.1-nano-2025-04-14:acme::abc123")
+            .unwrap()
+            .context_window,
+        1_047_576
+    );
+    assert_eq!(ModelCapabilities::for_model("llama3"), None);
+}
+
+#[tokio::test]
+async fn model_cache_is_kept_per_request_options() {
+    let transport = MockTransport::new().on("/models", models_list());
+    let client = Client::new()
+        .with_transport(transport.clone())
+        .with_model_cache(Duration::from_secs(60));
+
+    client.models().list().await.unwrap();
+    for project in ["proj_a", "proj_b", "proj_a"] {
+        client
+            .models()
+            .header("OpenAI-Project", project)
+            .unwrap()
+            .list()
+            .await
+            .unwrap();
+    }
+    client
+        .models()
+        .query(&[("limit", "1")])
+        .unwrap()
+        .list()
+        .await
+        .unwrap();
+    client.models().list().await.unwrap();
+
+    let projects: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|request| request.headers.get("openai-project").cloned())
+        .collect();
+    assert_eq!(
+        projects,
+        [
+            None,
+            Some("proj_a".parse().unwrap()),
+            Some("proj_b".parse().unwrap()),
+            None
+        ]
+    );
+}
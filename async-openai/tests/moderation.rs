@@ -0,0 +1,104 @@
+//! Flagged state and categories of moderation results.
+use async_openai::{
+    config::OpenAIConfig,
+    transport::{MockResponse, MockTransport},
+    Client,
+};
+use serde_json::{json, Map, Value};
+
+const CATEGORIES: [&str; 13] = [
+    "hate",
+    "hate/threatening",
+    "harassment",
+    "harassment/threatening",
+    "illicit",
+    "illicit/violent",
+    "self-harm",
+    "self-harm/intent",
+    "self-harm/instructions",
+    "sexual",
+    "sexual/minors",
+    "violence",
+    "violence/graphic",
+];
+
+/// A moderation result flagging `flagged` categories.
+fn result(flagged: &[&str]) -> Value {
+    let mut categories = Map::new();
+    let mut scores = Map::new();
+    let mut input_types = Map::new();
+    for category in CATEGORIES {
+        let is_flagged = flagged.contains(&category);
+        categories.insert(category.into(), json!(is_flagged));
+        scores.insert(category.into(), json!(if is_flagged { 0.9 } else { 0.001 }));
+        input_types.insert(category.into(), json!(["text"]));
+    }
+    json!({
+        "flagged": !flagged.is_empty(),
+        "categories": categories,
+        "category_scores": scores,
+        "category_applied_input_types": input_types,
+    })
+}
+
+fn client(results: Vec<Value>) -> (Client<OpenAIConfig>, MockTransport) {
+    let transport = MockTransport::new().on(
+        "/moderations",
+        MockResponse::json(
+            200,
+            json!({
+                "id": "modr-123",
+                "model": "omni-moderation-latest",
+                "results": results,
+            }),
+        ),
+    );
+    let client = Client::new().with_transport(transport.clone());
+    (client, transport)
+}
+
+#[tokio::test]
+async fn is_flagged_returns_the_flagged_categories() {
+    let (client, transport) = client(vec![result(&["harassment", "violence/graphic"])]);
+
+    let (flagged, categories) = client.moderations().is_flagged("some text").await.unwrap();
+
+    assert!(flagged);
+    assert_eq!(categories, ["harassment", "violence/graphic"]);
+    assert_eq!(
+        transport.requests()[0].json().unwrap(),
+        json!({ "input": "some text" })
+    );
+}
+
+#[tokio::test]
+async fn is_flagged_merges_the_categories_of_array_inputs() {
+    let (client, transport) = client(vec![
+        result(&[]),
+        result(&["self-harm/intent"]),
+        result(&["hate", "self-harm/intent"]),
+    ]);
+
+    let (flagged, categories) = client
+        .moderations()
+        .is_flagged(["fine", "not fine", "also not fine"])
+        .await
+        .unwrap();
+
+    assert!(flagged);
+    assert_eq!(categories, ["hate", "self-harm/intent"]);
+    assert_eq!(
+        transport.requests()[0].json().unwrap()["input"],
+        json!(["fine", "not fine", "also not fine"])
+    );
+}
+
+#[tokio::test]
+async fn is_flagged_is_false_without_flagged_categories() {
+    let (client, _) = client(vec![result(&[])]);
+
+    let (flagged, categories) = client.moderations().is_flagged("hello").await.unwrap();
+
+    assert!(!flagged);
+    assert!(categories.is_empty());
+}
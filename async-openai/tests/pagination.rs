@@ -0,0 +1,101 @@
+//! Iterating over all the pages of list endpoints.
+#![allow(deprecated)]
+use async_openai::{
+    traits::RequestOptionsBuilder,
+    transport::{MockRequest, MockResponse, MockTransport},
+    Client,
+};
+use futures::{StreamExt, TryStreamExt};
+
+fn file(id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "object": "file",
+        "bytes": 120000,
+        "created_at": 1677610602,
+        "filename": "mydata.jsonl",
+        "purpose": "fine-tune",
+    })
+}
+
+fn files_page(ids: &[&str], has_more: bool) -> MockResponse {
+    MockResponse::json(
+        200,
+        serde_json::json!({
+            "object": "list",
+            "data": ids.iter().map(|id| file(id)).collect::<Vec<_>>(),
+            "first_id": ids.first(),
+            "last_id": ids.last(),
+            "has_more": has_more,
+        }),
+    )
+}
+
+fn query(request: &MockRequest) -> Vec<(String, String)> {
+    request.url.query_pairs().into_owned().collect()
+}
+
+#[tokio::test]
+async fn list_stream_follows_cursor_until_last_page() {
+    let transport = MockTransport::new()
+        .on("/files", files_page(&["file-1", "file-2"], true))
+        .on("/files", files_page(&["file-3"], false));
+    let client = Client::new().with_transport(transport.clone());
+
+    let files: Vec<_> = client
+        .files()
+        .query(&[("limit", "2"), ("order", "asc")])
+        .unwrap()
+        .list_stream()
+        .try_collect()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = files.iter().map(|file| file.id.as_str()).collect();
+    assert_eq!(ids, ["file-1", "file-2", "file-3"]);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        query(&requests[0]),
+        [("limit".into(), "2".into()), ("order".into(), "asc".into())]
+    );
+    assert_eq!(
+        query(&requests[1]),
+        [
+            ("limit".into(), "2".into()),
+            ("order".into(), "asc".into()),
+            ("after".into(), "file-2".into())
+        ]
+    );
+}
+
+#[tokio::test]
+async fn list_stream_fetches_pages_lazily() {
+    let transport = MockTransport::new()
+        .on("/files", files_page(&["file-1", "file-2"], true))
+        .on("/files", files_page(&["file-3"], false));
+    let client = Client::new().with_transport(transport.clone());
+
+    let files = client.files();
+    let first: Vec<_> = files.list_stream().take(2).collect().await;
+    assert_eq!(first.len(), 2);
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn list_stream_stops_on_error() {
+    let transport = MockTransport::new().on(
+        "/threads/thread_1/messages",
+        MockResponse::error(404, "not_found", "No thread found"),
+    );
+    let client = Client::new()
+        .with_transport(transport.clone())
+        .with_max_retries(0);
+
+    let threads = client.threads();
+    let messages = threads.messages("thread_1");
+    let results: Vec<_> = messages.list_stream().collect().await;
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+}
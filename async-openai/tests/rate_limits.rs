@@ -0,0 +1,171 @@
+//! Client side concurrency and rate limits.
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use async_openai::{
+    error::OpenAIError,
+    traits::RequestOptionsBuilder,
+    transport::{HttpTransport, MockResponse, MockTransport},
+    types::RateLimitInfo,
+    Client, RateLimits,
+};
+use futures::StreamExt;
+use reqwest::{Request, Response};
+use serde_json::json;
+
+mod common;
+use common::mock_server;
+
+fn models() -> MockTransport {
+    MockTransport::new().on(
+        "/models",
+        MockResponse::json(200, json!({ "object": "list", "data": [] })),
+    )
+}
+
+/// Responds after `delay`, recording the highest number of requests in flight.
+#[derive(Debug, Clone)]
+struct SlowTransport {
+    inner: MockTransport,
+    delay: Duration,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+impl HttpTransport for SlowTransport {
+    fn execute(
+        &self,
+        request: Request,
+    ) -> Pin<Box<dyn Future<Output = Result<Response, OpenAIError>> + Send + '_>> {
+        Box::pin(async move {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(self.delay).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.inner.execute(request).await
+        })
+    }
+}
+
+#[tokio::test]
+async fn concurrency_limit_queues_requests() {
+    let transport = SlowTransport {
+        inner: models(),
+        delay: Duration::from_millis(20),
+        in_flight: Default::default(),
+        max_in_flight: Default::default(),
+    };
+    let client = Client::new()
+        .with_transport(transport.clone())
+        .with_concurrency_limit(2);
+
+    let results =
+        futures::future::join_all((0..6).map(|_| async { client.models().list().await })).await;
+
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(transport.max_in_flight.load(Ordering::SeqCst), 2);
+    assert_eq!(transport.inner.requests().len(), 6);
+}
+
+#[tokio::test]
+async fn zero_concurrency_limit_sends_one_request_at_a_time() {
+    let transport = SlowTransport {
+        inner: models(),
+        delay: Duration::from_millis(5),
+        in_flight: Default::default(),
+        max_in_flight: Default::default(),
+    };
+    let client = Client::new()
+        .with_transport(transport.clone())
+        .with_concurrency_limit(0);
+
+    let results = tokio::time::timeout(
+        Duration::from_secs(5),
+        futures::future::join_all((0..3).map(|_| async { client.models().list().await })),
+    )
+    .await
+    .expect("requests should not wait forever");
+
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(transport.max_in_flight.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn streams_keep_their_slot_until_dropped() {
+    let chunk = r#"{"id":"chatcmpl-123","object":"chat.completion.chunk","created":1694268190,"model":"gpt-4o-mini","choices":[{"index":0,"delta":{"content":"Hello"},"finish_reason":null}]}"#;
+    let (api_base, requests) = mock_server(vec![common::MockResponse::new(
+        200,
+        format!("data: {chunk}\n\ndata: [DONE]\n\n"),
+    )
+    .header("content-type", "text/event-stream")])
+    .await;
+    let client = common::client(api_base).with_concurrency_limit(1);
+    let request = common::chat_request();
+
+    let mut first = client.chat().create_stream(request.clone()).await.unwrap();
+    assert!(first.next().await.unwrap().is_ok());
+
+    // the first stream is not read to its end yet
+    let second = tokio::time::timeout(
+        Duration::from_millis(50),
+        client.chat().create_stream(request.clone()),
+    )
+    .await;
+    assert!(second.is_err());
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    drop(first);
+    let mut second = client.chat().create_stream(request).await.unwrap();
+    assert!(second.next().await.unwrap().is_ok());
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn requests_per_minute_delay_requests() {
+    let transport = models();
+    let client = Client::new()
+        .with_transport(transport.clone())
+        .with_rate_limits(RateLimits::new().requests_per_minute(6000));
+    // 100 requests per second, none left
+    client.update_rate_limits(&RateLimitInfo {
+        limit_requests: 6000,
+        limit_tokens: 1_000_000,
+        remaining_requests: 0,
+        remaining_tokens: 1_000_000,
+        reset_requests: Duration::from_millis(10),
+        reset_tokens: Duration::ZERO,
+    });
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        client.models().list().await.unwrap();
+    }
+    assert!(started.elapsed() >= Duration::from_millis(28));
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn waiting_longer_than_the_timeout_fails() {
+    let transport = models();
+    let client = Client::new()
+        .with_transport(transport.clone())
+        .with_rate_limits(RateLimits::new().requests_per_minute(1));
+
+    client.models().list().await.unwrap();
+    let error = client
+        .models()
+        .timeout(Duration::from_millis(20))
+        .list()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, OpenAIError::Timeout(_)), "{error}");
+    assert_eq!(transport.requests().len(), 1);
+}
@@ -0,0 +1,208 @@
+//! Realtime sessions over a local WebSocket server.
+// the handshake callback signature is defined by tungstenite
+#![allow(clippy::result_large_err)]
+use async_openai::{
+    config::OpenAIConfig,
+    error::OpenAIError,
+    types::realtime::{
+        RealtimeClientEvent, RealtimeClientEventInputAudioBufferAppend,
+        RealtimeClientEventResponseCreate, RealtimeServerEvent,
+    },
+    Client,
+};
+use futures::{SinkExt, StreamExt};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::{
+    handshake::server::{ErrorResponse, Request, Response},
+    Message,
+};
+
+fn client(addr: std::net::SocketAddr) -> Client<OpenAIConfig> {
+    Client::with_config(
+        OpenAIConfig::new()
+            .with_api_base(format!("http://{addr}/v1"))
+            .with_api_key("test-key"),
+    )
+}
+
+/// Accepts one session, checks the handshake, and answers each `input_audio_buffer.append`
+/// with `input_audio_buffer.committed` and each `response.create` with an `error`.
+/// Returns the client events received.
+async fn realtime_server() -> (
+    std::net::SocketAddr,
+    tokio::task::JoinHandle<Vec<serde_json::Value>>,
+) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let handle = tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        let callback = |request: &Request, response: Response| {
+            assert_eq!(request.uri().path(), "/v1/realtime");
+            assert_eq!(request.uri().query(), Some("model=gpt-realtime"));
+            assert_eq!(
+                request.headers()["authorization"].to_str().unwrap(),
+                "Bearer test-key"
+            );
+            Ok::<_, ErrorResponse>(response)
+        };
+        let mut ws = tokio_tungstenite::accept_hdr_async(socket, callback)
+            .await
+            .unwrap();
+
+        // control frames are not server events
+        ws.send(Message::Ping(vec![1].into())).await.unwrap();
+
+        let mut received = vec![];
+        while let Some(Ok(message)) = ws.next().await {
+            let Message::Text(text) = message else {
+                continue;
+            };
+            let event: serde_json::Value = serde_json::from_str(&text).unwrap();
+            let reply = match event["type"].as_str().unwrap() {
+                "input_audio_buffer.append" => serde_json::json!({
+                    "type": "input_audio_buffer.committed",
+                    "event_id": "event_1",
+                    "previous_item_id": null,
+                    "item_id": "item_1",
+                }),
+                _ => serde_json::json!({
+                    "type": "error",
+                    "event_id": "event_2",
+                    "error": {
+                        "type": "invalid_request_error",
+                        "code": "invalid_value",
+                        "message": "Invalid value",
+                        "param": null,
+                        "event_id": null,
+                    },
+                }),
+            };
+            received.push(event);
+            ws.send(Message::Text(reply.to_string().into()))
+                .await
+                .unwrap();
+        }
+        received
+    });
+
+    (addr, handle)
+}
+
+#[tokio::test]
+async fn realtime_client_sends_and_receives_events() {
+    let (addr, server) = realtime_server().await;
+    let mut session = client(addr)
+        .realtime()
+        .connect("gpt-realtime")
+        .await
+        .unwrap();
+
+    session
+        .send(RealtimeClientEventInputAudioBufferAppend {
+            event_id: None,
+            audio: "AAAA".into(),
+        })
+        .await
+        .unwrap();
+    match session.next().await.unwrap().unwrap() {
+        RealtimeServerEvent::InputAudioBufferCommitted(event) => {
+            assert_eq!(event.item_id, "item_1")
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+
+    session
+        .send(RealtimeClientEventResponseCreate::default())
+        .await
+        .unwrap();
+    match session.next().await.unwrap().unwrap() {
+        RealtimeServerEvent::Error(event) => assert_eq!(event.error.message, "Invalid value"),
+        other => panic!("unexpected event: {other:?}"),
+    }
+
+    session.close().await.unwrap();
+    assert!(session.next().await.is_none());
+
+    let received = server.await.unwrap();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0]["type"], "input_audio_buffer.append");
+    assert_eq!(received[0]["audio"], "AAAA");
+    assert_eq!(received[1]["type"], "response.create");
+}
+
+#[tokio::test]
+async fn realtime_client_split_halves() {
+    let (addr, server) = realtime_server().await;
+    let session = client(addr)
+        .realtime()
+        .connect("gpt-realtime")
+        .await
+        .unwrap();
+    let (mut sender, mut receiver) = session.split();
+
+    let send = tokio::spawn(async move {
+        sender
+            .send(RealtimeClientEvent::InputAudioBufferAppend(
+                RealtimeClientEventInputAudioBufferAppend {
+                    event_id: Some("client_1".into()),
+                    audio: "AAAA".into(),
+                },
+            ))
+            .await
+            .unwrap();
+        sender
+    });
+
+    let event = receiver.next().await.unwrap().unwrap();
+    assert!(matches!(
+        event,
+        RealtimeServerEvent::InputAudioBufferCommitted(_)
+    ));
+
+    let mut sender = send.await.unwrap();
+    sender.close().await.unwrap();
+    assert!(receiver.next().await.is_none());
+
+    let received = server.await.unwrap();
+    assert_eq!(received[0]["event_id"], "client_1");
+}
+
+#[tokio::test]
+async fn realtime_client_handshake_error_is_api_error() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        let callback = |_: &Request, _: Response| {
+            let body = serde_json::json!({
+                "error": {
+                    "message": "Incorrect API key provided",
+                    "type": "invalid_request_error",
+                    "param": null,
+                    "code": "invalid_api_key",
+                }
+            });
+            Err(tokio_tungstenite::tungstenite::http::Response::builder()
+                .status(401)
+                .header("x-request-id", "req_123")
+                .body(Some(body.to_string()))
+                .unwrap())
+        };
+        let _ = tokio_tungstenite::accept_hdr_async(socket, callback).await;
+    });
+
+    let error = client(addr)
+        .realtime()
+        .connect("gpt-realtime")
+        .await
+        .unwrap_err();
+    assert_eq!(error.request_id(), Some("req_123"));
+    match error {
+        OpenAIError::ApiError(error) => {
+            assert_eq!(error.status, Some(401));
+            assert_eq!(error.code.as_deref(), Some("invalid_api_key"));
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
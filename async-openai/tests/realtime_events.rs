@@ -0,0 +1,207 @@
+//! (De)serialization of Realtime client and server events.
+use async_openai::{
+    error::OpenAIError,
+    types::realtime::{
+        RealtimeClientEvent, RealtimeClientEventInputAudioBufferAppend, RealtimeServerEvent,
+        RealtimeServerEventResponseAudioDelta,
+    },
+};
+use serde_json::json;
+
+/// Deserializes `value` into `T`, and checks that it serializes back to `value`.
+fn round_trip<T>(value: serde_json::Value) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let event: T = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&event).unwrap(), value);
+    event
+}
+
+#[test]
+fn client_events_round_trip() {
+    let event = round_trip(json!({
+        "type": "session.update",
+        "event_id": "event_1",
+        "session": { "type": "realtime", "model": "gpt-realtime" },
+    }));
+    assert!(matches!(event, RealtimeClientEvent::SessionUpdate(_)));
+
+    let event = round_trip(json!({ "type": "input_audio_buffer.append", "audio": "AAAA" }));
+    match event {
+        RealtimeClientEvent::InputAudioBufferAppend(event) => assert_eq!(event.audio, "AAAA"),
+        other => panic!("unexpected event: {other:?}"),
+    }
+
+    let event = round_trip(json!({ "type": "input_audio_buffer.commit" }));
+    assert!(matches!(
+        event,
+        RealtimeClientEvent::InputAudioBufferCommit(_)
+    ));
+
+    let event = round_trip(json!({ "type": "input_audio_buffer.clear" }));
+    assert!(matches!(
+        event,
+        RealtimeClientEvent::InputAudioBufferClear(_)
+    ));
+
+    let event = round_trip(json!({
+        "type": "conversation.item.create",
+        "item": {
+            "type": "message",
+            "role": "user",
+            "content": [{ "type": "input_text", "text": "Hello" }],
+        },
+    }));
+    assert!(matches!(
+        event,
+        RealtimeClientEvent::ConversationItemCreate(_)
+    ));
+
+    let event = round_trip(json!({ "type": "response.create", "response": null }));
+    assert!(matches!(event, RealtimeClientEvent::ResponseCreate(_)));
+}
+
+#[test]
+fn server_events_round_trip() {
+    let event = round_trip(json!({
+        "type": "session.created",
+        "event_id": "event_1",
+        "session": { "type": "realtime", "model": "gpt-realtime" },
+    }));
+    assert!(matches!(event, RealtimeServerEvent::SessionCreated(_)));
+
+    let event = round_trip(json!({
+        "type": "response.output_audio.delta",
+        "event_id": "event_2",
+        "response_id": "resp_1",
+        "item_id": "item_1",
+        "output_index": 0,
+        "content_index": 0,
+        "delta": "AAAA",
+    }));
+    match event {
+        RealtimeServerEvent::ResponseOutputAudioDelta(event) => assert_eq!(event.delta, "AAAA"),
+        other => panic!("unexpected event: {other:?}"),
+    }
+
+    let event = round_trip(json!({
+        "type": "response.done",
+        "event_id": "event_3",
+        "response": {
+            "audio": null,
+            "id": "resp_1",
+            "max_output_tokens": "inf",
+            "object": "realtime.response",
+            "output": [],
+            "output_modalities": ["audio"],
+            "status": "completed",
+            "status_details": null,
+            "usage": null,
+        },
+    }));
+    assert!(matches!(event, RealtimeServerEvent::ResponseDone(_)));
+
+    let event = round_trip(json!({
+        "type": "error",
+        "event_id": "event_4",
+        "error": {
+            "type": "invalid_request_error",
+            "code": "invalid_value",
+            "message": "Invalid value",
+            "param": null,
+            "event_id": null,
+        },
+    }));
+    match event {
+        RealtimeServerEvent::Error(event) => assert_eq!(event.error.message, "Invalid value"),
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
+
+#[test]
+fn unknown_events_are_kept_raw() {
+    let raw = json!({ "type": "session.future_event", "event_id": "event_1", "value": 1 });
+
+    match round_trip(raw.clone()) {
+        RealtimeServerEvent::Unknown { r#type, raw: event } => {
+            assert_eq!(r#type, "session.future_event");
+            assert_eq!(event, raw);
+        }
+        other => panic!("unexpected event: {other:?}"),
+    }
+
+    match round_trip(raw.clone()) {
+        RealtimeClientEvent::Unknown { r#type, .. } => assert_eq!(r#type, "session.future_event"),
+        other => panic!("unexpected event: {other:?}"),
+    }
+}
+
+#[test]
+fn invalid_known_events_fail() {
+    // a modeled type with an invalid payload is an error, not an unknown event
+    let error = serde_json::from_value::<RealtimeServerEvent>(
+        json!({ "type": "input_audio_buffer.committed", "event_id": 1 }),
+    )
+    .unwrap_err();
+    assert!(error.to_string().contains("invalid type"), "{error}");
+
+    assert!(
+        serde_json::from_value::<RealtimeServerEvent>(json!({ "event_id": "event_1" })).is_err()
+    );
+    assert!(serde_json::from_value::<RealtimeClientEvent>(json!({ "type": 1 })).is_err());
+}
+
+#[test]
+fn client_event_serializes_with_type() {
+    let event: RealtimeClientEvent = RealtimeClientEventInputAudioBufferAppend {
+        event_id: None,
+        audio: "AAAA".into(),
+    }
+    .into();
+    assert_eq!(
+        serde_json::to_value(&event).unwrap(),
+        json!({ "type": "input_audio_buffer.append", "audio": "AAAA" })
+    );
+}
+
+fn audio_delta(delta: &str) -> RealtimeServerEventResponseAudioDelta {
+    serde_json::from_value(json!({
+        "event_id": "event_1",
+        "response_id": "resp_1",
+        "item_id": "item_1",
+        "output_index": 0,
+        "content_index": 0,
+        "delta": delta,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn pcm16_audio_round_trip() {
+    let samples = [0, 1, -1, i16::MAX, i16::MIN, 256];
+    let append = RealtimeClientEventInputAudioBufferAppend::from_pcm16(&samples);
+    // little-endian bytes: 00 00 01 00 ff ff ff 7f 00 80 00 01
+    assert_eq!(append.audio, "AAABAP///38AgAAB");
+    assert_eq!(append.pcm16_samples().unwrap(), samples);
+
+    let delta = audio_delta(&append.audio);
+    assert_eq!(delta.pcm16_samples().unwrap(), samples);
+
+    assert!(audio_delta("").pcm16_samples().unwrap().is_empty());
+}
+
+#[test]
+fn pcm16_audio_rejects_odd_byte_lengths() {
+    // three bytes: 00 01 02
+    let error = audio_delta("AAEC").pcm16_samples().unwrap_err();
+    assert!(
+        matches!(&error, OpenAIError::InvalidArgument(message) if message.contains("3 bytes")),
+        "{error}"
+    );
+
+    // one byte: ff
+    assert!(audio_delta("/w==").pcm16_samples().is_err());
+
+    assert!(audio_delta("not base64!").pcm16_samples().is_err());
+}
@@ -0,0 +1,128 @@
+//! Reasoning settings of requests, and reasoning tokens and summaries of responses.
+use async_openai::types::{
+    chat::{CreateChatCompletionResponse, ReasoningEffort},
+    responses::{CreateResponseArgs, ReasoningArgs, ReasoningSummary, Response},
+};
+use serde_json::json;
+
+#[test]
+fn reasoning_effort_serializes_lowercase() {
+    for (effort, value) in [
+        (ReasoningEffort::None, "none"),
+        (ReasoningEffort::Minimal, "minimal"),
+        (ReasoningEffort::Low, "low"),
+        (ReasoningEffort::Medium, "medium"),
+        (ReasoningEffort::High, "high"),
+        (ReasoningEffort::Xhigh, "xhigh"),
+    ] {
+        assert_eq!(serde_json::to_value(&effort).unwrap(), value);
+        assert_eq!(
+            serde_json::from_value::<ReasoningEffort>(json!(value)).unwrap(),
+            effort
+        );
+    }
+}
+
+#[test]
+fn response_request_with_reasoning() {
+    let request = CreateResponseArgs::default()
+        .model("gpt-5")
+        .input("How many r's are in strawberry?")
+        .reasoning(
+            ReasoningArgs::default()
+                .effort(ReasoningEffort::Xhigh)
+                .summary(ReasoningSummary::Detailed)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap()["reasoning"],
+        json!({ "effort": "xhigh", "summary": "detailed" })
+    );
+}
+
+#[test]
+fn chat_completion_reasoning_tokens() {
+    let response: CreateChatCompletionResponse = serde_json::from_value(json!({
+        "id": "chatcmpl-123",
+        "object": "chat.completion",
+        "created": 1738960610,
+        "model": "o3-mini-2025-01-31",
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": "There are three." },
+            "finish_reason": "stop"
+        }],
+        "usage": {
+            "prompt_tokens": 13,
+            "completion_tokens": 203,
+            "total_tokens": 216,
+            "completion_tokens_details": { "reasoning_tokens": 192 }
+        }
+    }))
+    .unwrap();
+
+    let details = response.usage.unwrap().completion_tokens_details.unwrap();
+    assert_eq!(details.reasoning_tokens, Some(192));
+}
+
+#[test]
+fn response_reasoning_summary_and_tokens() {
+    let response: Response = serde_json::from_value(json!({
+        "id": "resp_123",
+        "object": "response",
+        "created_at": 1741476542,
+        "status": "completed",
+        "model": "o4-mini-2025-04-16",
+        "output": [
+            {
+                "type": "reasoning",
+                "id": "rs_123",
+                "summary": [
+                    { "type": "summary_text", "text": "**Counting letters**" },
+                    { "type": "summary_text", "text": "The word has three r's." }
+                ]
+            },
+            {
+                "type": "message",
+                "id": "msg_123",
+                "status": "completed",
+                "role": "assistant",
+                "content": [{
+                    "type": "output_text",
+                    "text": "There are three r's in strawberry.",
+                    "annotations": []
+                }]
+            }
+        ],
+        "reasoning": { "effort": "xhigh", "summary": "detailed" },
+        "usage": {
+            "input_tokens": 14,
+            "input_tokens_details": { "cached_tokens": 0 },
+            "output_tokens": 330,
+            "output_tokens_details": { "reasoning_tokens": 256 },
+            "total_tokens": 344
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        response.reasoning_summary().as_deref(),
+        Some("**Counting letters**\n\nThe word has three r's.")
+    );
+    assert_eq!(
+        response.reasoning.as_ref().unwrap().effort,
+        Some(ReasoningEffort::Xhigh)
+    );
+    assert_eq!(
+        response
+            .usage
+            .unwrap()
+            .output_tokens_details
+            .reasoning_tokens,
+        256
+    );
+}
@@ -0,0 +1,154 @@
+//! Recording responses of a server with `RecordingTransport`, and replaying them without it.
+use std::path::PathBuf;
+
+use async_openai::{
+    config::OpenAIConfig,
+    error::OpenAIError,
+    transport::{RecordingMode, RecordingTransport},
+    types::chat::{ChatCompletionRequestUserMessage, CreateChatCompletionRequestArgs},
+    Client,
+};
+
+mod common;
+use common::{mock_server_recording, MockResponse};
+
+const COMPLETION: &str = r#"{
+    "id": "chatcmpl-123",
+    "object": "chat.completion",
+    "created": 1677652288,
+    "model": "gpt-4o-mini",
+    "choices": [{
+        "index": 0,
+        "message": {"role": "assistant", "content": "Hello there!"},
+        "finish_reason": "stop"
+    }]
+}"#;
+
+fn recordings_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "async-openai-recordings-{name}-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn client(api_base: &str, transport: RecordingTransport) -> Client<OpenAIConfig> {
+    Client::with_config(
+        OpenAIConfig::new()
+            .with_api_base(api_base)
+            .with_api_key("sk-secret"),
+    )
+    .with_transport(transport)
+}
+
+async fn ask(client: &Client<OpenAIConfig>, content: &str) -> Result<String, OpenAIError> {
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from(content).into()])
+        .build()?;
+    let response = client.chat().create(request).await?;
+    Ok(response.choices[0].message.content.clone().unwrap())
+}
+
+#[tokio::test]
+async fn records_then_replays_offline() {
+    let dir = recordings_dir("replay");
+    let (api_base, received) = mock_server_recording(vec![
+        MockResponse::new(200, COMPLETION).header("content-type", "application/json")
+    ])
+    .await;
+
+    let recording = client(
+        &api_base,
+        RecordingTransport::new(&dir, RecordingMode::RecordOrReplay),
+    );
+    assert_eq!(ask(&recording, "Hello").await.unwrap(), "Hello there!");
+    // the second call is replayed
+    assert_eq!(ask(&recording, "Hello").await.unwrap(), "Hello there!");
+    assert_eq!(received.lock().unwrap().len(), 1);
+
+    let recordings: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(recordings.len(), 1);
+    let name = recordings[0].file_name().unwrap().to_str().unwrap();
+    assert!(name.starts_with("v1_chat_completions-") && name.ends_with(".json"));
+    let recorded = std::fs::read_to_string(&recordings[0]).unwrap();
+    assert!(!recorded.contains("sk-secret"));
+    assert!(recorded.contains("REDACTED"));
+
+    // replayed without a server, even with another key
+    let replaying = Client::with_config(
+        OpenAIConfig::new()
+            .with_api_base(&api_base)
+            .with_api_key("sk-other"),
+    )
+    .with_transport(RecordingTransport::new(&dir, RecordingMode::Replay));
+    assert_eq!(ask(&replaying, "Hello").await.unwrap(), "Hello there!");
+    assert_eq!(received.lock().unwrap().len(), 1);
+
+    // another body has no recording
+    let err = ask(&replaying, "Bye").await.unwrap_err();
+    assert!(matches!(err, OpenAIError::InvalidArgument(_)));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn record_mode_overwrites_recordings() {
+    let dir = recordings_dir("record");
+    let (api_base, received) =
+        mock_server_recording(vec![MockResponse::new(200, COMPLETION)]).await;
+
+    let recording = client(
+        &api_base,
+        RecordingTransport::new(&dir, RecordingMode::Record),
+    );
+    ask(&recording, "Hello").await.unwrap();
+    ask(&recording, "Hello").await.unwrap();
+
+    assert_eq!(received.lock().unwrap().len(), 2);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn binary_bodies_replay_unchanged_and_account_headers_are_redacted() {
+    const AUDIO: &[u8] = &[0xff, 0xfb, 0x90, 0x00, 0x80, 0x7f];
+    let dir = recordings_dir("binary");
+    let (api_base, received) = mock_server_recording(vec![MockResponse::bytes(200, AUDIO)
+        .header("set-cookie", "__cf_bm=cookie-secret")
+        .header("openai-organization", "org-secret")])
+    .await;
+
+    let recording = client(
+        &api_base,
+        RecordingTransport::new(&dir, RecordingMode::RecordOrReplay),
+    );
+    let content = recording.files().content("file-abc123").await.unwrap();
+    assert_eq!(content.as_ref(), AUDIO);
+
+    let path = std::fs::read_dir(&dir)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap()
+        .path();
+    let recorded = std::fs::read_to_string(path).unwrap();
+    assert!(recorded.contains(r#""encoding": "base64""#));
+    assert!(!recorded.contains("cookie-secret"));
+    assert!(!recorded.contains("org-secret"));
+
+    let replaying = client(
+        &api_base,
+        RecordingTransport::new(&dir, RecordingMode::Replay),
+    );
+    let content = replaying.files().content("file-abc123").await.unwrap();
+    assert_eq!(content.as_ref(), AUDIO);
+    assert_eq!(received.lock().unwrap().len(), 1);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,273 @@
+//! Per-request options of API groups.
+use std::collections::HashMap;
+
+use async_openai::{
+    config::{AzureConfig, CompatConfig, OpenAIConfig, OPENAI_BETA_HEADER},
+    error::OpenAIError,
+    traits::RequestOptionsBuilder,
+    transport::{MockResponse, MockTransport},
+    Client,
+};
+use futures::StreamExt;
+use serde_json::json;
+
+mod common;
+
+/// `transport` answering chat completions once its own responses are used up.
+fn chat_transport(transport: &MockTransport) -> MockTransport {
+    transport.clone().on(
+        "/chat/completions",
+        MockResponse::json(
+            200,
+            json!({
+                "id": "chatcmpl-123",
+                "object": "chat.completion",
+                "created": 1677652288,
+                "model": "gpt-4o-mini",
+                "choices": [{
+                    "index": 0,
+                    "message": { "role": "assistant", "content": "Hello!" },
+                    "finish_reason": "stop"
+                }]
+            }),
+        ),
+    )
+}
+
+#[tokio::test]
+async fn extra_body_is_merged_into_request() {
+    let transport = MockTransport::new();
+    let client = common::transport_client(&chat_transport(&transport));
+
+    client
+        .chat()
+        .extra_body(HashMap::from([
+            ("safety_settings".to_string(), json!({ "level": "strict" })),
+            // not set by the request, so not serialized
+            ("temperature".to_string(), json!(0.5)),
+        ]))
+        .create(common::chat_request())
+        .await
+        .unwrap();
+
+    let body = transport.requests()[0].json().unwrap();
+    assert_eq!(body["model"], "gpt-4o-mini");
+    assert_eq!(body["safety_settings"], json!({ "level": "strict" }));
+    assert_eq!(body["temperature"], 0.5);
+}
+
+#[tokio::test]
+async fn extra_body_does_not_override_request_fields() {
+    let transport = MockTransport::new();
+    let client = common::transport_client(&chat_transport(&transport));
+
+    let result = client
+        .chat()
+        .extra_body(HashMap::from([("model".to_string(), json!("gpt-4o"))]))
+        .create(common::chat_request())
+        .await;
+
+    assert!(
+        matches!(result, Err(OpenAIError::InvalidArgument(message)) if message.contains("model"))
+    );
+    assert!(transport.requests().is_empty());
+}
+
+#[tokio::test]
+async fn extra_body_collision_fails_stream() {
+    let transport = MockTransport::new();
+    let client = common::transport_client(&chat_transport(&transport));
+
+    let mut stream = client
+        .chat()
+        .extra_body(HashMap::from([("model".to_string(), json!("gpt-4o"))]))
+        .create_stream(common::chat_request())
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        stream.next().await,
+        Some(Err(OpenAIError::InvalidArgument(_)))
+    ));
+    assert!(stream.next().await.is_none());
+}
+
+#[tokio::test]
+async fn headers_are_layered_on_config_headers() {
+    let transport = MockTransport::new();
+    let config = OpenAIConfig::new()
+        .with_api_key("test")
+        .with_project_id("proj_config")
+        .with_header("x-gateway", "config")
+        .unwrap();
+    let client = Client::with_config(config).with_transport(chat_transport(&transport));
+
+    client
+        .chat()
+        .header("x-portkey-provider", "openai")
+        .unwrap()
+        .header("OpenAI-Project", "proj_request")
+        .unwrap()
+        .create(common::chat_request())
+        .await
+        .unwrap();
+
+    let headers = &transport.requests()[0].headers;
+    assert_eq!(headers["authorization"], "Bearer test");
+    assert_eq!(headers["x-gateway"], "config");
+    assert_eq!(headers["x-portkey-provider"], "openai");
+    assert_eq!(headers.get_all("openai-project").iter().count(), 1);
+    assert_eq!(headers["openai-project"], "proj_request");
+}
+
+#[tokio::test]
+async fn compat_config_drops_beta_headers_of_requests() {
+    let transport = MockTransport::new();
+    let config = CompatConfig::new().without_beta_headers();
+    let client = Client::with_config(config).with_transport(chat_transport(&transport));
+
+    client
+        .chat()
+        .header(OPENAI_BETA_HEADER, "assistants=v2")
+        .unwrap()
+        .header("x-gateway", "request")
+        .unwrap()
+        .create(common::chat_request())
+        .await
+        .unwrap();
+
+    let headers = &transport.requests()[0].headers;
+    assert!(headers.get(OPENAI_BETA_HEADER).is_none());
+    assert!(headers.get("authorization").is_none());
+    assert_eq!(headers["x-gateway"], "request");
+}
+
+#[tokio::test]
+async fn query_is_appended_to_config_query() {
+    let transport = MockTransport::new();
+    let config = AzureConfig::new()
+        .with_api_base("https://example.openai.azure.com")
+        .with_deployment_id("gpt-4o-mini")
+        .with_api_version("2024-10-21")
+        .with_api_key("test");
+    let client = Client::with_config(config).with_transport(chat_transport(&transport));
+
+    client
+        .chat()
+        .query(&[("trace", "1")])
+        .unwrap()
+        .create(common::chat_request())
+        .await
+        .unwrap();
+
+    let query: Vec<(String, String)> = transport.requests()[0]
+        .url
+        .query_pairs()
+        .into_owned()
+        .collect();
+    assert_eq!(
+        query,
+        [
+            ("api-version".to_string(), "2024-10-21".to_string()),
+            ("trace".to_string(), "1".to_string())
+        ]
+    );
+}
+
+#[tokio::test]
+async fn build_request_matches_the_sent_request() {
+    let transport = MockTransport::new();
+    let config = AzureConfig::new()
+        .with_api_base("https://example.openai.azure.com")
+        .with_deployment_id("gpt-4o-mini")
+        .with_api_version("2024-10-21")
+        .with_api_key("test");
+    let client = Client::with_config(config).with_transport(chat_transport(&transport));
+    let chat = client
+        .chat()
+        .header("x-gateway", "request")
+        .unwrap()
+        .query(&[("trace", "1")])
+        .unwrap()
+        .extra_body(HashMap::from([(
+            "safety_settings".to_string(),
+            json!({ "level": "strict" }),
+        )]));
+
+    let prepared = chat.build_request(&common::chat_request()).unwrap();
+    assert!(transport.requests().is_empty());
+
+    chat.create(common::chat_request()).await.unwrap();
+    let sent = &transport.requests()[0];
+    assert_eq!(prepared.method, sent.method);
+    assert_eq!(prepared.url, sent.url);
+    assert_eq!(prepared.headers, sent.headers);
+    assert_eq!(prepared.body, Some(sent.json().unwrap()));
+
+    assert_eq!(
+        prepared.url.as_str(),
+        "https://example.openai.azure.com/openai/deployments/gpt-4o-mini/chat/completions?api-version=2024-10-21&trace=1"
+    );
+    assert_eq!(prepared.headers["api-key"], "test");
+    assert_eq!(prepared.headers["x-gateway"], "request");
+    assert_eq!(
+        prepared.body.unwrap()["safety_settings"],
+        json!({ "level": "strict" })
+    );
+}
+
+#[tokio::test]
+async fn idempotency_key_is_sent() {
+    let transport = MockTransport::new();
+    common::transport_client(&chat_transport(&transport))
+        .with_idempotency_keys()
+        .chat()
+        .idempotency_key("batch-2024-06-01")
+        .unwrap()
+        .create(common::chat_request())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        transport.requests()[0].headers["idempotency-key"],
+        "batch-2024-06-01"
+    );
+}
+
+#[tokio::test]
+async fn generated_idempotency_key_is_kept_by_retries() {
+    let transport = MockTransport::new().on(
+        "/chat/completions",
+        MockResponse::error(429, "rate_limit_exceeded", "Rate limit reached")
+            .header("retry-after-ms", "1"),
+    );
+    let client = common::transport_client(&chat_transport(&transport)).with_idempotency_keys();
+    client.chat().create(common::chat_request()).await.unwrap();
+    client.chat().create(common::chat_request()).await.unwrap();
+
+    let keys: Vec<String> = transport
+        .requests()
+        .iter()
+        .map(|request| request.headers["idempotency-key"].to_str().unwrap().into())
+        .collect();
+    assert_eq!(keys.len(), 3);
+    assert_eq!(keys[0], keys[1]);
+    assert_ne!(keys[1], keys[2]);
+    let groups: Vec<usize> = keys[0].split('-').map(str::len).collect();
+    assert_eq!(groups, [8, 4, 4, 4, 12]);
+    assert_eq!(&keys[0][14..15], "4");
+}
+
+#[tokio::test]
+async fn idempotency_keys_are_not_sent_by_default() {
+    let transport = MockTransport::new();
+    common::transport_client(&chat_transport(&transport))
+        .chat()
+        .create(common::chat_request())
+        .await
+        .unwrap();
+
+    assert!(!transport.requests()[0]
+        .headers
+        .contains_key("idempotency-key"));
+}
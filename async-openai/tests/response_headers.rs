@@ -0,0 +1,103 @@
+//! Response headers are returned alongside the deserialized response body.
+use async_openai::{
+    config::OpenAIConfig,
+    error::OpenAIError,
+    types::chat::{ChatCompletionRequestUserMessage, CreateChatCompletionRequestArgs},
+    Client,
+};
+
+mod common;
+use common::{mock_server, MockResponse};
+
+const CHAT_COMPLETION: &str = r#"{
+  "id": "chatcmpl-123",
+  "object": "chat.completion",
+  "created": 1677652288,
+  "model": "gpt-4o-mini",
+  "choices": [{
+    "index": 0,
+    "message": {"role": "assistant", "content": "Hello there!"},
+    "finish_reason": "stop"
+  }],
+  "usage": {"prompt_tokens": 9, "completion_tokens": 3, "total_tokens": 12}
+}"#;
+
+#[tokio::test]
+async fn chat_create_with_headers() {
+    let (api_base, _) = mock_server(vec![MockResponse::new(200, CHAT_COMPLETION)
+        .header("x-ratelimit-remaining-requests", "59")
+        .header("x-ratelimit-reset-tokens", "6m0s")])
+    .await;
+
+    let client = Client::with_config(
+        OpenAIConfig::new()
+            .with_api_base(api_base)
+            .with_api_key("test"),
+    );
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello!").into()])
+        .build()
+        .unwrap();
+
+    let (response, headers) = client.chat().create_with_headers(request).await.unwrap();
+
+    assert_eq!(response.id, "chatcmpl-123");
+    assert_eq!(headers["x-ratelimit-remaining-requests"], "59");
+    assert_eq!(headers["x-ratelimit-reset-tokens"], "6m0s");
+}
+
+#[tokio::test]
+async fn request_id_of_responses_and_errors() {
+    let (api_base, _) = mock_server(vec![
+        MockResponse::new(200, CHAT_COMPLETION).header("x-request-id", "req_ok"),
+        MockResponse::new(
+            400,
+            r#"{"error": {"message": "Invalid model", "type": "invalid_request_error", "param": "model", "code": null}}"#,
+        )
+        .header("x-request-id", "req_bad_request"),
+        MockResponse::new(413, "<html>Request Entity Too Large</html>")
+            .header("content-type", "text/html")
+            .header("x-request-id", "req_too_large"),
+        MockResponse::new(500, "upstream failure").header("x-request-id", "req_server_error"),
+    ])
+    .await;
+
+    let client = Client::with_config(
+        OpenAIConfig::new()
+            .with_api_base(api_base)
+            .with_api_key("test"),
+    )
+    .with_max_retries(0);
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello!").into()])
+        .build()
+        .unwrap();
+
+    let (_, headers) = client
+        .chat()
+        .create_with_headers(request.clone())
+        .await
+        .unwrap();
+    assert_eq!(headers["x-request-id"], "req_ok");
+
+    let error = client.chat().create(request.clone()).await.unwrap_err();
+    assert_eq!(error.request_id(), Some("req_bad_request"));
+
+    // an error body that is not JSON, e.g. from a proxy
+    let error = client.chat().create(request.clone()).await.unwrap_err();
+    assert_eq!(error.request_id(), Some("req_too_large"));
+    assert!(matches!(&error, OpenAIError::ApiError(api_error) if api_error.status == Some(413)));
+
+    let error = client.chat().create(request).await.unwrap_err();
+    match &error {
+        OpenAIError::ApiError(api_error) => {
+            assert_eq!(api_error.status, Some(500));
+            assert_eq!(api_error.request_id.as_deref(), Some("req_server_error"));
+        }
+        other => panic!("expected an API error, got {other:?}"),
+    }
+}
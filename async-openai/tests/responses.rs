@@ -0,0 +1,284 @@
+//! Responses API endpoints.
+use async_openai::{
+    config::OpenAIConfig,
+    error::OpenAIError,
+    transport::{MockResponse, MockTransport},
+    types::responses::{
+        CreateResponseArgs, EasyInputMessage, InputParam, ResponseStreamEvent, Status,
+    },
+    Client,
+};
+use futures::StreamExt;
+use serde_json::json;
+
+mod common;
+
+fn response(id: &str, status: &str, text: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "object": "response",
+        "created_at": 1741476542,
+        "status": status,
+        "model": "gpt-4.1-mini",
+        "output": [{
+            "type": "message",
+            "id": "msg_1",
+            "status": "completed",
+            "role": "assistant",
+            "content": [{ "type": "output_text", "text": text, "annotations": [] }]
+        }]
+    })
+}
+
+#[tokio::test]
+async fn create_sends_input_instructions_and_previous_response_id() {
+    let transport = MockTransport::new().on(
+        "/responses",
+        MockResponse::json(200, response("resp_2", "completed", "Paris.")),
+    );
+
+    let request = CreateResponseArgs::default()
+        .model("gpt-4.1-mini")
+        .instructions("Answer in one word.")
+        .input("And its capital?")
+        .previous_response_id("resp_1")
+        .build()
+        .unwrap();
+    let response = common::transport_client(&transport)
+        .responses()
+        .create(request)
+        .await
+        .unwrap();
+
+    assert_eq!(response.id, "resp_2");
+    assert_eq!(response.status, Status::Completed);
+    assert_eq!(response.output_text(), "Paris.");
+
+    let body = transport.requests()[0].json().unwrap();
+    assert_eq!(body["input"], "And its capital?");
+    assert_eq!(body["instructions"], "Answer in one word.");
+    assert_eq!(body["previous_response_id"], "resp_1");
+}
+
+#[tokio::test]
+async fn create_accepts_structured_input_items() {
+    let transport = MockTransport::new().on(
+        "/responses",
+        MockResponse::json(200, response("resp_1", "completed", "Hi")),
+    );
+
+    let input: InputParam = vec![
+        EasyInputMessage::from("Hello"),
+        EasyInputMessage::from("How are you?"),
+    ]
+    .into();
+    let request = CreateResponseArgs::default()
+        .model("gpt-4.1-mini")
+        .input(input)
+        .build()
+        .unwrap();
+    common::transport_client(&transport)
+        .responses()
+        .create(request)
+        .await
+        .unwrap();
+
+    let body = transport.requests()[0].json().unwrap();
+    assert_eq!(body["input"][0]["role"], "user");
+    assert_eq!(body["input"][1]["content"], "How are you?");
+}
+
+#[tokio::test]
+async fn retrieve_delete_and_cancel() {
+    let transport = MockTransport::new()
+        .on(
+            "/responses/resp_1",
+            MockResponse::json(200, response("resp_1", "completed", "Hi")),
+        )
+        .on(
+            "/responses/resp_1",
+            MockResponse::json(
+                200,
+                json!({ "id": "resp_1", "object": "response.deleted", "deleted": true }),
+            ),
+        )
+        .on(
+            "/responses/resp_2/cancel",
+            MockResponse::json(200, response("resp_2", "cancelled", "")),
+        );
+    let client = common::transport_client(&transport);
+
+    let retrieved = client.responses().retrieve("resp_1").await.unwrap();
+    assert_eq!(retrieved.output_text(), "Hi");
+
+    let deleted = client.responses().delete("resp_1").await.unwrap();
+    assert!(deleted.deleted);
+
+    let cancelled = client.responses().cancel("resp_2").await.unwrap();
+    assert_eq!(cancelled.status, Status::Cancelled);
+
+    let requests: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|request| format!("{} {}", request.method, request.url.path()))
+        .collect();
+    assert_eq!(
+        requests,
+        [
+            "GET /v1/responses/resp_1",
+            "DELETE /v1/responses/resp_1",
+            "POST /v1/responses/resp_2/cancel",
+        ]
+    );
+}
+
+const STREAM: &str = concat!(
+    "event: response.output_text.delta\n",
+    "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"Hel\"}\n\n",
+    "event: response.output_text.delta\n",
+    "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":2,\"item_id\":\"msg_1\",\"output_index\":0,\"content_index\":0,\"delta\":\"lo\"}\n\n",
+    "event: response.completed\n",
+    "data: {\"type\":\"response.completed\",\"sequence_number\":3,\"response\":{\"id\":\"resp_1\",\"object\":\"response\",\"created_at\":1741476542,\"status\":\"completed\",\"model\":\"gpt-4.1-mini\",\"output\":[]}}\n\n",
+);
+
+#[tokio::test]
+async fn create_stream_yields_typed_events() {
+    let (api_base, _) = common::mock_server(vec![
+        common::MockResponse::new(200, STREAM).header("content-type", "text/event-stream")
+    ])
+    .await;
+    let client = Client::with_config(OpenAIConfig::new().with_api_base(api_base));
+
+    let request = CreateResponseArgs::default()
+        .model("gpt-4.1-mini")
+        .input("Say hello")
+        .build()
+        .unwrap();
+    let mut stream = client.responses().create_stream(request).await.unwrap();
+
+    let mut text = String::new();
+    let mut completed = None;
+    while let Some(event) = stream.next().await {
+        match event.unwrap() {
+            ResponseStreamEvent::ResponseOutputTextDelta(delta) => text.push_str(&delta.delta),
+            ResponseStreamEvent::ResponseCompleted(event) => completed = Some(event.response),
+            _ => {}
+        }
+    }
+    assert_eq!(text, "Hello");
+    assert_eq!(completed.unwrap().id, "resp_1");
+}
+
+fn template() -> async_openai::types::responses::CreateResponse {
+    CreateResponseArgs::default()
+        .model("gpt-4.1-mini")
+        .instructions("Be brief.")
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn chain_threads_previous_response_id() {
+    let transport = MockTransport::new()
+        .on(
+            "/responses",
+            MockResponse::json(200, response("resp_1", "completed", "Paris.")),
+        )
+        .on(
+            "/responses",
+            MockResponse::json(200, response("resp_2", "completed", "About 2 million.")),
+        )
+        .on(
+            "/responses",
+            MockResponse::json(200, response("resp_3", "completed", "Lyon.")),
+        );
+    let client = common::transport_client(&transport);
+
+    let mut chain = client.responses().chain(template());
+    chain.send("Capital of France?").await.unwrap();
+    let answer = chain.send("Population?").await.unwrap();
+    assert_eq!(answer.output_text(), "About 2 million.");
+    assert_eq!(chain.previous_response_id(), Some("resp_2"));
+    assert_eq!(chain.turns().len(), 2);
+    assert_eq!(
+        chain.turns()[0].input,
+        InputParam::from("Capital of France?")
+    );
+
+    // fork after the first turn
+    let mut fork = chain.fork("resp_1");
+    assert_eq!(fork.turns().len(), 1);
+    fork.send("Second largest city?").await.unwrap();
+    assert_eq!(chain.turns().len(), 2);
+
+    let bodies: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|request| request.json().unwrap())
+        .collect();
+    assert!(bodies[0].get("previous_response_id").is_none());
+    assert_eq!(bodies[1]["previous_response_id"], "resp_1");
+    assert_eq!(bodies[1]["input"], "Population?");
+    assert_eq!(bodies[1]["instructions"], "Be brief.");
+    assert_eq!(bodies[2]["previous_response_id"], "resp_1");
+}
+
+fn previous_response_not_found() -> MockResponse {
+    MockResponse::json(
+        400,
+        json!({
+            "error": {
+                "message": "Previous response with id 'resp_1' not found.",
+                "type": "invalid_request_error",
+                "param": "previous_response_id",
+                "code": "previous_response_not_found"
+            }
+        }),
+    )
+}
+
+#[tokio::test]
+async fn chain_resends_history_when_previous_response_expired() {
+    let transport = MockTransport::new()
+        .on(
+            "/responses",
+            MockResponse::json(200, response("resp_1", "completed", "Paris.")),
+        )
+        .on("/responses", previous_response_not_found())
+        .on(
+            "/responses",
+            MockResponse::json(200, response("resp_2", "completed", "About 2 million.")),
+        );
+    let client = common::transport_client(&transport);
+
+    let mut chain = client.responses().chain(template());
+    chain.send("Capital of France?").await.unwrap();
+    chain.send("Population?").await.unwrap();
+    assert_eq!(chain.previous_response_id(), Some("resp_2"));
+
+    let retry = transport.requests()[2].json().unwrap();
+    assert!(retry.get("previous_response_id").is_none());
+    let input = retry["input"].as_array().unwrap();
+    assert_eq!(input.len(), 3);
+    assert_eq!(input[0]["content"], "Capital of France?");
+    assert_eq!(input[1]["role"], "assistant");
+    assert_eq!(input[1]["content"][0]["text"], "Paris.");
+    assert_eq!(input[2]["content"], "Population?");
+}
+
+#[tokio::test]
+async fn chain_forked_from_unknown_response_returns_expired_error() {
+    let transport = MockTransport::new().on("/responses", previous_response_not_found());
+    let client = common::transport_client(&transport);
+
+    let chain = client.responses().chain(template());
+    let mut fork = chain.fork("resp_1");
+    let error = fork.send("Population?").await.unwrap_err();
+
+    let OpenAIError::ApiError(error) = error else {
+        panic!("expected an API error, got {error:?}");
+    };
+    assert_eq!(error.code.as_deref(), Some("previous_response_not_found"));
+    assert_eq!(transport.requests().len(), 1);
+    assert!(fork.turns().is_empty());
+}
@@ -0,0 +1,211 @@
+//! Hosted tools of the Responses API, and the output items of responses.
+use async_openai::types::responses::{
+    CodeInterpreterTool, CreateResponseArgs, FileSearchTool, OutputItem, Response, ToolChoiceParam,
+    ToolChoiceTypes, WebSearchApproximateLocationArgs, WebSearchToolArgs, WebSearchToolCallAction,
+    WebSearchToolFilters, WebSearchToolSearchContextSize,
+};
+use serde_json::json;
+
+#[test]
+fn hosted_tools_in_request() {
+    let web_search = WebSearchToolArgs::default()
+        .filters(WebSearchToolFilters::allowed_domains([
+            "pubmed.ncbi.nlm.nih.gov",
+        ]))
+        .user_location(
+            WebSearchApproximateLocationArgs::default()
+                .country("GB")
+                .city("London")
+                .build()
+                .unwrap(),
+        )
+        .search_context_size(WebSearchToolSearchContextSize::High)
+        .build()
+        .unwrap();
+
+    let request = CreateResponseArgs::default()
+        .model("gpt-4.1")
+        .input("Latest research on sleep?")
+        .tool(web_search)
+        .tool(FileSearchTool::new(["vs_1", "vs_2"]))
+        .tool(CodeInterpreterTool::with_files(["file-1"]))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&request).unwrap()["tools"],
+        json!([
+            {
+                "type": "web_search",
+                "filters": { "allowed_domains": ["pubmed.ncbi.nlm.nih.gov"] },
+                "user_location": { "type": "approximate", "city": "London", "country": "GB" },
+                "search_context_size": "high"
+            },
+            { "type": "file_search", "vector_store_ids": ["vs_1", "vs_2"] },
+            {
+                "type": "code_interpreter",
+                "container": { "type": "auto", "file_ids": ["file-1"] }
+            }
+        ])
+    );
+}
+
+#[test]
+fn web_search_and_file_search_results_deserialize() {
+    let response: Response = serde_json::from_value(json!({
+        "id": "resp_1",
+        "object": "response",
+        "created_at": 1741476777,
+        "status": "completed",
+        "model": "gpt-4.1-2025-04-14",
+        "output": [
+            {
+                "type": "web_search_call",
+                "id": "ws_1",
+                "status": "completed",
+                "action": {
+                    "type": "search",
+                    "query": "positive news today",
+                    "sources": [{ "type": "url", "url": "https://example.com/news" }]
+                }
+            },
+            {
+                "type": "file_search_call",
+                "id": "fs_1",
+                "status": "completed",
+                "queries": ["sleep"],
+                "results": [{
+                    "attributes": {},
+                    "file_id": "file-1",
+                    "filename": "sleep.pdf",
+                    "score": 0.92,
+                    "text": "Adults need 7 to 9 hours."
+                }]
+            },
+            {
+                "type": "message",
+                "id": "msg_1",
+                "status": "completed",
+                "role": "assistant",
+                "content": [{
+                    "type": "output_text",
+                    "text": "A new park opened today.",
+                    "annotations": [
+                        {
+                            "type": "url_citation",
+                            "start_index": 0,
+                            "end_index": 24,
+                            "url": "https://example.com/news",
+                            "title": "Good news"
+                        },
+                        {
+                            "type": "file_citation",
+                            "file_id": "file-1",
+                            "filename": "sleep.pdf",
+                            "index": 24
+                        }
+                    ]
+                }]
+            }
+        ]
+    }))
+    .unwrap();
+
+    let OutputItem::WebSearchCall(call) = &response.output[0] else {
+        panic!("expected a web search call");
+    };
+    let WebSearchToolCallAction::Search(search) = &call.action else {
+        panic!("expected a search action");
+    };
+    assert_eq!(search.query, "positive news today");
+    assert_eq!(
+        search.sources.as_ref().unwrap()[0].url,
+        "https://example.com/news"
+    );
+
+    let OutputItem::FileSearchCall(call) = &response.output[1] else {
+        panic!("expected a file search call");
+    };
+    assert_eq!(call.results.as_ref().unwrap()[0].filename, "sleep.pdf");
+
+    let citations = response.url_citations();
+    assert_eq!(citations.len(), 1);
+    assert_eq!(citations[0].title, "Good news");
+    assert_eq!((citations[0].start_index, citations[0].end_index), (0, 24));
+}
+
+#[test]
+fn output_text_concatenates_message_text_only() {
+    let response: Response = serde_json::from_value(json!({
+        "id": "resp_1",
+        "object": "response",
+        "created_at": 1741476777,
+        "status": "completed",
+        "model": "o4-mini",
+        "output": [
+            { "type": "reasoning", "id": "rs_1", "summary": [] },
+            {
+                "type": "function_call",
+                "id": "fc_1",
+                "call_id": "call_1",
+                "name": "get_weather",
+                "arguments": "{}",
+                "status": "completed"
+            },
+            {
+                "type": "message",
+                "id": "msg_1",
+                "status": "completed",
+                "role": "assistant",
+                "content": [
+                    { "type": "output_text", "text": "It is ", "annotations": [] },
+                    { "type": "refusal", "refusal": "ignored" },
+                    { "type": "output_text", "text": "sunny", "annotations": [] }
+                ]
+            },
+            {
+                "type": "message",
+                "id": "msg_2",
+                "status": "completed",
+                "role": "assistant",
+                "content": [{ "type": "output_text", "text": ".", "annotations": [] }]
+            }
+        ]
+    }))
+    .unwrap();
+    assert_eq!(response.output_text(), "It is sunny.");
+
+    let mut without_text = response.clone();
+    without_text.output.truncate(2);
+    assert_eq!(without_text.output_text(), "");
+}
+
+#[test]
+fn hosted_tool_choice_serde() {
+    let choices = [
+        (
+            ToolChoiceParam::from(ToolChoiceTypes::FileSearch),
+            json!({"type": "file_search"}),
+        ),
+        (
+            ToolChoiceTypes::WebSearchPreview.into(),
+            json!({"type": "web_search_preview"}),
+        ),
+        (
+            ToolChoiceTypes::CodeInterpreter.into(),
+            json!({"type": "code_interpreter"}),
+        ),
+        (
+            "get_weather".into(),
+            json!({"type": "function", "name": "get_weather"}),
+        ),
+    ];
+
+    for (choice, expected) in choices {
+        assert_eq!(serde_json::to_value(&choice).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<ToolChoiceParam>(expected).unwrap(),
+            choice
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Retry behavior of the client against a local mock server.
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use async_openai::{config::OpenAIConfig, error::OpenAIError, Client};
+
+mod common;
+use common::{mock_server, MockResponse};
+
+const MODELS: &str = r#"{"object":"list","data":[]}"#;
+const RATE_LIMIT: &str = r#"{"error":{"message":"Rate limit reached","type":"requests","param":null,"code":"rate_limit_exceeded"}}"#;
+
+fn client_with_backoff(api_base: String) -> Client<OpenAIConfig> {
+    let backoff = backoff::ExponentialBackoffBuilder::new()
+        .with_max_elapsed_time(Some(Duration::from_secs(5)))
+        .build();
+
+    common::client(api_base).with_backoff(backoff)
+}
+
+#[tokio::test]
+async fn retries_rate_limited_requests_until_success() {
+    let (api_base, requests) = mock_server(vec![
+        MockResponse::new(429, RATE_LIMIT).header("retry-after-ms", "10"),
+        MockResponse::new(429, RATE_LIMIT).header("retry-after", "0.01"),
+        MockResponse::new(200, MODELS),
+    ])
+    .await;
+
+    let response = client_with_backoff(api_base).models().list().await;
+
+    assert!(response.is_ok(), "{response:?}");
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn retries_rate_limited_requests_with_a_text_body() {
+    // as returned by proxies and gateways in front of the API
+    let (api_base, requests) = mock_server(vec![
+        MockResponse::new(429, "Too Many Requests")
+            .header("content-type", "text/plain")
+            .header("retry-after-ms", "10"),
+        MockResponse::new(200, MODELS),
+    ])
+    .await;
+
+    let response = client_with_backoff(api_base).models().list().await;
+
+    assert!(response.is_ok(), "{response:?}");
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn stops_after_max_retries() {
+    let (api_base, requests) = mock_server(vec![MockResponse::new(503, "unavailable")]).await;
+
+    let response = client_with_backoff(api_base)
+        .with_max_retries(2)
+        .models()
+        .list()
+        .await;
+
+    assert!(matches!(response, Err(OpenAIError::ApiError(_))));
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn does_not_retry_client_errors() {
+    let (api_base, requests) = mock_server(vec![MockResponse::new(
+        400,
+        r#"{"error":{"message":"bad","type":"invalid_request_error","param":null,"code":null}}"#,
+    )])
+    .await;
+
+    let response = client_with_backoff(api_base).models().list().await;
+
+    assert!(matches!(response, Err(OpenAIError::ApiError(_))));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,153 @@
+//! Polling Assistants runs until they finish or require action.
+#![allow(deprecated)]
+use std::time::Duration;
+
+use async_openai::{
+    transport::{MockResponse, MockTransport},
+    types::assistants::{
+        CreateRunRequestArgs, RunStatus, SubmitToolOutputsRunRequest, ToolsOutputs,
+    },
+    Client,
+};
+use serde_json::json;
+
+fn run(status: &str, required_action: serde_json::Value) -> MockResponse {
+    MockResponse::json(
+        200,
+        json!({
+            "id": "run_1",
+            "object": "thread.run",
+            "created_at": 1699063290,
+            "thread_id": "thread_1",
+            "assistant_id": "asst_1",
+            "status": status,
+            "required_action": required_action,
+            "last_error": null,
+            "expires_at": null,
+            "started_at": 1699063290,
+            "cancelled_at": null,
+            "failed_at": null,
+            "completed_at": null,
+            "incomplete_details": null,
+            "model": "gpt-4o",
+            "instructions": "",
+            "tools": [],
+            "metadata": {},
+            "usage": null,
+            "truncation_strategy": null,
+            "tool_choice": null,
+            "parallel_tool_calls": true,
+            "response_format": null,
+        }),
+    )
+}
+
+fn tool_call_action() -> serde_json::Value {
+    json!({
+        "type": "submit_tool_outputs",
+        "submit_tool_outputs": {
+            "tool_calls": [{
+                "id": "call_1",
+                "type": "function",
+                "function": { "name": "get_weather", "arguments": "{\"location\":\"Paris\"}" }
+            }]
+        }
+    })
+}
+
+#[tokio::test]
+async fn create_and_poll_stops_at_requires_action_then_completes() {
+    let transport = MockTransport::new()
+        .on("/threads/thread_1/runs", run("queued", json!(null)))
+        .on("/threads/thread_1/runs/run_1", run("queued", json!(null)))
+        .on(
+            "/threads/thread_1/runs/run_1",
+            run("in_progress", json!(null)),
+        )
+        .on(
+            "/threads/thread_1/runs/run_1",
+            run("requires_action", tool_call_action()),
+        )
+        .on(
+            "/threads/thread_1/runs/run_1",
+            run("in_progress", json!(null)),
+        )
+        .on(
+            "/threads/thread_1/runs/run_1",
+            run("completed", json!(null)),
+        )
+        .on(
+            "/threads/thread_1/runs/run_1/submit_tool_outputs",
+            run("queued", json!(null)),
+        );
+    let client = Client::new().with_transport(transport.clone());
+    let threads = client.threads();
+    let runs = threads.runs("thread_1");
+
+    let request = CreateRunRequestArgs::default()
+        .assistant_id("asst_1")
+        .build()
+        .unwrap();
+    let run = runs
+        .create_and_poll(request, Duration::from_millis(1))
+        .await
+        .unwrap();
+
+    assert_eq!(run.status, RunStatus::RequiresAction);
+    let tool_calls = run.required_tool_calls();
+    assert_eq!(tool_calls.len(), 1);
+    assert_eq!(tool_calls[0].function.name, "get_weather");
+
+    let outputs = SubmitToolOutputsRunRequest {
+        tool_outputs: vec![ToolsOutputs {
+            tool_call_id: Some(tool_calls[0].id.clone()),
+            output: Some("21C".into()),
+        }],
+        stream: None,
+    };
+    let run = runs
+        .submit_tool_outputs_and_poll(&run.id, outputs, Duration::from_millis(1))
+        .await
+        .unwrap();
+
+    assert_eq!(run.status, RunStatus::Completed);
+    assert!(run.required_tool_calls().is_empty());
+
+    let requests = transport.requests();
+    // create, 3 polls, submit, 2 polls
+    assert_eq!(requests.len(), 7);
+    assert_eq!(
+        requests[4].json().unwrap()["tool_outputs"][0]["tool_call_id"],
+        "call_1"
+    );
+}
+
+#[tokio::test]
+async fn poll_returns_terminal_runs() {
+    let transport =
+        MockTransport::new().on("/threads/thread_1/runs/run_1", run("failed", json!(null)));
+    let client = Client::new().with_transport(transport.clone());
+
+    let run = client
+        .threads()
+        .runs("thread_1")
+        .poll("run_1", Duration::from_millis(1))
+        .await
+        .unwrap();
+
+    assert_eq!(run.status, RunStatus::Failed);
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[test]
+fn terminal_run_statuses() {
+    assert!(RunStatus::Completed.is_terminal());
+    assert!(RunStatus::Failed.is_terminal());
+    assert!(RunStatus::Cancelled.is_terminal());
+    assert!(RunStatus::Incomplete.is_terminal());
+    assert!(RunStatus::Expired.is_terminal());
+    assert!(!RunStatus::Queued.is_terminal());
+    assert!(!RunStatus::InProgress.is_terminal());
+    assert!(!RunStatus::Cancelling.is_terminal());
+    assert!(!RunStatus::RequiresAction.is_terminal());
+}
@@ -0,0 +1,27 @@
+use async_openai::types::chat::{ChatCompletionRequestMessage, CreateChatCompletionRequest};
+
+#[test]
+fn request_json_schema() {
+    let schema = schemars::schema_for!(CreateChatCompletionRequest).to_value();
+
+    let properties = &schema["properties"];
+    assert!(properties["messages"].is_object());
+    assert!(properties["model"].is_object());
+    let required = schema["required"].as_array().unwrap();
+    assert!(required.contains(&"messages".into()));
+}
+
+#[test]
+fn tagged_enum_json_schema() {
+    let schema = schemars::schema_for!(ChatCompletionRequestMessage).to_value();
+
+    let roles: Vec<_> = schema["oneOf"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|variant| variant["properties"]["role"]["const"].clone())
+        .collect();
+    assert!(roles.contains(&"system".into()));
+    assert!(roles.contains(&"user".into()));
+    assert!(roles.contains(&"tool".into()));
+}
@@ -1,7 +1,15 @@
 use async_openai::types::chat::{
-    ChatCompletionRequestSystemMessageArgs, ChatCompletionRequestUserMessageArgs,
-    ChatCompletionStreamOptions, CreateChatCompletionRequest, CreateChatCompletionRequestArgs,
+    ChatChoiceLogprobs, ChatCompletionAudio, ChatCompletionAudioFormat, ChatCompletionAudioVoice,
+    ChatCompletionRequestAssistantMessage, ChatCompletionRequestMessage,
+    ChatCompletionRequestSystemMessageArgs, ChatCompletionRequestUserMessage,
+    ChatCompletionRequestUserMessageArgs, ChatCompletionResponseMessage,
+    ChatCompletionStreamOptions, ContentFilterSeverity, CreateChatCompletionRequest,
+    CreateChatCompletionRequestArgs, CreateChatCompletionResponse,
+    CreateChatCompletionStreamResponse, FinishReason, InputAudioFormat, LogitBias,
+    PredictionContent, PredictionContentContent, ResponseModalities, ServiceTier, ToolChoice,
+    ToolChoiceAllowedMode, UserContent,
 };
+use async_openai::types::ModelId;
 
 #[test]
 fn chat_types_serde() {
@@ -60,3 +68,657 @@ fn stream_options_none_fields_not_serialized() {
     let deserialized: ChatCompletionStreamOptions = serde_json::from_str(&serialized).unwrap();
     assert_eq!(stream_options, deserialized);
 }
+
+#[test]
+fn stream_options_in_request() {
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello").into()])
+        .stream(true)
+        .stream_options(
+            ChatCompletionStreamOptions::default()
+                .with_include_usage(true)
+                .with_include_obfuscation(false),
+        )
+        .build()
+        .unwrap();
+
+    let serialized = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        serialized["stream_options"],
+        serde_json::json!({ "include_usage": true, "include_obfuscation": false })
+    );
+
+    let deserialized: CreateChatCompletionRequest = serde_json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, request);
+}
+
+#[test]
+#[allow(deprecated)]
+fn seed_and_system_fingerprint() {
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello").into()])
+        .seed(42)
+        .build()
+        .unwrap();
+    assert_eq!(serde_json::to_value(&request).unwrap()["seed"], 42);
+
+    let chunk: CreateChatCompletionStreamResponse = serde_json::from_str(
+        r#"{
+            "id": "chatcmpl-123",
+            "object": "chat.completion.chunk",
+            "created": 1694268190,
+            "model": "gpt-4o-mini",
+            "system_fingerprint": "fp_44709d6fcb",
+            "choices": [{ "index": 0, "delta": { "content": "Hi" }, "finish_reason": null }]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(chunk.system_fingerprint.as_deref(), Some("fp_44709d6fcb"));
+}
+
+#[test]
+fn refusal_deserialized() {
+    let response: CreateChatCompletionResponse = serde_json::from_str(
+        r#"{
+            "id": "chatcmpl-123",
+            "object": "chat.completion",
+            "created": 1677652288,
+            "model": "gpt-4o-mini",
+            "choices": [{
+                "index": 0,
+                "message": {"role": "assistant", "content": null, "refusal": "I can't help with that."},
+                "finish_reason": "stop"
+            }]
+        }"#,
+    )
+    .unwrap();
+    let message = &response.choices[0].message;
+    assert!(message.is_refusal());
+    assert_eq!(message.refusal(), Some("I can't help with that."));
+    assert_eq!(message.content, None);
+
+    let chunk: CreateChatCompletionStreamResponse = serde_json::from_str(
+        r#"{
+            "id": "chatcmpl-123",
+            "object": "chat.completion.chunk",
+            "created": 1677652288,
+            "model": "gpt-4o-mini",
+            "choices": [{"index": 0, "delta": {"refusal": "I can't"}, "finish_reason": null}]
+        }"#,
+    )
+    .unwrap();
+    let delta = &chunk.choices[0].delta;
+    assert!(delta.is_refusal());
+    assert_eq!(delta.refusal(), Some("I can't"));
+    assert_eq!(delta.content, None);
+}
+
+#[test]
+fn logit_bias_serde() {
+    let logit_bias: LogitBias = [(50256, -100)].into_iter().collect();
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .logit_bias(logit_bias.clone())
+        .build()
+        .unwrap();
+
+    let serialized = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        serialized["logit_bias"],
+        serde_json::json!({ "50256": -100 })
+    );
+
+    let deserialized: CreateChatCompletionRequest = serde_json::from_value(serialized).unwrap();
+    assert_eq!(deserialized.logit_bias, Some(logit_bias));
+}
+
+#[test]
+fn response_message_into_request_message() {
+    let response: CreateChatCompletionResponse = serde_json::from_str(
+        r#"{
+            "id": "chatcmpl-123",
+            "object": "chat.completion",
+            "created": 1677652288,
+            "model": "gpt-4o-mini",
+            "choices": [{
+                "index": 0,
+                "message": {
+                    "role": "assistant",
+                    "content": null,
+                    "refusal": null,
+                    "tool_calls": [{
+                        "id": "call_abc",
+                        "type": "function",
+                        "function": { "name": "get_weather", "arguments": "{\"location\":\"Paris\"}" }
+                    }]
+                },
+                "finish_reason": "tool_calls"
+            }]
+        }"#,
+    )
+    .unwrap();
+    let message = response.choices[0].message.clone();
+
+    let assistant = ChatCompletionRequestAssistantMessage::from(message.clone());
+    assert_eq!(assistant.content, None);
+    assert_eq!(assistant.tool_calls, message.tool_calls);
+
+    let request_message: ChatCompletionRequestMessage = message.into();
+    assert_eq!(
+        serde_json::to_value(&request_message).unwrap(),
+        serde_json::json!({
+            "role": "assistant",
+            "tool_calls": [{
+                "id": "call_abc",
+                "type": "function",
+                "function": { "name": "get_weather", "arguments": "{\"location\":\"Paris\"}" }
+            }]
+        })
+    );
+
+    let refusal: ChatCompletionResponseMessage = serde_json::from_value(serde_json::json!({
+        "role": "assistant",
+        "content": "Sure",
+        "refusal": "I can't help with that."
+    }))
+    .unwrap();
+    let refusal = ChatCompletionRequestAssistantMessage::from(refusal);
+    assert_eq!(refusal.refusal.as_deref(), Some("I can't help with that."));
+    assert_eq!(
+        serde_json::to_value(&refusal.content).unwrap(),
+        serde_json::json!("Sure")
+    );
+}
+
+#[test]
+fn model_id_in_request_builders() {
+    let request = CreateChatCompletionRequestArgs::default()
+        .model(ModelId::GPT_4O_MINI)
+        .messages([ChatCompletionRequestUserMessageArgs::default()
+            .content("Hello")
+            .build()
+            .unwrap()
+            .into()])
+        .build()
+        .unwrap();
+    assert_eq!(request.model, ModelId::GPT_4O_MINI);
+
+    let serialized = serde_json::to_value(&request).unwrap();
+    assert_eq!(serialized["model"], "gpt-4o-mini");
+
+    let model: ModelId = serde_json::from_value(serialized["model"].clone()).unwrap();
+    assert_eq!(model, "gpt-4o-mini");
+    assert_eq!(ModelId::from("gpt-new-model").to_string(), "gpt-new-model");
+}
+
+#[test]
+fn prompt_caching_serde() {
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .prompt_cache_key("tenant-42")
+        .messages([ChatCompletionRequestUserMessageArgs::default()
+            .content("Hello")
+            .build()
+            .unwrap()
+            .into()])
+        .build()
+        .unwrap();
+    let serialized = serde_json::to_value(&request).unwrap();
+    assert_eq!(serialized["prompt_cache_key"], "tenant-42");
+    assert_eq!(
+        serde_json::from_value::<CreateChatCompletionRequest>(serialized).unwrap(),
+        request
+    );
+
+    // recorded response of a request hitting the prompt cache
+    let json = r#"{
+        "id": "chatcmpl-BpXxXcMiTzXl4Q5rnrAvhEI5Ccfx4",
+        "object": "chat.completion",
+        "created": 1751567438,
+        "model": "gpt-4o-mini-2024-07-18",
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": "Hello! How can I help?", "refusal": null, "annotations": [] },
+            "logprobs": null,
+            "finish_reason": "stop"
+        }],
+        "usage": {
+            "prompt_tokens": 2006,
+            "completion_tokens": 300,
+            "total_tokens": 2306,
+            "prompt_tokens_details": { "cached_tokens": 1920, "audio_tokens": 0 },
+            "completion_tokens_details": {
+                "reasoning_tokens": 0,
+                "audio_tokens": 0,
+                "accepted_prediction_tokens": 0,
+                "rejected_prediction_tokens": 0
+            }
+        },
+        "service_tier": "default",
+        "system_fingerprint": "fp_34a54ae93c"
+    }"#;
+    let response: CreateChatCompletionResponse = serde_json::from_str(json).unwrap();
+    let usage = response.usage.as_ref().unwrap();
+    let details = usage.prompt_tokens_details.as_ref().unwrap();
+    assert_eq!(details.cached_tokens, Some(1920));
+    assert_eq!(details.audio_tokens, Some(0));
+
+    let reserialized = serde_json::to_string(&response).unwrap();
+    assert_eq!(
+        serde_json::from_str::<CreateChatCompletionResponse>(&reserialized).unwrap(),
+        response
+    );
+}
+
+#[test]
+fn user_content_parts_serde() {
+    let message: ChatCompletionRequestMessage = UserContent::new()
+        .text("Describe these")
+        .image_url("https://example.com/cat.png")
+        .audio(b"hello", InputAudioFormat::Wav)
+        .file("file-abc123")
+        .file_data("notes.txt", b"hello", "text/plain")
+        .into();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        serde_json::json!({
+            "role": "user",
+            "content": [
+                { "type": "text", "text": "Describe these" },
+                { "type": "image_url", "image_url": { "url": "https://example.com/cat.png", "detail": null } },
+                { "type": "input_audio", "input_audio": { "data": "aGVsbG8=", "format": "wav" } },
+                { "type": "file", "file": { "file_id": "file-abc123" } },
+                {
+                    "type": "file",
+                    "file": { "file_data": "data:text/plain;base64,aGVsbG8=", "filename": "notes.txt" }
+                },
+            ]
+        })
+    );
+}
+
+#[test]
+fn audio_output_serde() {
+    let request = CreateChatCompletionRequestArgs::default()
+        .model(ModelId::GPT_4O_AUDIO_PREVIEW)
+        .modalities([ResponseModalities::Text, ResponseModalities::Audio])
+        .audio(ChatCompletionAudio::new(
+            ChatCompletionAudioVoice::Alloy,
+            ChatCompletionAudioFormat::Wav,
+        ))
+        .messages([ChatCompletionRequestUserMessage::from("Say hello").into()])
+        .build()
+        .unwrap();
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(json["modalities"], serde_json::json!(["text", "audio"]));
+    assert_eq!(
+        json["audio"],
+        serde_json::json!({ "voice": "alloy", "format": "wav" })
+    );
+
+    let json = r#"{
+        "id": "chatcmpl-AXQ4zTuAcvjyNerpawxPfALMpuZ5F",
+        "object": "chat.completion",
+        "created": 1732560427,
+        "model": "gpt-4o-audio-preview-2024-10-01",
+        "choices": [{
+            "index": 0,
+            "message": {
+                "role": "assistant",
+                "content": null,
+                "refusal": null,
+                "audio": {
+                    "id": "audio_6744555cc6d48190b67e70798ab606c3",
+                    "data": "UklGRiQAAABXQVZF",
+                    "expires_at": 1732564044,
+                    "transcript": "Hello! How can I assist you today?"
+                }
+            },
+            "finish_reason": "stop"
+        }],
+        "usage": {
+            "prompt_tokens": 17,
+            "completion_tokens": 263,
+            "total_tokens": 280,
+            "prompt_tokens_details": { "cached_tokens": 0, "audio_tokens": 0 },
+            "completion_tokens_details": { "reasoning_tokens": 0, "audio_tokens": 250, "text_tokens": 13 }
+        }
+    }"#;
+    let response: CreateChatCompletionResponse = serde_json::from_str(json).unwrap();
+    let message = &response.choices[0].message;
+    let audio = message.audio.as_ref().unwrap();
+    assert_eq!(audio.transcript, "Hello! How can I assist you today?");
+    assert_eq!(audio.expires_at, 1732564044);
+    assert_eq!(audio.decode().unwrap(), b"RIFF$\0\0\0WAVE");
+
+    // the next turn refers to the audio by id
+    let next: ChatCompletionRequestMessage = message.clone().into();
+    assert_eq!(
+        serde_json::to_value(&next).unwrap()["audio"],
+        serde_json::json!({ "id": "audio_6744555cc6d48190b67e70798ab606c3" })
+    );
+}
+
+#[test]
+fn prediction_serde() {
+    let code = "fn main() {\n    println!(\"Hello\");\n}\n";
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o")
+        .messages([ChatCompletionRequestUserMessage::from("Rename main to run").into()])
+        .prediction(code)
+        .build()
+        .unwrap();
+
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        value["prediction"],
+        serde_json::json!({ "type": "content", "content": code })
+    );
+    let deserialized: CreateChatCompletionRequest = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        deserialized.prediction,
+        Some(PredictionContent::Content(PredictionContentContent::Text(
+            code.into()
+        )))
+    );
+
+    let json = r#"{
+        "id": "chatcmpl-AAA",
+        "object": "chat.completion",
+        "created": 1730918900,
+        "model": "gpt-4o-2024-08-06",
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": "fn run() {}" },
+            "finish_reason": "stop"
+        }],
+        "usage": {
+            "prompt_tokens": 43,
+            "completion_tokens": 50,
+            "total_tokens": 93,
+            "completion_tokens_details": {
+                "reasoning_tokens": 0,
+                "accepted_prediction_tokens": 18,
+                "rejected_prediction_tokens": 10
+            }
+        }
+    }"#;
+    let response: CreateChatCompletionResponse = serde_json::from_str(json).unwrap();
+    let details = response
+        .usage
+        .as_ref()
+        .unwrap()
+        .completion_tokens_details
+        .as_ref()
+        .unwrap();
+    assert_eq!(details.accepted_prediction_tokens, Some(18));
+    assert_eq!(details.rejected_prediction_tokens, Some(10));
+
+    let round_trip: CreateChatCompletionResponse =
+        serde_json::from_value(serde_json::to_value(&response).unwrap()).unwrap();
+    assert_eq!(round_trip, response);
+}
+
+#[test]
+fn finish_reason_serde() {
+    for (json, reason) in [
+        ("\"stop\"", FinishReason::Stop),
+        ("\"length\"", FinishReason::Length),
+        ("\"tool_calls\"", FinishReason::ToolCalls),
+        ("\"content_filter\"", FinishReason::ContentFilter),
+        ("\"function_call\"", FinishReason::FunctionCall),
+        ("\"eos\"", FinishReason::Other("eos".into())),
+    ] {
+        assert_eq!(serde_json::from_str::<FinishReason>(json).unwrap(), reason);
+        assert_eq!(serde_json::to_string(&reason).unwrap(), json);
+    }
+
+    let chunk: CreateChatCompletionStreamResponse = serde_json::from_str(
+        r#"{
+            "id": "chatcmpl-123",
+            "object": "chat.completion.chunk",
+            "created": 1694268190,
+            "model": "llama-3",
+            "choices": [{"index": 0, "delta": {}, "finish_reason": "eos"}]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        chunk.choices[0].finish_reason,
+        Some(FinishReason::Other("eos".into()))
+    );
+}
+
+#[test]
+fn azure_content_filter_results_serde() {
+    let json = r#"{
+        "id": "chatcmpl-7R1nGnsXO8n4oi9UPz2f3UHdgAYMn",
+        "object": "chat.completion",
+        "created": 1686676106,
+        "model": "gpt-4o-2024-08-06",
+        "prompt_filter_results": [{
+            "prompt_index": 0,
+            "content_filter_results": {
+                "hate": {"filtered": false, "severity": "safe"},
+                "self_harm": {"filtered": false, "severity": "safe"},
+                "sexual": {"filtered": false, "severity": "safe"},
+                "violence": {"filtered": false, "severity": "low"},
+                "jailbreak": {"filtered": false, "detected": false}
+            }
+        }],
+        "choices": [{
+            "index": 0,
+            "finish_reason": "content_filter",
+            "message": {"role": "assistant", "content": null},
+            "content_filter_results": {
+                "hate": {"filtered": false, "severity": "safe"},
+                "violence": {"filtered": true, "severity": "high"},
+                "protected_material_code": {
+                    "filtered": false,
+                    "detected": true,
+                    "citation": {"URL": "https://github.com/octocat/hello", "license": "MIT"}
+                }
+            }
+        }]
+    }"#;
+    let response: CreateChatCompletionResponse = serde_json::from_str(json).unwrap();
+
+    let prompt = &response.prompt_filter_results.as_ref().unwrap()[0];
+    assert_eq!(prompt.prompt_index, 0);
+    assert!(!prompt.content_filter_results.is_filtered());
+    assert_eq!(
+        prompt
+            .content_filter_results
+            .violence
+            .as_ref()
+            .unwrap()
+            .severity,
+        ContentFilterSeverity::Low
+    );
+
+    let results = response.choices[0].content_filter_results.as_ref().unwrap();
+    assert!(results.is_filtered());
+    let code = results.protected_material_code.as_ref().unwrap();
+    assert_eq!(
+        code.citation.as_ref().unwrap().url.as_deref(),
+        Some("https://github.com/octocat/hello")
+    );
+
+    let round_trip: CreateChatCompletionResponse =
+        serde_json::from_value(serde_json::to_value(&response).unwrap()).unwrap();
+    assert_eq!(round_trip, response);
+
+    // responses without them, like those of OpenAI, serialize without them
+    let mut response = response;
+    response.prompt_filter_results = None;
+    response.choices[0].content_filter_results = None;
+    let value = serde_json::to_value(&response).unwrap();
+    assert!(value.get("prompt_filter_results").is_none());
+    assert!(value["choices"][0].get("content_filter_results").is_none());
+}
+
+#[test]
+fn logprobs_serde_and_perplexity() {
+    let response: CreateChatCompletionResponse = serde_json::from_value(serde_json::json!({
+        "id": "chatcmpl-123",
+        "object": "chat.completion",
+        "created": 1677652288,
+        "model": "gpt-4o-mini",
+        "choices": [{
+            "index": 0,
+            "message": {"role": "assistant", "content": "Hi!"},
+            "finish_reason": "stop",
+            "logprobs": {
+                "content": [
+                    {
+                        "token": "Hi",
+                        "logprob": -0.5,
+                        "bytes": [72, 105],
+                        "top_logprobs": [
+                            {"token": "Hi", "logprob": -0.5, "bytes": [72, 105]},
+                            {"token": "Hello", "logprob": -1.2, "bytes": null}
+                        ]
+                    },
+                    {"token": "!", "logprob": -1.5, "bytes": [33]}
+                ],
+                "refusal": null
+            }
+        }]
+    }))
+    .unwrap();
+
+    let logprobs = response.choices[0].logprobs.as_ref().unwrap();
+    let content = logprobs.content.as_ref().unwrap();
+    assert_eq!(content[0].bytes.as_deref(), Some("Hi".as_bytes()));
+    assert_eq!(content[0].top_logprobs[1].token, "Hello");
+    assert_eq!(content[0].top_logprobs[1].bytes, None);
+    assert!(content[1].top_logprobs.is_empty());
+    assert!((content[0].probability() - (-0.5f32).exp()).abs() < 1e-6);
+
+    // exp(-(-0.5 + -1.5) / 2) = e
+    let perplexity = logprobs.perplexity().unwrap();
+    assert!((perplexity - std::f32::consts::E).abs() < 1e-5);
+
+    let empty = ChatChoiceLogprobs {
+        content: Some(vec![]),
+        refusal: None,
+    };
+    assert_eq!(empty.perplexity(), None);
+}
+
+#[test]
+fn tool_choice_serde() {
+    let choices = [
+        (ToolChoice::none(), serde_json::json!("none")),
+        (ToolChoice::auto(), serde_json::json!("auto")),
+        (ToolChoice::required(), serde_json::json!("required")),
+        (
+            ToolChoice::function("get_weather"),
+            serde_json::json!({"type": "function", "function": {"name": "get_weather"}}),
+        ),
+        (
+            ToolChoice::custom("grammar"),
+            serde_json::json!({"type": "custom", "custom": {"name": "grammar"}}),
+        ),
+        (
+            ToolChoice::allowed_functions(
+                ToolChoiceAllowedMode::Required,
+                ["get_weather", "get_time"],
+            ),
+            serde_json::json!({
+                "type": "allowed_tools",
+                "allowed_tools": {
+                    "mode": "required",
+                    "tools": [
+                        {"type": "function", "function": {"name": "get_weather"}},
+                        {"type": "function", "function": {"name": "get_time"}}
+                    ]
+                }
+            }),
+        ),
+    ];
+
+    for (choice, expected) in choices {
+        assert_eq!(serde_json::to_value(&choice).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<ToolChoice>(expected).unwrap(),
+            choice
+        );
+    }
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .tool_choice(ToolChoice::function("get_weather"))
+        .build()
+        .unwrap();
+    assert_eq!(
+        request.tool_choice,
+        Some(ToolChoice::function("get_weather"))
+    );
+}
+
+#[test]
+fn tool_choice_from_str_serde() {
+    for (choice, expected) in [
+        ("none", serde_json::json!("none")),
+        ("auto", serde_json::json!("auto")),
+        ("required", serde_json::json!("required")),
+        (
+            "get_weather",
+            serde_json::json!({"type": "function", "function": {"name": "get_weather"}}),
+        ),
+    ] {
+        let request = CreateChatCompletionRequestArgs::default()
+            .model("gpt-4o-mini")
+            .tool_choice(choice)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["tool_choice"],
+            expected
+        );
+    }
+}
+
+#[test]
+fn service_tier_serde() {
+    for (tier, json) in [
+        (ServiceTier::Auto, "auto"),
+        (ServiceTier::Default, "default"),
+        (ServiceTier::Flex, "flex"),
+        (ServiceTier::Scale, "scale"),
+        (ServiceTier::Priority, "priority"),
+        (ServiceTier::Other("batch".into()), "batch"),
+    ] {
+        assert_eq!(serde_json::to_value(&tier).unwrap(), json);
+        assert_eq!(
+            serde_json::from_value::<ServiceTier>(json.into()).unwrap(),
+            tier
+        );
+    }
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .service_tier(ServiceTier::Priority)
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&request).unwrap()["service_tier"],
+        "priority"
+    );
+
+    // the response reports the tier that served the request
+    let response: CreateChatCompletionResponse = serde_json::from_value(serde_json::json!({
+        "id": "chatcmpl-123",
+        "object": "chat.completion",
+        "created": 1677652288,
+        "model": "gpt-4o-mini",
+        "choices": [],
+        "service_tier": "default"
+    }))
+    .unwrap();
+    assert_eq!(response.service_tier, Some(ServiceTier::Default));
+}
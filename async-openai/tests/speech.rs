@@ -0,0 +1,75 @@
+//! Streaming the audio bytes of text to speech as they are generated.
+use async_openai::{
+    error::OpenAIError,
+    transport::{MockResponse, MockTransport},
+    types::audio::{CreateSpeechRequestArgs, SpeechModel, SpeechResponseFormat, StreamFormat},
+    Client,
+};
+use futures::StreamExt;
+
+#[tokio::test]
+async fn speech_audio_stream() {
+    let audio: Vec<u8> = (0..=255).cycle().take(4096).collect();
+    let transport = MockTransport::new().on("/audio/speech", MockResponse::new(200, audio.clone()));
+    let client = Client::new().with_transport(transport.clone());
+
+    let request = CreateSpeechRequestArgs::default()
+        .input("Hello")
+        .model(SpeechModel::Gpt4oMiniTts)
+        .response_format(SpeechResponseFormat::Pcm)
+        .build()
+        .unwrap();
+    let mut stream = client
+        .audio()
+        .speech()
+        .create_audio_stream(request)
+        .await
+        .unwrap();
+
+    let mut received = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        received.extend_from_slice(&chunk.unwrap());
+    }
+    assert_eq!(received, audio);
+
+    let body = transport.requests()[0].json().unwrap();
+    assert_eq!(body["response_format"], "pcm");
+}
+
+#[tokio::test]
+async fn speech_audio_stream_errors() {
+    let transport = MockTransport::new().on(
+        "/audio/speech",
+        MockResponse::error(400, "invalid_value", "Invalid voice"),
+    );
+    let client = Client::new().with_transport(transport.clone());
+
+    let request = CreateSpeechRequestArgs::default()
+        .input("Hello")
+        .build()
+        .unwrap();
+    let error = client
+        .audio()
+        .speech()
+        .create_audio_stream(request)
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(error, OpenAIError::ApiError(e) if e.message == "Invalid voice"));
+
+    // SSE events are streamed by `create_stream`
+    let request = CreateSpeechRequestArgs::default()
+        .input("Hello")
+        .stream_format(StreamFormat::SSE)
+        .build()
+        .unwrap();
+    let error = client
+        .audio()
+        .speech()
+        .create_audio_stream(request)
+        .await
+        .err()
+        .unwrap();
+    assert!(matches!(error, OpenAIError::InvalidArgument(_)));
+    assert_eq!(transport.requests().len(), 1);
+}
@@ -0,0 +1,87 @@
+//! Cancelling or dropping a streaming response closes the connection.
+use std::time::Duration;
+
+use async_openai::{traits::CancelStream, types::chat::CreateChatCompletionRequest};
+use futures::StreamExt;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    sync::oneshot,
+};
+
+mod common;
+
+const CHUNK: &str = r#"{"id":"chatcmpl-123","object":"chat.completion.chunk","created":1694268190,"model":"gpt-4o-mini","choices":[{"index":0,"delta":{"role":"assistant","content":"Hello"},"finish_reason":null}]}"#;
+
+/// Sends one chunk and then keeps the stream open, reports when the client disconnects.
+async fn stalled_stream_server() -> (String, oneshot::Receiver<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (disconnected_tx, disconnected_rx) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0u8; 64 * 1024];
+        let mut read = 0;
+        while !buf[..read].windows(4).any(|w| w == b"\r\n\r\n") {
+            read += socket.read(&mut buf[read..]).await.unwrap();
+        }
+
+        let event = format!("data: {CHUNK}\n\n");
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\ntransfer-encoding: chunked\r\n\r\n{:x}\r\n{event}\r\n",
+            event.len()
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+
+        // a read of zero bytes (or an error) means the client closed the connection
+        while let Ok(len) = socket.read(&mut buf).await {
+            if len == 0 {
+                break;
+            }
+        }
+        let _ = disconnected_tx.send(());
+    });
+
+    (format!("http://{addr}/v1"), disconnected_rx)
+}
+
+fn request() -> CreateChatCompletionRequest {
+    CreateChatCompletionRequest {
+        stream: Some(true),
+        ..common::chat_request()
+    }
+}
+
+#[tokio::test]
+async fn cancel_closes_connection() {
+    let (api_base, disconnected) = stalled_stream_server().await;
+    let client = common::client(api_base);
+
+    let mut stream = client.chat().create_stream(request()).await.unwrap();
+    let first = stream.next().await.unwrap().unwrap();
+    assert_eq!(first.choices[0].delta.content.as_deref(), Some("Hello"));
+
+    stream.cancel();
+    assert!(stream.next().await.is_none());
+
+    tokio::time::timeout(Duration::from_secs(5), disconnected)
+        .await
+        .expect("server did not see a disconnect")
+        .unwrap();
+}
+
+#[tokio::test]
+async fn drop_closes_connection() {
+    let (api_base, disconnected) = stalled_stream_server().await;
+    let client = common::client(api_base);
+
+    let mut stream = client.chat().create_stream(request()).await.unwrap();
+    stream.next().await.unwrap().unwrap();
+    drop(stream);
+
+    tokio::time::timeout(Duration::from_secs(5), disconnected)
+        .await
+        .expect("server did not see a disconnect")
+        .unwrap();
+}
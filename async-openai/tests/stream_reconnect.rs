@@ -0,0 +1,141 @@
+//! Resuming interrupted streams with `Last-Event-ID`.
+use std::sync::{Arc, Mutex};
+
+use async_openai::{
+    traits::RequestOptionsBuilder,
+    types::chat::{
+        ChatCompletionRequestUserMessage, CreateChatCompletionRequest,
+        CreateChatCompletionRequestArgs,
+    },
+};
+use futures::StreamExt;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+mod common;
+
+fn event(id: Option<u32>, content: &str) -> String {
+    let chunk = format!(
+        r#"{{"id":"chatcmpl-123","object":"chat.completion.chunk","created":1694268190,"model":"gpt-4o-mini","choices":[{{"index":0,"delta":{{"content":"{content}"}},"finish_reason":null}}]}}"#
+    );
+    match id {
+        Some(id) => format!("id: {id}\ndata: {chunk}\n\n"),
+        None => format!("data: {chunk}\n\n"),
+    }
+}
+
+fn chunked(data: &str) -> String {
+    format!("{:x}\r\n{data}\r\n", data.len())
+}
+
+/// The first connection sends two events and drops mid-stream, later connections send the
+/// rest of the stream. Returns the `Last-Event-ID` header of every request.
+async fn flaky_stream_server(with_ids: bool) -> (String, Arc<Mutex<Vec<Option<String>>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let last_event_ids = Arc::new(Mutex::new(Vec::new()));
+    let recorded = last_event_ids.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 64 * 1024];
+            let mut read = 0;
+            while !buf[..read].windows(4).any(|w| w == b"\r\n\r\n") {
+                read += socket.read(&mut buf[read..]).await.unwrap();
+            }
+            let head = String::from_utf8_lossy(&buf[..read]).to_lowercase();
+            let last_event_id = head
+                .lines()
+                .find_map(|line| line.strip_prefix("last-event-id:"))
+                .map(|value| value.trim().to_string());
+            let first = {
+                let mut recorded = recorded.lock().unwrap();
+                recorded.push(last_event_id);
+                recorded.len() == 1
+            };
+
+            let id = |id| with_ids.then_some(id);
+            let mut response = "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\ntransfer-encoding: chunked\r\n\r\n".to_string();
+            if first {
+                response.push_str(&chunked(&event(id(1), "Hello")));
+                response.push_str(&chunked(&event(id(2), " there")));
+                // the connection drops before the end of the chunked body
+                socket.write_all(response.as_bytes()).await.unwrap();
+            } else {
+                response.push_str(&chunked(&event(id(3), "!")));
+                response.push_str(&chunked("data: [DONE]\n\n"));
+                response.push_str("0\r\n\r\n");
+                socket.write_all(response.as_bytes()).await.unwrap();
+                let _ = socket.shutdown().await;
+            }
+        }
+    });
+
+    (format!("http://{addr}/v1"), last_event_ids)
+}
+
+fn request() -> CreateChatCompletionRequest {
+    CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello").into()])
+        .stream(true)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn resumes_stream_with_last_event_id() {
+    let (api_base, last_event_ids) = flaky_stream_server(true).await;
+
+    let stream = common::client(api_base)
+        .chat()
+        .stream_reconnect(2)
+        .create_stream(request())
+        .await
+        .unwrap();
+    let chunks: Vec<_> = stream.collect().await;
+
+    let content: String = chunks
+        .into_iter()
+        .map(|chunk| chunk.unwrap().choices[0].delta.content.clone().unwrap())
+        .collect();
+    assert_eq!(content, "Hello there!");
+    assert_eq!(*last_event_ids.lock().unwrap(), [None, Some("2".into())]);
+}
+
+#[tokio::test]
+async fn does_not_reconnect_streams_without_event_ids() {
+    let (api_base, last_event_ids) = flaky_stream_server(false).await;
+
+    let stream = common::client(api_base)
+        .chat()
+        .stream_reconnect(2)
+        .create_stream(request())
+        .await
+        .unwrap();
+    let chunks: Vec<_> = stream.collect().await;
+
+    assert_eq!(chunks.len(), 3);
+    assert!(chunks[0].is_ok());
+    assert!(chunks[1].is_ok());
+    assert!(chunks[2].is_err());
+    assert_eq!(last_event_ids.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn does_not_reconnect_by_default() {
+    let (api_base, last_event_ids) = flaky_stream_server(true).await;
+
+    let stream = common::client(api_base)
+        .chat()
+        .create_stream(request())
+        .await
+        .unwrap();
+    let chunks: Vec<_> = stream.collect().await;
+
+    assert!(chunks.last().unwrap().is_err());
+    assert_eq!(last_event_ids.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,154 @@
+//! Structured outputs with JSON schemas derived from `schemars::JsonSchema` types.
+use async_openai::{
+    config::OpenAIConfig,
+    error::OpenAIError,
+    types::chat::{
+        ChatCompletionRequestUserMessage, CreateChatCompletionRequestArgs, ResponseFormat,
+        ResponseFormatJsonSchema,
+    },
+    Client,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+mod common;
+use common::{mock_server, MockResponse};
+
+/// Answer to a math question.
+#[derive(Debug, Deserialize, PartialEq, schemars::JsonSchema)]
+struct MathAnswer {
+    final_answer: String,
+    steps: Vec<Step>,
+    note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, schemars::JsonSchema)]
+struct Step {
+    explanation: String,
+}
+
+#[test]
+fn derived_schema_is_strict() {
+    let json_schema = ResponseFormatJsonSchema::for_type::<MathAnswer>();
+
+    assert_eq!(json_schema.name, "MathAnswer");
+    assert_eq!(json_schema.strict, Some(true));
+    assert_eq!(
+        json_schema.description.as_deref(),
+        Some("Answer to a math question.")
+    );
+
+    let schema = json_schema.schema.unwrap();
+    assert_eq!(schema["additionalProperties"], json!(false));
+    let mut required: Vec<_> = schema["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|name| name.as_str().unwrap())
+        .collect();
+    required.sort();
+    assert_eq!(required, ["final_answer", "note", "steps"]);
+    assert_eq!(
+        schema["$defs"]["Step"]["additionalProperties"],
+        json!(false)
+    );
+    assert_eq!(schema["$defs"]["Step"]["required"], json!(["explanation"]));
+
+    assert!(matches!(
+        ResponseFormat::json_schema_for::<MathAnswer>(),
+        ResponseFormat::JsonSchema { json_schema } if json_schema.name == "MathAnswer"
+    ));
+}
+
+/// A type with a field named like the `properties` keyword.
+#[derive(Debug, Deserialize, PartialEq, schemars::JsonSchema)]
+struct Listing {
+    properties: Vec<Property>,
+    owner: Option<Property>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, schemars::JsonSchema)]
+struct Property {
+    address: String,
+}
+
+#[test]
+fn derived_schema_keeps_fields_named_like_keywords() {
+    let schema = ResponseFormatJsonSchema::for_type::<Listing>()
+        .schema
+        .unwrap();
+
+    let properties = schema["properties"].as_object().unwrap();
+    let mut names: Vec<_> = properties.keys().map(String::as_str).collect();
+    names.sort();
+    assert_eq!(names, ["owner", "properties"]);
+    assert_eq!(schema["$defs"]["Property"]["required"], json!(["address"]));
+    assert_eq!(
+        schema["$defs"]["Property"]["additionalProperties"],
+        json!(false)
+    );
+}
+
+const ANSWER: &str = r#"{
+  "id": "chatcmpl-123",
+  "object": "chat.completion",
+  "created": 1677652288,
+  "model": "gpt-4o-mini",
+  "choices": [{
+    "index": 0,
+    "message": {"role": "assistant", "content": "{\"final_answer\":\"x = -3.75\",\"steps\":[{\"explanation\":\"Subtract 7\"}],\"note\":null}"},
+    "finish_reason": "stop"
+  }]
+}"#;
+
+const REFUSAL: &str = r#"{
+  "id": "chatcmpl-456",
+  "object": "chat.completion",
+  "created": 1677652288,
+  "model": "gpt-4o-mini",
+  "choices": [{
+    "index": 0,
+    "message": {"role": "assistant", "content": null, "refusal": "I can't help with that."},
+    "finish_reason": "stop"
+  }]
+}"#;
+
+async fn create_typed(body: &'static str) -> Result<MathAnswer, OpenAIError> {
+    let (api_base, _) = mock_server(vec![MockResponse::new(200, body)]).await;
+    let client = Client::with_config(
+        OpenAIConfig::new()
+            .with_api_base(api_base)
+            .with_api_key("test"),
+    );
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("how can I solve 8x + 7 = -23").into()])
+        .build()
+        .unwrap();
+
+    client.chat().create_typed::<MathAnswer>(request).await
+}
+
+#[tokio::test]
+async fn create_typed_deserializes_content() {
+    let answer = create_typed(ANSWER).await.unwrap();
+
+    assert_eq!(
+        answer,
+        MathAnswer {
+            final_answer: "x = -3.75".into(),
+            steps: vec![Step {
+                explanation: "Subtract 7".into()
+            }],
+            note: None,
+        }
+    );
+}
+
+#[tokio::test]
+async fn create_typed_returns_refusal() {
+    let err = create_typed(REFUSAL).await.unwrap_err();
+
+    assert!(matches!(err, OpenAIError::Refusal(refusal) if refusal == "I can't help with that."));
+}
@@ -0,0 +1,89 @@
+//! Per-request timeouts set through `RequestOptionsBuilder`.
+use std::time::Duration;
+
+use async_openai::{
+    error::{OpenAIError, StreamError},
+    traits::RequestOptionsBuilder,
+    types::chat::{
+        ChatCompletionRequestUserMessage, CreateChatCompletionRequest,
+        CreateChatCompletionRequestArgs,
+    },
+};
+use futures::StreamExt;
+
+mod common;
+use common::{mock_server, MockResponse};
+
+const CHAT_COMPLETION: &str = r#"{
+  "id": "chatcmpl-123",
+  "object": "chat.completion",
+  "created": 1677652288,
+  "model": "gpt-4o-mini",
+  "choices": [{
+    "index": 0,
+    "message": {"role": "assistant", "content": "Hello there!"},
+    "finish_reason": "stop"
+  }]
+}"#;
+
+fn request(stream: bool) -> CreateChatCompletionRequest {
+    CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello!").into()])
+        .stream(stream)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn request_timeout_aborts_slow_response() {
+    let (api_base, requests) = mock_server(vec![
+        MockResponse::new(200, CHAT_COMPLETION).delay(Duration::from_secs(5))
+    ])
+    .await;
+
+    let result = common::client(api_base)
+        .chat()
+        .timeout(Duration::from_millis(100))
+        .create(request(false))
+        .await;
+
+    assert!(matches!(result, Err(OpenAIError::Reqwest(e)) if e.is_timeout()));
+    assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn request_timeout_allows_fast_response() {
+    let (api_base, _) = mock_server(vec![MockResponse::new(200, CHAT_COMPLETION)]).await;
+
+    let response = common::client(api_base)
+        .chat()
+        .timeout(Duration::from_secs(5))
+        .create(request(false))
+        .await
+        .unwrap();
+
+    assert_eq!(response.id, "chatcmpl-123");
+}
+
+#[tokio::test]
+async fn stream_idle_timeout_aborts_stalled_stream() {
+    let (api_base, _) = mock_server(vec![
+        MockResponse::new(200, "").delay(Duration::from_secs(5))
+    ])
+    .await;
+
+    let mut stream = common::client(api_base)
+        .chat()
+        .stream_idle_timeout(Duration::from_millis(100))
+        .create_stream(request(true))
+        .await
+        .unwrap();
+
+    let item = stream.next().await.unwrap();
+    assert!(matches!(
+        item,
+        Err(OpenAIError::StreamError(e)) if matches!(*e, StreamError::IdleTimeout(_))
+    ));
+    assert!(stream.next().await.is_none());
+}
@@ -0,0 +1,199 @@
+//! Token counts match the examples of the OpenAI cookbook.
+use async_openai::{
+    tokenizer::{count_chat_tokens, ChatHistoryTrimmer, Encoding},
+    types::chat::{
+        ChatCompletionMessageToolCall, ChatCompletionMessageToolCalls,
+        ChatCompletionRequestAssistantMessageArgs, ChatCompletionRequestMessage,
+        ChatCompletionRequestSystemMessageArgs, ChatCompletionRequestToolMessageArgs,
+        ChatCompletionRequestUserMessage, FunctionCall,
+    },
+};
+
+fn system(content: &str, name: Option<&str>) -> ChatCompletionRequestMessage {
+    let mut args = ChatCompletionRequestSystemMessageArgs::default();
+    args.content(content);
+    if let Some(name) = name {
+        args.name(name);
+    }
+    args.build().unwrap().into()
+}
+
+fn cookbook_messages() -> Vec<ChatCompletionRequestMessage> {
+    vec![
+        system("You are a helpful, pattern-following assistant that translates corporate jargon into plain English.", None),
+        system("New synergies will help drive top-line growth.", Some("example_user")),
+        system("Things working well together will increase revenue.", Some("example_assistant")),
+        system("Let's circle back when we have more bandwidth to touch base on opportunities for increased leverage.", Some("example_user")),
+        system("Let's talk later when we're less busy about how to do better.", Some("example_assistant")),
+        ChatCompletionRequestUserMessage::from("This late pivot means we don't have time to boil the ocean for the client deliverable.").into(),
+    ]
+}
+
+#[test]
+fn count_chat_tokens_matches_cookbook() {
+    let messages = cookbook_messages();
+
+    assert_eq!(
+        count_chat_tokens("gpt-3.5-turbo-0301", &messages).unwrap(),
+        127
+    );
+    assert_eq!(
+        count_chat_tokens("gpt-3.5-turbo-0613", &messages).unwrap(),
+        129
+    );
+    assert_eq!(count_chat_tokens("gpt-4-0613", &messages).unwrap(), 129);
+    assert_eq!(count_chat_tokens("gpt-4o", &messages).unwrap(), 124);
+    assert_eq!(count_chat_tokens("gpt-4o-mini", &messages).unwrap(), 124);
+}
+
+#[test]
+fn encoding_for_model() {
+    assert_eq!(
+        Encoding::for_model("gpt-4o-2024-08-06").unwrap(),
+        Encoding::O200kBase
+    );
+    assert_eq!(Encoding::for_model("o3-mini").unwrap(), Encoding::O200kBase);
+    assert_eq!(
+        Encoding::for_model("gpt-4-turbo").unwrap(),
+        Encoding::Cl100kBase
+    );
+    assert_eq!(
+        Encoding::for_model("gpt-3.5-turbo").unwrap(),
+        Encoding::Cl100kBase
+    );
+    assert_eq!(
+        Encoding::for_model("ft:gpt-4o-mini-2024-07-18:org::abc123").unwrap(),
+        Encoding::O200kBase
+    );
+}
+
+#[test]
+fn count_chat_tokens_rejects_unknown_models() {
+    let messages = cookbook_messages();
+
+    assert!(count_chat_tokens("text-davinci-003", &messages).is_err());
+    assert!(count_chat_tokens("llama3", &messages).is_err());
+}
+
+fn user(content: &str) -> ChatCompletionRequestMessage {
+    ChatCompletionRequestUserMessage::from(content).into()
+}
+
+fn assistant(content: &str) -> ChatCompletionRequestMessage {
+    ChatCompletionRequestAssistantMessageArgs::default()
+        .content(content)
+        .build()
+        .unwrap()
+        .into()
+}
+
+fn tool_call(id: &str) -> ChatCompletionRequestMessage {
+    ChatCompletionRequestAssistantMessageArgs::default()
+        .tool_calls(vec![ChatCompletionMessageToolCalls::Function(
+            ChatCompletionMessageToolCall {
+                id: id.into(),
+                function: FunctionCall {
+                    name: "get_weather".into(),
+                    arguments: r#"{"location":"Paris"}"#.into(),
+                },
+            },
+        )])
+        .build()
+        .unwrap()
+        .into()
+}
+
+fn tool_result(id: &str) -> ChatCompletionRequestMessage {
+    ChatCompletionRequestToolMessageArgs::default()
+        .tool_call_id(id)
+        .content("Sunny, 25 degrees")
+        .build()
+        .unwrap()
+        .into()
+}
+
+#[test]
+fn trimmer_keeps_everything_within_budget() {
+    let messages = vec![
+        system("You are a helpful assistant.", None),
+        user("Hello!"),
+        assistant("Hi, how can I help?"),
+    ];
+
+    let (trimmed, tokens) = ChatHistoryTrimmer::new("gpt-4o", 1000)
+        .trim(&messages)
+        .unwrap();
+
+    assert_eq!(trimmed, messages);
+    assert_eq!(tokens, count_chat_tokens("gpt-4o", &messages).unwrap());
+}
+
+#[test]
+fn trimmer_drops_oldest_messages_and_keeps_system_prompt() {
+    let messages = vec![
+        system("You are a helpful assistant.", None),
+        user("What is the capital of France?"),
+        assistant("The capital of France is Paris."),
+        user("And of Germany?"),
+    ];
+    let budget = count_chat_tokens("gpt-4o", &[messages[0].clone(), messages[3].clone()]).unwrap();
+
+    let (trimmed, tokens) = ChatHistoryTrimmer::new("gpt-4o", budget)
+        .trim(&messages)
+        .unwrap();
+
+    assert_eq!(trimmed, vec![messages[0].clone(), messages[3].clone()]);
+    assert_eq!(tokens, budget);
+}
+
+#[test]
+fn trimmer_keeps_tool_calls_with_their_results() {
+    let messages = vec![
+        system("You are a helpful assistant.", None),
+        user("What is the weather in Paris?"),
+        tool_call("call_1"),
+        tool_result("call_1"),
+        assistant("It is sunny in Paris."),
+    ];
+    let expected = [&messages[..1], &messages[2..]].concat();
+    let budget = count_chat_tokens("gpt-4o", &expected).unwrap();
+
+    let (trimmed, _) = ChatHistoryTrimmer::new("gpt-4o", budget)
+        .trim(&messages)
+        .unwrap();
+    assert_eq!(trimmed, expected);
+
+    // one token less and the tool call has to go together with its result
+    let (trimmed, _) = ChatHistoryTrimmer::new("gpt-4o", budget - 1)
+        .trim(&messages)
+        .unwrap();
+    assert_eq!(trimmed, vec![messages[0].clone(), messages[4].clone()]);
+}
+
+#[test]
+fn trimmer_removes_orphaned_tool_results() {
+    let messages = vec![
+        system("You are a helpful assistant.", None),
+        tool_result("call_1"),
+        user("Hello!"),
+    ];
+
+    let (trimmed, _) = ChatHistoryTrimmer::new("gpt-4o", 1000)
+        .trim(&messages)
+        .unwrap();
+
+    assert_eq!(trimmed, vec![messages[0].clone(), messages[2].clone()]);
+}
+
+#[test]
+fn trimmer_errors_when_last_message_does_not_fit() {
+    let messages = vec![
+        system("You are a helpful assistant.", None),
+        user("Hello!"),
+        user(&"This message is far too long. ".repeat(100)),
+    ];
+
+    assert!(ChatHistoryTrimmer::new("gpt-4o", 100)
+        .trim(&messages)
+        .is_err());
+}
@@ -0,0 +1,211 @@
+//! Typed function tools dispatched with `ToolRegistry`.
+use async_openai::types::chat::{
+    ChatCompletionMessageCustomToolCall, ChatCompletionMessageToolCall,
+    ChatCompletionMessageToolCalls, ChatCompletionRequestMessage,
+    ChatCompletionRequestToolMessageContent, ChatCompletionRequestUserMessage, ChatCompletionTools,
+    CreateChatCompletionRequestArgs, CustomTool, CustomToolChatCompletions, CustomToolProperties,
+    FunctionCall, FunctionObject, ToolError, ToolRegistry,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+/// Current weather of a location.
+#[derive(Deserialize, schemars::JsonSchema)]
+struct GetWeather {
+    location: String,
+    unit: Option<String>,
+}
+
+#[derive(Deserialize, schemars::JsonSchema)]
+struct Echo {
+    text: String,
+}
+
+fn registry() -> ToolRegistry {
+    let mut registry = ToolRegistry::new();
+    registry
+        .register("get_weather", "Get the weather", |params: GetWeather| {
+            json!({
+                "location": params.location,
+                "unit": params.unit.unwrap_or_else(|| "celsius".into()),
+                "temperature": 21
+            })
+        })
+        .register("echo", "Echo the text", |params: Echo| params.text);
+    registry
+}
+
+fn tool_call(name: &str, arguments: &str) -> ChatCompletionMessageToolCall {
+    ChatCompletionMessageToolCall {
+        id: format!("call_{name}"),
+        function: FunctionCall {
+            name: name.into(),
+            arguments: arguments.into(),
+        },
+    }
+}
+
+fn content(message: &ChatCompletionRequestToolMessageContent) -> &str {
+    match message {
+        ChatCompletionRequestToolMessageContent::Text(text) => text,
+        other => panic!("unexpected content: {other:?}"),
+    }
+}
+
+#[test]
+fn tools_are_strict_function_definitions() {
+    let tools = registry().tools();
+    assert_eq!(tools.len(), 2);
+
+    let ChatCompletionTools::Function(tool) = &tools[0] else {
+        panic!("expected a function tool");
+    };
+    assert_eq!(tool.function.name, "get_weather");
+    assert_eq!(
+        tool.function.description.as_deref(),
+        Some("Get the weather")
+    );
+    assert_eq!(tool.function.strict, Some(true));
+
+    let parameters = tool.function.parameters.as_ref().unwrap();
+    assert_eq!(parameters["type"], "object");
+    assert_eq!(parameters["additionalProperties"], json!(false));
+    let mut required: Vec<_> = parameters["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|name| name.as_str().unwrap())
+        .collect();
+    required.sort();
+    assert_eq!(required, ["location", "unit"]);
+}
+
+#[test]
+fn function_tools_from_schema_in_request() {
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Weather in Paris?").into()])
+        .function_tools([
+            FunctionObject::from_schema::<GetWeather>("get_weather", "Get the weather"),
+            FunctionObject::from_schema::<Echo>("echo", "Echo the text"),
+        ])
+        .tool(CustomToolChatCompletions {
+            custom: CustomToolProperties {
+                name: "grep".into(),
+                ..Default::default()
+            },
+        })
+        .build()
+        .unwrap();
+
+    let json = serde_json::to_value(&request).unwrap();
+    let tools = json["tools"].as_array().unwrap();
+    assert_eq!(tools.len(), 3);
+    assert_eq!(tools[0]["type"], "function");
+    assert_eq!(tools[0]["function"]["name"], "get_weather");
+    assert_eq!(tools[0]["function"]["strict"], true);
+    assert_eq!(
+        tools[1]["function"]["parameters"]["required"],
+        json!(["text"])
+    );
+    assert_eq!(tools[2]["type"], "custom");
+
+    // the same definitions as the registry
+    let ChatCompletionTools::Function(registered) = &registry().tools()[1] else {
+        panic!("expected a function tool");
+    };
+    assert_eq!(
+        registered.function.parameters,
+        FunctionObject::from_schema::<Echo>("echo", "Echo the text").parameters
+    );
+}
+
+#[test]
+fn dispatch_calls_the_typed_handler() {
+    let registry = registry();
+
+    let result = registry.dispatch(&tool_call(
+        "get_weather",
+        r#"{"location":"Paris","unit":null}"#,
+    ));
+    assert!(!result.is_error());
+    assert_eq!(result.message.tool_call_id, "call_get_weather");
+    let output: serde_json::Value = serde_json::from_str(content(&result.message.content)).unwrap();
+    assert_eq!(
+        output,
+        json!({ "location": "Paris", "unit": "celsius", "temperature": 21 })
+    );
+
+    // string outputs are sent as is
+    let result = registry.dispatch(&tool_call("echo", r#"{"text":"hello"}"#));
+    assert_eq!(content(&result.message.content), "hello");
+}
+
+#[test]
+fn unknown_tools_and_invalid_arguments_are_reported_to_the_model() {
+    let registry = registry();
+
+    let result = registry.dispatch(&tool_call("get_time", "{}"));
+    assert_eq!(
+        result.error,
+        Some(ToolError::UnknownTool {
+            name: "get_time".into()
+        })
+    );
+    assert_eq!(result.message.tool_call_id, "call_get_time");
+    let output: serde_json::Value = serde_json::from_str(content(&result.message.content)).unwrap();
+    assert_eq!(output, json!({ "error": "unknown tool: get_time" }));
+
+    let result = registry.dispatch(&tool_call("get_weather", r#"{"city":"Paris"}"#));
+    assert!(matches!(
+        result.error,
+        Some(ToolError::InvalidArguments { ref name, .. }) if name == "get_weather"
+    ));
+    let output: serde_json::Value = serde_json::from_str(content(&result.message.content)).unwrap();
+    assert!(output["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("invalid arguments for get_weather"));
+}
+
+#[test]
+fn dispatch_all_keeps_order_and_converts_to_messages() {
+    let registry = registry();
+    let tool_calls = [
+        ChatCompletionMessageToolCalls::Function(tool_call("echo", r#"{"text":"a"}"#)),
+        ChatCompletionMessageToolCalls::Custom(ChatCompletionMessageCustomToolCall {
+            id: "call_custom".into(),
+            custom_tool: CustomTool {
+                name: "grammar".into(),
+                input: "x".into(),
+            },
+        }),
+        ChatCompletionMessageToolCalls::Function(tool_call("echo", r#"{"text":"b"}"#)),
+    ];
+
+    let results = registry.dispatch_all(&tool_calls);
+    let errors: Vec<_> = results.iter().map(|result| result.is_error()).collect();
+    assert_eq!(errors, [false, true, false]);
+
+    let messages: Vec<ChatCompletionRequestMessage> = results.into_iter().map(Into::into).collect();
+    let ids: Vec<_> = messages
+        .iter()
+        .map(|message| match message {
+            ChatCompletionRequestMessage::Tool(message) => message.tool_call_id.as_str(),
+            other => panic!("unexpected message: {other:?}"),
+        })
+        .collect();
+    assert_eq!(ids, ["call_echo", "call_custom", "call_echo"]);
+}
+
+#[test]
+fn registering_a_name_again_replaces_the_function() {
+    let mut registry = registry();
+    registry.register("echo", "Shout the text", |params: Echo| {
+        params.text.to_uppercase()
+    });
+
+    assert_eq!(registry.tools().len(), 2);
+    let result = registry.dispatch(&tool_call("echo", r#"{"text":"hi"}"#));
+    assert_eq!(content(&result.message.content), "HI");
+}
@@ -0,0 +1,150 @@
+//! Fields recorded on the `openai.request` span of each request.
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use async_openai::{
+    transport::{MockResponse, MockTransport},
+    types::chat::{ChatCompletionRequestUserMessage, CreateChatCompletionRequestArgs},
+    Client,
+};
+use serde_json::json;
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+type Fields = HashMap<String, String>;
+
+/// Records the fields of every span, by span name. Clones share their spans.
+#[derive(Clone, Default)]
+struct Recorder {
+    next_id: Arc<AtomicU64>,
+    spans: Arc<Mutex<HashMap<u64, (&'static str, Fields)>>>,
+}
+
+impl Recorder {
+    fn span(&self, name: &str) -> Fields {
+        let spans = self.spans.lock().unwrap();
+        let mut matching = spans.values().filter(|(n, _)| *n == name);
+        let (_, fields) = matching.next().expect("span was not created");
+        assert!(matching.next().is_none(), "span was created more than once");
+        fields.clone()
+    }
+}
+
+struct Visitor<'a>(&'a mut Fields);
+
+impl Visit for Visitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.insert(field.name().into(), format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().into(), value.into());
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+        let mut fields = Fields::new();
+        span.record(&mut Visitor(&mut fields));
+        self.spans
+            .lock()
+            .unwrap()
+            .insert(id, (span.metadata().name(), fields));
+        span::Id::from_u64(id)
+    }
+
+    fn record(&self, span: &span::Id, values: &span::Record<'_>) {
+        if let Some((_, fields)) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+            values.record(&mut Visitor(fields));
+        }
+    }
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+fn request() -> async_openai::types::chat::CreateChatCompletionRequest {
+    CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .messages([ChatCompletionRequestUserMessage::from("Hello").into()])
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn span_records_request_response_and_usage() {
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let transport = MockTransport::new().on(
+        "/chat/completions",
+        MockResponse::json(
+            200,
+            json!({
+                "id": "chatcmpl-123",
+                "object": "chat.completion",
+                "created": 1677652288,
+                "model": "gpt-4o-mini",
+                "choices": [{
+                    "index": 0,
+                    "message": { "role": "assistant", "content": "Hello there!" },
+                    "finish_reason": "stop"
+                }],
+                "usage": { "prompt_tokens": 9, "completion_tokens": 3, "total_tokens": 12 }
+            }),
+        )
+        .header("x-request-id", "req_123"),
+    );
+    let client = Client::new().with_transport(transport);
+    client.chat().create(request()).await.unwrap();
+
+    let fields = recorder.span("openai.request");
+    assert_eq!(fields["method"], "POST");
+    assert_eq!(fields["endpoint"], "/v1/chat/completions");
+    assert_eq!(fields["model"], "gpt-4o-mini");
+    assert_eq!(fields["status"], "200");
+    assert_eq!(fields["request_id"], "req_123");
+    assert_eq!(fields["prompt_tokens"], "9");
+    assert_eq!(fields["completion_tokens"], "3");
+    assert_eq!(fields["total_tokens"], "12");
+    assert!(fields.contains_key("latency_ms"));
+    assert!(!fields.contains_key("error"));
+}
+
+#[tokio::test]
+async fn span_records_errors() {
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let transport = MockTransport::new().on(
+        "/chat/completions",
+        MockResponse::error(400, "invalid_request_error", "Invalid model")
+            .header("x-request-id", "req_456"),
+    );
+    let client = Client::new().with_transport(transport);
+    client.chat().create(request()).await.unwrap_err();
+
+    let fields = recorder.span("openai.request");
+    assert_eq!(fields["status"], "400");
+    assert_eq!(fields["request_id"], "req_456");
+    assert!(fields["error"].contains("Invalid model"), "{fields:?}");
+    assert!(!fields.contains_key("total_tokens"));
+}
@@ -0,0 +1,143 @@
+//! Multipart large-file uploads through the Uploads API.
+use std::sync::atomic::Ordering;
+
+use async_openai::{
+    error::OpenAIError,
+    types::{
+        uploads::{
+            AddUploadPartRequest, CompleteUploadRequest, CreateUploadRequestArgs, UploadPurpose,
+            MAX_UPLOAD_PART_BYTES,
+        },
+        InputSource,
+    },
+};
+
+mod common;
+use common::{mock_server, mock_server_recording, MockResponse};
+
+const UPLOAD: &str = r#"{
+  "id": "upload_abc123",
+  "object": "upload",
+  "bytes": 10,
+  "created_at": 1719184911,
+  "filename": "training_examples.jsonl",
+  "purpose": "fine-tune",
+  "status": "pending",
+  "expires_at": 1719127296
+}"#;
+
+const PART_1: &str =
+    r#"{"id":"part_1","object":"upload.part","created_at":1719185911,"upload_id":"upload_abc123"}"#;
+const PART_2: &str =
+    r#"{"id":"part_2","object":"upload.part","created_at":1719185912,"upload_id":"upload_abc123"}"#;
+
+const COMPLETED: &str = r#"{
+  "id": "upload_abc123",
+  "object": "upload",
+  "bytes": 10,
+  "created_at": 1719184911,
+  "filename": "training_examples.jsonl",
+  "purpose": "fine-tune",
+  "status": "completed",
+  "expires_at": 1719127296,
+  "file": {
+    "id": "file-xyz321",
+    "object": "file",
+    "bytes": 10,
+    "created_at": 1719186911,
+    "filename": "training_examples.jsonl",
+    "purpose": "fine-tune"
+  }
+}"#;
+
+fn part(bytes: &'static [u8]) -> AddUploadPartRequest {
+    AddUploadPartRequest {
+        data: InputSource::Bytes {
+            filename: "part".into(),
+            bytes: bytes::Bytes::from_static(bytes),
+        },
+    }
+}
+
+#[tokio::test]
+async fn upload_in_parts() {
+    let (api_base, recorded) = mock_server_recording(vec![
+        MockResponse::new(200, UPLOAD),
+        MockResponse::new(200, PART_1),
+        MockResponse::new(200, PART_2),
+        MockResponse::new(200, COMPLETED),
+    ])
+    .await;
+    let client = common::client(api_base);
+    let uploads = client.uploads();
+
+    let content = b"0123456789";
+    let upload = uploads
+        .create(
+            CreateUploadRequestArgs::default()
+                .filename("training_examples.jsonl")
+                .purpose(UploadPurpose::FineTune)
+                .bytes(content.len() as u64)
+                .mime_type("text/jsonl")
+                .build()
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let mut part_ids = Vec::new();
+    for range in [0..6, 6..10] {
+        let part = uploads
+            .add_part(&upload.id, part(&content[range]))
+            .await
+            .unwrap();
+        part_ids.push(part.id);
+    }
+
+    let completed = uploads
+        .complete(
+            &upload.id,
+            CompleteUploadRequest {
+                part_ids,
+                md5: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(completed.file.unwrap().id, "file-xyz321");
+
+    let recorded = recorded.lock().unwrap();
+    assert!(recorded[0].head.starts_with("POST /v1/uploads "));
+    assert!(recorded[1]
+        .head
+        .starts_with("POST /v1/uploads/upload_abc123/parts "));
+    assert!(String::from_utf8_lossy(&recorded[1].body).contains("012345"));
+    assert!(String::from_utf8_lossy(&recorded[2].body).contains("6789"));
+    assert!(recorded[3]
+        .head
+        .starts_with("POST /v1/uploads/upload_abc123/complete "));
+    let body: serde_json::Value = serde_json::from_slice(&recorded[3].body).unwrap();
+    assert_eq!(body["part_ids"], serde_json::json!(["part_1", "part_2"]));
+}
+
+#[tokio::test]
+async fn add_part_rejects_oversized_part_locally() {
+    let (api_base, requests) = mock_server(vec![MockResponse::new(200, PART_1)]).await;
+    let client = common::client(api_base);
+
+    let request = AddUploadPartRequest {
+        data: InputSource::Stream {
+            filename: "part".into(),
+            stream: async_openai::types::InputStream::new(futures::stream::empty()),
+            length: Some(MAX_UPLOAD_PART_BYTES + 1),
+        },
+    };
+    let err = client
+        .uploads()
+        .add_part("upload_abc123", request)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, OpenAIError::InvalidArgument(_)));
+    assert_eq!(requests.load(Ordering::SeqCst), 0);
+}
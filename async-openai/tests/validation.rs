@@ -0,0 +1,260 @@
+//! Local range checks done by request builders before a request is sent.
+use async_openai::{
+    error::OpenAIError,
+    types::{
+        assistants::{
+            CreateAssistantRequestArgs, CreateRunRequestArgs, CreateThreadAndRunRequestArgs,
+            ModifyAssistantRequestArgs,
+        },
+        chat::{
+            ChatCompletionNamedToolChoice, ChatCompletionToolChoiceOption,
+            CreateChatCompletionRequestArgs, FunctionObject, ToolChoiceOptions,
+        },
+        completions::CreateCompletionRequestArgs,
+        responses::CreateResponseArgs,
+    },
+};
+
+fn invalid_argument(result: Result<(), OpenAIError>, name: &str) {
+    match result {
+        Err(OpenAIError::InvalidArgument(msg)) => assert!(msg.contains(name), "{msg}"),
+        other => panic!("expected invalid {name}, got {other:?}"),
+    }
+}
+
+#[test]
+fn chat_completion_builder_validates_ranges() {
+    let mut args = CreateChatCompletionRequestArgs::default();
+    args.model("gpt-4o-mini")
+        .temperature(2.0)
+        .top_p(0.0)
+        .n(1)
+        .presence_penalty(-2.0)
+        .frequency_penalty(2.0)
+        .max_completion_tokens(1u32);
+    assert!(args.validate().is_ok());
+    assert!(args.build().is_ok());
+
+    invalid_argument(args.clone().temperature(2.1).validate(), "temperature");
+    invalid_argument(args.clone().top_p(1.5).validate(), "top_p");
+    invalid_argument(args.clone().n(0).validate(), "n");
+    invalid_argument(
+        args.clone().presence_penalty(-2.5).validate(),
+        "presence_penalty",
+    );
+    invalid_argument(
+        args.clone().frequency_penalty(3.0).validate(),
+        "frequency_penalty",
+    );
+    invalid_argument(
+        args.clone().max_completion_tokens(0u32).validate(),
+        "max_completion_tokens",
+    );
+    invalid_argument(args.clone().top_logprobs(21).validate(), "top_logprobs");
+
+    // build runs the same checks
+    let err = args.temperature(-0.1).build().unwrap_err();
+    assert!(matches!(err, OpenAIError::InvalidArgument(msg) if msg.contains("temperature")));
+}
+
+#[test]
+fn completion_builder_validates_ranges() {
+    let mut args = CreateCompletionRequestArgs::default();
+    args.model("gpt-3.5-turbo-instruct")
+        .prompt("Say this is a test")
+        .logprobs(5);
+    assert!(args.build().is_ok());
+
+    invalid_argument(args.clone().logprobs(6).validate(), "logprobs");
+    invalid_argument(args.clone().best_of(21).validate(), "best_of");
+    assert!(args.n(129).build().is_err());
+}
+
+#[test]
+fn response_builder_validates_ranges() {
+    let mut args = CreateResponseArgs::default();
+    args.model("gpt-4.1")
+        .input("Hello")
+        .max_output_tokens(16u32);
+    assert!(args.build().is_ok());
+
+    invalid_argument(
+        args.clone().max_output_tokens(15u32).validate(),
+        "max_output_tokens",
+    );
+    invalid_argument(args.clone().top_p(-1.0).validate(), "top_p");
+}
+
+#[test]
+#[allow(deprecated)]
+fn assistants_builders_validate_ranges() {
+    let result = CreateAssistantRequestArgs::default()
+        .model("gpt-4o-mini")
+        .temperature(2.5)
+        .build();
+    invalid_argument(result.map(|_| ()), "temperature");
+    let result = ModifyAssistantRequestArgs::default().top_p(1.5).build();
+    invalid_argument(result.map(|_| ()), "top_p");
+    let result = CreateRunRequestArgs::default()
+        .assistant_id("asst_abc")
+        .temperature(-0.5)
+        .build();
+    invalid_argument(result.map(|_| ()), "temperature");
+    let result = CreateThreadAndRunRequestArgs::default()
+        .assistant_id("asst_abc")
+        .top_p(-1.0)
+        .build();
+    invalid_argument(result.map(|_| ()), "top_p");
+
+    assert!(CreateRunRequestArgs::default()
+        .assistant_id("asst_abc")
+        .temperature(2.0)
+        .top_p(1.0)
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn build_unchecked_skips_validation() {
+    // e.g. for a compatible server accepting a higher temperature
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("local-model")
+        .temperature(3.0)
+        .build_unchecked()
+        .unwrap();
+    assert_eq!(request.temperature, Some(3.0));
+
+    let request = CreateCompletionRequestArgs::default()
+        .model("local-model")
+        .prompt("Hello")
+        .n(200)
+        .build_unchecked()
+        .unwrap();
+    assert_eq!(request.n, Some(200));
+
+    let request = CreateResponseArgs::default()
+        .model("local-model")
+        .input("Hello")
+        .top_p(1.5)
+        .build_unchecked()
+        .unwrap();
+    assert_eq!(request.top_p, Some(1.5));
+}
+
+#[test]
+fn chat_completion_builder_validates_logit_bias() {
+    let mut args = CreateChatCompletionRequestArgs::default();
+    args.model("gpt-4o-mini")
+        .bias_token(50256, -100)
+        .bias_token(1734, 100);
+    let request = args.build().unwrap();
+    assert_eq!(request.logit_bias.unwrap().0.len(), 2);
+
+    invalid_argument(args.bias_token(1734, 101).validate(), "logit_bias");
+}
+
+#[test]
+fn builders_validate_stop_sequences() {
+    use async_openai::types::chat::Stop;
+
+    let mut args = CreateChatCompletionRequestArgs::default();
+    args.model("gpt-4o-mini").stop("\n");
+    assert_eq!(args.build().unwrap().stop, Some(Stop::String("\n".into())));
+
+    args.stop(["a", "b", "c", "d"]);
+    assert!(args.validate().is_ok());
+    invalid_argument(
+        args.clone().stop(["a", "b", "c", "d", "e"]).validate(),
+        "stop",
+    );
+    invalid_argument(args.stop(Vec::<String>::new()).validate(), "stop");
+
+    let mut args = CreateCompletionRequestArgs::default();
+    args.model("gpt-3.5-turbo-instruct")
+        .stop(vec!["a".to_string(); 5]);
+    invalid_argument(args.validate(), "stop");
+}
+
+#[test]
+#[allow(deprecated)]
+fn chat_completion_builder_routes_max_output_tokens() {
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("o3-mini")
+        .max_output_tokens(1000)
+        .build()
+        .unwrap();
+    assert_eq!(request.max_completion_tokens, Some(1000));
+    assert_eq!(request.max_tokens, None);
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-3.5-turbo")
+        .max_output_tokens(1000)
+        .build()
+        .unwrap();
+    assert_eq!(request.max_tokens, Some(1000));
+    assert_eq!(request.max_completion_tokens, None);
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("my-local-model")
+        .max_output_tokens(1000)
+        .build()
+        .unwrap();
+    assert_eq!(request.max_completion_tokens, Some(1000));
+
+    let mut args = CreateChatCompletionRequestArgs::default();
+    args.model("o3-mini").max_tokens(1000u32);
+    invalid_argument(args.validate(), "max_tokens");
+}
+
+#[test]
+fn chat_completion_builder_sets_tool_choice_and_parallel_tool_calls() {
+    let weather = FunctionObject {
+        name: "get_weather".into(),
+        description: None,
+        parameters: None,
+        strict: None,
+    };
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .tool(weather.clone())
+        .tool_choice("get_weather")
+        .sequential_tool_calls()
+        .build()
+        .unwrap();
+    assert_eq!(
+        request.tool_choice,
+        Some(ChatCompletionToolChoiceOption::Function(
+            ChatCompletionNamedToolChoice::from("get_weather")
+        ))
+    );
+    assert_eq!(request.parallel_tool_calls, Some(false));
+
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        json["tool_choice"],
+        serde_json::json!({"type": "function", "function": {"name": "get_weather"}})
+    );
+    assert_eq!(json["parallel_tool_calls"], false);
+
+    for (mode, expected) in [
+        (ToolChoiceOptions::None, "none"),
+        (ToolChoiceOptions::Auto, "auto"),
+        (ToolChoiceOptions::Required, "required"),
+    ] {
+        let request = CreateChatCompletionRequestArgs::default()
+            .model("gpt-4o-mini")
+            .tool(weather.clone())
+            .tool_choice(mode)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["tool_choice"],
+            expected
+        );
+    }
+
+    let mut args = CreateChatCompletionRequestArgs::default();
+    args.model("gpt-4o-mini").sequential_tool_calls();
+    invalid_argument(args.validate(), "parallel_tool_calls");
+}
@@ -0,0 +1,226 @@
+//! Vector store files and file batches, with their chunking strategy, polled until they finish.
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_openai::{
+    error::OpenAIError,
+    transport::{MockResponse, MockTransport},
+    types::vectorstores::{
+        ChunkingStrategy, CreateVectorStoreFileBatchRequestArgs, CreateVectorStoreFileRequest,
+        VectorStoreFileBatchObject, VectorStoreFileBatchStatus,
+    },
+    Client, PollOptions,
+};
+
+fn batch(status: &str, completed: u32, failed: u32) -> MockResponse {
+    MockResponse::json(
+        200,
+        serde_json::json!({
+            "id": "vsfb_123",
+            "object": "vector_store.files_batch",
+            "created_at": 1699061776,
+            "vector_store_id": "vs_abc123",
+            "status": status,
+            "file_counts": {
+                "in_progress": 3 - completed - failed,
+                "completed": completed,
+                "failed": failed,
+                "cancelled": 0,
+                "total": 3
+            }
+        }),
+    )
+}
+
+#[tokio::test]
+async fn create_file_batch_and_poll_until_completed() {
+    let transport = MockTransport::new()
+        .on(
+            "/vector_stores/vs_abc123/file_batches",
+            batch("in_progress", 0, 0),
+        )
+        .on(
+            "/vector_stores/vs_abc123/file_batches/vsfb_123",
+            batch("in_progress", 1, 0),
+        )
+        .on(
+            "/vector_stores/vs_abc123/file_batches/vsfb_123",
+            batch("completed", 2, 1),
+        );
+    let client = Client::new().with_transport(transport.clone());
+
+    let progress = Arc::new(Mutex::new(vec![]));
+    let recorded = progress.clone();
+    let options = PollOptions::new(Duration::from_millis(1)).on_progress(
+        move |batch: &VectorStoreFileBatchObject| {
+            recorded.lock().unwrap().push(batch.file_counts.completed)
+        },
+    );
+
+    let batch = client
+        .vector_stores()
+        .create_file_batch_and_poll(
+            "vs_abc123",
+            ["file-1", "file-2", "file-3"],
+            Some(ChunkingStrategy::fixed(400, 100)),
+            options,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(batch.status, VectorStoreFileBatchStatus::Completed);
+    assert_eq!(batch.file_counts.failed, 1);
+    assert_eq!(*progress.lock().unwrap(), [1, 2]);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(
+        requests[0].json().unwrap(),
+        serde_json::json!({
+            "file_ids": ["file-1", "file-2", "file-3"],
+            "chunking_strategy": {
+                "type": "static",
+                "static": { "max_chunk_size_tokens": 400, "chunk_overlap_tokens": 100 }
+            }
+        })
+    );
+    assert_eq!(requests[1].method, "GET");
+}
+
+#[tokio::test]
+async fn wait_for_completion_returns_cancelled_batches() {
+    let transport = MockTransport::new().on(
+        "/vector_stores/vs_abc123/file_batches/vsfb_123",
+        batch("cancelled", 1, 0),
+    );
+    let client = Client::new().with_transport(transport.clone());
+
+    let batch = client
+        .vector_stores()
+        .file_batches("vs_abc123")
+        .wait_for_completion("vsfb_123", Duration::from_millis(1))
+        .await
+        .unwrap();
+
+    assert_eq!(batch.status, VectorStoreFileBatchStatus::Cancelled);
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn create_file_with_chunking_strategy() {
+    let transport = MockTransport::new().on(
+        "/vector_stores/vs_abc123/files",
+        MockResponse::json(
+            200,
+            serde_json::json!({
+                "id": "file-1",
+                "object": "vector_store.file",
+                "usage_bytes": 1234,
+                "created_at": 1699061776,
+                "vector_store_id": "vs_abc123",
+                "status": "in_progress",
+                "last_error": null,
+                "chunking_strategy": {
+                    "type": "static",
+                    "static": { "max_chunk_size_tokens": 1200, "chunk_overlap_tokens": 600 }
+                }
+            }),
+        ),
+    );
+    let client = Client::new().with_transport(transport.clone());
+
+    let file = client
+        .vector_stores()
+        .create_file(
+            "vs_abc123",
+            "file-1",
+            Some(ChunkingStrategy::fixed(1200, 600)),
+        )
+        .await
+        .unwrap();
+    assert_eq!(file.id, "file-1");
+
+    client
+        .vector_stores()
+        .create_file("vs_abc123", "file-1", None)
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(
+        requests[0].json().unwrap(),
+        serde_json::json!({
+            "file_id": "file-1",
+            "chunking_strategy": {
+                "type": "static",
+                "static": { "max_chunk_size_tokens": 1200, "chunk_overlap_tokens": 600 }
+            }
+        })
+    );
+    assert_eq!(
+        requests[1].json().unwrap(),
+        serde_json::json!({ "file_id": "file-1" })
+    );
+}
+
+#[tokio::test]
+async fn invalid_chunking_strategy_is_not_sent() {
+    let transport = MockTransport::new();
+    let client = Client::new().with_transport(transport.clone());
+
+    let error = client
+        .vector_stores()
+        .create_file(
+            "vs_abc123",
+            "file-1",
+            Some(ChunkingStrategy::fixed(800, 800)),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(error, OpenAIError::InvalidArgument(_)));
+
+    let error = client
+        .vector_stores()
+        .create_file_batch_and_poll(
+            "vs_abc123",
+            ["file-1"],
+            Some(ChunkingStrategy::fixed(50, 10)),
+            Duration::from_millis(1),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(error, OpenAIError::InvalidArgument(_)));
+
+    assert!(transport.requests().is_empty());
+}
+
+#[test]
+fn chunking_strategy_validation() {
+    assert!(ChunkingStrategy::Auto.validate().is_ok());
+    assert!(ChunkingStrategy::fixed(800, 400).validate().is_ok());
+    assert!(ChunkingStrategy::fixed(4096, 0).validate().is_ok());
+    assert!(ChunkingStrategy::fixed(800, 401).validate().is_err());
+    assert!(ChunkingStrategy::fixed(4097, 100).validate().is_err());
+
+    let error = CreateVectorStoreFileBatchRequestArgs::default()
+        .file_ids(vec!["file-1".to_string()])
+        .chunking_strategy(ChunkingStrategy::fixed(800, 900))
+        .build()
+        .unwrap_err();
+    assert!(
+        matches!(&error, OpenAIError::InvalidArgument(message) if message.contains("chunk_overlap_tokens")),
+        "{error}"
+    );
+
+    let error = CreateVectorStoreFileBatchRequestArgs::default()
+        .files([CreateVectorStoreFileRequest {
+            file_id: "file-1".into(),
+            chunking_strategy: Some(ChunkingStrategy::fixed(100, 60)),
+            attributes: None,
+        }])
+        .build()
+        .unwrap_err();
+    assert!(matches!(error, OpenAIError::InvalidArgument(_)));
+}
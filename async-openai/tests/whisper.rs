@@ -1,5 +1,11 @@
-use async_openai::types::audio::CreateTranslationRequestArgs;
+use async_openai::error::OpenAIError;
+use async_openai::types::audio::{
+    AudioInput, AudioResponseFormat, Caption, CreateTranslationRequestArgs, TimestampGranularity,
+    TranslationResponseFormat,
+};
 use async_openai::{types::audio::CreateTranscriptionRequestArgs, Client};
+use bytes::Bytes;
+use std::time::Duration;
 use tokio_test::assert_err;
 
 #[tokio::test]
@@ -33,3 +39,329 @@ async fn translate_sendable_test() {
 
     assert_err!(response); // FileReadError("cannot extract file name from ")
 }
+
+mod common;
+use common::{mock_server_recording, MockResponse};
+
+const TRANSCRIPTION: &str = r#"{"text":"hello","usage":{"type":"duration","seconds":1}}"#;
+
+#[tokio::test]
+async fn transcribe_from_stream_with_length() {
+    let (api_base, recorded) =
+        mock_server_recording(vec![MockResponse::new(200, TRANSCRIPTION)]).await;
+    let client = common::client(api_base);
+
+    let chunks: Vec<Result<Bytes, std::io::Error>> = vec![
+        Ok(Bytes::from_static(b"RIFF")),
+        Ok(Bytes::from_static(b"audio-")),
+        Ok(Bytes::from_static(b"data")),
+    ];
+    let request = CreateTranscriptionRequestArgs::default()
+        .file(AudioInput::from_stream(
+            "speech.wav".into(),
+            futures::stream::iter(chunks),
+            Some(14),
+        ))
+        .model("whisper-1")
+        .build()
+        .unwrap();
+
+    let response = client
+        .audio()
+        .transcription()
+        .create(request)
+        .await
+        .unwrap();
+    assert_eq!(response.text, "hello");
+
+    let recorded = recorded.lock().unwrap();
+    let request = &recorded[0];
+    assert!(request.header("content-length").is_some());
+    let body = String::from_utf8_lossy(&request.body);
+    assert!(body.contains(r#"filename="speech.wav""#));
+    assert!(body.contains("RIFFaudio-data"));
+}
+
+#[tokio::test]
+async fn transcribe_from_reader_without_length() {
+    let (api_base, recorded) =
+        mock_server_recording(vec![MockResponse::new(200, TRANSCRIPTION)]).await;
+    let client = common::client(api_base);
+
+    let audio = vec![7u8; 256 * 1024];
+    let request = CreateTranscriptionRequestArgs::default()
+        .file(AudioInput::from_reader(
+            "speech.mp3".into(),
+            std::io::Cursor::new(audio.clone()),
+            None,
+        ))
+        .model("whisper-1")
+        .build()
+        .unwrap();
+
+    client
+        .audio()
+        .transcription()
+        .create(request)
+        .await
+        .unwrap();
+
+    let recorded = recorded.lock().unwrap();
+    let request = &recorded[0];
+    assert_eq!(request.header("transfer-encoding"), Some("chunked"));
+    assert!(request
+        .body
+        .windows(audio.len())
+        .any(|window| window == audio.as_slice()));
+}
+
+#[tokio::test]
+async fn transcribe_from_consumed_stream_fails() {
+    let (api_base, _) = mock_server_recording(vec![MockResponse::new(200, TRANSCRIPTION)]).await;
+    let client = common::client(api_base);
+
+    let request = CreateTranscriptionRequestArgs::default()
+        .file(AudioInput::from_reader(
+            "speech.mp3".into(),
+            std::io::Cursor::new(b"audio".to_vec()),
+            None,
+        ))
+        .model("whisper-1")
+        .build()
+        .unwrap();
+
+    client
+        .audio()
+        .transcription()
+        .create(request.clone())
+        .await
+        .unwrap();
+
+    // clones share the stream, which has been read by the first request
+    let err = client
+        .audio()
+        .transcription()
+        .create(request)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, OpenAIError::FileReadError(_)));
+}
+
+#[tokio::test]
+async fn transcribe_from_stream_is_not_retried() {
+    let (api_base, recorded) = mock_server_recording(vec![MockResponse::new(
+        503,
+        r#"{"error":{"message":"overloaded","type":"server_error","param":null,"code":null}}"#,
+    )])
+    .await;
+    let client = common::client(api_base);
+
+    let request = CreateTranscriptionRequestArgs::default()
+        .file(AudioInput::from_reader(
+            "speech.mp3".into(),
+            std::io::Cursor::new(b"audio".to_vec()),
+            None,
+        ))
+        .model("whisper-1")
+        .build()
+        .unwrap();
+
+    let err = client
+        .audio()
+        .transcription()
+        .create(request)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, OpenAIError::ApiError(e) if e.status == Some(503)));
+    assert_eq!(recorded.lock().unwrap().len(), 1);
+}
+
+const VERBOSE_TRANSCRIPTION: &str = r#"{
+    "task": "transcribe",
+    "language": "english",
+    "duration": 1.2,
+    "text": "Hello world",
+    "words": [
+        {"word": "Hello", "start": 0.0, "end": 0.5},
+        {"word": "world", "start": 0.6, "end": 1.1}
+    ],
+    "segments": [{
+        "id": 0, "seek": 0, "start": 0.0, "end": 1.1, "text": " Hello world",
+        "tokens": [50364, 2425, 1002], "temperature": 0.0, "avg_logprob": -0.2,
+        "compression_ratio": 0.8, "no_speech_prob": 0.01
+    }],
+    "usage": {"type": "duration", "seconds": 2}
+}"#;
+
+#[tokio::test]
+async fn transcribe_verbose_json_timestamps() {
+    let (api_base, recorded) =
+        mock_server_recording(vec![MockResponse::new(200, VERBOSE_TRANSCRIPTION)]).await;
+    let client = common::client(api_base);
+
+    let request = CreateTranscriptionRequestArgs::default()
+        .file(AudioInput::from_vec_u8(
+            "speech.wav".into(),
+            b"RIFF".to_vec(),
+        ))
+        .model("whisper-1")
+        .timestamp_granularities(vec![
+            TimestampGranularity::Word,
+            TimestampGranularity::Segment,
+        ])
+        .build()
+        .unwrap();
+
+    let response = client
+        .audio()
+        .transcription()
+        .create_verbose_json(request)
+        .await
+        .unwrap();
+
+    let words = response.words.unwrap();
+    assert_eq!(words[1].word, "world");
+    assert_eq!((words[1].start, words[1].end), (0.6, 1.1));
+    assert_eq!(response.segments.unwrap()[0].end, 1.1);
+
+    let recorded = recorded.lock().unwrap();
+    let body = String::from_utf8_lossy(&recorded[0].body);
+    // with `byot`, requests are sent as they are
+    #[cfg(not(feature = "byot"))]
+    assert!(body.contains("name=\"response_format\"\r\n\r\nverbose_json"));
+    assert!(body.contains("name=\"timestamp_granularities[]\"\r\n\r\nword"));
+    assert!(body.contains("name=\"timestamp_granularities[]\"\r\n\r\nsegment"));
+}
+
+#[test]
+fn timestamp_granularities_require_verbose_json() {
+    let err = CreateTranscriptionRequestArgs::default()
+        .model("whisper-1")
+        .response_format(AudioResponseFormat::Srt)
+        .timestamp_granularities(vec![TimestampGranularity::Word])
+        .build()
+        .unwrap_err();
+    assert!(matches!(err, OpenAIError::InvalidArgument(_)));
+
+    CreateTranscriptionRequestArgs::default()
+        .model("whisper-1")
+        .response_format(AudioResponseFormat::VerboseJson)
+        .timestamp_granularities(vec![TimestampGranularity::Word])
+        .build()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn transcribe_srt_captions() {
+    let srt = "1\r\n00:00:00,000 --> 00:00:01,500\r\nHello\r\n\r\n2\r\n00:00:01,500 --> 00:00:03,250\r\nHow are\r\nyou?\r\n\r\n";
+    let (api_base, _) = mock_server_recording(vec![MockResponse::new(200, srt)]).await;
+    let client = common::client(api_base);
+
+    let request = CreateTranscriptionRequestArgs::default()
+        .file(AudioInput::from_vec_u8(
+            "speech.wav".into(),
+            b"RIFF".to_vec(),
+        ))
+        .model("whisper-1")
+        .response_format(AudioResponseFormat::Srt)
+        .build()
+        .unwrap();
+    let text = client
+        .audio()
+        .transcription()
+        .create_text(request)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        Caption::parse_srt(&text).unwrap(),
+        vec![
+            Caption {
+                start: Duration::ZERO,
+                end: Duration::from_millis(1500),
+                text: "Hello".into(),
+            },
+            Caption {
+                start: Duration::from_millis(1500),
+                end: Duration::from_millis(3250),
+                text: "How are\nyou?".into(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_vtt_captions() {
+    let vtt = "WEBVTT\n\nNOTE generated\nby whisper\n\n00:00.000 --> 00:02.000\nHello\n\nintro\n01:00:02.000 --> 01:00:04.500 align:start\nmulti\nline\n";
+
+    let captions = Caption::parse_vtt(vtt).unwrap();
+    assert_eq!(captions.len(), 2);
+    assert_eq!(captions[0].end, Duration::from_secs(2));
+    assert_eq!(captions[1].start, Duration::from_secs(3602));
+    assert_eq!(captions[1].end, Duration::from_millis(3_604_500));
+    assert_eq!(captions[1].text, "multi\nline");
+
+    assert!(Caption::parse_vtt("00:00.000 --> 00:02.000\nHello\n").is_err());
+    assert!(Caption::parse_srt("1\n00:00:00 --> 00:00:01,000\nHello\n").is_err());
+    assert!(Caption::parse_srt("Hello\n").is_err());
+}
+
+#[tokio::test]
+async fn translate_verbose_json_from_stream() {
+    let verbose = r#"{"language": "english", "duration": 1.5, "text": "Hello", "segments": [{
+        "id": 0, "seek": 0, "start": 0.0, "end": 1.5, "text": " Hello", "tokens": [2425],
+        "temperature": 0.0, "avg_logprob": -0.2, "compression_ratio": 0.8, "no_speech_prob": 0.01
+    }]}"#;
+    let (api_base, recorded) = mock_server_recording(vec![
+        MockResponse::new(200, verbose),
+        MockResponse::new(200, "1\n00:00:00,000 --> 00:00:01,500\nHello\n"),
+    ])
+    .await;
+    let client = common::client(api_base);
+
+    let chunks: Vec<Result<Bytes, std::io::Error>> = vec![Ok(Bytes::from_static(b"RIFFdata"))];
+    let request = CreateTranslationRequestArgs::default()
+        .file(AudioInput::from_stream(
+            "hallo.wav".into(),
+            futures::stream::iter(chunks),
+            Some(8),
+        ))
+        .model("whisper-1")
+        .prompt("Greetings")
+        .temperature(0.2)
+        .build()
+        .unwrap();
+    let response = client
+        .audio()
+        .translation()
+        .create_verbose_json(request)
+        .await
+        .unwrap();
+    assert_eq!(response.duration, 1.5);
+    assert_eq!(response.segments.unwrap()[0].text, " Hello");
+
+    let request = CreateTranslationRequestArgs::default()
+        .file(AudioInput::from_vec_u8(
+            "hallo.wav".into(),
+            b"RIFF".to_vec(),
+        ))
+        .model("whisper-1")
+        .response_format(TranslationResponseFormat::Srt)
+        .build()
+        .unwrap();
+    let srt = client
+        .audio()
+        .translation()
+        .create_text(request)
+        .await
+        .unwrap();
+    assert_eq!(Caption::parse_srt(&srt).unwrap()[0].text, "Hello");
+
+    let recorded = recorded.lock().unwrap();
+    let body = String::from_utf8_lossy(&recorded[0].body);
+    assert!(body.contains(r#"filename="hallo.wav""#));
+    assert!(body.contains("name=\"prompt\"\r\n\r\nGreetings"));
+    assert!(body.contains("name=\"temperature\"\r\n\r\n0.2"));
+    let body = String::from_utf8_lossy(&recorded[1].body);
+    assert!(body.contains("name=\"response_format\"\r\n\r\nsrt"));
+}
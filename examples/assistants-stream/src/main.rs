@@ -0,0 +1,79 @@
+#![allow(deprecated)]
+
+use std::error::Error;
+use std::io::Write;
+
+use async_openai::{
+    config::{OpenAIConfig, OPENAI_BETA_HEADER},
+    types::assistants::{
+        AssistantStreamEvent, CreateAssistantRequestArgs, CreateMessageRequest, CreateRunRequest,
+        CreateThreadRequest, MessageRole,
+    },
+    Client,
+};
+use futures::StreamExt;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let config =
+        OpenAIConfig::default().with_header(OPENAI_BETA_HEADER, "assistants=v2".to_string())?;
+    let client = Client::with_config(config);
+
+    let assistant = client
+        .assistants()
+        .create(
+            CreateAssistantRequestArgs::default()
+                .instructions("You are a helpful assistant, answer in a few sentences.")
+                .model("gpt-4o")
+                .build()?,
+        )
+        .await?;
+
+    let thread = client
+        .threads()
+        .create(CreateThreadRequest::default())
+        .await?;
+
+    client
+        .threads()
+        .messages(&thread.id)
+        .create(CreateMessageRequest {
+            role: MessageRole::User,
+            content: "Why is the sky blue?".into(),
+            ..Default::default()
+        })
+        .await?;
+
+    let mut stream = client
+        .threads()
+        .runs(&thread.id)
+        .create_stream(CreateRunRequest {
+            assistant_id: assistant.id.clone(),
+            ..Default::default()
+        })
+        .await?;
+
+    // print the tokens of the message as they arrive
+    let mut lock = std::io::stdout().lock();
+    while let Some(event) = stream.next().await {
+        match event {
+            Ok(AssistantStreamEvent::ThreadMessageDelta(delta)) => {
+                write!(lock, "{}", delta.text())?;
+                lock.flush()?;
+            }
+            Ok(AssistantStreamEvent::ThreadRunCompleted(run)) => {
+                writeln!(lock, "\n\nusage: {:?}", run.usage)?;
+            }
+            Ok(AssistantStreamEvent::ErrorEvent(error)) => {
+                writeln!(lock, "\nerror: {error}")?;
+            }
+            Ok(_) => {}
+            Err(e) => writeln!(lock, "\nerror: {e}")?,
+        }
+    }
+
+    client.threads().delete(&thread.id).await?;
+    client.assistants().delete(&assistant.id).await?;
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use async_openai::{
     config::{OpenAIConfig, OPENAI_BETA_HEADER},
     traits::RequestOptionsBuilder,
@@ -14,7 +14,7 @@ async fn make_request(
 
     let response: Response = client.responses().create_byot(request).await?;
 
-    println!("\nResponse:\n{}", response.output_text().ok_or("None")?);
+    println!("\nResponse:\n{}", response.output_text());
 
     Ok(response)
 }
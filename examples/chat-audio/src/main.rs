@@ -0,0 +1,63 @@
+use std::error::Error;
+
+use async_openai::{
+    types::{
+        chat::{
+            ChatCompletionAudio, ChatCompletionAudioFormat, ChatCompletionAudioVoice,
+            ChatCompletionRequestMessage, ChatCompletionRequestUserMessage,
+            CreateChatCompletionRequestArgs, ResponseModalities,
+        },
+        ModelId,
+    },
+    Client,
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let client = Client::new();
+
+    let mut messages: Vec<ChatCompletionRequestMessage> = vec![
+        ChatCompletionRequestUserMessage::from("Is a golden retriever a good family dog?").into(),
+    ];
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model(ModelId::GPT_4O_AUDIO_PREVIEW)
+        .modalities([ResponseModalities::Text, ResponseModalities::Audio])
+        .audio(ChatCompletionAudio::new(
+            ChatCompletionAudioVoice::Alloy,
+            ChatCompletionAudioFormat::Wav,
+        ))
+        .messages(messages.clone())
+        .build()?;
+
+    let response = client.chat().create(request).await?;
+    let message = response.choices[0].message.clone();
+
+    if let Some(audio) = &message.audio {
+        println!("Transcript: {}", audio.transcript);
+        std::fs::create_dir_all("./data")?;
+        std::fs::write("./data/answer.wav", audio.decode()?)?;
+        println!("Audio saved to ./data/answer.wav");
+    }
+
+    // the audio of the previous turn is referred to by its id in the next turn
+    messages.push(message.into());
+    messages.push(ChatCompletionRequestUserMessage::from("Why do you say so?").into());
+
+    let request = CreateChatCompletionRequestArgs::default()
+        .model(ModelId::GPT_4O_AUDIO_PREVIEW)
+        .modalities([ResponseModalities::Text, ResponseModalities::Audio])
+        .audio(ChatCompletionAudio::new(
+            ChatCompletionAudioVoice::Alloy,
+            ChatCompletionAudioFormat::Wav,
+        ))
+        .messages(messages)
+        .build()?;
+
+    let response = client.chat().create(request).await?;
+    if let Some(audio) = &response.choices[0].message.audio {
+        println!("Transcript: {}", audio.transcript);
+    }
+
+    Ok(())
+}
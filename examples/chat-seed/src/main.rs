@@ -0,0 +1,64 @@
+//! Sends the same request with a fixed `seed` several times, and reports when the
+//! `system_fingerprint` of the backend changes, since outputs for the same seed only
+//! repeat while the fingerprint stays the same.
+//!
+//! OpenAI has deprecated `seed` and `system_fingerprint`, they are still sent and returned.
+use std::error::Error;
+
+use async_openai::{
+    types::chat::{
+        ChatCompletionRequestUserMessage, ChatCompletionStreamAccumulator,
+        CreateChatCompletionRequest, CreateChatCompletionRequestArgs,
+    },
+    Client,
+};
+use futures::StreamExt;
+
+#[allow(deprecated)]
+fn request(stream: bool) -> Result<CreateChatCompletionRequest, Box<dyn Error>> {
+    Ok(CreateChatCompletionRequestArgs::default()
+        .model("gpt-4o-mini")
+        .max_completion_tokens(64u32)
+        .seed(42)
+        .temperature(0.0)
+        .stream(stream)
+        .messages([ChatCompletionRequestUserMessage::from(
+            "Write a one sentence story about a lighthouse.",
+        )
+        .into()])
+        .build()?)
+}
+
+#[tokio::main]
+#[allow(deprecated)]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let client = Client::new();
+
+    let mut previous: Option<(Option<String>, Option<String>)> = None;
+    for attempt in 1..=3 {
+        let response = client.chat().create(request(false)?).await?;
+        let fingerprint = response.system_fingerprint.clone();
+        let content = response.choices[0].message.content.clone();
+
+        println!("#{attempt} fingerprint: {fingerprint:?}\n{content:?}\n");
+        if let Some((previous_fingerprint, previous_content)) = &previous {
+            if *previous_fingerprint != fingerprint {
+                println!("backend changed: outputs for the same seed may differ");
+            } else if *previous_content != content {
+                println!("same backend, different output: determinism is best effort");
+            }
+        }
+        previous = Some((fingerprint, content));
+    }
+
+    // streamed chunks carry the fingerprint too
+    let mut stream = client.chat().create_stream(request(true)?).await?;
+    let mut accumulator = ChatCompletionStreamAccumulator::new();
+    while let Some(chunk) = stream.next().await {
+        accumulator.push(&chunk?);
+    }
+    let response = accumulator.finish();
+    println!("streamed fingerprint: {:?}", response.system_fingerprint);
+
+    Ok(())
+}
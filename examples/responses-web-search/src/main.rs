@@ -2,9 +2,8 @@ use std::error::Error;
 
 use async_openai::{
     types::responses::{
-        CreateResponseArgs, Tool, WebSearchApproximateLocationArgs,
-        WebSearchApproximateLocationType, WebSearchToolArgs, WebSearchToolFilters,
-        WebSearchToolSearchContextSize,
+        CreateResponseArgs, WebSearchApproximateLocationArgs, WebSearchApproximateLocationType,
+        WebSearchToolArgs, WebSearchToolFilters, WebSearchToolSearchContextSize,
     },
     Client,
 };
@@ -21,9 +20,9 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     let web_search = WebSearchToolArgs::default()
         .search_context_size(WebSearchToolSearchContextSize::Low)
-        .filters(WebSearchToolFilters {
-            allowed_domains: Some(vec!["news.ycombinator.com".to_string()]),
-        })
+        .filters(WebSearchToolFilters::allowed_domains([
+            "news.ycombinator.com",
+        ]))
         .user_location(user_location)
         .build()?;
 
@@ -32,12 +31,17 @@ async fn main() -> Result<(), Box<dyn Error>> {
         .input(
             "Search the Hacker News front page and summarize the top 3 stories in one sentence each. And also see if there was any new stories about Apple on Hacker News.",
         )
-        .tools(vec![Tool::WebSearch(web_search)])
+        .tool(web_search)
         .build()?;
 
     let response = client.responses().create(request).await?;
 
-    println!("Response: {:#?}", response);
+    println!("{}", response.output_text());
+
+    println!("\nSources:");
+    for citation in response.url_citations() {
+        println!("- {}: {}", citation.title, citation.url);
+    }
 
     Ok(())
 }
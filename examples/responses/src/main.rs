@@ -41,7 +41,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     let response = client.responses().create(request).await?;
 
-    println!("\n SDK: output_text()\n: {:?}", response.output_text());
+    println!("\n SDK: output_text()\n: {}", response.output_text());
 
     for output in response.output {
         println!("\nOutput: {:?}\n", output);
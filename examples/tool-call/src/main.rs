@@ -2,8 +2,7 @@ use std::collections::HashMap;
 use std::io::{stdout, Write};
 
 use async_openai::types::chat::{
-    ChatCompletionMessageToolCalls, ChatCompletionRequestAssistantMessageArgs,
-    ChatCompletionRequestMessage, ChatCompletionRequestToolMessage,
+    ChatCompletionMessageToolCalls, ChatCompletionRequestMessage, ChatCompletionRequestToolMessage,
     ChatCompletionRequestUserMessage, ChatCompletionTool, FunctionObjectArgs,
 };
 use async_openai::{types::chat::CreateChatCompletionRequestArgs, Client};
@@ -52,7 +51,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .message
         .clone();
 
-    if let Some(tool_calls) = response_message.tool_calls {
+    if let Some(tool_calls) = response_message.tool_calls.clone() {
         let mut handles = Vec::new();
         for tool_call_enum in tool_calls {
             // Extract the function tool call from the enum
@@ -78,19 +77,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let mut messages: Vec<ChatCompletionRequestMessage> =
             ChatCompletionRequestUserMessage::from(user_prompt).into();
 
-        // Convert ChatCompletionMessageToolCall to ChatCompletionMessageToolCalls enum
-        let tool_calls: Vec<ChatCompletionMessageToolCalls> = function_responses
-            .iter()
-            .map(|(tool_call, _response_content)| {
-                ChatCompletionMessageToolCalls::Function(tool_call.clone())
-            })
-            .collect();
-
-        let assistant_messages: ChatCompletionRequestMessage =
-            ChatCompletionRequestAssistantMessageArgs::default()
-                .tool_calls(tool_calls)
-                .build()?
-                .into();
+        // The assistant message with the tool calls goes back to the model along with their results
+        let assistant_messages: ChatCompletionRequestMessage = response_message.into();
 
         let tool_messages: Vec<ChatCompletionRequestMessage> = function_responses
             .iter()
@@ -2,22 +2,36 @@
 //! Script to add utoipa::ToSchema annotations to async-openai types.
 //!
 //! This script processes all .rs files in async-openai/src/types/
-//! and adds:
-//! 1. Separate `#[derive(utoipa::ToSchema)]` lines for structs and enums.
-//! 2. `#[schema(value_type = ...)]` on fields/variants whose type is
-//!    `serde_json::Value`, `Option<serde_json::Value>`, `Vec<serde_json::Value>`,
-//!    or `HashMap<_, serde_json::Value>`.
+//! and adds, by default:
+//! 1. Separate `#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]` lines for
+//!    structs and enums.
+//! 2. `#[cfg_attr(feature = "utoipa", schema(value_type = ...))]` on fields/variants
+//!    whose type is `serde_json::Value`, `Option<serde_json::Value>`,
+//!    `Vec<serde_json::Value>`, or `HashMap<_, serde_json::Value>`.
+//!
+//! 3. `#[cfg_attr(feature = "utoipa", schema(bound = "T: utoipa::ToSchema"))]` after the
+//!    derive of generic types, bounding every type parameter.
+//!
+//! All are gated behind the `utoipa` feature of async-openai, so that the crate only
+//! depends on utoipa when it is enabled.
+//!
+//! With `--schemars`, it adds `#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]`
+//! to the same structs and enums instead. schemars needs no `value_type` or `bound`
+//! annotations: it supports `serde_json::Value` and bounds type parameters itself. Types
+//! already deriving `utoipa::ToSchema` get it too, even when skipped by the field type checks.
+//!
+//! In both modes, types deriving neither `Serialize` nor `Deserialize` are skipped.
 //!
 //! Features:
 //! - Uses syn crate for proper Rust AST parsing
 //! - Adds separate derive lines instead of modifying existing ones
-//! - Idempotent: skips types/fields that already have the annotations
+//! - Idempotent: skips types/fields that already have the annotations, gated or not
 //! - No import statements added (uses fully qualified paths)
 //! - Preserves all comments and original formatting (text insertion, not AST rewrite)
 //!
 //! Note: `CompoundFilter.filters: Vec<Filter>` is a genuine recursive cycle that
 //! cannot be auto-detected from types alone; annotate it manually:
-//!   #[schema(value_type = Vec<Object>)]
+//!   #[cfg_attr(feature = "utoipa", schema(value_type = Vec<Object>))]
 
 use anyhow::{Context, Result};
 use std::collections::HashSet;
@@ -36,6 +50,9 @@ fn get_skip_list() -> HashSet<&'static str> {
         // error.rs: internal client error enums containing reqwest::Error etc.
         "OpenAIError",
         "StreamError",
+        "BatchOutputError", // Contains serde_json::Error
+        "RegisteredTool",   // Contains Box<dyn Fn>
+        "ToolRegistry",     // Contains RegisteredTool
     ]
     .iter()
     .copied()
@@ -49,6 +66,7 @@ fn get_problematic_types() -> Vec<&'static str> {
         "Arc<",
         "PathBuf",
         "InputSource",
+        "VideoEditInput", // Contains InputSource
         "WebSearchPreview",
         "AudioInput",
         "FileInput",
@@ -96,14 +114,91 @@ fn get_schema_value_type(ty: &Type) -> Option<&'static str> {
     None
 }
 
-/// Returns true if `attrs` already contains a `#[schema(value_type = ...)]`.
+/// The derive inserted on structs and enums, only compiled with the `utoipa` feature.
+const UTOIPA_DERIVE: &str = r#"#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]"#;
+
+/// The derive inserted with `--schemars`, only compiled with the `schema` feature.
+const SCHEMARS_DERIVE: &str = r#"#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]"#;
+
+/// The schema derive the script adds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Utoipa,
+    Schemars,
+}
+
+impl Mode {
+    fn from_args() -> Result<Self> {
+        let mut mode = Mode::Utoipa;
+        for arg in std::env::args().skip(1) {
+            match arg.as_str() {
+                "--schemars" => mode = Mode::Schemars,
+                _ => anyhow::bail!(
+                    "Unknown argument: {arg}\nUsage: add-utoipa-annotations [--schemars]"
+                ),
+            }
+        }
+        Ok(mode)
+    }
+
+    fn derive(self) -> &'static str {
+        match self {
+            Mode::Utoipa => UTOIPA_DERIVE,
+            Mode::Schemars => SCHEMARS_DERIVE,
+        }
+    }
+
+    /// Whether `attrs` already derive the trait of this mode.
+    fn has_derive(self, attrs: &[Attribute]) -> bool {
+        match self {
+            Mode::Utoipa => has_utoipa_derive(attrs),
+            Mode::Schemars => has_derive_of(attrs, "schemars::JsonSchema"),
+        }
+    }
+}
+
+/// The `schema(value_type = ...)` attribute for `value_type`, gated like [UTOIPA_DERIVE].
+fn schema_value_type_attr(value_type: &str) -> String {
+    format!(r#"#[cfg_attr(feature = "utoipa", schema(value_type = {value_type}))]"#)
+}
+
+/// The `schema(bound = ...)` attribute requiring `ToSchema` for every type parameter of
+/// `generics`, e.g. `T: utoipa::ToSchema`, or `None` for types without type parameters.
+fn schema_bound_attr(generics: &syn::Generics) -> Option<String> {
+    let bounds: Vec<String> = generics
+        .type_params()
+        .map(|param| format!("{}: utoipa::ToSchema", param.ident))
+        .collect();
+    if bounds.is_empty() {
+        return None;
+    }
+    Some(format!(
+        r#"#[cfg_attr(feature = "utoipa", schema(bound = "{}"))]"#,
+        bounds.join(", ")
+    ))
+}
+
+/// Returns true if `attrs` already contains a `schema(bound = ...)`, gated or not.
+fn has_schema_bound_attr(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        let tokens = quote::quote!(#attr).to_string().replace(' ', "");
+        (attr.path().is_ident("schema") && tokens.contains("bound="))
+            || (attr.path().is_ident("cfg_attr") && tokens.contains("schema(bound="))
+    })
+}
+
+/// Returns true if `attrs` already contains a `#[schema(value_type = ...)]`, or the same
+/// attribute gated with `#[cfg_attr(feature = "utoipa", schema(value_type = ...))]`.
 fn has_schema_value_type_attr(attrs: &[Attribute]) -> bool {
     attrs.iter().any(|attr| {
-        if !attr.path().is_ident("schema") {
-            return false;
-        }
         let tokens = quote::quote!(#attr).to_string().replace(' ', "");
-        tokens.contains("value_type=")
+        if attr.path().is_ident("schema") {
+            tokens.contains("value_type=")
+        } else if attr.path().is_ident("cfg_attr") {
+            tokens.contains("schema(value_type=")
+        } else {
+            false
+        }
     })
 }
 
@@ -117,7 +212,10 @@ impl FieldTypeChecker {
     fn new() -> Self {
         Self {
             has_problematic_type: false,
-            problematic_types: get_problematic_types().iter().map(|s| s.to_string()).collect(),
+            problematic_types: get_problematic_types()
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
         }
     }
 
@@ -142,19 +240,42 @@ impl<'ast> Visit<'ast> for FieldTypeChecker {
     }
 }
 
-/// Check if derive attributes already contain utoipa::ToSchema
+/// Check if derive attributes already contain utoipa::ToSchema, either in a plain
+/// `#[derive(...)]` or in a gated `#[cfg_attr(feature = "utoipa", derive(...))]`
 fn has_utoipa_derive(attrs: &[Attribute]) -> bool {
-    for attr in attrs {
+    has_derive_of(attrs, "utoipa::ToSchema")
+}
+
+/// Check if `attrs` derive `Serialize` or `Deserialize`, i.e. the type is part of the API
+/// rather than a client side helper.
+fn has_serde_derive(attrs: &[Attribute]) -> bool {
+    has_derive_of(attrs, "Serialize") || has_derive_of(attrs, "Deserialize")
+}
+
+/// Check if derive attributes, plain or gated with `cfg_attr`, already contain `path`.
+fn has_derive_of(attrs: &[Attribute], path: &str) -> bool {
+    attrs.iter().any(|attr| {
+        let Meta::List(ref meta_list) = attr.meta else {
+            return false;
+        };
+        let tokens = meta_list.tokens.to_string().replace(' ', "");
         if attr.path().is_ident("derive") {
-            if let Meta::List(ref meta_list) = attr.meta {
-                let tokens = meta_list.tokens.to_string();
-                if tokens.contains("utoipa :: ToSchema") || tokens.contains("utoipa::ToSchema") {
-                    return true;
-                }
-            }
+            tokens.contains(path)
+        } else if attr.path().is_ident("cfg_attr") {
+            tokens.contains("derive(") && tokens.contains(path)
+        } else {
+            false
         }
-    }
-    false
+    })
+}
+
+/// Returns the end line (1-indexed) of the attribute deriving utoipa::ToSchema, if any.
+fn get_utoipa_derive_end_line(attrs: &[Attribute]) -> Option<usize> {
+    attrs
+        .iter()
+        .filter(|attr| has_utoipa_derive(std::slice::from_ref(*attr)))
+        .map(|attr| attr.span().end().line)
+        .max()
 }
 
 /// Returns the end line (1-indexed) of the last `#[derive(...)]` attribute, or 0 if none.
@@ -219,14 +340,16 @@ fn apply_insertions(content: &str, mut insertions: Vec<Insertion>) -> String {
 /// Visitor that collects needed insertions without modifying the AST.
 struct InsertionCollector<'src> {
     content: &'src str,
+    mode: Mode,
     insertions: Vec<Insertion>,
     skip_list: HashSet<&'static str>,
 }
 
 impl<'src> InsertionCollector<'src> {
-    fn new(content: &'src str) -> Self {
+    fn new(content: &'src str, mode: Mode) -> Self {
         Self {
             content,
+            mode,
             insertions: Vec::new(),
             skip_list: get_skip_list(),
         }
@@ -237,9 +360,15 @@ impl<'src> InsertionCollector<'src> {
         if self.skip_list.contains(name.as_str()) {
             return false;
         }
-        if has_utoipa_derive(&item.attrs) {
+        if self.mode.has_derive(&item.attrs) {
+            return false;
+        }
+        if !has_serde_derive(&item.attrs) {
             return false;
         }
+        if self.mode == Mode::Schemars && has_utoipa_derive(&item.attrs) {
+            return true;
+        }
         if FieldTypeChecker::check_fields(&item.fields) {
             return false;
         }
@@ -251,9 +380,15 @@ impl<'src> InsertionCollector<'src> {
         if self.skip_list.contains(name.as_str()) {
             return false;
         }
-        if has_utoipa_derive(&item.attrs) {
+        if self.mode.has_derive(&item.attrs) {
+            return false;
+        }
+        if !has_serde_derive(&item.attrs) {
             return false;
         }
+        if self.mode == Mode::Schemars && has_utoipa_derive(&item.attrs) {
+            return true;
+        }
         for variant in &item.variants {
             if FieldTypeChecker::check_fields(&variant.fields) {
                 return false;
@@ -262,17 +397,49 @@ impl<'src> InsertionCollector<'src> {
         true
     }
 
-    fn add_derive_insertion(&mut self, attrs: &[Attribute], keyword_line: usize) {
+    /// Inserts the derive, followed by the `schema(bound = ...)` of generic types so that the
+    /// helper attribute comes after the derive introducing it.
+    fn add_derive_insertion(
+        &mut self,
+        attrs: &[Attribute],
+        generics: &syn::Generics,
+        keyword_line: usize,
+    ) {
         let last_derive_line = get_last_derive_end_line(attrs);
         let before_line = if last_derive_line > 0 {
             last_derive_line + 1
         } else {
             keyword_line
         };
+        let mut attr_text = self.mode.derive().to_string();
+        if self.mode == Mode::Utoipa {
+            if let Some(bound) = schema_bound_attr(generics) {
+                attr_text = format!("{attr_text}\n{bound}");
+            }
+        }
         self.insertions.push(Insertion {
             before_line,
             indent: String::new(),
-            attr_text: "#[derive(utoipa::ToSchema)]".to_string(),
+            attr_text,
+        });
+    }
+
+    /// Inserts the `schema(bound = ...)` of a generic type that already derives ToSchema
+    /// without it, right after that derive.
+    fn add_bound_insertion(&mut self, attrs: &[Attribute], generics: &syn::Generics) {
+        if has_schema_bound_attr(attrs) {
+            return;
+        }
+        let (Some(derive_line), Some(bound)) = (
+            get_utoipa_derive_end_line(attrs),
+            schema_bound_attr(generics),
+        ) else {
+            return;
+        };
+        self.insertions.push(Insertion {
+            before_line: derive_line + 1,
+            indent: get_leading_whitespace(self.content, derive_line),
+            attr_text: bound,
         });
     }
 
@@ -291,7 +458,7 @@ impl<'src> InsertionCollector<'src> {
             self.insertions.push(Insertion {
                 before_line: line_no,
                 indent,
-                attr_text: format!("#[schema(value_type = {value_type})]"),
+                attr_text: schema_value_type_attr(value_type),
             });
         }
     }
@@ -309,7 +476,7 @@ impl<'src> InsertionCollector<'src> {
                     self.insertions.push(Insertion {
                         before_line: line_no,
                         indent,
-                        attr_text: format!("#[schema(value_type = {value_type})]"),
+                        attr_text: schema_value_type_attr(value_type),
                     });
                 }
             }
@@ -319,9 +486,21 @@ impl<'src> InsertionCollector<'src> {
 
 impl<'src, 'ast> Visit<'ast> for InsertionCollector<'src> {
     fn visit_item_struct(&mut self, item: &'ast syn::ItemStruct) {
-        if self.should_add_derive_to_struct(item) {
+        let derives = if self.should_add_derive_to_struct(item) {
             let kw_line = item.struct_token.span().start().line;
-            self.add_derive_insertion(&item.attrs, kw_line);
+            self.add_derive_insertion(&item.attrs, &item.generics, kw_line);
+            true
+        } else if has_utoipa_derive(&item.attrs) {
+            if self.mode == Mode::Utoipa {
+                self.add_bound_insertion(&item.attrs, &item.generics);
+            }
+            true
+        } else {
+            false
+        };
+        // fields of types without ToSchema need no `value_type`
+        if self.mode != Mode::Utoipa || !derives {
+            return;
         }
         if let syn::Fields::Named(ref named) = item.fields {
             for field in &named.named {
@@ -331,9 +510,20 @@ impl<'src, 'ast> Visit<'ast> for InsertionCollector<'src> {
     }
 
     fn visit_item_enum(&mut self, item: &'ast syn::ItemEnum) {
-        if self.should_add_derive_to_enum(item) {
+        let derives = if self.should_add_derive_to_enum(item) {
             let kw_line = item.enum_token.span().start().line;
-            self.add_derive_insertion(&item.attrs, kw_line);
+            self.add_derive_insertion(&item.attrs, &item.generics, kw_line);
+            true
+        } else if has_utoipa_derive(&item.attrs) {
+            if self.mode == Mode::Utoipa {
+                self.add_bound_insertion(&item.attrs, &item.generics);
+            }
+            true
+        } else {
+            false
+        };
+        if self.mode != Mode::Utoipa || !derives {
+            return;
         }
         for variant in &item.variants {
             // Named fields inside a variant: annotation on the field
@@ -369,7 +559,7 @@ fn get_special_case_insertions(path: &Path, content: &str) -> Vec<Insertion> {
         SpecialCase {
             file_name: "filter.rs",
             field_line: "pub filters: Vec<Filter>,",
-            annotation: "#[schema(value_type = Vec<Object>)]",
+            annotation: r#"#[cfg_attr(feature = "utoipa", schema(value_type = Vec<Object>))]"#,
         },
     ];
 
@@ -407,7 +597,7 @@ fn get_special_case_insertions(path: &Path, content: &str) -> Vec<Insertion> {
 }
 
 /// Process a single Rust file: collect insertions and apply them to the original text.
-fn process_file(path: &Path) -> Result<bool> {
+fn process_file(path: &Path, mode: Mode) -> Result<bool> {
     let content = std::fs::read_to_string(path)
         .with_context(|| format!("Failed to read file: {}", path.display()))?;
 
@@ -419,11 +609,13 @@ fn process_file(path: &Path) -> Result<bool> {
         }
     };
 
-    let mut collector = InsertionCollector::new(&content);
+    let mut collector = InsertionCollector::new(&content, mode);
     collector.visit_file(&syntax_tree);
 
     let mut insertions = collector.insertions;
-    insertions.extend(get_special_case_insertions(path, &content));
+    if mode == Mode::Utoipa {
+        insertions.extend(get_special_case_insertions(path, &content));
+    }
 
     if insertions.is_empty() {
         return Ok(false);
@@ -436,7 +628,7 @@ fn process_file(path: &Path) -> Result<bool> {
 }
 
 /// Process all files in the types directory
-fn process_types_directory() -> Result<()> {
+fn process_types_directory(mode: Mode) -> Result<()> {
     let types_dir = PathBuf::from("async-openai/src/types");
 
     if !types_dir.exists() {
@@ -463,7 +655,7 @@ fn process_types_directory() -> Result<()> {
         total_files += 1;
         print!("Processing {}...", relative_path.display());
 
-        match process_file(path) {
+        match process_file(path, mode) {
             Ok(true) => {
                 files_modified += 1;
                 println!(" ✅ Modified");
@@ -486,7 +678,7 @@ fn process_types_directory() -> Result<()> {
 }
 
 /// Process additional files outside of `src/types/` listed in `get_extra_files()`.
-fn process_extra_files() -> Result<()> {
+fn process_extra_files(mode: Mode) -> Result<()> {
     let extra_files = get_extra_files();
     if extra_files.is_empty() {
         return Ok(());
@@ -502,7 +694,7 @@ fn process_extra_files() -> Result<()> {
         }
 
         print!("Processing {}...", file);
-        match process_file(&path) {
+        match process_file(&path, mode) {
             Ok(true) => println!(" ✅ Modified"),
             Ok(false) => println!(" ⏭️  No changes needed"),
             Err(e) => println!(" ❌ Failed: {}", e),
@@ -513,7 +705,11 @@ fn process_extra_files() -> Result<()> {
 }
 
 fn main() -> Result<()> {
-    println!("🚀 async-openai utoipa Annotation Script (Rust version)");
+    let mode = Mode::from_args()?;
+    match mode {
+        Mode::Utoipa => println!("🚀 async-openai utoipa Annotation Script (Rust version)"),
+        Mode::Schemars => println!("🚀 async-openai schemars Annotation Script (Rust version)"),
+    }
     println!("{}", "=".repeat(60));
 
     if !Path::new("async-openai/Cargo.toml").exists() {
@@ -524,11 +720,17 @@ fn main() -> Result<()> {
         );
     }
 
-    process_types_directory()?;
-    process_extra_files()?;
+    process_types_directory(mode)?;
+    process_extra_files(mode)?;
 
     println!("\n✅ Annotation script completed successfully!");
     println!("\n📝 Next steps:");
+    if mode == Mode::Schemars {
+        println!("   1. cargo fmt");
+        println!("   2. cargo check -p async-openai --features full,schema");
+        println!("   3. git diff");
+        return Ok(());
+    }
     println!("   1. cargo fmt");
     println!("   2. cargo run --package xtask openapi   # regenerate openapi.json");
     println!("      OR: make build.ts-client            # regenerate + TypeScript types");